@@ -0,0 +1,118 @@
+-- Soft delete for authors and publications.
+--
+-- `DELETE /authors/{id}` and `DELETE /publications/{id}` now set `deleted_at`
+-- (and record the deleting user in `modifier`) instead of removing the row, so
+-- audit history and links from archived exports survive. `POST .../restore`
+-- clears it again. A row is deleted iff `deleted_at IS NOT NULL`.
+--
+-- The statistics views are recreated to ignore soft-deleted rows, so counts on
+-- the web pages match what the API returns by default.
+
+ALTER TABLE authors ADD COLUMN deleted_at TIMESTAMPTZ;
+ALTER TABLE publications ADD COLUMN deleted_at TIMESTAMPTZ;
+
+COMMENT ON COLUMN authors.deleted_at IS 'Soft-delete timestamp; NULL for live rows';
+COMMENT ON COLUMN publications.deleted_at IS 'Soft-delete timestamp; NULL for live rows';
+
+CREATE INDEX idx_authors_deleted_at ON authors(deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX idx_publications_deleted_at ON publications(deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- author_stats: skip deleted authors and deleted publications
+DROP MATERIALIZED VIEW IF EXISTS author_stats;
+
+CREATE MATERIALIZED VIEW author_stats AS
+SELECT
+    a.id,
+    a.full_name,
+    a.family_name,
+    COUNT(DISTINCT p.id) AS publication_count,
+    COUNT(DISTINCT cr.id) AS committee_role_count,
+    COUNT(DISTINCT CASE
+        WHEN cr."position" = ANY (ARRAY['chair'::committee_position, 'co_chair'::committee_position])
+        THEN cr.id ELSE NULL::uuid END) AS leadership_count,
+    ARRAY_AGG(DISTINCT c.venue ORDER BY c.venue) FILTER (WHERE c.venue IS NOT NULL) AS venues,
+    MIN(c.year) AS first_year,
+    MAX(c.year) AS last_year,
+    COALESCE(
+        (SELECT app.affiliation
+         FROM (
+             SELECT au2.affiliation, c2.year
+             FROM authorships au2
+             JOIN publications p2 ON au2.publication_id = p2.id AND p2.deleted_at IS NULL
+             JOIN conferences c2 ON p2.conference_id = c2.id
+             WHERE au2.author_id = a.id
+               AND au2.affiliation IS NOT NULL AND au2.affiliation <> ''
+             UNION ALL
+             SELECT cr2.affiliation, c2.year
+             FROM committee_roles cr2
+             JOIN conferences c2 ON cr2.conference_id = c2.id
+             WHERE cr2.author_id = a.id
+               AND cr2.affiliation IS NOT NULL AND cr2.affiliation <> ''
+         ) app
+         ORDER BY app.year DESC NULLS LAST
+         LIMIT 1),
+        a.affiliation
+    ) AS recent_affiliation
+FROM authors a
+LEFT JOIN authorships au ON a.id = au.author_id
+LEFT JOIN publications p ON au.publication_id = p.id AND p.deleted_at IS NULL
+LEFT JOIN committee_roles cr ON a.id = cr.author_id
+LEFT JOIN conferences c ON p.conference_id = c.id OR cr.conference_id = c.id
+WHERE a.deleted_at IS NULL
+GROUP BY a.id, a.full_name, a.family_name, a.affiliation;
+
+CREATE UNIQUE INDEX idx_author_stats_id ON author_stats(id);
+
+-- conference_stats: skip deleted publications and deleted authors
+DROP MATERIALIZED VIEW IF EXISTS conference_stats;
+
+CREATE MATERIALIZED VIEW conference_stats AS
+SELECT
+    c.id,
+    c.venue,
+    c.year,
+    COUNT(DISTINCT p.id) as publication_count,
+    COUNT(DISTINCT CASE WHEN p.paper_type = 'regular' THEN p.id END) as regular_paper_count,
+    COUNT(DISTINCT CASE WHEN p.paper_type = 'invited' THEN p.id END) as invited_talk_count,
+    COUNT(DISTINCT CASE WHEN p.award IS NOT NULL THEN p.id END) as award_count,
+    COUNT(DISTINCT cr.id) as committee_member_count,
+    COUNT(DISTINCT a.id) as unique_author_count,
+    c.submission_count,
+    c.acceptance_count,
+    CASE
+        WHEN c.submission_count > 0 AND c.acceptance_count IS NOT NULL
+        THEN ROUND((c.acceptance_count::numeric / c.submission_count::numeric) * 100, 1)
+        ELSE NULL
+    END as acceptance_rate
+FROM conferences c
+LEFT JOIN publications p ON c.id = p.conference_id AND p.deleted_at IS NULL
+LEFT JOIN committee_roles cr ON c.id = cr.conference_id
+LEFT JOIN authorships au ON p.id = au.publication_id
+LEFT JOIN authors a ON au.author_id = a.id AND a.deleted_at IS NULL
+GROUP BY c.id, c.venue, c.year, c.submission_count, c.acceptance_count;
+
+CREATE UNIQUE INDEX idx_conference_stats_id ON conference_stats(id);
+
+-- coauthor_pairs: only live publications and authors
+DROP MATERIALIZED VIEW IF EXISTS coauthor_pairs;
+
+CREATE MATERIALIZED VIEW coauthor_pairs AS
+SELECT
+    a1.author_id as author1_id,
+    a2.author_id as author2_id,
+    COUNT(DISTINCT a1.publication_id) as collaboration_count
+FROM authorships a1
+JOIN authorships a2 ON a1.publication_id = a2.publication_id
+    AND a1.author_id < a2.author_id  -- Avoid duplicates and self-pairs
+JOIN publications p ON a1.publication_id = p.id AND p.deleted_at IS NULL
+JOIN authors au1 ON a1.author_id = au1.id AND au1.deleted_at IS NULL
+JOIN authors au2 ON a2.author_id = au2.id AND au2.deleted_at IS NULL
+GROUP BY a1.author_id, a2.author_id;
+
+CREATE INDEX idx_coauthor_pairs_author1 ON coauthor_pairs(author1_id);
+CREATE INDEX idx_coauthor_pairs_author2 ON coauthor_pairs(author2_id);
+CREATE UNIQUE INDEX idx_coauthor_pairs_unique ON coauthor_pairs(author1_id, author2_id);
+
+COMMENT ON MATERIALIZED VIEW author_stats IS 'Pre-computed author statistics (live rows only) - refresh after bulk updates';
+COMMENT ON MATERIALIZED VIEW conference_stats IS 'Pre-computed conference statistics (live rows only) - refresh after bulk updates';
+COMMENT ON MATERIALIZED VIEW coauthor_pairs IS 'Coauthor collaboration counts (live rows only) - refresh after bulk updates';
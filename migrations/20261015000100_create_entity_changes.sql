@@ -0,0 +1,24 @@
+-- Audit trail of changes to authors and conferences.
+--
+-- One row per create/update/delete/restore, written in the same transaction as
+-- the change itself. `diff` maps each changed field to `{"old": ..., "new": ...}`
+-- (creates have `old` = null, hard deletes have `new` = null). Rows are kept
+-- after the entity is removed, so there is deliberately no foreign key.
+
+CREATE TABLE entity_changes (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    entity_type         TEXT NOT NULL,        -- 'author' | 'conference'
+    entity_id           UUID NOT NULL,
+    action              TEXT NOT NULL,        -- 'create' | 'update' | 'delete' | 'restore'
+    modifier            TEXT,                 -- Who made the change (from the request)
+    changed_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    diff                JSONB NOT NULL DEFAULT '{}'::jsonb,
+
+    CONSTRAINT valid_entity_type CHECK (entity_type IN ('author', 'conference')),
+    CONSTRAINT valid_action CHECK (action IN ('create', 'update', 'delete', 'restore'))
+);
+
+CREATE INDEX idx_entity_changes_entity ON entity_changes(entity_type, entity_id, changed_at DESC);
+
+COMMENT ON TABLE entity_changes IS 'Audit trail: one row per change to an author or conference';
+COMMENT ON COLUMN entity_changes.diff IS 'Changed fields as {"field": {"old": ..., "new": ...}}';
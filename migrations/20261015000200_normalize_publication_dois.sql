@@ -0,0 +1,11 @@
+-- Canonicalize existing publication DOIs to the form the API now stores:
+-- resolver URL / `doi:` prefix stripped, lowercased (see src/utils/doi.rs).
+-- Values that still don't look like a DOI afterwards are left untouched for
+-- manual review.
+
+UPDATE publications
+SET doi = lower(regexp_replace(trim(doi), '^(https?://(dx\.)?doi\.org/|doi\.org/|doi:\s*)', '', 'i')),
+    updated_at = NOW()
+WHERE doi IS NOT NULL
+  AND lower(regexp_replace(trim(doi), '^(https?://(dx\.)?doi\.org/|doi\.org/|doi:\s*)', '', 'i')) ~ '^10\.[0-9]+(\.[0-9]+)*/\S+$'
+  AND doi <> lower(regexp_replace(trim(doi), '^(https?://(dx\.)?doi\.org/|doi\.org/|doi:\s*)', '', 'i'));
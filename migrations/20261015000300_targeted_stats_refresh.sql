@@ -0,0 +1,162 @@
+-- Targeted statistics refresh.
+--
+-- A materialized view can only be refreshed as a whole, which became the
+-- bottleneck of the ingest loop once the database grew: adding one talk meant
+-- recomputing every author. The three stats views become plain summary tables
+-- with the same names and columns, filled from regular `*_source` views that
+-- hold the old definitions. Each table gets a `refresh_*` function:
+--
+--   SELECT refresh_author_stats();                 -- everything
+--   SELECT refresh_author_stats(ARRAY[...]::uuid[]); -- just these authors
+--
+-- A refresh deletes and re-inserts the affected rows in one transaction, so
+-- readers keep seeing the previous rows until it commits (the same guarantee
+-- REFRESH ... CONCURRENTLY gave). Columns stay nullable, as they were on the
+-- views, so queries reading the stats see unchanged types.
+--
+-- Targeted refreshes only touch the rows asked for. An author removed from a
+-- conference is not reached by refreshing that conference; run a full refresh
+-- after merges or bulk deletes.
+
+-- Sources: the definitions from 20261015000000_soft_delete_authors_publications.sql
+
+CREATE VIEW author_stats_source AS
+SELECT
+    a.id,
+    a.full_name,
+    a.family_name,
+    COUNT(DISTINCT p.id) AS publication_count,
+    COUNT(DISTINCT cr.id) AS committee_role_count,
+    COUNT(DISTINCT CASE
+        WHEN cr."position" = ANY (ARRAY['chair'::committee_position, 'co_chair'::committee_position])
+        THEN cr.id ELSE NULL::uuid END) AS leadership_count,
+    ARRAY_AGG(DISTINCT c.venue ORDER BY c.venue) FILTER (WHERE c.venue IS NOT NULL) AS venues,
+    MIN(c.year) AS first_year,
+    MAX(c.year) AS last_year,
+    COALESCE(
+        (SELECT app.affiliation
+         FROM (
+             SELECT au2.affiliation, c2.year
+             FROM authorships au2
+             JOIN publications p2 ON au2.publication_id = p2.id AND p2.deleted_at IS NULL
+             JOIN conferences c2 ON p2.conference_id = c2.id
+             WHERE au2.author_id = a.id
+               AND au2.affiliation IS NOT NULL AND au2.affiliation <> ''
+             UNION ALL
+             SELECT cr2.affiliation, c2.year
+             FROM committee_roles cr2
+             JOIN conferences c2 ON cr2.conference_id = c2.id
+             WHERE cr2.author_id = a.id
+               AND cr2.affiliation IS NOT NULL AND cr2.affiliation <> ''
+         ) app
+         ORDER BY app.year DESC NULLS LAST
+         LIMIT 1),
+        a.affiliation
+    ) AS recent_affiliation
+FROM authors a
+LEFT JOIN authorships au ON a.id = au.author_id
+LEFT JOIN publications p ON au.publication_id = p.id AND p.deleted_at IS NULL
+LEFT JOIN committee_roles cr ON a.id = cr.author_id
+LEFT JOIN conferences c ON p.conference_id = c.id OR cr.conference_id = c.id
+WHERE a.deleted_at IS NULL
+GROUP BY a.id, a.full_name, a.family_name, a.affiliation;
+
+CREATE VIEW conference_stats_source AS
+SELECT
+    c.id,
+    c.venue,
+    c.year,
+    COUNT(DISTINCT p.id) as publication_count,
+    COUNT(DISTINCT CASE WHEN p.paper_type = 'regular' THEN p.id END) as regular_paper_count,
+    COUNT(DISTINCT CASE WHEN p.paper_type = 'invited' THEN p.id END) as invited_talk_count,
+    COUNT(DISTINCT CASE WHEN p.award IS NOT NULL THEN p.id END) as award_count,
+    COUNT(DISTINCT cr.id) as committee_member_count,
+    COUNT(DISTINCT a.id) as unique_author_count,
+    c.submission_count,
+    c.acceptance_count,
+    CASE
+        WHEN c.submission_count > 0 AND c.acceptance_count IS NOT NULL
+        THEN ROUND((c.acceptance_count::numeric / c.submission_count::numeric) * 100, 1)
+        ELSE NULL
+    END as acceptance_rate
+FROM conferences c
+LEFT JOIN publications p ON c.id = p.conference_id AND p.deleted_at IS NULL
+LEFT JOIN committee_roles cr ON c.id = cr.conference_id
+LEFT JOIN authorships au ON p.id = au.publication_id
+LEFT JOIN authors a ON au.author_id = a.id AND a.deleted_at IS NULL
+GROUP BY c.id, c.venue, c.year, c.submission_count, c.acceptance_count;
+
+CREATE VIEW coauthor_pairs_source AS
+SELECT
+    a1.author_id as author1_id,
+    a2.author_id as author2_id,
+    COUNT(DISTINCT a1.publication_id) as collaboration_count
+FROM authorships a1
+JOIN authorships a2 ON a1.publication_id = a2.publication_id
+    AND a1.author_id < a2.author_id  -- Avoid duplicates and self-pairs
+JOIN publications p ON a1.publication_id = p.id AND p.deleted_at IS NULL
+JOIN authors au1 ON a1.author_id = au1.id AND au1.deleted_at IS NULL
+JOIN authors au2 ON a2.author_id = au2.id AND au2.deleted_at IS NULL
+GROUP BY a1.author_id, a2.author_id;
+
+-- Summary tables replacing the materialized views
+
+DROP MATERIALIZED VIEW author_stats;
+CREATE TABLE author_stats AS SELECT * FROM author_stats_source;
+CREATE UNIQUE INDEX idx_author_stats_id ON author_stats(id);
+
+DROP MATERIALIZED VIEW conference_stats;
+CREATE TABLE conference_stats AS SELECT * FROM conference_stats_source;
+CREATE UNIQUE INDEX idx_conference_stats_id ON conference_stats(id);
+
+DROP MATERIALIZED VIEW coauthor_pairs;
+CREATE TABLE coauthor_pairs AS SELECT * FROM coauthor_pairs_source;
+CREATE INDEX idx_coauthor_pairs_author1 ON coauthor_pairs(author1_id);
+CREATE INDEX idx_coauthor_pairs_author2 ON coauthor_pairs(author2_id);
+CREATE UNIQUE INDEX idx_coauthor_pairs_unique ON coauthor_pairs(author1_id, author2_id);
+
+-- Refresh functions; NULL (the default) means every row
+
+CREATE FUNCTION refresh_author_stats(author_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    IF author_ids IS NULL THEN
+        DELETE FROM author_stats;
+        INSERT INTO author_stats SELECT * FROM author_stats_source;
+    ELSE
+        DELETE FROM author_stats WHERE id = ANY(author_ids);
+        INSERT INTO author_stats SELECT * FROM author_stats_source WHERE id = ANY(author_ids);
+    END IF;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION refresh_conference_stats(conference_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    IF conference_ids IS NULL THEN
+        DELETE FROM conference_stats;
+        INSERT INTO conference_stats SELECT * FROM conference_stats_source;
+    ELSE
+        DELETE FROM conference_stats WHERE id = ANY(conference_ids);
+        INSERT INTO conference_stats SELECT * FROM conference_stats_source WHERE id = ANY(conference_ids);
+    END IF;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Targeted: every pair involving one of the given authors
+CREATE FUNCTION refresh_coauthor_pairs(author_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    IF author_ids IS NULL THEN
+        DELETE FROM coauthor_pairs;
+        INSERT INTO coauthor_pairs SELECT * FROM coauthor_pairs_source;
+    ELSE
+        DELETE FROM coauthor_pairs
+        WHERE author1_id = ANY(author_ids) OR author2_id = ANY(author_ids);
+        INSERT INTO coauthor_pairs
+        SELECT * FROM coauthor_pairs_source
+        WHERE author1_id = ANY(author_ids) OR author2_id = ANY(author_ids);
+    END IF;
+END;
+$$ LANGUAGE plpgsql;
+
+COMMENT ON TABLE author_stats IS 'Pre-computed author statistics (live rows only) - SELECT refresh_author_stats() after bulk updates';
+COMMENT ON TABLE conference_stats IS 'Pre-computed conference statistics (live rows only) - SELECT refresh_conference_stats() after bulk updates';
+COMMENT ON TABLE coauthor_pairs IS 'Coauthor collaboration counts (live rows only) - SELECT refresh_coauthor_pairs() after bulk updates';
@@ -0,0 +1,57 @@
+-- Record when each stats table was last recomputed.
+--
+-- Pages that show stats derive their ETag partly from these timestamps, so a
+-- refresh invalidates cached copies. Targeted refreshes bump the timestamp too:
+-- it only says "something in the table may have changed".
+
+CREATE TABLE stats_refreshes (
+    stats_table         TEXT PRIMARY KEY,
+    refreshed_at        TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO stats_refreshes (stats_table)
+VALUES ('author_stats'), ('conference_stats'), ('coauthor_pairs');
+
+COMMENT ON TABLE stats_refreshes IS 'Last refresh_* run per stats table (full or targeted)';
+
+CREATE OR REPLACE FUNCTION refresh_author_stats(author_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    IF author_ids IS NULL THEN
+        DELETE FROM author_stats;
+        INSERT INTO author_stats SELECT * FROM author_stats_source;
+    ELSE
+        DELETE FROM author_stats WHERE id = ANY(author_ids);
+        INSERT INTO author_stats SELECT * FROM author_stats_source WHERE id = ANY(author_ids);
+    END IF;
+    UPDATE stats_refreshes SET refreshed_at = NOW() WHERE stats_table = 'author_stats';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION refresh_conference_stats(conference_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    IF conference_ids IS NULL THEN
+        DELETE FROM conference_stats;
+        INSERT INTO conference_stats SELECT * FROM conference_stats_source;
+    ELSE
+        DELETE FROM conference_stats WHERE id = ANY(conference_ids);
+        INSERT INTO conference_stats SELECT * FROM conference_stats_source WHERE id = ANY(conference_ids);
+    END IF;
+    UPDATE stats_refreshes SET refreshed_at = NOW() WHERE stats_table = 'conference_stats';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION refresh_coauthor_pairs(author_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    IF author_ids IS NULL THEN
+        DELETE FROM coauthor_pairs;
+        INSERT INTO coauthor_pairs SELECT * FROM coauthor_pairs_source;
+    ELSE
+        DELETE FROM coauthor_pairs
+        WHERE author1_id = ANY(author_ids) OR author2_id = ANY(author_ids);
+        INSERT INTO coauthor_pairs
+        SELECT * FROM coauthor_pairs_source
+        WHERE author1_id = ANY(author_ids) OR author2_id = ANY(author_ids);
+    END IF;
+    UPDATE stats_refreshes SET refreshed_at = NOW() WHERE stats_table = 'coauthor_pairs';
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,34 @@
+-- Canonical institutions for affiliation analytics.
+--
+-- Affiliations stay free text on authors, authorships and committee roles;
+-- this table is the curated list they are matched against (by name, alias or
+-- acronym, see utils::affiliation). Authors get a nullable link that the API
+-- fills in on create/update when their affiliation matches, and that new
+-- institutions backfill for authors not linked yet.
+
+CREATE TABLE institutions (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name                TEXT NOT NULL,        -- Canonical name: "Massachusetts Institute of Technology"
+    aliases             TEXT[] NOT NULL DEFAULT '{}',  -- Other spellings: {"MIT"}
+    ror_id              TEXT,                 -- Research Organization Registry id, bare: 042nb2s44
+    country_code        CHAR(2),              -- ISO 3166-1 alpha-2 code
+
+    -- Audit fields
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    creator             TEXT NOT NULL,
+    modifier            TEXT NOT NULL,
+
+    CONSTRAINT unique_institution_name UNIQUE (name),
+    CONSTRAINT valid_ror_id CHECK (ror_id IS NULL OR ror_id ~ '^0[0-9a-hjkmnp-z]{6}[0-9]{2}$'),
+    CONSTRAINT valid_institution_country_code CHECK (country_code IS NULL OR country_code ~ '^[A-Z]{2}$')
+);
+
+CREATE UNIQUE INDEX idx_institutions_ror_id ON institutions(ror_id) WHERE ror_id IS NOT NULL;
+
+ALTER TABLE authors ADD COLUMN institution_id UUID REFERENCES institutions(id) ON DELETE SET NULL;
+CREATE INDEX idx_authors_institution_id ON authors(institution_id) WHERE institution_id IS NOT NULL;
+
+COMMENT ON TABLE institutions IS 'Curated institutions that free-text affiliations are matched against';
+COMMENT ON COLUMN institutions.ror_id IS 'ROR id without the https://ror.org/ prefix';
+COMMENT ON COLUMN authors.institution_id IS 'Best-effort match of authors.affiliation to an institution';
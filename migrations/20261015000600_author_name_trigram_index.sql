@@ -0,0 +1,11 @@
+-- Trigram index for fuzzy author search (`GET /authors?fuzzy=true`).
+--
+-- The search filters with `term <% normalized_name` (word similarity) and
+-- ranks by `word_similarity`, both of which this index serves. The plain
+-- `?search=` listing matches only `normalized_name LIKE '%term%'` (with the
+-- term normalized) so that it can use this index too; `full_name`,
+-- `family_name` and `given_name` are not indexed for substring search.
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX idx_authors_normalized_name_trgm ON authors USING GIN (normalized_name gin_trgm_ops);
@@ -0,0 +1,21 @@
+-- Stored responses for POST requests sent with an `Idempotency-Key` header.
+--
+-- A retried request with the same key gets the first response back instead
+-- of creating a second row (see middleware::idempotency). Keys are scoped to
+-- the caller's API token and kept for 24 hours; expired rows are pruned by
+-- the middleware itself.
+
+CREATE TABLE idempotency_keys (
+    client              TEXT NOT NULL,        -- md5 of the API token, so tokens aren't stored
+    key                 TEXT NOT NULL,
+    path                TEXT NOT NULL,        -- Path and query the key was first used with
+    request_hash        TEXT NOT NULL,        -- md5 of the request body
+    status              SMALLINT,             -- NULL while the first request is still running
+    content_type        TEXT,
+    body                BYTEA,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (client, key)
+);
+
+CREATE INDEX idx_idempotency_keys_created_at ON idempotency_keys(created_at);
@@ -0,0 +1,27 @@
+-- Author-disambiguation review queue.
+--
+-- Pairs of authors whose names are close enough to be the same person.
+-- `POST /review/authors/scan` adds newly detected pairs as `pending`; a
+-- curator's decision is kept here so the pair isn't surfaced again
+-- (`merged`, `kept_separate`) or is moved behind the pending ones
+-- (`deferred`).
+
+CREATE TABLE review_queue (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    author_id           UUID NOT NULL REFERENCES authors(id) ON DELETE CASCADE,
+    other_author_id     UUID NOT NULL REFERENCES authors(id) ON DELETE CASCADE,
+    score               DOUBLE PRECISION NOT NULL,  -- name_similarity when detected
+    status              TEXT NOT NULL DEFAULT 'pending',
+    note                TEXT,
+    resolved_by         TEXT,
+    resolved_at         TIMESTAMPTZ,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- One row per unordered pair
+    CONSTRAINT review_pair_ordered CHECK (author_id < other_author_id),
+    CONSTRAINT unique_review_pair UNIQUE (author_id, other_author_id),
+    CONSTRAINT valid_review_status CHECK (status IN ('pending', 'deferred', 'merged', 'kept_separate'))
+);
+
+CREATE INDEX idx_review_queue_status ON review_queue(status);
+CREATE INDEX idx_review_queue_other_author ON review_queue(other_author_id);
@@ -0,0 +1,17 @@
+-- Word count of each publication's abstract, for charting abstract length over
+-- time (`abstract_word_count` in API responses, `GET /publications?sort=`).
+--
+-- Words are runs of non-whitespace. NULL when there is no abstract, 0 for a
+-- blank one. Generated, so it can't drift from `abstract`.
+
+ALTER TABLE publications ADD COLUMN abstract_word_count INTEGER GENERATED ALWAYS AS (
+    CASE
+        WHEN abstract IS NULL THEN NULL
+        WHEN abstract !~ '\S' THEN 0
+        ELSE array_length(regexp_split_to_array(btrim(abstract, E' \t\r\n'), '\s+'), 1)
+    END
+) STORED;
+
+CREATE INDEX idx_publications_abstract_word_count ON publications(abstract_word_count);
+
+COMMENT ON COLUMN publications.abstract_word_count IS 'Whitespace-separated words in abstract; NULL without an abstract';
@@ -0,0 +1,48 @@
+-- Committee co-membership: the service-network counterpart of coauthor_pairs.
+--
+-- Two authors "served together" when they hold roles on the same committee
+-- (conference + committee type) of a conference. Several roles on one
+-- committee (e.g. member and chair) still count as one shared committee.
+-- Like coauthor_pairs, this is a summary table filled from a *_source view
+-- and kept current with refresh_committee_pairs(uuid[]).
+
+CREATE VIEW committee_pairs_source AS
+SELECT
+    cr1.author_id as author1_id,
+    cr2.author_id as author2_id,
+    COUNT(DISTINCT (cr1.conference_id, cr1.committee)) as served_together_count,
+    MAX(c.year) as last_year
+FROM committee_roles cr1
+JOIN committee_roles cr2 ON cr1.conference_id = cr2.conference_id
+    AND cr1.committee = cr2.committee
+    AND cr1.author_id < cr2.author_id  -- Avoid duplicates and self-pairs
+JOIN conferences c ON cr1.conference_id = c.id
+JOIN authors au1 ON cr1.author_id = au1.id AND au1.deleted_at IS NULL
+JOIN authors au2 ON cr2.author_id = au2.id AND au2.deleted_at IS NULL
+GROUP BY cr1.author_id, cr2.author_id;
+
+CREATE TABLE committee_pairs AS SELECT * FROM committee_pairs_source;
+CREATE INDEX idx_committee_pairs_author1 ON committee_pairs(author1_id);
+CREATE INDEX idx_committee_pairs_author2 ON committee_pairs(author2_id);
+CREATE UNIQUE INDEX idx_committee_pairs_unique ON committee_pairs(author1_id, author2_id);
+
+INSERT INTO stats_refreshes (stats_table) VALUES ('committee_pairs');
+
+-- Targeted: every pair involving one of the given authors
+CREATE FUNCTION refresh_committee_pairs(author_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    IF author_ids IS NULL THEN
+        DELETE FROM committee_pairs;
+        INSERT INTO committee_pairs SELECT * FROM committee_pairs_source;
+    ELSE
+        DELETE FROM committee_pairs
+        WHERE author1_id = ANY(author_ids) OR author2_id = ANY(author_ids);
+        INSERT INTO committee_pairs
+        SELECT * FROM committee_pairs_source
+        WHERE author1_id = ANY(author_ids) OR author2_id = ANY(author_ids);
+    END IF;
+    UPDATE stats_refreshes SET refreshed_at = NOW() WHERE stats_table = 'committee_pairs';
+END;
+$$ LANGUAGE plpgsql;
+
+COMMENT ON TABLE committee_pairs IS 'Committee co-membership counts (live authors only) - SELECT refresh_committee_pairs() after bulk updates';
@@ -0,0 +1,36 @@
+-- One live author per normalized name.
+--
+-- Writers that find-or-create authors by name (the committee scraper, the
+-- importers) did a SELECT and then an INSERT, so two of them racing could
+-- both miss and both insert. With this index they can upsert instead:
+--
+--   INSERT INTO authors (...) VALUES (...)
+--   ON CONFLICT (normalized_name) WHERE deleted_at IS NULL
+--   DO UPDATE SET normalized_name = EXCLUDED.normalized_name
+--   RETURNING id
+--
+-- Soft-deleted authors (including the losing side of a merge) are left out,
+-- so they never block or capture a new author with the same name.
+
+-- Refuse to run over existing duplicates rather than pick a winner here;
+-- merge them first (tools/dedup_authors.py or POST /review/authors/:id/resolve)
+DO $$
+DECLARE
+    duplicate_count INTEGER;
+BEGIN
+    SELECT COUNT(*) INTO duplicate_count
+    FROM (
+        SELECT normalized_name
+        FROM authors
+        WHERE deleted_at IS NULL
+        GROUP BY normalized_name
+        HAVING COUNT(*) > 1
+    ) duplicates;
+    IF duplicate_count > 0 THEN
+        RAISE EXCEPTION 'Cannot add unique index - % normalized names are shared by several live authors', duplicate_count;
+    END IF;
+END $$;
+
+CREATE UNIQUE INDEX idx_authors_normalized_name_live
+    ON authors(normalized_name)
+    WHERE deleted_at IS NULL;
@@ -0,0 +1,16 @@
+-- First-class contribution flags on authorships, replacing ad-hoc keys in
+-- `metadata`: corresponding author, equal contribution (co-first authors, or
+-- any group the paper marks as contributing equally), and a free-text note
+-- such as "equal contribution with the second author".
+
+ALTER TABLE authorships
+    ADD COLUMN is_corresponding      BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN is_equal_contribution BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN contribution_note     TEXT;
+
+-- "This author's co-first papers" looks up a handful of rows per author
+CREATE INDEX idx_authorships_equal_contribution ON authorships(author_id) WHERE is_equal_contribution;
+
+COMMENT ON COLUMN authorships.is_corresponding IS 'Marked as a corresponding author on the paper';
+COMMENT ON COLUMN authorships.is_equal_contribution IS 'Marked as contributing equally (e.g. co-first author)';
+COMMENT ON COLUMN authorships.contribution_note IS 'Free-text contribution statement for this author, if the paper gives one';
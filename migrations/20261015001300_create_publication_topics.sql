@@ -0,0 +1,22 @@
+-- Research-area tags on publications, for topical browsing.
+--
+-- Topics are free-form but normalized by the API (see utils::topic) to
+-- lowercase, hyphen-separated slugs such as "error-correction", so differently
+-- typed spellings of one area land on the same tag.
+
+CREATE TABLE publication_topics (
+    publication_id      UUID NOT NULL REFERENCES publications(id) ON DELETE CASCADE,
+    topic               TEXT NOT NULL,        -- Normalized slug: "error-correction"
+
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    creator             TEXT NOT NULL,
+
+    PRIMARY KEY (publication_id, topic),
+    CONSTRAINT valid_topic CHECK (topic ~ '^[a-z0-9]+(-[a-z0-9]+)*$' AND length(topic) <= 64)
+);
+
+-- Lookups by topic (GET /publications?topic=..., GET /topics)
+CREATE INDEX idx_publication_topics_topic ON publication_topics(topic);
+
+COMMENT ON TABLE publication_topics IS 'Research-area tags on publications';
+COMMENT ON COLUMN publication_topics.topic IS 'Lowercase hyphen-separated slug, e.g. error-correction';
@@ -0,0 +1,9 @@
+-- Prefix index for author autocomplete (`GET /authors/autocomplete`).
+--
+-- `normalized_name LIKE 'ein%'` can't use the default-collation btree or, for
+-- one- and two-character prefixes, the trigram index; `text_pattern_ops`
+-- serves it at keystroke rate. Word-prefix and similarity matches still go
+-- through `idx_authors_normalized_name_trgm`.
+
+CREATE INDEX idx_authors_normalized_name_prefix ON authors(normalized_name text_pattern_ops)
+    WHERE deleted_at IS NULL;
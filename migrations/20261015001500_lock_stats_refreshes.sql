@@ -0,0 +1,52 @@
+-- Serialize stats refreshes.
+--
+-- A refresh deletes and re-inserts rows. Two running at once (the admin
+-- refresh and a write-triggered one) both delete, then both insert, and the
+-- second fails on the table's unique index. SHARE ROW EXCLUSIVE conflicts with
+-- itself but not with readers, so a refresh waits for any running one on the
+-- same table while reads carry on against the last committed rows.
+
+CREATE OR REPLACE FUNCTION refresh_author_stats(author_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    LOCK TABLE author_stats IN SHARE ROW EXCLUSIVE MODE;
+    IF author_ids IS NULL THEN
+        DELETE FROM author_stats;
+        INSERT INTO author_stats SELECT * FROM author_stats_source;
+    ELSE
+        DELETE FROM author_stats WHERE id = ANY(author_ids);
+        INSERT INTO author_stats SELECT * FROM author_stats_source WHERE id = ANY(author_ids);
+    END IF;
+    UPDATE stats_refreshes SET refreshed_at = NOW() WHERE stats_table = 'author_stats';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION refresh_conference_stats(conference_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    LOCK TABLE conference_stats IN SHARE ROW EXCLUSIVE MODE;
+    IF conference_ids IS NULL THEN
+        DELETE FROM conference_stats;
+        INSERT INTO conference_stats SELECT * FROM conference_stats_source;
+    ELSE
+        DELETE FROM conference_stats WHERE id = ANY(conference_ids);
+        INSERT INTO conference_stats SELECT * FROM conference_stats_source WHERE id = ANY(conference_ids);
+    END IF;
+    UPDATE stats_refreshes SET refreshed_at = NOW() WHERE stats_table = 'conference_stats';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION refresh_coauthor_pairs(author_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    LOCK TABLE coauthor_pairs IN SHARE ROW EXCLUSIVE MODE;
+    IF author_ids IS NULL THEN
+        DELETE FROM coauthor_pairs;
+        INSERT INTO coauthor_pairs SELECT * FROM coauthor_pairs_source;
+    ELSE
+        DELETE FROM coauthor_pairs
+        WHERE author1_id = ANY(author_ids) OR author2_id = ANY(author_ids);
+        INSERT INTO coauthor_pairs
+        SELECT * FROM coauthor_pairs_source
+        WHERE author1_id = ANY(author_ids) OR author2_id = ANY(author_ids);
+    END IF;
+    UPDATE stats_refreshes SET refreshed_at = NOW() WHERE stats_table = 'coauthor_pairs';
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,19 @@
+-- Serialize committee_pairs refreshes the same way as the other stats tables
+-- (see 20261015001500_lock_stats_refreshes.sql).
+
+CREATE OR REPLACE FUNCTION refresh_committee_pairs(author_ids uuid[] DEFAULT NULL) RETURNS void AS $$
+BEGIN
+    LOCK TABLE committee_pairs IN SHARE ROW EXCLUSIVE MODE;
+    IF author_ids IS NULL THEN
+        DELETE FROM committee_pairs;
+        INSERT INTO committee_pairs SELECT * FROM committee_pairs_source;
+    ELSE
+        DELETE FROM committee_pairs
+        WHERE author1_id = ANY(author_ids) OR author2_id = ANY(author_ids);
+        INSERT INTO committee_pairs
+        SELECT * FROM committee_pairs_source
+        WHERE author1_id = ANY(author_ids) OR author2_id = ANY(author_ids);
+    END IF;
+    UPDATE stats_refreshes SET refreshed_at = NOW() WHERE stats_table = 'committee_pairs';
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,35 @@
+-- UTC start of a talk (`talk_datetime_utc` in API responses), from its
+-- wall-clock talk_date and talk_time in the conference's IANA timezone.
+--
+-- NULL when any part is missing, the zone name is unknown, or the local time
+-- doesn't exist (skipped by a DST change). A time repeated by a DST change
+-- resolves to its first, earlier occurrence.
+
+CREATE OR REPLACE FUNCTION talk_datetime_utc(p_conference_id uuid, p_talk_date date, p_talk_time time)
+RETURNS timestamptz AS $$
+DECLARE
+    zone text;
+    local_start timestamp := p_talk_date + p_talk_time;
+    utc_start timestamptz;
+BEGIN
+    SELECT timezone INTO zone FROM conferences WHERE id = p_conference_id;
+    IF zone IS NULL OR local_start IS NULL THEN
+        RETURN NULL;
+    END IF;
+    utc_start := local_start AT TIME ZONE zone;
+    -- A skipped time is read with the offset from before the change, so it
+    -- doesn't survive the round trip
+    IF utc_start AT TIME ZONE zone <> local_start THEN
+        RETURN NULL;
+    END IF;
+    -- A repeated time is read with the offset from after the change (the later instant)
+    IF (utc_start - INTERVAL '1 hour') AT TIME ZONE zone = local_start THEN
+        RETURN utc_start - INTERVAL '1 hour';
+    END IF;
+    RETURN utc_start;
+EXCEPTION
+    -- Unknown time zone name
+    WHEN invalid_parameter_value THEN
+        RETURN NULL;
+END;
+$$ LANGUAGE plpgsql STABLE;
@@ -0,0 +1,8 @@
+-- Response headers replayed with a stored idempotent response (Location,
+-- ETag, X-Page-Size), as a JSON object of lowercase name to value. NULL
+-- while the first request is still running.
+--
+-- Expired keys are now pruned by a background task rather than on every
+-- request, so a claim also takes over a key older than 24 hours.
+
+ALTER TABLE idempotency_keys ADD COLUMN headers JSONB;
@@ -0,0 +1,73 @@
+use axum::http::StatusCode;
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::models::EntityChange;
+use crate::utils::json_diff;
+
+/// Serialize a record for `record_change`.
+pub(crate) fn snapshot<T: Serialize>(record: &T) -> Value {
+    serde_json::to_value(record).unwrap_or(Value::Null)
+}
+
+/// Write an `entity_changes` row inside the caller's transaction, so the audit
+/// entry commits or rolls back together with the change it describes.
+///
+/// `before`/`after` are `snapshot`s of the record; use `Value::Null` for the
+/// missing side on create and hard delete.
+pub(crate) async fn record_change(
+    tx: &mut Transaction<'_, Postgres>,
+    entity_type: &str,
+    entity_id: Uuid,
+    action: &str,
+    modifier: Option<&str>,
+    before: &Value,
+    after: &Value,
+) -> Result<(), StatusCode> {
+    sqlx::query!(
+        r#"
+        INSERT INTO entity_changes (entity_type, entity_id, action, modifier, diff)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+        entity_type,
+        entity_id,
+        action,
+        modifier,
+        json_diff(before, after)
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to record {} change: {:?}", entity_type, e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(())
+}
+
+/// Audit trail of one record, oldest first.
+pub(crate) async fn fetch_history(
+    pool: &sqlx::Pool<Postgres>,
+    entity_type: &str,
+    entity_id: Uuid,
+) -> Result<Vec<EntityChange>, StatusCode> {
+    sqlx::query_as!(
+        EntityChange,
+        r#"
+        SELECT id, entity_type, entity_id, action, modifier, changed_at, diff
+        FROM entity_changes
+        WHERE entity_type = $1 AND entity_id = $2
+        ORDER BY changed_at, id
+        "#,
+        entity_type,
+        entity_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch {} history: {:?}", entity_type, e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
@@ -1,65 +1,127 @@
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use std::collections::HashMap;
 use sqlx::{Pool, Postgres};
 use utoipa::IntoParams;
 use uuid::Uuid;
 
-use crate::models::{Author, CreateAuthor, UpdateAuthor, normalize_name};
+use super::audit::{fetch_history, record_change, snapshot};
+use super::error::{is_unique_violation, ApiError};
+use super::events;
+use super::institutions::match_institution;
+use super::page_size::with_page_size;
+use super::params::{sync_after_id, IncludeDeletedQuery, IncludeQuery, SoftDeleteQuery, UpdatedSinceQuery};
+use crate::models::{
+    Author, AuthorList, AuthorPage, AuthorStatsSummary, AuthorSuggestion, AuthorTalk, AuthorWithStats, AuthorPublication, AuthorTimelineYear, CommitteeCollaborator, CommitteePosition, CommitteeTimelineEntry, CommitteeType,
+    CreateAuthor, EntityChange, OrcidEnrichment, PaperType, ScoredAuthor, TopAuthor, UpdateAuthor, normalize_name,
+};
 use crate::utils::{
-    clamp_pagination, validate_optional_text_len, validate_optional_url, validate_text_len,
-    MAX_NAME_LEN,
+    canonical_venue, clamp_pagination, cursor, escape_like, http, name_similarity, orcid, validate_author_name, validate_optional_text_len, validate_optional_url, validate_text_len,
+    validate_url,
+    supplied, CollapseWhitespace, UpdateMode, MAX_NAME_LEN,
 };
+use crate::utils::vcard::{self, Contact};
 
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct AuthorQuery {
-    /// Search term for author name
+    /// Search term for author name; matched as a substring of the normalized
+    /// name, so case and accents don't matter
     pub search: Option<String>,
     /// Maximum number of results (default: 100)
     pub limit: Option<i64>,
     /// Number of results to skip (default: 0)
     pub offset: Option<i64>,
+    /// Also return soft-deleted authors (default: false)
+    pub include_deleted: Option<bool>,
+    /// Keyset pagination: empty for the first page, then the previous page's
+    /// `next_cursor`. Switches the response to an `AuthorPage`, ordered by
+    /// family name, given name and id; `offset` is ignored.
+    pub cursor: Option<String>,
+    /// Rank by name similarity to `search` instead of substring matching, so
+    /// accents and typos still match; each result gets a `score`. Needs
+    /// `search`, and can't be combined with `cursor`.
+    pub fuzzy: Option<bool>,
 }
 
+/// Fuzzy search drops candidates scoring below this
+const MIN_FUZZY_SCORE: f64 = 0.3;
+
+/// Most candidates a fuzzy search scores in memory
+const FUZZY_CANDIDATE_LIMIT: i64 = 1000;
+
+/// Minimum `word_similarity` of the term to an author's `normalized_name` for
+/// the author to be a fuzzy-search candidate
+const TRIGRAM_THRESHOLD: f64 = 0.3;
+
 #[utoipa::path(
     get,
     path = "/authors",
     tag = "authors",
-    params(AuthorQuery),
+    params(AuthorQuery, UpdatedSinceQuery, IncludeQuery),
     responses(
-        (status = 200, description = "List of authors", body = Vec<Author>),
+        (status = 200, description = "List of authors; an `AuthorPage` when `cursor` is given, `ScoredAuthor`s best first with `fuzzy=true`, `AuthorWithStats` with `include=stats`", body = AuthorList, headers(("x-page-size" = i64, description = "Effective `limit` after clamping to `MAX_PAGE_SIZE`"))),
+        (status = 400, description = "Malformed cursor, negative limit/offset, `fuzzy` without `search` or with `cursor`, an unknown `include` (only `stats`, which works without `cursor` and `fuzzy`), or `after_id` without `updated_since`"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn list_authors(
     State(pool): State<Pool<Postgres>>,
     Query(query): Query<AuthorQuery>,
-) -> Result<Json<Vec<Author>>, StatusCode> {
-    let (limit, offset) = clamp_pagination(query.limit, query.offset);
+    Query(sync): Query<UpdatedSinceQuery>,
+    Query(expand): Query<IncludeQuery>,
+) -> Result<Response, ApiError> {
+    let (limit, offset) = clamp_pagination(query.limit, query.offset).map_err(ApiError::bad_request)?;
+    let include_deleted = query.include_deleted.unwrap_or(false);
+    let after_id = sync_after_id(sync.updated_since, sync.after_id)?;
+    let with_stats = expand.parse(&["stats"])?.contains(&"stats");
+    if with_stats && (query.cursor.is_some() || query.fuzzy.unwrap_or(false)) {
+        return Err(ApiError::bad_request("include=stats can't be combined with cursor or fuzzy"));
+    }
+
+    if query.fuzzy.unwrap_or(false) {
+        let search = query.search.as_deref().ok_or_else(|| ApiError::bad_request("fuzzy=true needs a search term"))?;
+        if query.cursor.is_some() {
+            return Err(ApiError::bad_request("fuzzy=true can't be combined with cursor"));
+        }
+        let authors = list_authors_fuzzy(&pool, search, limit, offset, include_deleted, sync.updated_since, after_id).await?;
+        return Ok(with_page_size(Json(authors), limit));
+    }
+
+    if let Some(after) = query.cursor.as_deref() {
+        let page = list_authors_page(&pool, &query, after, limit, include_deleted, sync.updated_since, after_id).await?;
+        return Ok(with_page_size(Json(page), limit));
+    }
 
     let authors = if let Some(search) = &query.search {
-        let search_pattern = format!("%{}%", search);
+        // Only `normalized_name`, which the trigram index serves
+        let search_pattern = format!("%{}%", escape_like(&normalize_name(search)));
         sqlx::query_as!(
             Author,
             r#"
             SELECT
                 id, full_name, family_name, given_name,
-                normalized_name, orcid, homepage_url, affiliation,
-                created_at, updated_at
+                normalized_name, orcid, homepage_url, affiliation, institution_id,
+                created_at, updated_at, deleted_at
             FROM authors
-            WHERE full_name ILIKE $1
-               OR family_name ILIKE $1
-               OR given_name ILIKE $1
-               OR normalized_name ILIKE $1
-            ORDER BY family_name, given_name
+            WHERE normalized_name LIKE $1
+              AND ($4 OR deleted_at IS NULL)
+              AND ($5::timestamptz IS NULL OR (updated_at, id) > ($5, $6))
+            ORDER BY CASE WHEN $5::timestamptz IS NOT NULL THEN updated_at END,
+                     CASE WHEN $5::timestamptz IS NOT NULL THEN id END, family_name, given_name
             LIMIT $2 OFFSET $3
             "#,
             search_pattern,
             limit,
-            offset
+            offset,
+            include_deleted,
+            sync.updated_since,
+            after_id
         )
         .fetch_all(&pool)
         .await
@@ -69,14 +131,20 @@ pub async fn list_authors(
             r#"
             SELECT
                 id, full_name, family_name, given_name,
-                normalized_name, orcid, homepage_url, affiliation,
-                created_at, updated_at
+                normalized_name, orcid, homepage_url, affiliation, institution_id,
+                created_at, updated_at, deleted_at
             FROM authors
-            ORDER BY family_name, given_name
+            WHERE ($3 OR deleted_at IS NULL)
+              AND ($4::timestamptz IS NULL OR (updated_at, id) > ($4, $5))
+            ORDER BY CASE WHEN $4::timestamptz IS NOT NULL THEN updated_at END,
+                     CASE WHEN $4::timestamptz IS NOT NULL THEN id END, family_name, given_name
             LIMIT $1 OFFSET $2
             "#,
             limit,
-            offset
+            offset,
+            include_deleted,
+            sync.updated_since,
+            after_id
         )
         .fetch_all(&pool)
         .await
@@ -86,40 +154,987 @@ pub async fn list_authors(
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
-    Ok(Json(authors))
+    if with_stats {
+        return Ok(with_page_size(Json(with_author_stats(&pool, authors).await?), limit));
+    }
+    Ok(with_page_size(Json(authors), limit))
+}
+
+/// Embed each author's `author_stats` counts, for `include=stats`.
+async fn with_author_stats(pool: &Pool<Postgres>, authors: Vec<Author>) -> Result<Vec<AuthorWithStats>, StatusCode> {
+    let ids: Vec<Uuid> = authors.iter().map(|a| a.id).collect();
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            id as "id!", publication_count, committee_role_count,
+            first_year, last_year, venues
+        FROM author_stats
+        WHERE id = ANY($1)
+        "#,
+        &ids
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch author stats: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let mut stats: HashMap<Uuid, AuthorStatsSummary> = rows
+        .into_iter()
+        .map(|r| {
+            let summary = AuthorStatsSummary {
+                publication_count: r.publication_count.unwrap_or(0),
+                committee_role_count: r.committee_role_count.unwrap_or(0),
+                first_year: r.first_year,
+                last_year: r.last_year,
+                venues: r.venues.unwrap_or_default(),
+            };
+            (r.id, summary)
+        })
+        .collect();
+    Ok(authors
+        .into_iter()
+        .map(|author| AuthorWithStats { stats: stats.remove(&author.id).unwrap_or_default(), author })
+        .collect())
+}
+
+/// Fuzzy mode of `list_authors`. Candidates come from the trigram index on
+/// `normalized_name` (word similarity to the normalized term), best first, and
+/// are then scored with `name_similarity`, which also handles accents,
+/// reordering and single-word typos.
+async fn list_authors_fuzzy(
+    pool: &Pool<Postgres>,
+    search: &str,
+    limit: i64,
+    offset: i64,
+    include_deleted: bool,
+    updated_since: Option<DateTime<Utc>>,
+    after_id: Uuid,
+) -> Result<Vec<ScoredAuthor>, StatusCode> {
+    let term = normalize_name(search);
+    if term.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    // `<%` keeps rows above this threshold; the 0.6 default misses typos like "einstien"
+    sqlx::query(&format!("SET LOCAL pg_trgm.word_similarity_threshold = {}", TRIGRAM_THRESHOLD))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to set trigram threshold: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let candidates = sqlx::query_as!(
+        Author,
+        r#"
+        SELECT
+            id, full_name, family_name, given_name,
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
+        FROM authors
+        WHERE $1 <% normalized_name
+          AND ($2 OR deleted_at IS NULL)
+          AND ($3::timestamptz IS NULL OR (updated_at, id) > ($3, $5))
+        ORDER BY word_similarity($1, normalized_name) DESC, id
+        LIMIT $4
+        "#,
+        term,
+        include_deleted,
+        updated_since,
+        FUZZY_CANDIDATE_LIMIT,
+        after_id
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch fuzzy author candidates: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let mut scored: Vec<ScoredAuthor> = candidates
+        .into_iter()
+        .map(|author| ScoredAuthor { score: name_similarity(search, &author.full_name), author })
+        .filter(|a| a.score >= MIN_FUZZY_SCORE)
+        .collect();
+    // Stable, so equal scores keep the trigram order
+    scored.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    Ok(scored.into_iter().skip(offset as usize).take(limit as usize).collect())
+}
+
+/// Cursor mode of `list_authors`. Unlike offsets, the `(family_name,
+/// given_name, id)` key stays put when rows are inserted or deleted mid-scan,
+/// so a full export sees every author exactly once.
+async fn list_authors_page(
+    pool: &Pool<Postgres>,
+    query: &AuthorQuery,
+    after: &str,
+    limit: i64,
+    include_deleted: bool,
+    updated_since: Option<DateTime<Utc>>,
+    since_id: Uuid,
+) -> Result<AuthorPage, StatusCode> {
+    let after: Option<(String, String, Uuid)> = if after.is_empty() {
+        None
+    } else {
+        Some(cursor::decode(after).ok_or(StatusCode::BAD_REQUEST)?)
+    };
+    let (after_family, after_given, after_id) = match after {
+        Some((family, given, id)) => (Some(family), Some(given), Some(id)),
+        None => (None, None, None),
+    };
+    let search_pattern = query
+        .search
+        .as_ref()
+        .map(|s| format!("%{}%", escape_like(&normalize_name(s))));
+
+    // One extra row tells us whether there is a next page
+    let mut authors = sqlx::query_as!(
+        Author,
+        r#"
+        SELECT
+            id, full_name, family_name, given_name,
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
+        FROM authors
+        WHERE ($1::text IS NULL OR normalized_name LIKE $1)
+          AND ($2 OR deleted_at IS NULL)
+          AND ($3::timestamptz IS NULL OR (updated_at, id) > ($3, $8))
+          AND ($4::uuid IS NULL
+               OR (COALESCE(family_name, ''), COALESCE(given_name, ''), id) > ($5, $6, $4))
+        ORDER BY COALESCE(family_name, ''), COALESCE(given_name, ''), id
+        LIMIT $7
+        "#,
+        search_pattern,
+        include_deleted,
+        updated_since,
+        after_id,
+        after_family,
+        after_given,
+        limit + 1,
+        since_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch authors page: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let next_cursor = if authors.len() as i64 > limit {
+        authors.truncate(limit as usize);
+        authors.last().map(|a| {
+            cursor::encode(&(
+                a.family_name.clone().unwrap_or_default(),
+                a.given_name.clone().unwrap_or_default(),
+                a.id,
+            ))
+        })
+    } else {
+        None
+    };
+
+    Ok(AuthorPage { authors, next_cursor })
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AutocompleteQuery {
+    /// What has been typed so far, e.g. `ein`; matched against normalized names
+    pub q: String,
+    /// Maximum number of suggestions (default: 10, at most 50)
+    pub limit: Option<i64>,
+}
+
+/// Most suggestions one autocomplete request returns
+const MAX_AUTOCOMPLETE_LIMIT: i64 = 50;
+
+/// Shortest term that also gets word-prefix and similarity matches; below
+/// three characters the trigram index has nothing to look up
+const MIN_FUZZY_AUTOCOMPLETE_LEN: usize = 3;
+
+/// How long clients and proxies may reuse an autocomplete response
+const AUTOCOMPLETE_CACHE_CONTROL: &str = "public, max-age=60";
+
+#[utoipa::path(
+    get,
+    path = "/authors/autocomplete",
+    tag = "authors",
+    params(AutocompleteQuery),
+    responses(
+        (status = 200, description = "Live authors whose name starts with `q`, then (for three or more characters) those with a word starting with it, then similar names; cacheable for a minute", body = Vec<AuthorSuggestion>),
+        (status = 400, description = "Missing `q` or a negative limit"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn author_autocomplete(
+    State(pool): State<Pool<Postgres>>,
+    Query(query): Query<AutocompleteQuery>,
+) -> Result<Response, ApiError> {
+    let (limit, _) = clamp_pagination(Some(query.limit.unwrap_or(10)), None).map_err(ApiError::bad_request)?;
+    let limit = limit.min(MAX_AUTOCOMPLETE_LIMIT);
+    validate_text_len(&query.q, MAX_NAME_LEN)?;
+
+    let term = normalize_name(&query.q);
+    let suggestions = if term.is_empty() {
+        Vec::new()
+    } else {
+        // One LIMITed branch per index, so neither falls back to a full scan:
+        // whole-name prefixes through `text_pattern_ops`, word prefixes and
+        // `<%` (similarity above the pg_trgm default) through the trigram one.
+        // An author found by both keeps its better rank.
+        sqlx::query_as!(
+            AuthorSuggestion,
+            r#"
+            WITH matches AS (
+                (SELECT id, full_name, affiliation, 0 AS rank, 1::real AS score
+                 FROM authors
+                 WHERE deleted_at IS NULL AND normalized_name LIKE $2 || '%'
+                 ORDER BY normalized_name USING ~<~, id
+                 LIMIT $3)
+                UNION ALL
+                (SELECT id, full_name, affiliation,
+                        CASE WHEN normalized_name LIKE '% ' || $2 || '%' THEN 1 ELSE 2 END,
+                        word_similarity($1, normalized_name)
+                 FROM authors
+                 WHERE $4 AND deleted_at IS NULL
+                   AND (normalized_name LIKE '% ' || $2 || '%' OR $1 <% normalized_name)
+                 ORDER BY 4, 5 DESC, full_name, id
+                 LIMIT $3)
+            )
+            SELECT id as "id!", full_name as "full_name!", affiliation
+            FROM (
+                SELECT DISTINCT ON (id) id, full_name, affiliation, rank, score
+                FROM matches
+                ORDER BY id, rank
+            ) m
+            ORDER BY rank, score DESC, full_name, id
+            LIMIT $3
+            "#,
+            term,
+            escape_like(&term),
+            limit,
+            term.chars().count() >= MIN_FUZZY_AUTOCOMPLETE_LEN
+        )
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch author suggestions: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+    };
+
+    Ok(([(header::CACHE_CONTROL, AUTOCOMPLETE_CACHE_CONTROL)], Json(suggestions)).into_response())
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TopAuthorsQuery {
+    /// Only count papers and committee roles at this venue (QIP, QCRYPT, TQC; case-insensitive)
+    pub venue: Option<String>,
+    /// Ranking metric: publications (default), committee_roles or leadership
+    pub by: Option<String>,
+    /// Maximum number of results (default: 20)
+    pub limit: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/authors/top",
+    tag = "authors",
+    params(TopAuthorsQuery),
+    responses(
+        (status = 200, description = "Authors ranked by the chosen count, highest first; authors with a zero count are left out", body = Vec<TopAuthor>),
+        (status = 400, description = "Unknown venue or ranking metric, or a negative limit"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn top_authors(
+    State(pool): State<Pool<Postgres>>,
+    Query(query): Query<TopAuthorsQuery>,
+) -> Result<Json<Vec<TopAuthor>>, ApiError> {
+    let venue = match query.venue.as_deref() {
+        Some(v) => Some(canonical_venue(v).ok_or(StatusCode::BAD_REQUEST)?),
+        None => None,
+    };
+    let by = query.by.as_deref().unwrap_or("publications");
+    if !matches!(by, "publications" | "committee_roles" | "leadership") {
+        return Err(StatusCode::BAD_REQUEST.into());
+    }
+    let (limit, _) = clamp_pagination(Some(query.limit.unwrap_or(20)), None).map_err(ApiError::bad_request)?;
+
+    // Without a venue the counts come straight from `author_stats`; with one,
+    // they are recounted over that venue's conferences for the candidates
+    // `author_stats` lists as having appeared there.
+    let authors = sqlx::query_as!(
+        TopAuthor,
+        r#"
+        WITH counts AS (
+            SELECT
+                s.id, s.full_name, s.recent_affiliation as affiliation,
+                CASE WHEN $1::text IS NULL THEN COALESCE(s.publication_count, 0) ELSE (
+                    SELECT COUNT(DISTINCT p.id)
+                    FROM authorships au
+                    JOIN publications p ON au.publication_id = p.id AND p.deleted_at IS NULL
+                    JOIN conferences c ON p.conference_id = c.id
+                    WHERE au.author_id = s.id AND c.venue = $1
+                ) END as publication_count,
+                CASE WHEN $1::text IS NULL THEN COALESCE(s.committee_role_count, 0) ELSE (
+                    SELECT COUNT(*)
+                    FROM committee_roles cr
+                    JOIN conferences c ON cr.conference_id = c.id
+                    WHERE cr.author_id = s.id AND c.venue = $1
+                ) END as committee_role_count,
+                CASE WHEN $1::text IS NULL THEN COALESCE(s.leadership_count, 0) ELSE (
+                    SELECT COUNT(*)
+                    FROM committee_roles cr
+                    JOIN conferences c ON cr.conference_id = c.id
+                    WHERE cr.author_id = s.id AND c.venue = $1
+                      AND cr.position IN ('chair', 'co_chair')
+                ) END as leadership_count
+            FROM author_stats s
+            WHERE $1::text IS NULL OR $1 = ANY(s.venues)
+        ),
+        ranked AS (
+            SELECT *,
+                CASE $2
+                    WHEN 'committee_roles' THEN committee_role_count
+                    WHEN 'leadership' THEN leadership_count
+                    ELSE publication_count
+                END as score
+            FROM counts
+        )
+        SELECT
+            id as "id!", full_name as "full_name!", affiliation,
+            publication_count as "publication_count!",
+            committee_role_count as "committee_role_count!",
+            leadership_count as "leadership_count!"
+        FROM ranked
+        WHERE score > 0
+        ORDER BY score DESC, full_name, id
+        LIMIT $3
+        "#,
+        venue,
+        by,
+        limit
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch top authors: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(authors))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct OrphanAuthorsQuery {
+    /// Maximum number of results (default: 100)
+    pub limit: Option<i64>,
+    /// Number of results to skip (default: 0)
+    pub offset: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/authors/orphans",
+    tag = "authors",
+    params(OrphanAuthorsQuery),
+    responses(
+        (status = 200, description = "Live authors with no authorships and no committee roles, newest first", body = Vec<Author>, headers(("x-page-size" = i64, description = "Effective `limit` after clamping to `MAX_PAGE_SIZE`"))),
+        (status = 400, description = "Negative limit/offset"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn orphan_authors(
+    State(pool): State<Pool<Postgres>>,
+    Query(query): Query<OrphanAuthorsQuery>,
+) -> Result<Response, ApiError> {
+    let (limit, offset) = clamp_pagination(query.limit, query.offset).map_err(ApiError::bad_request)?;
+
+    // Left behind by merges and aborted imports; nothing links to them, so
+    // they can be deleted without touching any publication or committee
+    let authors = sqlx::query_as!(
+        Author,
+        r#"
+        SELECT
+            a.id, a.full_name, a.family_name, a.given_name,
+            a.normalized_name, a.orcid, a.homepage_url, a.affiliation, a.institution_id,
+            a.created_at, a.updated_at, a.deleted_at
+        FROM authors a
+        WHERE a.deleted_at IS NULL
+          AND NOT EXISTS (SELECT 1 FROM authorships au WHERE au.author_id = a.id)
+          AND NOT EXISTS (SELECT 1 FROM committee_roles cr WHERE cr.author_id = a.id)
+        ORDER BY a.created_at DESC, a.id
+        LIMIT $1 OFFSET $2
+        "#,
+        limit,
+        offset
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch orphan authors: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(with_page_size(Json(authors), limit))
+}
+
+#[utoipa::path(
+    get,
+    path = "/authors/{id}",
+    tag = "authors",
+    params(("id" = Uuid, Path, description = "Author ID"), IncludeDeletedQuery, IncludeQuery),
+    responses(
+        (status = 200, description = "Author found; an `AuthorWithStats` with `include=stats`", body = Author),
+        (status = 400, description = "Unknown `include` (only `stats` is supported)"),
+        (status = 404, description = "Author not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_author(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<IncludeDeletedQuery>,
+    Query(expand): Query<IncludeQuery>,
+) -> Result<Response, ApiError> {
+    let with_stats = expand.parse(&["stats"])?.contains(&"stats");
+    let author = sqlx::query_as!(
+        Author,
+        r#"
+        SELECT
+            id, full_name, family_name, given_name,
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
+        FROM authors
+        WHERE id = $1 AND ($2 OR deleted_at IS NULL)
+        "#,
+        id,
+        query.include_deleted.unwrap_or(false)
+    )
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch author: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    if with_stats {
+        let mut authors = with_author_stats(&pool, vec![author]).await?;
+        return Ok(Json(authors.remove(0)).into_response());
+    }
+    Ok(Json(author).into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/authors/{id}/vcard",
+    tag = "authors",
+    params(("id" = Uuid, Path, description = "Author ID")),
+    responses(
+        (status = 200, description = "vCard 4.0 contact card (text/vcard) with name, affiliation, homepage and ORCID", body = String, content_type = "text/vcard"),
+        (status = 404, description = "Author not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn author_vcard(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, StatusCode> {
+    let author = sqlx::query!(
+        r#"
+        SELECT full_name, family_name, given_name, affiliation, homepage_url, orcid
+        FROM authors
+        WHERE id = $1 AND deleted_at IS NULL
+        "#,
+        id
+    )
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch author: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    let card = vcard::render(&Contact {
+        id,
+        full_name: &author.full_name,
+        family_name: author.family_name.as_deref(),
+        given_name: author.given_name.as_deref(),
+        organization: author.affiliation.as_deref(),
+        homepage_url: author.homepage_url.as_deref(),
+        orcid: author.orcid.as_deref(),
+    });
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/vcard; charset=utf-8".to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}.vcf\"", id)),
+        ],
+        card,
+    )
+        .into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/authors/by-orcid/{orcid}",
+    tag = "authors",
+    params(("orcid" = String, Path, description = "ORCID iD (0000-0002-1825-0097), or its orcid.org URL"), IncludeDeletedQuery),
+    responses(
+        (status = 200, description = "Author with this ORCID", body = Author),
+        (status = 400, description = "Not an ORCID iD"),
+        (status = 404, description = "No author has this ORCID"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_author_by_orcid(
+    State(pool): State<Pool<Postgres>>,
+    Path(orcid_id): Path<String>,
+    Query(query): Query<IncludeDeletedQuery>,
+) -> Result<Json<Author>, ApiError> {
+    let orcid_id = orcid::normalize(&orcid_id).ok_or_else(|| {
+        ApiError::bad_request(format!(
+            "Invalid ORCID iD \"{}\"; expected the form 0000-0002-1825-0097",
+            orcid_id
+        ))
+    })?;
+
+    let author = sqlx::query_as!(
+        Author,
+        r#"
+        SELECT
+            id, full_name, family_name, given_name,
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
+        FROM authors
+        WHERE orcid = $1 AND ($2 OR deleted_at IS NULL)
+        "#,
+        orcid_id,
+        query.include_deleted.unwrap_or(false)
+    )
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch author by ORCID: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(Json(author))
+}
+
+#[utoipa::path(
+    get,
+    path = "/authors/{id}/timeline",
+    tag = "authors",
+    params(("id" = Uuid, Path, description = "Author ID")),
+    responses(
+        (status = 200, description = "Per-year publication counts with running total, ordered by year", body = Vec<AuthorTimelineYear>),
+        (status = 404, description = "Author not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn author_timeline(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<AuthorTimelineYear>>, StatusCode> {
+    let exists = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM authors WHERE id = $1 AND deleted_at IS NULL)",
+        id
+    )
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to look up author: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .unwrap_or(false);
+
+    if !exists {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let timeline = sqlx::query_as!(
+        AuthorTimelineYear,
+        r#"
+        SELECT
+            c.year,
+            COUNT(DISTINCT p.id) as "publication_count!",
+            (SUM(COUNT(DISTINCT p.id)) OVER (ORDER BY c.year))::bigint as "cumulative_count!",
+            ARRAY_AGG(DISTINCT c.venue ORDER BY c.venue) as "venues!"
+        FROM authorships au
+        JOIN publications p ON au.publication_id = p.id
+        JOIN conferences c ON p.conference_id = c.id
+        WHERE au.author_id = $1 AND p.deleted_at IS NULL
+        GROUP BY c.year
+        ORDER BY c.year
+        "#,
+        id
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch author timeline: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(timeline))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AuthorPublicationsQuery {
+    /// Only papers where the author is `first` (position 1) or `last` (highest position on that paper)
+    pub position: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/authors/{id}/publications",
+    tag = "authors",
+    params(("id" = Uuid, Path, description = "Author ID"), AuthorPublicationsQuery),
+    responses(
+        (status = 200, description = "The author's live publications with their byline position, newest conference first", body = Vec<AuthorPublication>),
+        (status = 400, description = "Unknown position"),
+        (status = 404, description = "Author not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn author_publications(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<AuthorPublicationsQuery>,
+) -> Result<Json<Vec<AuthorPublication>>, ApiError> {
+    let position = query.position.as_deref().map(str::to_ascii_lowercase);
+    if let Some(position) = position.as_deref().filter(|p| !matches!(*p, "first" | "last")) {
+        return Err(ApiError::bad_request(format!(
+            "Unknown position \"{}\"; expected first or last",
+            position
+        )));
+    }
+
+    let exists = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM authors WHERE id = $1 AND deleted_at IS NULL)",
+        id
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to look up author: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .unwrap_or(false);
+    if !exists {
+        return Err(StatusCode::NOT_FOUND.into());
+    }
+
+    // "Last" is relative to each paper's own byline, so the window runs over
+    // every authorship of the author's papers, not just theirs
+    let publications = sqlx::query_as!(
+        AuthorPublication,
+        r#"
+        WITH bylines AS (
+            SELECT
+                au.publication_id, au.author_id, au.author_position, au.published_as_name,
+                COUNT(*) OVER (PARTITION BY au.publication_id) as author_count,
+                MAX(au.author_position) OVER (PARTITION BY au.publication_id) as last_position
+            FROM authorships au
+            WHERE au.publication_id IN (SELECT publication_id FROM authorships WHERE author_id = $1)
+        )
+        SELECT
+            p.id as publication_id, p.title, p.conference_id, c.venue, c.year,
+            b.published_as_name, b.author_position,
+            b.author_count as "author_count!"
+        FROM bylines b
+        JOIN publications p ON p.id = b.publication_id AND p.deleted_at IS NULL
+        JOIN conferences c ON c.id = p.conference_id
+        WHERE b.author_id = $1
+          AND ($2::text IS NULL
+               OR ($2 = 'first' AND b.author_position = 1)
+               OR ($2 = 'last' AND b.author_position = b.last_position))
+        ORDER BY c.year DESC, c.venue, p.title
+        "#,
+        id,
+        position
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch author publications: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(publications))
+}
+
+#[utoipa::path(
+    get,
+    path = "/authors/{id}/talks",
+    tag = "authors",
+    params(("id" = Uuid, Path, description = "Author ID")),
+    responses(
+        (status = 200, description = "Live publications the author presented, newest conference first", body = Vec<AuthorTalk>),
+        (status = 404, description = "Author not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn author_talks(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<AuthorTalk>>, StatusCode> {
+    let exists = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM authors WHERE id = $1 AND deleted_at IS NULL)",
+        id
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to look up author: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .unwrap_or(false);
+    if !exists {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let talks = sqlx::query_as!(
+        AuthorTalk,
+        r#"
+        SELECT
+            p.id as publication_id, p.title,
+            p.paper_type as "paper_type: PaperType",
+            p.conference_id, c.venue, c.year,
+            p.session_name, p.talk_date, p.talk_time,
+            talk_datetime_utc(p.conference_id, p.talk_date, p.talk_time) as talk_datetime_utc,
+            p.duration_minutes
+        FROM publications p
+        JOIN conferences c ON c.id = p.conference_id
+        WHERE p.presenter_author_id = $1 AND p.deleted_at IS NULL
+        ORDER BY c.year DESC, p.talk_date DESC NULLS LAST, p.talk_time DESC NULLS LAST, p.title
+        "#,
+        id
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch author talks: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(talks))
+}
+
+#[utoipa::path(
+    get,
+    path = "/authors/{id}/committee-timeline",
+    tag = "authors",
+    params(("id" = Uuid, Path, description = "Author ID")),
+    responses(
+        (status = 200, description = "Committee roles ordered by conference year", body = Vec<CommitteeTimelineEntry>),
+        (status = 404, description = "Author not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn author_committee_timeline(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<CommitteeTimelineEntry>>, StatusCode> {
+    let exists = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM authors WHERE id = $1 AND deleted_at IS NULL)",
+        id
+    )
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to look up author: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .unwrap_or(false);
+
+    if !exists {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    // Gaps-and-islands: within each (venue, committee), year minus its dense rank
+    // is constant across a run of consecutive years, so it identifies the run.
+    let timeline = sqlx::query_as!(
+        CommitteeTimelineEntry,
+        r#"
+        WITH roles AS (
+            SELECT
+                cr.id, cr.conference_id, c.venue, c.year,
+                cr.committee, cr.position, cr.role_title,
+                c.year - DENSE_RANK() OVER (
+                    PARTITION BY c.venue, cr.committee ORDER BY c.year
+                ) as run_id
+            FROM committee_roles cr
+            JOIN conferences c ON cr.conference_id = c.id
+            WHERE cr.author_id = $1
+        ),
+        runs AS (
+            SELECT
+                venue, committee, run_id,
+                COUNT(DISTINCT year) as run_length
+            FROM roles
+            GROUP BY venue, committee, run_id
+        )
+        SELECT
+            r.id as "committee_role_id!",
+            r.conference_id as "conference_id!",
+            r.venue as "venue!",
+            r.year as "year!",
+            r.committee as "committee!: CommitteeType",
+            r.position as "position!: CommitteePosition",
+            r.role_title,
+            runs.run_length as "consecutive_years!"
+        FROM roles r
+        JOIN runs USING (venue, committee, run_id)
+        ORDER BY r.year, r.venue, r.committee, r.position
+        "#,
+        id
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch committee timeline: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(timeline))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct CommitteeCollaboratorsQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
 }
 
 #[utoipa::path(
     get,
-    path = "/authors/{id}",
+    path = "/authors/{id}/committee-collaborators",
     tag = "authors",
-    params(("id" = Uuid, Path, description = "Author ID")),
+    params(("id" = Uuid, Path, description = "Author ID"), CommitteeCollaboratorsQuery),
     responses(
-        (status = 200, description = "Author found", body = Author),
-        (status = 404, description = "Author not found")
+        (status = 200, description = "Authors who served on the same committees, most shared committees first", body = Vec<CommitteeCollaborator>),
+        (status = 400, description = "Invalid limit or offset"),
+        (status = 404, description = "Author not found"),
+        (status = 500, description = "Internal server error")
     )
 )]
-pub async fn get_author(
+pub async fn author_committee_collaborators(
     State(pool): State<Pool<Postgres>>,
     Path(id): Path<Uuid>,
-) -> Result<Json<Author>, StatusCode> {
-    let author = sqlx::query_as!(
-        Author,
+    Query(query): Query<CommitteeCollaboratorsQuery>,
+) -> Result<Response, ApiError> {
+    let (limit, offset) = clamp_pagination(query.limit, query.offset).map_err(ApiError::bad_request)?;
+
+    let exists = sqlx::query_scalar!(
+        "SELECT EXISTS(SELECT 1 FROM authors WHERE id = $1 AND deleted_at IS NULL)",
+        id
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to look up author: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .unwrap_or(false);
+    if !exists {
+        return Err(StatusCode::NOT_FOUND.into());
+    }
+
+    // Read from the committee_pairs summary table, as the author page does for
+    // coauthor_pairs: counts are as of the last refresh_committee_pairs()
+    let collaborators = sqlx::query_as!(
+        CommitteeCollaborator,
         r#"
         SELECT
-            id, full_name, family_name, given_name,
-            normalized_name, orcid, homepage_url, affiliation,
-            created_at, updated_at
-        FROM authors
-        WHERE id = $1
+            a.id as author_id,
+            a.full_name,
+            cp.served_together_count as "served_together_count!",
+            cp.last_year as "last_year!"
+        FROM committee_pairs cp
+        JOIN authors a ON a.id = CASE
+            WHEN cp.author1_id = $1 THEN cp.author2_id
+            ELSE cp.author1_id
+        END
+        WHERE (cp.author1_id = $1 OR cp.author2_id = $1)
+          AND a.deleted_at IS NULL
+        ORDER BY cp.served_together_count DESC, cp.last_year DESC, a.full_name, a.id
+        LIMIT $2 OFFSET $3
         "#,
-        id
+        id,
+        limit,
+        offset
     )
-    .fetch_one(&pool)
+    .fetch_all(&pool)
     .await
-    .map_err(|_| StatusCode::NOT_FOUND)?;
+    .map_err(|e| {
+        tracing::error!("Failed to fetch committee collaborators: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
 
-    Ok(Json(author))
+    Ok(with_page_size(Json(collaborators), limit))
+}
+
+/// 409 for an author write that hit a unique index, with the id of the author
+/// already holding the value. Live normalized names are unique (migration
+/// 20261015001100), as are ORCID iDs. `id` is the author being written, if it
+/// already exists. Runs on the pool: the failed transaction is aborted.
+async fn author_conflict(
+    pool: &Pool<Postgres>,
+    e: &sqlx::Error,
+    id: Option<Uuid>,
+    full_name: &str,
+    normalized: &str,
+    orcid: Option<&str>,
+) -> ApiError {
+    let by_orcid =
+        e.as_database_error().and_then(|db_err| db_err.constraint()) == Some("authors_orcid_unique");
+    let existing = sqlx::query_scalar!(
+        r#"
+        SELECT id FROM authors
+        WHERE CASE WHEN $1 THEN orcid = $2
+                   ELSE normalized_name = $3 AND deleted_at IS NULL END
+          AND id IS DISTINCT FROM $4
+        LIMIT 1
+        "#,
+        by_orcid,
+        orcid,
+        normalized,
+        id
+    )
+    .fetch_optional(pool)
+    .await;
+
+    let existing = match existing {
+        Ok(existing) => existing,
+        Err(e) => {
+            tracing::error!("Failed to look up existing author: {:?}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into();
+        }
+    };
+
+    let error = ApiError::conflict(match orcid {
+        Some(orcid) if by_orcid => format!("An author with ORCID iD {} already exists", orcid),
+        _ => format!("An author named like \"{}\" already exists", full_name),
+    });
+    match existing {
+        Some(id) => error.with_id(id),
+        None => error,
+    }
 }
 
 #[utoipa::path(
@@ -130,6 +1145,8 @@ pub async fn get_author(
     responses(
         (status = 201, description = "Author created", body = Author),
         (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 409, description = "A live author with the same normalized name, or any author with the same ORCID iD, exists; its id is in the body"),
+        (status = 422, description = "`full_name` is blank or has no letters"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -138,9 +1155,12 @@ pub async fn get_author(
 )]
 pub async fn create_author(
     State(pool): State<Pool<Postgres>>,
-    Json(new_author): Json<CreateAuthor>,
-) -> Result<(StatusCode, Json<Author>), StatusCode> {
+    Json(mut new_author): Json<CreateAuthor>,
+) -> Result<(StatusCode, Json<Author>), ApiError> {
+    new_author.full_name.collapse_whitespace();
+    new_author.affiliation.collapse_whitespace();
     validate_text_len(&new_author.full_name, MAX_NAME_LEN)?;
+    validate_author_name(&new_author.full_name)?;
     validate_optional_text_len(new_author.family_name.as_deref(), MAX_NAME_LEN)?;
     validate_optional_text_len(new_author.given_name.as_deref(), MAX_NAME_LEN)?;
     validate_optional_text_len(new_author.affiliation.as_deref(), MAX_NAME_LEN)?;
@@ -148,19 +1168,26 @@ pub async fn create_author(
 
     let normalized = normalize_name(&new_author.full_name);
 
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let institution_id = match_institution(&mut tx, new_author.affiliation.as_deref()).await?;
+
     let author = sqlx::query_as!(
         Author,
         r#"
         INSERT INTO authors (
             full_name, family_name, given_name,
-            normalized_name, orcid, homepage_url, affiliation,
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
             creator, modifier
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
         RETURNING
             id, full_name, family_name, given_name,
-            normalized_name, orcid, homepage_url, affiliation,
-            created_at, updated_at
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
         "#,
         new_author.full_name,
         new_author.family_name,
@@ -169,16 +1196,50 @@ pub async fn create_author(
         new_author.orcid,
         new_author.homepage_url,
         new_author.affiliation,
+        institution_id,
         new_author.creator,
         new_author.modifier
     )
-    .fetch_one(&pool)
-    .await
-    .map_err(|e| {
-        tracing::error!("Failed to create author: {:?}", e);
+    .fetch_one(&mut *tx)
+    .await;
+
+    let author = match author {
+        Ok(author) => author,
+        Err(e) if is_unique_violation(&e) => {
+            return Err(author_conflict(
+                &pool,
+                &e,
+                None,
+                &new_author.full_name,
+                &normalized,
+                new_author.orcid.as_deref(),
+            )
+            .await);
+        }
+        Err(e) => {
+            tracing::error!("Failed to create author: {:?}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR.into());
+        }
+    };
+
+    record_change(
+        &mut tx,
+        "author",
+        author.id,
+        "create",
+        Some(&new_author.modifier),
+        &serde_json::Value::Null,
+        &snapshot(&author),
+    )
+    .await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit author: {:?}", e);
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
+    events::publish("created", "author", author.id);
+
     Ok((StatusCode::CREATED, Json(author)))
 }
 
@@ -189,9 +1250,11 @@ pub async fn create_author(
     params(("id" = Uuid, Path, description = "Author ID")),
     request_body = UpdateAuthor,
     responses(
-        (status = 200, description = "Author updated", body = Author),
+        (status = 200, description = "Author replaced", body = Author),
         (status = 401, description = "Unauthorized - missing or invalid token"),
         (status = 404, description = "Author not found"),
+        (status = 409, description = "Author was modified since the given `updated_at`, or another live author has the new normalized name or ORCID iD (its id is in the body)"),
+        (status = 422, description = "Required field `full_name` missing, blank or without letters"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -202,35 +1265,90 @@ pub async fn update_author(
     State(pool): State<Pool<Postgres>>,
     Path(id): Path<Uuid>,
     Json(update): Json<UpdateAuthor>,
-) -> Result<Json<Author>, StatusCode> {
+) -> Result<Json<Author>, ApiError> {
+    apply_author_update(&pool, id, update, UpdateMode::Replace).await
+}
+
+#[utoipa::path(
+    patch,
+    path = "/authors/{id}",
+    tag = "authors",
+    params(("id" = Uuid, Path, description = "Author ID")),
+    request_body = UpdateAuthor,
+    responses(
+        (status = 200, description = "Author updated", body = Author),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Author not found"),
+        (status = 409, description = "Author was modified since the given `updated_at`, or another live author has the new normalized name or ORCID iD (its id is in the body)"),
+        (status = 422, description = "`full_name` is blank or has no letters"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn patch_author(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Json(update): Json<UpdateAuthor>,
+) -> Result<Json<Author>, ApiError> {
+    apply_author_update(&pool, id, update, UpdateMode::Merge).await
+}
+
+async fn apply_author_update(
+    pool: &Pool<Postgres>,
+    id: Uuid,
+    mut update: UpdateAuthor,
+    mode: UpdateMode,
+) -> Result<Json<Author>, ApiError> {
+    update.full_name.collapse_whitespace();
+    update.affiliation.collapse_whitespace();
     validate_optional_text_len(update.full_name.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.family_name.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.given_name.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.affiliation.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_url(update.homepage_url.as_deref())?;
+    if let Some(full_name) = update.full_name.as_deref() {
+        validate_author_name(full_name)?;
+    }
+    validate_optional_text_len(supplied(&update.family_name), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&update.given_name), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&update.affiliation), MAX_NAME_LEN)?;
+    validate_optional_url(supplied(&update.homepage_url))?;
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
 
-    // First fetch the existing author
+    // First fetch the existing author, locking it until the audit row is written
     let existing = sqlx::query_as!(
         Author,
         r#"
         SELECT
             id, full_name, family_name, given_name,
-            normalized_name, orcid, homepage_url, affiliation,
-            created_at, updated_at
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
         FROM authors
-        WHERE id = $1
+        WHERE id = $1 AND deleted_at IS NULL
+        FOR UPDATE
         "#,
         id
     )
-    .fetch_optional(&pool)
+    .fetch_optional(&mut *tx)
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     .ok_or(StatusCode::NOT_FOUND)?;
+    let before = snapshot(&existing);
 
-    let new_full_name = update.full_name.unwrap_or(existing.full_name);
+    let new_full_name = mode.required(update.full_name, existing.full_name)?;
     let normalized = normalize_name(&new_full_name);
+    let affiliation = mode.optional(update.affiliation, existing.affiliation.clone());
+    // Re-match only when the affiliation changes, so a link fixed by hand sticks
+    let institution_id = if affiliation == existing.affiliation {
+        existing.institution_id
+    } else {
+        match_institution(&mut tx, affiliation.as_deref()).await?
+    };
+
+    let orcid = mode.optional(update.orcid, existing.orcid);
 
-    // Update with provided values or keep existing
     let author = sqlx::query_as!(
         Author,
         r#"
@@ -243,31 +1361,62 @@ pub async fn update_author(
             orcid = $5,
             homepage_url = $6,
             affiliation = $7,
-            modifier = $8,
+            institution_id = $8,
+            modifier = $9,
             updated_at = NOW()
-        WHERE id = $9
+        WHERE id = $10
+          AND ($11::timestamptz IS NULL OR updated_at = $11)
         RETURNING
             id, full_name, family_name, given_name,
-            normalized_name, orcid, homepage_url, affiliation,
-            created_at, updated_at
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
         "#,
         new_full_name,
-        update.family_name.or(existing.family_name),
-        update.given_name.or(existing.given_name),
+        mode.optional(update.family_name, existing.family_name),
+        mode.optional(update.given_name, existing.given_name),
         normalized,
-        update.orcid.or(existing.orcid),
-        update.homepage_url.or(existing.homepage_url),
-        update.affiliation.or(existing.affiliation),
+        orcid,
+        mode.optional(update.homepage_url, existing.homepage_url),
+        affiliation,
+        institution_id,
         update.modifier,
-        id
+        id,
+        update.updated_at
     )
-    .fetch_one(&pool)
-    .await
-    .map_err(|e| {
-        tracing::error!("Failed to update author: {:?}", e);
+    .fetch_optional(&mut *tx)
+    .await;
+
+    let author = match author {
+        Ok(author) => author,
+        Err(e) if is_unique_violation(&e) => {
+            return Err(author_conflict(pool, &e, Some(id), &new_full_name, &normalized, orcid.as_deref()).await);
+        }
+        Err(e) => {
+            tracing::error!("Failed to update author: {:?}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR.into());
+        }
+    }
+    // The row exists (we hold its lock), so no match means a stale `updated_at`
+    .ok_or(StatusCode::CONFLICT)?;
+
+    record_change(
+        &mut tx,
+        "author",
+        id,
+        "update",
+        Some(&update.modifier),
+        &before,
+        &snapshot(&author),
+    )
+    .await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit author update: {:?}", e);
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
+    events::publish("updated", "author", id);
+
     Ok(Json(author))
 }
 
@@ -275,11 +1424,11 @@ pub async fn update_author(
     delete,
     path = "/authors/{id}",
     tag = "authors",
-    params(("id" = Uuid, Path, description = "Author ID")),
+    params(("id" = Uuid, Path, description = "Author ID"), SoftDeleteQuery),
     responses(
-        (status = 204, description = "Author deleted"),
+        (status = 204, description = "Author soft-deleted"),
         (status = 401, description = "Unauthorized - missing or invalid token"),
-        (status = 404, description = "Author not found"),
+        (status = 404, description = "Author not found or already deleted"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -289,15 +1438,373 @@ pub async fn update_author(
 pub async fn delete_author(
     State(pool): State<Pool<Postgres>>,
     Path(id): Path<Uuid>,
+    Query(query): Query<SoftDeleteQuery>,
 ) -> Result<StatusCode, StatusCode> {
-    let result = sqlx::query!("DELETE FROM authors WHERE id = $1", id)
-        .execute(&pool)
+    validate_optional_text_len(query.modifier.as_deref(), MAX_NAME_LEN)?;
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let deleted_at = sqlx::query_scalar!(
+        r#"
+        UPDATE authors
+        SET deleted_at = NOW(), modifier = COALESCE($2, modifier), updated_at = NOW()
+        WHERE id = $1 AND deleted_at IS NULL
+        RETURNING deleted_at
+        "#,
+        id,
+        query.modifier
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    record_change(
+        &mut tx,
+        "author",
+        id,
+        "delete",
+        query.modifier.as_deref(),
+        &serde_json::json!({ "deleted_at": null }),
+        &serde_json::json!({ "deleted_at": deleted_at }),
+    )
+    .await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit author delete: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    events::publish("deleted", "author", id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/authors/{id}/restore",
+    tag = "authors",
+    params(("id" = Uuid, Path, description = "Author ID"), SoftDeleteQuery),
+    responses(
+        (status = 200, description = "Author restored", body = Author),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Author not found or not deleted"),
+        (status = 409, description = "A live author has the same normalized name; its id is in the body"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_author(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<SoftDeleteQuery>,
+) -> Result<Json<Author>, ApiError> {
+    validate_optional_text_len(query.modifier.as_deref(), MAX_NAME_LEN)?;
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // Capture deleted_at before clearing it, for the audit diff
+    let deleted = sqlx::query!(
+        r#"
+        SELECT deleted_at, full_name, normalized_name
+        FROM authors
+        WHERE id = $1 AND deleted_at IS NOT NULL
+        FOR UPDATE
+        "#,
+        id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    let author = sqlx::query_as!(
+        Author,
+        r#"
+        UPDATE authors
+        SET deleted_at = NULL, modifier = COALESCE($2, modifier), updated_at = NOW()
+        WHERE id = $1 AND deleted_at IS NOT NULL
+        RETURNING
+            id, full_name, family_name, given_name,
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
+        "#,
+        id,
+        query.modifier
+    )
+    .fetch_one(&mut *tx)
+    .await;
+
+    let author = match author {
+        Ok(author) => author,
+        // Another live author took the name while this one was deleted
+        Err(e) if is_unique_violation(&e) => {
+            return Err(author_conflict(&pool, &e, Some(id), &deleted.full_name, &deleted.normalized_name, None).await);
+        }
+        Err(e) => {
+            tracing::error!("Failed to restore author: {:?}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR.into());
+        }
+    };
+
+    record_change(
+        &mut tx,
+        "author",
+        id,
+        "restore",
+        query.modifier.as_deref(),
+        &serde_json::json!({ "deleted_at": deleted.deleted_at }),
+        &serde_json::json!({ "deleted_at": null }),
+    )
+    .await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit author restore: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    events::publish("updated", "author", id);
+
+    Ok(Json(author))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct OrcidEnrichQuery {
+    /// Overwrite fields that already have a value (default: false, only fill empty ones)
+    pub force: Option<bool>,
+    /// Who is making the change; stored in `modifier` and the audit trail
+    pub modifier: Option<String>,
+}
+
+/// Take `found` for an author field when the field is empty (or `force`),
+/// noting the field in `updated` if that changes it.
+fn fill(
+    field: &str,
+    current: Option<String>,
+    found: Option<String>,
+    force: bool,
+    updated: &mut Vec<String>,
+) -> Option<String> {
+    let empty = current.as_deref().is_none_or(|c| c.trim().is_empty());
+    match found {
+        Some(found) if (empty || force) && current.as_deref() != Some(found.as_str()) => {
+            updated.push(field.to_string());
+            Some(found)
+        }
+        _ => current,
+    }
+}
+
+/// Fetch the public ORCID record for an iD. Upstream failures are 502s; an
+/// iD ORCID doesn't know is a 404.
+async fn fetch_orcid_profile(orcid_id: &str) -> Result<orcid::OrcidProfile, ApiError> {
+    let base = std::env::var("ORCID_API_URL").unwrap_or_else(|_| orcid::DEFAULT_API_URL.to_string());
+    let url = format!("{}/{}/record", base.trim_end_matches('/'), orcid_id);
+    let bad_gateway = |message: String| ApiError::new(StatusCode::BAD_GATEWAY, message);
+
+    let response = http::get(&url, "application/json")
         .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        .map_err(|e| {
+            tracing::warn!("Failed to fetch ORCID record {}: {}", orcid_id, e);
+            bad_gateway("Could not reach ORCID".to_string())
+        })?;
+    match response.status {
+        200 => {}
+        404 => {
+            return Err(ApiError::new(
+                StatusCode::NOT_FOUND,
+                format!("ORCID has no public record for {}", orcid_id),
+            ))
+        }
+        status => {
+            tracing::warn!("ORCID returned {} for {}", status, orcid_id);
+            return Err(bad_gateway(format!("ORCID returned HTTP {}", status)));
+        }
+    }
+
+    let record: serde_json::Value = serde_json::from_slice(&response.body).map_err(|e| {
+        tracing::warn!("Unparseable ORCID record for {}: {}", orcid_id, e);
+        bad_gateway("ORCID returned an unreadable record".to_string())
+    })?;
+    Ok(orcid::parse_record(&record))
+}
+
+#[utoipa::path(
+    post,
+    path = "/authors/{id}/enrich/orcid",
+    tag = "authors",
+    params(("id" = Uuid, Path, description = "Author ID"), OrcidEnrichQuery),
+    responses(
+        (status = 200, description = "Empty given/family name, homepage and affiliation filled from the author's public ORCID record (all of them with `force=true`)", body = OrcidEnrichment),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Author not found, or ORCID has no record for their iD"),
+        (status = 422, description = "Author has no valid ORCID iD"),
+        (status = 500, description = "Internal server error"),
+        (status = 502, description = "ORCID could not be reached or returned an error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn enrich_author_from_orcid(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<OrcidEnrichQuery>,
+) -> Result<Json<OrcidEnrichment>, ApiError> {
+    validate_optional_text_len(query.modifier.as_deref(), MAX_NAME_LEN)?;
+    let force = query.force.unwrap_or(false);
+
+    let stored_orcid = sqlx::query_scalar!(
+        "SELECT orcid FROM authors WHERE id = $1 AND deleted_at IS NULL",
+        id
+    )
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch author: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+    let orcid_id = stored_orcid.as_deref().and_then(orcid::normalize).ok_or_else(|| {
+        ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, "Author has no valid ORCID iD to enrich from")
+    })?;
 
-    if result.rows_affected() == 0 {
+    // Fetch before taking the row lock, so a slow ORCID doesn't hold it
+    let mut profile = fetch_orcid_profile(&orcid_id).await?;
+    profile.homepage_url = profile.homepage_url.filter(|url| validate_url(url).is_ok());
+    profile.affiliation.collapse_whitespace();
+    for field in [&mut profile.given_name, &mut profile.family_name, &mut profile.affiliation] {
+        *field = field.take().filter(|value| validate_text_len(value, MAX_NAME_LEN).is_ok());
+    }
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let existing = sqlx::query_as!(
+        Author,
+        r#"
+        SELECT
+            id, full_name, family_name, given_name,
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
+        FROM authors
+        WHERE id = $1 AND deleted_at IS NULL
+        FOR UPDATE
+        "#,
+        id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .ok_or(StatusCode::NOT_FOUND)?;
+    let before = snapshot(&existing);
+
+    let mut updated_fields = Vec::new();
+    let given_name = fill("given_name", existing.given_name.clone(), profile.given_name, force, &mut updated_fields);
+    let family_name = fill("family_name", existing.family_name.clone(), profile.family_name, force, &mut updated_fields);
+    let homepage_url = fill("homepage_url", existing.homepage_url.clone(), profile.homepage_url, force, &mut updated_fields);
+    let affiliation = fill("affiliation", existing.affiliation.clone(), profile.affiliation, force, &mut updated_fields);
+    if updated_fields.is_empty() {
+        return Ok(Json(OrcidEnrichment { author: existing, updated_fields }));
+    }
+
+    let institution_id = if affiliation == existing.affiliation {
+        existing.institution_id
+    } else {
+        match_institution(&mut tx, affiliation.as_deref()).await?
+    };
+
+    let author = sqlx::query_as!(
+        Author,
+        r#"
+        UPDATE authors
+        SET
+            given_name = $1,
+            family_name = $2,
+            homepage_url = $3,
+            affiliation = $4,
+            institution_id = $5,
+            modifier = COALESCE($6, modifier),
+            updated_at = NOW()
+        WHERE id = $7
+        RETURNING
+            id, full_name, family_name, given_name,
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
+        "#,
+        given_name,
+        family_name,
+        homepage_url,
+        affiliation,
+        institution_id,
+        query.modifier,
+        id
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to enrich author: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    record_change(
+        &mut tx,
+        "author",
+        id,
+        "update",
+        query.modifier.as_deref(),
+        &before,
+        &snapshot(&author),
+    )
+    .await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit author enrichment: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    events::publish("updated", "author", id);
+
+    Ok(Json(OrcidEnrichment { author, updated_fields }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/authors/{id}/history",
+    tag = "authors",
+    params(("id" = Uuid, Path, description = "Author ID")),
+    responses(
+        (status = 200, description = "Audit trail of the author, oldest first", body = Vec<EntityChange>),
+        (status = 404, description = "Author not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn author_history(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<EntityChange>>, StatusCode> {
+    // Soft-deleted authors keep their history
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM authors WHERE id = $1) as "exists!""#,
+        id
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if !exists {
         return Err(StatusCode::NOT_FOUND);
     }
 
-    Ok(StatusCode::NO_CONTENT)
+    Ok(Json(fetch_history(&pool, "author", id).await?))
 }
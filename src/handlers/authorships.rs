@@ -8,22 +8,19 @@ use sqlx::{Pool, Postgres};
 use utoipa::IntoParams;
 use uuid::Uuid;
 
-use crate::models::{Authorship, CreateAuthorship, UpdateAuthorship};
+use super::error::{is_unique_violation, ApiError};
+use super::events;
+use crate::models::{Authorship, CreateAuthorship, ReorderAuthorships, UpdateAuthorship};
 use crate::utils::{
-    validate_metadata, validate_optional_text_len, validate_text_len, MAX_NAME_LEN,
+    validate_metadata, validate_optional_text_len, validate_text_len, supplied, CollapseWhitespace, UpdateMode, MAX_NAME_LEN, MAX_TITLE_LEN,
 };
 
-/// PostgreSQL SQLSTATE for `unique_violation`.
-const PG_UNIQUE_VIOLATION: &str = "23505";
-
 /// Map an SQLx error to a status code, treating unique-constraint violations as 409.
 /// Used for authorship inserts where the `(publication_id, author_position)` UNIQUE
 /// constraint can fire if two clients race to claim the same slot.
 fn map_db_error(err: &sqlx::Error) -> StatusCode {
-    if let Some(db_err) = err.as_database_error() {
-        if db_err.code().as_deref() == Some(PG_UNIQUE_VIOLATION) {
-            return StatusCode::CONFLICT;
-        }
+    if is_unique_violation(err) {
+        return StatusCode::CONFLICT;
     }
     StatusCode::INTERNAL_SERVER_ERROR
 }
@@ -34,6 +31,10 @@ pub struct AuthorshipQuery {
     pub publication_id: Option<Uuid>,
     /// Filter by author ID
     pub author_id: Option<Uuid>,
+    /// Only corresponding (`true`) or other (`false`) authorships
+    pub is_corresponding: Option<bool>,
+    /// Only equal-contribution (`true`, e.g. an author's co-first papers) or other (`false`) authorships
+    pub is_equal_contribution: Option<bool>,
 }
 
 #[utoipa::path(
@@ -54,40 +55,64 @@ pub async fn list_authorships(
         (Some(pub_id), Some(auth_id)) => {
             sqlx::query_as::<_, Authorship>(
                 r#"SELECT id, publication_id, author_id, author_position, published_as_name, 
-                   affiliation, COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
-                   FROM authorships WHERE publication_id = $1 AND author_id = $2 ORDER BY author_position"#,
+                   affiliation, is_corresponding, is_equal_contribution, contribution_note,
+                   COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
+                   FROM authorships WHERE publication_id = $1 AND author_id = $2
+                   AND ($3::bool IS NULL OR is_corresponding = $3)
+                   AND ($4::bool IS NULL OR is_equal_contribution = $4)
+                   ORDER BY author_position"#,
             )
             .bind(pub_id)
             .bind(auth_id)
+            .bind(query.is_corresponding)
+            .bind(query.is_equal_contribution)
             .fetch_all(&pool)
             .await
         }
         (Some(pub_id), None) => {
             sqlx::query_as::<_, Authorship>(
                 r#"SELECT id, publication_id, author_id, author_position, published_as_name, 
-                   affiliation, COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
-                   FROM authorships WHERE publication_id = $1 ORDER BY author_position"#,
+                   affiliation, is_corresponding, is_equal_contribution, contribution_note,
+                   COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
+                   FROM authorships WHERE publication_id = $1
+                   AND ($2::bool IS NULL OR is_corresponding = $2)
+                   AND ($3::bool IS NULL OR is_equal_contribution = $3)
+                   ORDER BY author_position"#,
             )
             .bind(pub_id)
+            .bind(query.is_corresponding)
+            .bind(query.is_equal_contribution)
             .fetch_all(&pool)
             .await
         }
         (None, Some(auth_id)) => {
             sqlx::query_as::<_, Authorship>(
                 r#"SELECT id, publication_id, author_id, author_position, published_as_name, 
-                   affiliation, COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
-                   FROM authorships WHERE author_id = $1 ORDER BY created_at DESC"#,
+                   affiliation, is_corresponding, is_equal_contribution, contribution_note,
+                   COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
+                   FROM authorships WHERE author_id = $1
+                   AND ($2::bool IS NULL OR is_corresponding = $2)
+                   AND ($3::bool IS NULL OR is_equal_contribution = $3)
+                   ORDER BY created_at DESC"#,
             )
             .bind(auth_id)
+            .bind(query.is_corresponding)
+            .bind(query.is_equal_contribution)
             .fetch_all(&pool)
             .await
         }
         (None, None) => {
             sqlx::query_as::<_, Authorship>(
                 r#"SELECT id, publication_id, author_id, author_position, published_as_name, 
-                   affiliation, COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
-                   FROM authorships ORDER BY created_at DESC LIMIT 100"#,
+                   affiliation, is_corresponding, is_equal_contribution, contribution_note,
+                   COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
+                   FROM authorships
+                   WHERE ($1::bool IS NULL OR is_corresponding = $1)
+                     AND ($2::bool IS NULL OR is_equal_contribution = $2)
+                   ORDER BY created_at DESC LIMIT 100"#,
             )
+            .bind(query.is_corresponding)
+            .bind(query.is_equal_contribution)
             .fetch_all(&pool)
             .await
         }
@@ -105,7 +130,8 @@ pub async fn list_authorships(
     params(("id" = Uuid, Path, description = "Authorship ID")),
     responses(
         (status = 200, description = "Authorship found", body = Authorship),
-        (status = 404, description = "Authorship not found")
+        (status = 404, description = "Authorship not found"),
+        (status = 500, description = "Internal server error")
     )
 )]
 pub async fn get_authorship(
@@ -114,7 +140,8 @@ pub async fn get_authorship(
 ) -> Result<Json<Authorship>, StatusCode> {
     sqlx::query_as::<_, Authorship>(
         r#"SELECT id, publication_id, author_id, author_position, published_as_name, 
-           affiliation, COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
+           affiliation, is_corresponding, is_equal_contribution, contribution_note,
+                   COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
            FROM authorships WHERE id = $1"#
     )
         .bind(id)
@@ -142,28 +169,37 @@ pub async fn get_authorship(
 )]
 pub async fn create_authorship(
     State(pool): State<Pool<Postgres>>,
-    Json(payload): Json<CreateAuthorship>,
+    Json(mut payload): Json<CreateAuthorship>,
 ) -> Result<(StatusCode, Json<Authorship>), StatusCode> {
+    payload.published_as_name.collapse_whitespace();
+    payload.affiliation.collapse_whitespace();
+    payload.contribution_note.collapse_whitespace();
     validate_text_len(&payload.published_as_name, MAX_NAME_LEN)?;
     validate_optional_text_len(payload.affiliation.as_deref(), MAX_NAME_LEN)?;
+    validate_optional_text_len(payload.contribution_note.as_deref(), MAX_TITLE_LEN)?;
     validate_metadata(payload.metadata.as_ref())?;
 
     let authorship = sqlx::query_as::<_, Authorship>(
         r#"
         INSERT INTO authorships (
             publication_id, author_id, author_position, published_as_name,
-            affiliation, metadata, creator, modifier
+            affiliation, is_corresponding, is_equal_contribution, contribution_note,
+            metadata, creator, modifier
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
         RETURNING id, publication_id, author_id, author_position, published_as_name, 
-                  affiliation, COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at
+                  affiliation, is_corresponding, is_equal_contribution, contribution_note,
+                   COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at
         "#,
     )
-    .bind(&payload.publication_id)
-    .bind(&payload.author_id)
-    .bind(&payload.author_position)
+    .bind(payload.publication_id)
+    .bind(payload.author_id)
+    .bind(payload.author_position)
     .bind(&payload.published_as_name)
     .bind(&payload.affiliation)
+    .bind(payload.is_corresponding)
+    .bind(payload.is_equal_contribution)
+    .bind(&payload.contribution_note)
     .bind(payload.metadata.unwrap_or_else(|| serde_json::json!({})))
     .bind(&payload.creator)
     .bind(&payload.modifier)
@@ -179,6 +215,8 @@ pub async fn create_authorship(
         status
     })?;
 
+    events::publish("created", "authorship", authorship.id);
+
     Ok((StatusCode::CREATED, Json(authorship)))
 }
 
@@ -189,10 +227,11 @@ pub async fn create_authorship(
     params(("id" = Uuid, Path, description = "Authorship ID")),
     request_body = UpdateAuthorship,
     responses(
-        (status = 200, description = "Authorship updated", body = Authorship),
+        (status = 200, description = "Authorship replaced", body = Authorship),
         (status = 401, description = "Unauthorized - missing or invalid token"),
         (status = 404, description = "Authorship not found"),
         (status = 409, description = "Conflict - new author_position duplicates an existing one for this publication"),
+        (status = 422, description = "Required field `author_position` or `published_as_name` missing"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -204,18 +243,57 @@ pub async fn update_authorship(
     Path(id): Path<Uuid>,
     Json(payload): Json<UpdateAuthorship>,
 ) -> Result<Json<Authorship>, StatusCode> {
+    apply_authorship_update(&pool, id, payload, UpdateMode::Replace).await
+}
+
+#[utoipa::path(
+    patch,
+    path = "/authorships/{id}",
+    tag = "authorships",
+    params(("id" = Uuid, Path, description = "Authorship ID")),
+    request_body = UpdateAuthorship,
+    responses(
+        (status = 200, description = "Authorship updated", body = Authorship),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Authorship not found"),
+        (status = 409, description = "Conflict - new author_position duplicates an existing one for this publication"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn patch_authorship(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<UpdateAuthorship>,
+) -> Result<Json<Authorship>, StatusCode> {
+    apply_authorship_update(&pool, id, payload, UpdateMode::Merge).await
+}
+
+async fn apply_authorship_update(
+    pool: &Pool<Postgres>,
+    id: Uuid,
+    mut payload: UpdateAuthorship,
+    mode: UpdateMode,
+) -> Result<Json<Authorship>, StatusCode> {
+    payload.published_as_name.collapse_whitespace();
+    payload.affiliation.collapse_whitespace();
+    payload.contribution_note.collapse_whitespace();
     validate_optional_text_len(payload.published_as_name.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(payload.affiliation.as_deref(), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&payload.affiliation), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&payload.contribution_note), MAX_TITLE_LEN)?;
     validate_metadata(payload.metadata.as_ref())?;
 
     // First check if authorship exists
     let existing = sqlx::query_as::<_, Authorship>(
         r#"SELECT id, publication_id, author_id, author_position, published_as_name, 
-           affiliation, COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
+           affiliation, is_corresponding, is_equal_contribution, contribution_note,
+                   COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at 
            FROM authorships WHERE id = $1"#
     )
         .bind(id)
-        .fetch_optional(&pool)
+        .fetch_optional(pool)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .ok_or(StatusCode::NOT_FOUND)?;
@@ -223,24 +301,31 @@ pub async fn update_authorship(
     let authorship = sqlx::query_as::<_, Authorship>(
         r#"
         UPDATE authorships SET
-            author_position = COALESCE($1, author_position),
-            published_as_name = COALESCE($2, published_as_name),
-            affiliation = COALESCE($3, affiliation),
-            metadata = COALESCE($4, metadata),
-            modifier = $5,
+            author_position = $1,
+            published_as_name = $2,
+            affiliation = $3,
+            is_corresponding = $4,
+            is_equal_contribution = $5,
+            contribution_note = $6,
+            metadata = $7,
+            modifier = $8,
             updated_at = NOW()
-        WHERE id = $6
+        WHERE id = $9
         RETURNING id, publication_id, author_id, author_position, published_as_name, 
-                  affiliation, COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at
+                  affiliation, is_corresponding, is_equal_contribution, contribution_note,
+                   COALESCE(metadata, '{}'::jsonb) as metadata, created_at, updated_at
         "#,
     )
-    .bind(payload.author_position.or(Some(existing.author_position)))
-    .bind(payload.published_as_name.or(Some(existing.published_as_name)))
-    .bind(payload.affiliation.or(existing.affiliation))
-    .bind(payload.metadata.or(Some(existing.metadata)))
+    .bind(mode.required(payload.author_position, existing.author_position)?)
+    .bind(mode.required(payload.published_as_name, existing.published_as_name)?)
+    .bind(mode.optional(payload.affiliation, existing.affiliation))
+    .bind(mode.or_default(payload.is_corresponding, existing.is_corresponding, false))
+    .bind(mode.or_default(payload.is_equal_contribution, existing.is_equal_contribution, false))
+    .bind(mode.optional(payload.contribution_note, existing.contribution_note))
+    .bind(mode.or_default(payload.metadata, existing.metadata, serde_json::json!({})))
     .bind(&payload.modifier)
     .bind(id)
-    .fetch_one(&pool)
+    .fetch_one(pool)
     .await
     .map_err(|e| {
         let status = map_db_error(&e);
@@ -252,9 +337,128 @@ pub async fn update_authorship(
         status
     })?;
 
+    events::publish("updated", "authorship", id);
+
     Ok(Json(authorship))
 }
 
+#[utoipa::path(
+    put,
+    path = "/publications/{id}/authorships/order",
+    tag = "authorships",
+    params(("id" = Uuid, Path, description = "Publication ID")),
+    request_body = ReorderAuthorships,
+    responses(
+        (status = 200, description = "Authorships in their new order", body = Vec<Authorship>),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Publication not found"),
+        (status = 422, description = "`author_ids` is not exactly the publication's current authors"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn reorder_authorships(
+    State(pool): State<Pool<Postgres>>,
+    Path(publication_id): Path<Uuid>,
+    Json(payload): Json<ReorderAuthorships>,
+) -> Result<Json<Vec<Authorship>>, ApiError> {
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let exists = sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM publications WHERE id = $1 AND deleted_at IS NULL)",
+    )
+    .bind(publication_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if !exists {
+        return Err(StatusCode::NOT_FOUND.into());
+    }
+
+    // Lock the author list so a concurrent add/remove can't slip in between
+    // the check and the rewrite
+    let mut current = sqlx::query_scalar::<_, Uuid>(
+        "SELECT author_id FROM authorships WHERE publication_id = $1 FOR UPDATE",
+    )
+    .bind(publication_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut requested = payload.author_ids.clone();
+    current.sort();
+    requested.sort();
+    if requested.windows(2).any(|w| w[0] == w[1]) {
+        return Err(ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "author_ids contains duplicates",
+        ));
+    }
+    if requested != current {
+        return Err(ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!(
+                "author_ids must list exactly the publication's {} current authors",
+                current.len()
+            ),
+        ));
+    }
+
+    // UNIQUE (publication_id, author_position) is checked row by row, so move
+    // everyone out of the way first; otherwise swapping two authors collides
+    sqlx::query(
+        "UPDATE authorships SET author_position = -author_position WHERE publication_id = $1",
+    )
+    .bind(publication_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to clear author positions");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let mut authorships = sqlx::query_as::<_, Authorship>(
+        r#"
+        UPDATE authorships a SET
+            author_position = o.position::int,
+            modifier = $3,
+            updated_at = NOW()
+        FROM UNNEST($2::uuid[]) WITH ORDINALITY AS o(author_id, position)
+        WHERE a.publication_id = $1 AND a.author_id = o.author_id
+        RETURNING a.id, a.publication_id, a.author_id, a.author_position, a.published_as_name,
+                  a.affiliation, a.is_corresponding, a.is_equal_contribution, a.contribution_note,
+                  COALESCE(a.metadata, '{}'::jsonb) as metadata, a.created_at, a.updated_at
+        "#,
+    )
+    .bind(publication_id)
+    .bind(&payload.author_ids)
+    .bind(&payload.modifier)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to reorder authorships");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit authorship reorder: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    for authorship in &authorships {
+        events::publish("updated", "authorship", authorship.id);
+    }
+
+    authorships.sort_by_key(|a| a.author_position);
+    Ok(Json(authorships))
+}
+
 #[utoipa::path(
     delete,
     path = "/authorships/{id}",
@@ -283,6 +487,7 @@ pub async fn delete_authorship(
     if result.rows_affected() == 0 {
         Err(StatusCode::NOT_FOUND)
     } else {
+        events::publish("deleted", "authorship", id);
         Ok(StatusCode::NO_CONTENT)
     }
 }
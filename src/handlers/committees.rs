@@ -1,19 +1,28 @@
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
+    response::{IntoResponse, Response},
     Json,
 };
+use chrono::NaiveDate;
 use serde::Deserialize;
+use std::collections::HashMap;
 use sqlx::{Pool, Postgres};
 use utoipa::IntoParams;
 use uuid::Uuid;
 
+use super::error::ApiError;
+use super::events;
+use super::page_size::with_page_size;
+use super::params::{sync_after_id, IncludeQuery, UpdatedSinceQuery};
+use super::resolve::resolve_conference_filter;
 use crate::models::{
-    CommitteePosition, CommitteeRole, CommitteeType, CreateCommitteeRole, UpdateCommitteeRole,
+    CommitteePosition, CommitteeRole, CommitteeRoleAuthor, CommitteeRoleWithAuthor, CommitteeType, CreateCommitteeRole,
+    UpdateCommitteeRole,
 };
 use crate::utils::{
-    clamp_pagination, parse_conference_slug, validate_metadata, validate_optional_text_len,
-    MAX_NAME_LEN, MAX_TITLE_LEN,
+    clamp_pagination, validate_metadata, validate_optional_text_len,
+    validate_term, supplied, CollapseWhitespace, UpdateMode, MAX_NAME_LEN, MAX_TITLE_LEN,
 };
 
 #[derive(Debug, Deserialize, IntoParams)]
@@ -34,57 +43,40 @@ pub struct CommitteeQuery {
     pub offset: Option<i64>,
 }
 
-/// Resolve conference filter to UUID (from either conference_id or conference slug)
-async fn resolve_conference_filter(
-    pool: &Pool<Postgres>,
-    conference_id: Option<Uuid>,
-    conference_slug: Option<&str>,
-) -> Result<Option<Uuid>, StatusCode> {
-    // If conference_id is provided directly, use it
-    if let Some(id) = conference_id {
-        return Ok(Some(id));
-    }
-
-    // If conference slug is provided, resolve it
-    if let Some(slug) = conference_slug {
-        if let Some((venue, year)) = parse_conference_slug(slug) {
-            let result = sqlx::query_scalar!(
-                "SELECT id FROM conferences WHERE venue = $1 AND year = $2",
-                venue,
-                year
-            )
-            .fetch_optional(pool)
-            .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-            if let Some(id) = result {
-                return Ok(Some(id));
-            }
-            // Conference not found
-            return Err(StatusCode::NOT_FOUND);
-        }
-        // Invalid slug format
-        return Err(StatusCode::BAD_REQUEST);
-    }
-
-    Ok(None)
-}
-
 #[utoipa::path(
     get,
     path = "/committees",
     tag = "committees",
-    params(CommitteeQuery),
+    params(CommitteeQuery, UpdatedSinceQuery, IncludeQuery),
     responses(
-        (status = 200, description = "List of committee roles", body = Vec<CommitteeRole>),
+        (status = 200, description = "List of committee roles; `CommitteeRoleWithAuthor` with `include=author`", body = Vec<CommitteeRole>, headers(("x-page-size" = i64, description = "Effective `limit` after clamping to `MAX_PAGE_SIZE`"))),
+        (status = 400, description = "Invalid conference slug, committee type, or position, negative limit/offset, unknown `include` (only `author` is supported), or `after_id` without `updated_since`"),
+        (status = 404, description = "Conference not found"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn list_committee_roles(
     State(pool): State<Pool<Postgres>>,
     Query(query): Query<CommitteeQuery>,
-) -> Result<Json<Vec<CommitteeRole>>, StatusCode> {
-    let (limit, offset) = clamp_pagination(query.limit, query.offset);
+    Query(sync): Query<UpdatedSinceQuery>,
+    Query(expand): Query<IncludeQuery>,
+) -> Result<Response, ApiError> {
+    let with_author = expand.parse(&["author"])?.contains(&"author");
+    let (limit, offset) = clamp_pagination(query.limit, query.offset).map_err(ApiError::bad_request)?;
+    let after_id = sync_after_id(sync.updated_since, sync.after_id)?;
+
+    let committee_type = query
+        .committee_type
+        .as_deref()
+        .map(str::parse::<CommitteeType>)
+        .transpose()
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+    let position = query
+        .position
+        .as_deref()
+        .map(str::parse::<CommitteePosition>)
+        .transpose()
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
 
     // Resolve conference filter (supports both UUID and slug like QIP2024)
     let conf_id = resolve_conference_filter(&pool, query.conference_id, query.conference.as_deref()).await?;
@@ -103,35 +95,22 @@ pub async fn list_committee_roles(
                 created_at, updated_at
             FROM committee_roles
             WHERE conference_id = $1
-            ORDER BY committee, position, role_title
-            LIMIT $2 OFFSET $3
+              AND ($2::uuid IS NULL OR author_id = $2)
+              AND ($3::committee_type IS NULL OR committee = $3)
+              AND ($4::committee_position IS NULL OR position = $4)
+              AND ($7::timestamptz IS NULL OR (updated_at, id) > ($7, $8))
+            ORDER BY CASE WHEN $7::timestamptz IS NOT NULL THEN updated_at END,
+                     CASE WHEN $7::timestamptz IS NOT NULL THEN id END, committee, position, role_title
+            LIMIT $5 OFFSET $6
             "#,
             cid,
+            query.author_id,
+            committee_type as Option<CommitteeType>,
+            position as Option<CommitteePosition>,
             limit,
-            offset
-        )
-        .fetch_all(&pool)
-        .await
-    } else if let Some(auth_id) = query.author_id {
-        sqlx::query_as!(
-            CommitteeRole,
-            r#"
-            SELECT
-                id, conference_id, author_id,
-                committee as "committee: CommitteeType",
-                position as "position: CommitteePosition",
-                role_title, term_start, term_end,
-                affiliation,
-                COALESCE(metadata, '{}'::jsonb) as "metadata!",
-                created_at, updated_at
-            FROM committee_roles
-            WHERE author_id = $1
-            ORDER BY created_at DESC
-            LIMIT $2 OFFSET $3
-            "#,
-            auth_id,
-            limit,
-            offset
+            offset,
+            sync.updated_since,
+            after_id
         )
         .fetch_all(&pool)
         .await
@@ -148,11 +127,21 @@ pub async fn list_committee_roles(
                 COALESCE(metadata, '{}'::jsonb) as "metadata!",
                 created_at, updated_at
             FROM committee_roles
-            ORDER BY created_at DESC
-            LIMIT $1 OFFSET $2
+            WHERE ($1::uuid IS NULL OR author_id = $1)
+              AND ($2::committee_type IS NULL OR committee = $2)
+              AND ($3::committee_position IS NULL OR position = $3)
+              AND ($6::timestamptz IS NULL OR (updated_at, id) > ($6, $7))
+            ORDER BY CASE WHEN $6::timestamptz IS NOT NULL THEN updated_at END,
+                     CASE WHEN $6::timestamptz IS NOT NULL THEN id END, created_at DESC
+            LIMIT $4 OFFSET $5
             "#,
+            query.author_id,
+            committee_type as Option<CommitteeType>,
+            position as Option<CommitteePosition>,
             limit,
-            offset
+            offset,
+            sync.updated_since,
+            after_id
         )
         .fetch_all(&pool)
         .await
@@ -162,23 +151,59 @@ pub async fn list_committee_roles(
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
-    Ok(Json(roles))
+    if with_author {
+        return Ok(with_page_size(Json(with_role_authors(&pool, roles).await?), limit));
+    }
+    Ok(with_page_size(Json(roles), limit))
+}
+
+/// Embed each role's author name, for `include=author`; one lookup for the
+/// whole page instead of one per row.
+async fn with_role_authors(
+    pool: &Pool<Postgres>,
+    roles: Vec<CommitteeRole>,
+) -> Result<Vec<CommitteeRoleWithAuthor>, StatusCode> {
+    let ids: Vec<Uuid> = roles.iter().map(|r| r.author_id).collect();
+    let rows = sqlx::query!("SELECT id, full_name FROM authors WHERE id = ANY($1)", &ids)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch committee role authors: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let names: HashMap<Uuid, String> = rows.into_iter().map(|r| (r.id, r.full_name)).collect();
+    // author_id is a foreign key, so every role has its author
+    Ok(roles
+        .into_iter()
+        .map(|role| CommitteeRoleWithAuthor {
+            author: CommitteeRoleAuthor {
+                id: role.author_id,
+                full_name: names.get(&role.author_id).cloned().unwrap_or_default(),
+            },
+            role,
+        })
+        .collect())
 }
 
 #[utoipa::path(
     get,
     path = "/committees/{id}",
     tag = "committees",
-    params(("id" = Uuid, Path, description = "Committee role ID")),
+    params(("id" = Uuid, Path, description = "Committee role ID"), IncludeQuery),
     responses(
-        (status = 200, description = "Committee role found", body = CommitteeRole),
-        (status = 404, description = "Committee role not found")
+        (status = 200, description = "Committee role found; a `CommitteeRoleWithAuthor` with `include=author`", body = CommitteeRole),
+        (status = 400, description = "Unknown `include` (only `author` is supported)"),
+        (status = 404, description = "Committee role not found"),
+        (status = 500, description = "Internal server error")
     )
 )]
 pub async fn get_committee_role(
     State(pool): State<Pool<Postgres>>,
     Path(id): Path<Uuid>,
-) -> Result<Json<CommitteeRole>, StatusCode> {
+    Query(expand): Query<IncludeQuery>,
+) -> Result<Response, ApiError> {
+    let with_author = expand.parse(&["author"])?.contains(&"author");
     let role = sqlx::query_as!(
         CommitteeRole,
         r#"
@@ -195,11 +220,31 @@ pub async fn get_committee_role(
         "#,
         id
     )
-    .fetch_one(&pool)
+    .fetch_optional(&pool)
     .await
-    .map_err(|_| StatusCode::NOT_FOUND)?;
+    .map_err(|e| {
+        tracing::error!("Failed to fetch committee role: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
 
-    Ok(Json(role))
+    if with_author {
+        let mut roles = with_role_authors(&pool, vec![role]).await?;
+        return Ok(Json(roles.remove(0)).into_response());
+    }
+    Ok(Json(role).into_response())
+}
+
+/// Start date of the conference, when recorded; terms are checked against its year
+async fn conference_date(pool: &Pool<Postgres>, conference_id: Uuid) -> Result<Option<NaiveDate>, StatusCode> {
+    let date = sqlx::query_scalar!("SELECT start_date FROM conferences WHERE id = $1", conference_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch conference date: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    Ok(date.flatten())
 }
 
 #[utoipa::path(
@@ -210,6 +255,7 @@ pub async fn get_committee_role(
     responses(
         (status = 201, description = "Committee role created", body = CommitteeRole),
         (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 422, description = "`term_start` after `term_end`, or a term outside the conference year"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -218,11 +264,16 @@ pub async fn get_committee_role(
 )]
 pub async fn create_committee_role(
     State(pool): State<Pool<Postgres>>,
-    Json(new_role): Json<CreateCommitteeRole>,
+    Json(mut new_role): Json<CreateCommitteeRole>,
 ) -> Result<(StatusCode, Json<CommitteeRole>), StatusCode> {
+    new_role.affiliation.collapse_whitespace();
     validate_optional_text_len(new_role.role_title.as_deref(), MAX_TITLE_LEN)?;
     validate_optional_text_len(new_role.affiliation.as_deref(), MAX_NAME_LEN)?;
     validate_metadata(new_role.metadata.as_ref())?;
+    if new_role.term_start.is_some() || new_role.term_end.is_some() {
+        let date = conference_date(&pool, new_role.conference_id).await?;
+        validate_term(new_role.term_start, new_role.term_end, date)?;
+    }
 
     let position = new_role.position.unwrap_or(CommitteePosition::Member);
 
@@ -265,6 +316,8 @@ pub async fn create_committee_role(
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
+    events::publish("created", "committee_role", role.id);
+
     Ok((StatusCode::CREATED, Json(role)))
 }
 
@@ -275,9 +328,10 @@ pub async fn create_committee_role(
     params(("id" = Uuid, Path, description = "Committee role ID")),
     request_body = UpdateCommitteeRole,
     responses(
-        (status = 200, description = "Committee role updated", body = CommitteeRole),
+        (status = 200, description = "Committee role replaced", body = CommitteeRole),
         (status = 401, description = "Unauthorized - missing or invalid token"),
         (status = 404, description = "Committee role not found"),
+        (status = 422, description = "Required field `committee` or `position` missing, or an invalid term"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -289,8 +343,43 @@ pub async fn update_committee_role(
     Path(id): Path<Uuid>,
     Json(update): Json<UpdateCommitteeRole>,
 ) -> Result<Json<CommitteeRole>, StatusCode> {
-    validate_optional_text_len(update.role_title.as_deref(), MAX_TITLE_LEN)?;
-    validate_optional_text_len(update.affiliation.as_deref(), MAX_NAME_LEN)?;
+    apply_committee_role_update(&pool, id, update, UpdateMode::Replace).await
+}
+
+#[utoipa::path(
+    patch,
+    path = "/committees/{id}",
+    tag = "committees",
+    params(("id" = Uuid, Path, description = "Committee role ID")),
+    request_body = UpdateCommitteeRole,
+    responses(
+        (status = 200, description = "Committee role updated", body = CommitteeRole),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Committee role not found"),
+        (status = 422, description = "`term_start` after `term_end`, or a term outside the conference year"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn patch_committee_role(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Json(update): Json<UpdateCommitteeRole>,
+) -> Result<Json<CommitteeRole>, StatusCode> {
+    apply_committee_role_update(&pool, id, update, UpdateMode::Merge).await
+}
+
+async fn apply_committee_role_update(
+    pool: &Pool<Postgres>,
+    id: Uuid,
+    mut update: UpdateCommitteeRole,
+    mode: UpdateMode,
+) -> Result<Json<CommitteeRole>, StatusCode> {
+    update.affiliation.collapse_whitespace();
+    validate_optional_text_len(supplied(&update.role_title), MAX_TITLE_LEN)?;
+    validate_optional_text_len(supplied(&update.affiliation), MAX_NAME_LEN)?;
     validate_metadata(update.metadata.as_ref())?;
 
     // First fetch the existing role
@@ -310,12 +399,18 @@ pub async fn update_committee_role(
         "#,
         id
     )
-    .fetch_optional(&pool)
+    .fetch_optional(pool)
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     .ok_or(StatusCode::NOT_FOUND)?;
 
-    // Update with provided values or keep existing
+    let term_start = mode.optional(update.term_start, existing.term_start);
+    let term_end = mode.optional(update.term_end, existing.term_end);
+    if term_start.is_some() || term_end.is_some() {
+        let date = conference_date(pool, existing.conference_id).await?;
+        validate_term(term_start, term_end, date)?;
+    }
+
     let role = sqlx::query_as!(
         CommitteeRole,
         r#"
@@ -340,23 +435,25 @@ pub async fn update_committee_role(
             COALESCE(metadata, '{}'::jsonb) as "metadata!",
             created_at, updated_at
         "#,
-        update.committee.unwrap_or(existing.committee) as CommitteeType,
-        update.position.unwrap_or(existing.position) as CommitteePosition,
-        update.role_title.or(existing.role_title),
-        update.term_start.or(existing.term_start),
-        update.term_end.or(existing.term_end),
-        update.affiliation.or(existing.affiliation),
-        update.metadata.unwrap_or(existing.metadata),
+        mode.required(update.committee, existing.committee)? as CommitteeType,
+        mode.required(update.position, existing.position)? as CommitteePosition,
+        mode.optional(update.role_title, existing.role_title),
+        term_start,
+        term_end,
+        mode.optional(update.affiliation, existing.affiliation),
+        mode.or_default(update.metadata, existing.metadata, serde_json::json!({})),
         update.modifier,
         id
     )
-    .fetch_one(&pool)
+    .fetch_one(pool)
     .await
     .map_err(|e| {
         tracing::error!("Failed to update committee role: {:?}", e);
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
+    events::publish("updated", "committee_role", id);
+
     Ok(Json(role))
 }
 
@@ -388,5 +485,7 @@ pub async fn delete_committee_role(
         return Err(StatusCode::NOT_FOUND);
     }
 
+    events::publish("deleted", "committee_role", id);
+
     Ok(StatusCode::NO_CONTENT)
 }
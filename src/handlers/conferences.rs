@@ -1,62 +1,71 @@
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
 use sqlx::{Pool, Postgres};
+use utoipa::IntoParams;
 use uuid::Uuid;
 
-use crate::models::{Conference, CreateConference, UpdateConference};
+use super::audit::{fetch_history, record_change, snapshot};
+use super::params::{sync_after_id, UpdatedSinceQuery};
+use super::etag::{is_fresh, not_modified, weak_etag, with_etag};
+use super::error::{is_unique_violation, ApiError};
+use super::events;
+use super::institutions::load_institutions;
+use super::resolve::resolve_conference;
+use crate::models::{
+    AcceptanceTrend, AffiliationCount, AnomalySeverity, CommitteeAnomaly, CommitteeChairs, CommitteePosition, CommitteeType, Conference,
+    ConferenceChair, ConferenceComparison, ConferenceComparisonRow, ConferenceCountryCount, ConferenceIndexEntry,
+    CreateConference, DeletedCommitteeRoles, DeletedPublications, EntityChange, NewAuthor, UpdateConference, Venue,
+    VenueConferenceCount,
+};
+use crate::utils::{affiliation, bibtex};
+use crate::utils::country::validate_country_code;
 use crate::utils::{
-    parse_conference_slug, validate_optional_text_len, validate_optional_url, validate_text_len,
-    MAX_NAME_LEN,
+    canonical_venue, max_conference_year, parse_conference_slug, validate_conference_year, validate_optional_text_len,
+    validate_optional_url, validate_text_len, validate_venue, supplied, UpdateMode, MAX_NAME_LEN,
 };
 
-/// Resolve a conference ID or slug to a UUID
-async fn resolve_conference_id(pool: &Pool<Postgres>, id_or_slug: &str) -> Result<Uuid, StatusCode> {
-    // Try parsing as UUID first
-    if let Ok(uuid) = Uuid::parse_str(id_or_slug) {
-        return Ok(uuid);
-    }
-
-    // Try parsing as slug (e.g., QIP2024, QCRYPT2018, TQC2022)
-    if let Some((venue, year)) = parse_conference_slug(id_or_slug) {
-        let result = sqlx::query_scalar!(
-            "SELECT id FROM conferences WHERE venue = $1 AND year = $2",
-            venue,
-            year
-        )
-        .fetch_optional(pool)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-        if let Some(id) = result {
-            return Ok(id);
-        }
-        return Err(StatusCode::NOT_FOUND);
-    }
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TrendQuery {
+    /// Restrict to one venue (QIP, QCRYPT, TQC; case-insensitive). All venues when omitted.
+    pub venue: Option<String>,
+}
 
-    // Invalid format
-    Err(StatusCode::BAD_REQUEST)
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct CompareQuery {
+    /// Comma-separated conference slugs (e.g., QIP2023,QIP2024,TQC2024)
+    pub slugs: String,
 }
 
+/// Upper bound on editions per comparison request
+const MAX_COMPARE_SLUGS: usize = 20;
+
 #[utoipa::path(
     get,
     path = "/conferences",
     tag = "conferences",
+    params(UpdatedSinceQuery),
     responses(
         (status = 200, description = "List all conferences", body = Vec<Conference>),
+        (status = 400, description = "Malformed `updated_since` or `after_id`, or `after_id` without `updated_since`"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn list_conferences(
     State(pool): State<Pool<Postgres>>,
-) -> Result<Json<Vec<Conference>>, StatusCode> {
+    Query(query): Query<UpdatedSinceQuery>,
+) -> Result<Json<Vec<Conference>>, ApiError> {
+    let after_id = sync_after_id(query.updated_since, query.after_id)?;
     let conferences = sqlx::query_as!(
         Conference,
         r#"
         SELECT
-            id, venue, year, start_date, end_date,
+            id, venue as "venue: Venue", year, start_date, end_date,
             city, country, country_code, is_virtual, is_hybrid,
             timezone, venue_name, website_url, proceedings_url,
             proceedings_publisher, proceedings_volume, proceedings_doi,
@@ -65,8 +74,12 @@ pub async fn list_conferences(
             archive_steering_url, archive_program_url,
             created_at, updated_at
         FROM conferences
-        ORDER BY year DESC, venue
-        "#
+        WHERE $1::timestamptz IS NULL OR (updated_at, id) > ($1, $2)
+        ORDER BY CASE WHEN $1::timestamptz IS NOT NULL THEN updated_at END,
+                 CASE WHEN $1::timestamptz IS NOT NULL THEN id END, year DESC, venue
+        "#,
+        query.updated_since,
+        after_id
     )
     .fetch_all(&pool)
     .await
@@ -78,76 +91,525 @@ pub async fn list_conferences(
     Ok(Json(conferences))
 }
 
+#[utoipa::path(
+    get,
+    path = "/conferences/trends",
+    tag = "conferences",
+    params(TrendQuery),
+    responses(
+        (status = 200, description = "Per-year submission/acceptance counts, ordered by year", body = Vec<AcceptanceTrend>),
+        (status = 400, description = "Unknown venue"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn conference_trends(
+    State(pool): State<Pool<Postgres>>,
+    Query(query): Query<TrendQuery>,
+) -> Result<Json<Vec<AcceptanceTrend>>, StatusCode> {
+    let venue = match query.venue.as_deref() {
+        Some(v) => Some(canonical_venue(v).ok_or(StatusCode::BAD_REQUEST)?),
+        None => None,
+    };
+
+    // Computed from `conferences` directly rather than `conference_stats` so the
+    // numbers are never stale between materialized-view refreshes.
+    let trends = sqlx::query_as!(
+        AcceptanceTrend,
+        r#"
+        SELECT
+            venue, year, submission_count, acceptance_count,
+            CASE
+                WHEN submission_count > 0 AND acceptance_count IS NOT NULL
+                THEN ROUND((acceptance_count::numeric / submission_count::numeric) * 100, 1)::float8
+                ELSE NULL
+            END as acceptance_rate
+        FROM conferences
+        WHERE ($1::text IS NULL OR venue = $1)
+        ORDER BY year, venue
+        "#,
+        venue
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch conference trends: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(trends))
+}
+
+#[utoipa::path(
+    get,
+    path = "/conferences/index.json",
+    tag = "conferences",
+    responses(
+        (status = 200, description = "Every conference with its slug and publication count, newest first", body = Vec<ConferenceIndexEntry>),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn conference_index(
+    State(pool): State<Pool<Postgres>>,
+) -> Result<Json<Vec<ConferenceIndexEntry>>, StatusCode> {
+    let entries = sqlx::query_as!(
+        ConferenceIndexEntry,
+        r#"
+        SELECT
+            LOWER(c.venue) || '-' || c.year::text as "slug!",
+            c.venue, c.year,
+            COALESCE(cs.publication_count, 0) as "publication_count!"
+        FROM conferences c
+        LEFT JOIN conference_stats cs ON c.id = cs.id
+        ORDER BY c.year DESC, c.venue
+        "#
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch conference index: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(entries))
+}
+
+#[utoipa::path(
+    get,
+    path = "/conferences/geo",
+    tag = "conferences",
+    responses(
+        (status = 200, description = "Conference counts per country (most first) with a per-venue breakdown; conferences without a country code are left out", body = Vec<ConferenceCountryCount>),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn conference_geo(
+    State(pool): State<Pool<Postgres>>,
+) -> Result<Json<Vec<ConferenceCountryCount>>, StatusCode> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            c.country_code as "country_code!", c.venue, COUNT(*) as "conference_count!",
+            (SELECT MODE() WITHIN GROUP (ORDER BY n.country)
+             FROM conferences n WHERE n.country_code = c.country_code) as country
+        FROM conferences c
+        WHERE c.country_code IS NOT NULL
+        GROUP BY c.country_code, c.venue
+        ORDER BY c.country_code, c.venue
+        "#
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch conference countries: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let mut countries: Vec<ConferenceCountryCount> = Vec::new();
+    for row in rows {
+        let venue = VenueConferenceCount {
+            venue: row.venue,
+            conference_count: row.conference_count,
+        };
+        match countries.last_mut() {
+            Some(country) if country.country_code == row.country_code => {
+                country.conference_count += venue.conference_count;
+                country.venues.push(venue);
+            }
+            _ => countries.push(ConferenceCountryCount {
+                country_code: row.country_code,
+                country: row.country,
+                conference_count: venue.conference_count,
+                venues: vec![venue],
+            }),
+        }
+    }
+    // Stable, so ties stay in country-code order
+    countries.sort_by_key(|c| std::cmp::Reverse(c.conference_count));
+
+    Ok(Json(countries))
+}
+
+#[utoipa::path(
+    get,
+    path = "/conferences/compare",
+    tag = "conferences",
+    params(CompareQuery),
+    responses(
+        (status = 200, description = "Stats for each found conference, plus slugs that were not found", body = ConferenceComparison),
+        (status = 400, description = "No slugs given, or more than 20"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn compare_conferences(
+    State(pool): State<Pool<Postgres>>,
+    Query(query): Query<CompareQuery>,
+) -> Result<Json<ConferenceComparison>, StatusCode> {
+    // Deduplicated on the edition a slug names, so `QIP2024` and `qip-2024`
+    // count once; malformed slugs only on their exact text
+    let mut requested: Vec<&str> = Vec::new();
+    let mut parsed: Vec<Option<(String, i32)>> = Vec::new();
+    for slug in query.slugs.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let key = parse_conference_slug(slug);
+        let seen = match &key {
+            Some(_) => parsed.contains(&key),
+            None => requested.iter().zip(&parsed).any(|(s, k)| k.is_none() && *s == slug),
+        };
+        if !seen {
+            requested.push(slug);
+            parsed.push(key);
+        }
+    }
+    if requested.is_empty() || requested.len() > MAX_COMPARE_SLUGS {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let (venues, years): (Vec<String>, Vec<i32>) = parsed.iter().flatten().cloned().unzip();
+
+    let conferences = sqlx::query_as!(
+        ConferenceComparisonRow,
+        r#"
+        SELECT
+            c.id,
+            c.venue || c.year::text as "slug!",
+            c.venue, c.year,
+            (SELECT COUNT(*) FROM publications p
+             WHERE p.conference_id = c.id AND p.deleted_at IS NULL) as "publication_count!",
+            (SELECT COUNT(*) FROM publications p
+             WHERE p.conference_id = c.id AND p.deleted_at IS NULL AND p.award IS NOT NULL) as "award_count!",
+            c.submission_count, c.acceptance_count,
+            CASE
+                WHEN c.submission_count > 0 AND c.acceptance_count IS NOT NULL
+                THEN ROUND((c.acceptance_count::numeric / c.submission_count::numeric) * 100, 1)::float8
+                ELSE NULL
+            END as acceptance_rate,
+            (SELECT COUNT(DISTINCT cr.author_id) FROM committee_roles cr
+             WHERE cr.conference_id = c.id) as "committee_member_count!",
+            (SELECT COUNT(DISTINCT cr.author_id) FROM committee_roles cr
+             WHERE cr.conference_id = c.id AND cr.committee = 'PC') as "pc_member_count!",
+            (SELECT COUNT(DISTINCT cr.author_id) FROM committee_roles cr
+             WHERE cr.conference_id = c.id AND cr.committee = 'OC') as "oc_member_count!",
+            (SELECT COUNT(DISTINCT cr.author_id) FROM committee_roles cr
+             WHERE cr.conference_id = c.id AND cr.committee = 'SC') as "sc_member_count!",
+            (SELECT COUNT(DISTINCT cr.author_id) FROM committee_roles cr
+             WHERE cr.conference_id = c.id AND cr.committee = 'Local') as "local_member_count!",
+            (SELECT COUNT(DISTINCT au.author_id) FROM authorships au
+             JOIN publications p ON au.publication_id = p.id
+             JOIN authors a ON au.author_id = a.id
+             WHERE p.conference_id = c.id AND p.deleted_at IS NULL
+               AND a.deleted_at IS NULL) as "unique_author_count!"
+        FROM UNNEST($1::text[], $2::int[]) WITH ORDINALITY AS w(venue, year, ord)
+        JOIN conferences c ON c.venue = w.venue AND c.year = w.year
+        ORDER BY w.ord
+        "#,
+        &venues,
+        &years
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to compare conferences: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // Malformed slugs and well-formed ones with no matching row, in request order
+    let not_found = requested
+        .iter()
+        .zip(&parsed)
+        .filter(|(_, key)| match key {
+            Some((venue, year)) => !conferences.iter().any(|c| &c.venue == venue && c.year == *year),
+            None => true,
+        })
+        .map(|(slug, _)| slug.to_string())
+        .collect();
+
+    Ok(Json(ConferenceComparison {
+        conferences,
+        not_found,
+    }))
+}
+
 #[utoipa::path(
     get,
     path = "/conferences/{id}",
     tag = "conferences",
     params(("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)")),
     responses(
-        (status = 200, description = "Conference found", body = Conference),
+        (status = 200, description = "Conference found; carries a weak `ETag`", body = Conference),
+        (status = 304, description = "Unchanged since the `If-None-Match` ETag"),
         (status = 404, description = "Conference not found"),
-        (status = 400, description = "Invalid ID format")
+        (status = 400, description = "Invalid ID format"),
+        (status = 500, description = "Internal server error")
     )
 )]
 pub async fn get_conference(
     State(pool): State<Pool<Postgres>>,
     Path(id_or_slug): Path<String>,
-) -> Result<Json<Conference>, StatusCode> {
-    // Try parsing as UUID first
-    if let Ok(uuid) = Uuid::parse_str(&id_or_slug) {
-        let conference = sqlx::query_as!(
-            Conference,
-            r#"
-            SELECT
-                id, venue, year, start_date, end_date,
-                city, country, country_code, is_virtual, is_hybrid,
-                timezone, venue_name, website_url, proceedings_url,
-                proceedings_publisher, proceedings_volume, proceedings_doi,
-                submission_count, acceptance_count,
-                archive_url, archive_organizers_url, archive_pc_url,
-                archive_steering_url, archive_program_url,
-                created_at, updated_at
-            FROM conferences
-            WHERE id = $1
-            "#,
-            uuid
-        )
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let conference = fetch_conference(&pool, &id_or_slug).await?;
+
+    // The response is just this row, so its updated_at is the whole validator
+    let etag = weak_etag(conference.updated_at, 0);
+    if is_fresh(&headers, &etag) {
+        return Ok(not_modified(&etag));
+    }
+    Ok(with_etag(Json(conference), &etag))
+}
+
+async fn fetch_conference(pool: &Pool<Postgres>, id_or_slug: &str) -> Result<Conference, StatusCode> {
+    let id = resolve_conference(pool, id_or_slug).await?;
+    sqlx::query_as!(
+        Conference,
+        r#"
+        SELECT
+            id, venue as "venue: Venue", year, start_date, end_date,
+            city, country, country_code, is_virtual, is_hybrid,
+            timezone, venue_name, website_url, proceedings_url,
+            proceedings_publisher, proceedings_volume, proceedings_doi,
+            submission_count, acceptance_count,
+            archive_url, archive_organizers_url, archive_pc_url,
+            archive_steering_url, archive_program_url,
+            created_at, updated_at
+        FROM conferences
+        WHERE id = $1
+        "#,
+        id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch conference: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)
+}
+
+#[utoipa::path(
+    get,
+    path = "/conferences/{id}/chairs",
+    tag = "conferences",
+    params(("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)")),
+    responses(
+        (status = 200, description = "Chairs and co-chairs grouped by committee", body = Vec<CommitteeChairs>),
+        (status = 400, description = "Invalid ID format"),
+        (status = 404, description = "Conference not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn conference_chairs(
+    State(pool): State<Pool<Postgres>>,
+    Path(id_or_slug): Path<String>,
+) -> Result<Json<Vec<CommitteeChairs>>, StatusCode> {
+    let id = resolve_conference(&pool, &id_or_slug).await?;
+
+    let exists = sqlx::query_scalar!("SELECT EXISTS(SELECT 1 FROM conferences WHERE id = $1)", id)
         .fetch_one(&pool)
         .await
-        .map_err(|_| StatusCode::NOT_FOUND)?;
+        .map_err(|e| {
+            tracing::error!("Failed to look up conference: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .unwrap_or(false);
 
-        return Ok(Json(conference));
+    if !exists {
+        return Err(StatusCode::NOT_FOUND);
     }
 
-    // Try parsing as slug (e.g., QIP2024, QCRYPT2018, TQC2022)
-    if let Some((venue, year)) = parse_conference_slug(&id_or_slug) {
-        let conference = sqlx::query_as!(
-            Conference,
-            r#"
-            SELECT
-                id, venue, year, start_date, end_date,
-                city, country, country_code, is_virtual, is_hybrid,
-                timezone, venue_name, website_url, proceedings_url,
-                proceedings_publisher, proceedings_volume, proceedings_doi,
-                submission_count, acceptance_count,
-                archive_url, archive_organizers_url, archive_pc_url,
-                archive_steering_url, archive_program_url,
-                created_at, updated_at
-            FROM conferences
-            WHERE venue = $1 AND year = $2
-            "#,
-            venue,
-            year
-        )
-        .fetch_one(&pool)
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            cr.id, cr.author_id, a.full_name,
+            cr.committee as "committee: CommitteeType",
+            cr.position as "position: CommitteePosition",
+            cr.role_title,
+            COALESCE(cr.affiliation, a.affiliation) as affiliation
+        FROM committee_roles cr
+        JOIN authors a ON cr.author_id = a.id
+        WHERE cr.conference_id = $1
+          AND cr.position IN ('chair', 'co_chair')
+          AND a.deleted_at IS NULL
+        ORDER BY cr.committee, cr.position, a.family_name, a.full_name
+        "#,
+        id
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch conference chairs: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // Rows arrive sorted by committee, so each group is a contiguous run
+    let mut groups: Vec<CommitteeChairs> = Vec::new();
+    for row in rows {
+        let chair = ConferenceChair {
+            committee_role_id: row.id,
+            author_id: row.author_id,
+            full_name: row.full_name,
+            position: row.position,
+            role_title: row.role_title,
+            affiliation: row.affiliation,
+        };
+        match groups.last_mut() {
+            Some(group) if group.committee == row.committee => group.chairs.push(chair),
+            _ => groups.push(CommitteeChairs {
+                committee: row.committee,
+                chairs: vec![chair],
+            }),
+        }
+    }
+
+    Ok(Json(groups))
+}
+
+/// A committee is flagged when its size is this many times above or below the
+/// venue's median for that committee.
+const COMMITTEE_SIZE_OUTLIER_FACTOR: f64 = 3.0;
+
+/// Other editions with that committee needed before its size has a norm.
+const MIN_EDITIONS_FOR_SIZE_NORM: i64 = 3;
+
+#[utoipa::path(
+    get,
+    path = "/conferences/{id}/committee-anomalies",
+    tag = "conferences",
+    params(("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)")),
+    responses(
+        (status = 200, description = "Suspicious committee data, errors first; empty when nothing looks off", body = Vec<CommitteeAnomaly>),
+        (status = 400, description = "Invalid ID format"),
+        (status = 404, description = "Conference not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn conference_committee_anomalies(
+    State(pool): State<Pool<Postgres>>,
+    Path(id_or_slug): Path<String>,
+) -> Result<Json<Vec<CommitteeAnomaly>>, StatusCode> {
+    let id = resolve_conference(&pool, &id_or_slug).await?;
+
+    let venue = sqlx::query_scalar!("SELECT venue FROM conferences WHERE id = $1", id)
+        .fetch_optional(&pool)
         .await
-        .map_err(|_| StatusCode::NOT_FOUND)?;
+        .map_err(|e| {
+            tracing::error!("Failed to look up conference: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let roles = sqlx::query!(
+        r#"
+        SELECT
+            cr.author_id, a.full_name,
+            cr.committee as "committee: CommitteeType",
+            cr.position as "position: CommitteePosition",
+            cr.position::text as "position_label!"
+        FROM committee_roles cr
+        JOIN authors a ON cr.author_id = a.id
+        WHERE cr.conference_id = $1
+          AND a.deleted_at IS NULL
+        ORDER BY cr.committee, a.full_name, cr.author_id, cr.position
+        "#,
+        id
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch committee roles: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // Median committee size over the venue's other editions that have that committee
+    let norms = sqlx::query!(
+        r#"
+        WITH sizes AS (
+            SELECT cr.conference_id, cr.committee, COUNT(DISTINCT cr.author_id) as size
+            FROM committee_roles cr
+            JOIN conferences c ON cr.conference_id = c.id
+            JOIN authors a ON cr.author_id = a.id
+            WHERE c.venue = $1 AND c.id <> $2 AND a.deleted_at IS NULL
+            GROUP BY cr.conference_id, cr.committee
+        )
+        SELECT
+            committee as "committee: CommitteeType",
+            percentile_cont(0.5) WITHIN GROUP (ORDER BY size) as "median!",
+            COUNT(*) as "editions!"
+        FROM sizes
+        GROUP BY committee
+        "#,
+        venue,
+        id
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch committee size norms: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let mut anomalies = Vec::new();
 
-        return Ok(Json(conference));
+    // Rows arrive grouped by committee and then by person
+    let mut positions: Vec<(&CommitteeType, Uuid, &str, Vec<&str>)> = Vec::new();
+    for role in &roles {
+        match positions.last_mut() {
+            Some((committee, author_id, _, labels)) if **committee == role.committee && *author_id == role.author_id => {
+                labels.push(&role.position_label)
+            }
+            _ => positions.push((&role.committee, role.author_id, &role.full_name, vec![&role.position_label])),
+        }
+    }
+    for (committee, _, full_name, labels) in &positions {
+        if labels.len() > 1 {
+            anomalies.push(CommitteeAnomaly {
+                severity: AnomalySeverity::Error,
+                message: format!("{} holds conflicting {:?} positions: {}", full_name, committee, labels.join(", ")),
+            });
+        }
+    }
+
+    let general_chairs: Vec<&str> = roles
+        .iter()
+        .filter(|r| r.committee == CommitteeType::OC && matches!(r.position, CommitteePosition::Chair))
+        .map(|r| r.full_name.as_str())
+        .collect();
+    if general_chairs.len() > 1 {
+        anomalies.push(CommitteeAnomaly {
+            severity: AnomalySeverity::Warning,
+            message: format!(
+                "{} general chairs (OC chair): {}; shared roles are usually co_chair",
+                general_chairs.len(),
+                general_chairs.join(", ")
+            ),
+        });
+    }
+
+    let size = |committee: &CommitteeType| positions.iter().filter(|(c, ..)| *c == committee).count();
+    if size(&CommitteeType::PC) == 0 {
+        anomalies.push(CommitteeAnomaly {
+            severity: AnomalySeverity::Warning,
+            message: "No program committee members recorded".to_string(),
+        });
+    }
+    for norm in norms.iter().filter(|n| n.editions >= MIN_EDITIONS_FOR_SIZE_NORM && n.median > 0.0) {
+        let actual = size(&norm.committee);
+        // An absent committee is only worth flagging for the PC, handled above
+        if actual == 0 {
+            continue;
+        }
+        let ratio = actual as f64 / norm.median;
+        if !(1.0 / COMMITTEE_SIZE_OUTLIER_FACTOR..=COMMITTEE_SIZE_OUTLIER_FACTOR).contains(&ratio) {
+            anomalies.push(CommitteeAnomaly {
+                severity: AnomalySeverity::Warning,
+                message: format!(
+                    "{:?} has {} members; the median over {} other {} editions is {}",
+                    norm.committee, actual, norm.editions, venue, norm.median
+                ),
+            });
+        }
     }
 
-    // Invalid format
-    Err(StatusCode::BAD_REQUEST)
+    anomalies.sort_by_key(|a| a.severity);
+    Ok(Json(anomalies))
 }
 
 #[utoipa::path(
@@ -158,6 +620,8 @@ pub async fn get_conference(
     responses(
         (status = 201, description = "Conference created", body = Conference),
         (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 409, description = "A conference with this venue and year already exists; the body carries its `id`"),
+        (status = 422, description = "Unknown venue, a year outside 1990 to five years ahead, or a country code that isn't ISO 3166-1 alpha-2; the message says which"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -167,11 +631,20 @@ pub async fn get_conference(
 pub async fn create_conference(
     State(pool): State<Pool<Postgres>>,
     Json(new_conference): Json<CreateConference>,
-) -> Result<(StatusCode, Json<Conference>), StatusCode> {
+) -> Result<(StatusCode, Json<Conference>), ApiError> {
     validate_text_len(&new_conference.venue, MAX_NAME_LEN)?;
+    let venue = validate_venue(&new_conference.venue)
+        .map_err(|message| ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, message))?;
+    validate_conference_year(new_conference.year, max_conference_year())
+        .map_err(|message| ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, message))?;
     validate_optional_text_len(new_conference.city.as_deref(), MAX_NAME_LEN)?;
     validate_optional_text_len(new_conference.country.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(new_conference.country_code.as_deref(), MAX_NAME_LEN)?;
+    let country_code = new_conference
+        .country_code
+        .as_deref()
+        .map(validate_country_code)
+        .transpose()
+        .map_err(|message| ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, message))?;
     validate_optional_text_len(new_conference.timezone.as_deref(), MAX_NAME_LEN)?;
     validate_optional_text_len(new_conference.venue_name.as_deref(), MAX_NAME_LEN)?;
     validate_optional_text_len(new_conference.proceedings_publisher.as_deref(), MAX_NAME_LEN)?;
@@ -185,6 +658,11 @@ pub async fn create_conference(
     validate_optional_url(new_conference.archive_steering_url.as_deref())?;
     validate_optional_url(new_conference.archive_program_url.as_deref())?;
 
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
     let conference = sqlx::query_as!(
         Conference,
         r#"
@@ -204,7 +682,7 @@ pub async fn create_conference(
             $19, $20, $21, $22, $23, $24, $25
         )
         RETURNING
-            id, venue, year, start_date, end_date,
+            id, venue as "venue: Venue", year, start_date, end_date,
             city, country, country_code, is_virtual, is_hybrid,
             timezone, venue_name, website_url, proceedings_url,
             proceedings_publisher, proceedings_volume, proceedings_doi,
@@ -213,13 +691,13 @@ pub async fn create_conference(
             archive_steering_url, archive_program_url,
             created_at, updated_at
         "#,
-        new_conference.venue,
+        venue,
         new_conference.year,
         new_conference.start_date,
         new_conference.end_date,
         new_conference.city,
         new_conference.country,
-        new_conference.country_code,
+        country_code,
         new_conference.is_virtual.unwrap_or(false),
         new_conference.is_hybrid.unwrap_or(false),
         new_conference.timezone,
@@ -239,13 +717,58 @@ pub async fn create_conference(
         new_conference.creator,
         new_conference.modifier
     )
-    .fetch_one(&pool)
-    .await
-    .map_err(|e| {
-        tracing::error!("Failed to create conference: {:?}", e);
+    .fetch_one(&mut *tx)
+    .await;
+
+    let conference = match conference {
+        Ok(conference) => conference,
+        Err(e) if is_unique_violation(&e) => {
+            // (venue, year) is unique; point the client at the existing row
+            let existing = sqlx::query_scalar!(
+                "SELECT id FROM conferences WHERE venue = $1 AND year = $2",
+                venue,
+                new_conference.year
+            )
+            .fetch_optional(&pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to look up existing conference: {:?}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+
+            let error = ApiError::conflict(format!(
+                "Conference {}{} already exists",
+                venue, new_conference.year
+            ));
+            return Err(match existing {
+                Some(id) => error.with_id(id),
+                None => error,
+            });
+        }
+        Err(e) => {
+            tracing::error!("Failed to create conference: {:?}", e);
+            return Err(StatusCode::INTERNAL_SERVER_ERROR.into());
+        }
+    };
+
+    record_change(
+        &mut tx,
+        "conference",
+        conference.id,
+        "create",
+        Some(&new_conference.modifier),
+        &serde_json::Value::Null,
+        &snapshot(&conference),
+    )
+    .await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit conference: {:?}", e);
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
+    events::publish("created", "conference", conference.id);
+
     Ok((StatusCode::CREATED, Json(conference)))
 }
 
@@ -256,10 +779,11 @@ pub async fn create_conference(
     params(("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)")),
     request_body = UpdateConference,
     responses(
-        (status = 200, description = "Conference updated", body = Conference),
+        (status = 200, description = "Conference replaced", body = Conference),
         (status = 401, description = "Unauthorized - missing or invalid token"),
         (status = 404, description = "Conference not found"),
         (status = 400, description = "Invalid ID format"),
+        (status = 422, description = "Required field `venue` or `year` missing, an unknown venue, a year outside 1990 to five years ahead, or an invalid country code"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -270,33 +794,86 @@ pub async fn update_conference(
     State(pool): State<Pool<Postgres>>,
     Path(id_or_slug): Path<String>,
     Json(update): Json<UpdateConference>,
-) -> Result<Json<Conference>, StatusCode> {
-    validate_optional_text_len(update.venue.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.city.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.country.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.country_code.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.timezone.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.venue_name.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.proceedings_publisher.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.proceedings_volume.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.proceedings_doi.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_url(update.website_url.as_deref())?;
-    validate_optional_url(update.proceedings_url.as_deref())?;
-    validate_optional_url(update.archive_url.as_deref())?;
-    validate_optional_url(update.archive_organizers_url.as_deref())?;
-    validate_optional_url(update.archive_pc_url.as_deref())?;
-    validate_optional_url(update.archive_steering_url.as_deref())?;
-    validate_optional_url(update.archive_program_url.as_deref())?;
+) -> Result<Json<Conference>, ApiError> {
+    apply_conference_update(&pool, &id_or_slug, update, UpdateMode::Replace).await
+}
 
-    // Resolve ID to UUID
-    let id = resolve_conference_id(&pool, &id_or_slug).await?;
+#[utoipa::path(
+    patch,
+    path = "/conferences/{id}",
+    tag = "conferences",
+    params(("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)")),
+    request_body = UpdateConference,
+    responses(
+        (status = 200, description = "Conference updated", body = Conference),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Conference not found"),
+        (status = 400, description = "Invalid ID format"),
+        (status = 422, description = "Unknown venue, a year outside 1990 to five years ahead, or a country code that isn't ISO 3166-1 alpha-2; the message says which"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn patch_conference(
+    State(pool): State<Pool<Postgres>>,
+    Path(id_or_slug): Path<String>,
+    Json(update): Json<UpdateConference>,
+) -> Result<Json<Conference>, ApiError> {
+    apply_conference_update(&pool, &id_or_slug, update, UpdateMode::Merge).await
+}
 
-    // First fetch the existing conference
+async fn apply_conference_update(
+    pool: &Pool<Postgres>,
+    id_or_slug: &str,
+    mut update: UpdateConference,
+    mode: UpdateMode,
+) -> Result<Json<Conference>, ApiError> {
+    validate_optional_text_len(update.venue.as_deref(), MAX_NAME_LEN)?;
+    let venue = update
+        .venue
+        .as_deref()
+        .map(validate_venue)
+        .transpose()
+        .map_err(|message| ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, message))?;
+    if let Some(year) = update.year {
+        validate_conference_year(year, max_conference_year())
+            .map_err(|message| ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, message))?;
+    }
+    validate_optional_text_len(supplied(&update.city), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&update.country), MAX_NAME_LEN)?;
+    if let Some(Some(code)) = &update.country_code {
+        let code = validate_country_code(code).map_err(|message| ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, message))?;
+        update.country_code = Some(Some(code.to_string()));
+    }
+    validate_optional_text_len(supplied(&update.timezone), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&update.venue_name), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&update.proceedings_publisher), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&update.proceedings_volume), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&update.proceedings_doi), MAX_NAME_LEN)?;
+    validate_optional_url(supplied(&update.website_url))?;
+    validate_optional_url(supplied(&update.proceedings_url))?;
+    validate_optional_url(supplied(&update.archive_url))?;
+    validate_optional_url(supplied(&update.archive_organizers_url))?;
+    validate_optional_url(supplied(&update.archive_pc_url))?;
+    validate_optional_url(supplied(&update.archive_steering_url))?;
+    validate_optional_url(supplied(&update.archive_program_url))?;
+
+    // Resolve ID to UUID
+    let id = resolve_conference(pool, id_or_slug).await?;
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // First fetch the existing conference, locking it until the audit row is written
     let existing = sqlx::query_as!(
         Conference,
         r#"
         SELECT
-            id, venue, year, start_date, end_date,
+            id, venue as "venue: Venue", year, start_date, end_date,
             city, country, country_code, is_virtual, is_hybrid,
             timezone, venue_name, website_url, proceedings_url,
             proceedings_publisher, proceedings_volume, proceedings_doi,
@@ -306,15 +883,16 @@ pub async fn update_conference(
             created_at, updated_at
         FROM conferences
         WHERE id = $1
+        FOR UPDATE
         "#,
         id
     )
-    .fetch_optional(&pool)
+    .fetch_optional(&mut *tx)
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     .ok_or(StatusCode::NOT_FOUND)?;
+    let before = snapshot(&existing);
 
-    // Update with provided values or keep existing
     let conference = sqlx::query_as!(
         Conference,
         r#"
@@ -347,7 +925,7 @@ pub async fn update_conference(
             updated_at = NOW()
         WHERE id = $25
         RETURNING
-            id, venue, year, start_date, end_date,
+            id, venue as "venue: Venue", year, start_date, end_date,
             city, country, country_code, is_virtual, is_hybrid,
             timezone, venue_name, website_url, proceedings_url,
             proceedings_publisher, proceedings_volume, proceedings_doi,
@@ -356,51 +934,82 @@ pub async fn update_conference(
             archive_steering_url, archive_program_url,
             created_at, updated_at
         "#,
-        update.venue.unwrap_or(existing.venue),
-        update.year.unwrap_or(existing.year),
-        update.start_date.or(existing.start_date),
-        update.end_date.or(existing.end_date),
-        update.city.or(existing.city),
-        update.country.or(existing.country),
-        update.country_code.or(existing.country_code),
-        update.is_virtual.or(existing.is_virtual).unwrap_or(false),
-        update.is_hybrid.or(existing.is_hybrid).unwrap_or(false),
-        update.timezone.or(existing.timezone),
-        update.venue_name.or(existing.venue_name),
-        update.website_url.or(existing.website_url),
-        update.proceedings_url.or(existing.proceedings_url),
-        update.proceedings_publisher.or(existing.proceedings_publisher),
-        update.proceedings_volume.or(existing.proceedings_volume),
-        update.proceedings_doi.or(existing.proceedings_doi),
-        update.submission_count.or(existing.submission_count),
-        update.acceptance_count.or(existing.acceptance_count),
-        update.archive_url.or(existing.archive_url),
-        update.archive_organizers_url.or(existing.archive_organizers_url),
-        update.archive_pc_url.or(existing.archive_pc_url),
-        update.archive_steering_url.or(existing.archive_steering_url),
-        update.archive_program_url.or(existing.archive_program_url),
+        mode.required(venue, existing.venue.as_str())?,
+        mode.required(update.year, existing.year)?,
+        mode.optional(update.start_date, existing.start_date),
+        mode.optional(update.end_date, existing.end_date),
+        mode.optional(update.city, existing.city),
+        mode.optional(update.country, existing.country),
+        mode.optional(update.country_code, existing.country_code),
+        mode.or_default(update.is_virtual, existing.is_virtual.unwrap_or(false), false),
+        mode.or_default(update.is_hybrid, existing.is_hybrid.unwrap_or(false), false),
+        mode.optional(update.timezone, existing.timezone),
+        mode.optional(update.venue_name, existing.venue_name),
+        mode.optional(update.website_url, existing.website_url),
+        mode.optional(update.proceedings_url, existing.proceedings_url),
+        mode.optional(update.proceedings_publisher, existing.proceedings_publisher),
+        mode.optional(update.proceedings_volume, existing.proceedings_volume),
+        mode.optional(update.proceedings_doi, existing.proceedings_doi),
+        mode.optional(update.submission_count, existing.submission_count),
+        mode.optional(update.acceptance_count, existing.acceptance_count),
+        mode.optional(update.archive_url, existing.archive_url),
+        mode.optional(update.archive_organizers_url, existing.archive_organizers_url),
+        mode.optional(update.archive_pc_url, existing.archive_pc_url),
+        mode.optional(update.archive_steering_url, existing.archive_steering_url),
+        mode.optional(update.archive_program_url, existing.archive_program_url),
         update.modifier,
         id
     )
-    .fetch_one(&pool)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| {
         tracing::error!("Failed to update conference: {:?}", e);
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
+    record_change(
+        &mut tx,
+        "conference",
+        id,
+        "update",
+        Some(&update.modifier),
+        &before,
+        &snapshot(&conference),
+    )
+    .await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit conference update: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    events::publish("updated", "conference", conference.id);
+
     Ok(Json(conference))
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DeleteConferenceQuery {
+    /// Who is deleting the conference; recorded in the audit trail
+    pub modifier: Option<String>,
+    /// Also delete the conference's publications (with their authorships) and
+    /// committee roles. Without it, a conference that still has any is a 409.
+    pub cascade: Option<bool>,
+}
+
 #[utoipa::path(
     delete,
     path = "/conferences/{id}",
     tag = "conferences",
-    params(("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)")),
+    params(
+        ("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)"),
+        DeleteConferenceQuery
+    ),
     responses(
         (status = 204, description = "Conference deleted"),
         (status = 401, description = "Unauthorized - missing or invalid token"),
         (status = 404, description = "Conference not found"),
+        (status = 409, description = "Conference still has publications or committee roles and `cascade` was not set"),
         (status = 400, description = "Invalid ID format"),
         (status = 500, description = "Internal server error")
     ),
@@ -411,16 +1020,633 @@ pub async fn update_conference(
 pub async fn delete_conference(
     State(pool): State<Pool<Postgres>>,
     Path(id_or_slug): Path<String>,
-) -> Result<StatusCode, StatusCode> {
-    let id = resolve_conference_id(&pool, &id_or_slug).await?;
-    let result = sqlx::query!("DELETE FROM conferences WHERE id = $1", id)
-        .execute(&pool)
+    Query(query): Query<DeleteConferenceQuery>,
+) -> Result<StatusCode, ApiError> {
+    validate_optional_text_len(query.modifier.as_deref(), MAX_NAME_LEN)?;
+
+    let id = resolve_conference(&pool, &id_or_slug).await?;
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // Lock the conference so no publication or role can be attached to it
+    // between counting its children and deleting it
+    sqlx::query_scalar!("SELECT id FROM conferences WHERE id = $1 FOR UPDATE", id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // Soft-deleted publications count too: they still reference the conference
+    let children = sqlx::query!(
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM publications WHERE conference_id = $1) as "publications!",
+            (SELECT COUNT(*) FROM committee_roles WHERE conference_id = $1) as "committee_roles!"
+        "#,
+        id
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to count conference children: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let mut removed_roles = Vec::new();
+    let mut removed_authorships = Vec::new();
+    let mut removed_publications = Vec::new();
+    if children.publications > 0 || children.committee_roles > 0 {
+        if !query.cascade.unwrap_or(false) {
+            return Err(ApiError::conflict(format!(
+                "Conference has {} publications and {} committee roles; \
+                 pass ?cascade=true to delete them as well",
+                children.publications, children.committee_roles
+            )));
+        }
+
+        tracing::warn!(
+            conference_id = %id,
+            publications = children.publications,
+            committee_roles = children.committee_roles,
+            "Cascade-deleting conference children"
+        );
+
+        removed_roles = sqlx::query_scalar!("DELETE FROM committee_roles WHERE conference_id = $1 RETURNING id", id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to delete committee roles: {:?}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+
+        // Deleted explicitly (rather than via ON DELETE CASCADE) so each gets an event
+        removed_authorships = sqlx::query_scalar!(
+            r#"
+            DELETE FROM authorships
+            WHERE publication_id IN (SELECT id FROM publications WHERE conference_id = $1)
+            RETURNING id
+            "#,
+            id
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to delete authorships: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+        removed_publications = sqlx::query_scalar!("DELETE FROM publications WHERE conference_id = $1 RETURNING id", id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to delete publications: {:?}", e);
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?;
+    }
+
+    let deleted = sqlx::query_as!(
+        Conference,
+        r#"
+        DELETE FROM conferences
+        WHERE id = $1
+        RETURNING
+            id, venue as "venue: Venue", year, start_date, end_date,
+            city, country, country_code, is_virtual, is_hybrid,
+            timezone, venue_name, website_url, proceedings_url,
+            proceedings_publisher, proceedings_volume, proceedings_doi,
+            submission_count, acceptance_count,
+            archive_url, archive_organizers_url, archive_pc_url,
+            archive_steering_url, archive_program_url,
+            created_at, updated_at
+        "#,
+        id
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to delete conference: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    record_change(
+        &mut tx,
+        "conference",
+        id,
+        "delete",
+        query.modifier.as_deref(),
+        &snapshot(&deleted),
+        &serde_json::Value::Null,
+    )
+    .await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit conference delete: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    for role_id in removed_roles {
+        events::publish("deleted", "committee_role", role_id);
+    }
+    for authorship_id in removed_authorships {
+        events::publish("deleted", "authorship", authorship_id);
+    }
+    for publication_id in removed_publications {
+        events::publish("deleted", "publication", publication_id);
+    }
+    events::publish("deleted", "conference", id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct WipeConferenceQuery {
+    /// Who is removing the rows; recorded in the conference's audit trail
+    pub modifier: Option<String>,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/conferences/{id}/publications",
+    tag = "conferences",
+    params(
+        ("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)"),
+        WipeConferenceQuery
+    ),
+    responses(
+        (status = 200, description = "All of the conference's publications (soft-deleted included) and their authorships removed", body = DeletedPublications),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Conference not found"),
+        (status = 400, description = "Invalid ID format"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_conference_publications(
+    State(pool): State<Pool<Postgres>>,
+    Path(id_or_slug): Path<String>,
+    Query(query): Query<WipeConferenceQuery>,
+) -> Result<Json<DeletedPublications>, ApiError> {
+    let id = resolve_conference(&pool, &id_or_slug).await?;
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // Hold the conference so a concurrent import can't attach new rows mid-wipe
+    sqlx::query_scalar!("SELECT id FROM conferences WHERE id = $1 FOR UPDATE", id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock conference: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // Deleted explicitly (rather than via ON DELETE CASCADE) to report the count
+    let authorships = sqlx::query_scalar!(
+        r#"
+        DELETE FROM authorships
+        WHERE publication_id IN (SELECT id FROM publications WHERE conference_id = $1)
+        RETURNING id
+        "#,
+        id
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to delete authorships: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let publications = sqlx::query_scalar!("DELETE FROM publications WHERE conference_id = $1 RETURNING id", id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to delete publications: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    // Audited on the conference as a change in how many rows it has
+    if !publications.is_empty() {
+        record_change(
+            &mut tx,
+            "conference",
+            id,
+            "update",
+            query.modifier.as_deref(),
+            &serde_json::json!({ "publications": publications.len(), "authorships": authorships.len() }),
+            &serde_json::json!({ "publications": 0, "authorships": 0 }),
+        )
+        .await?;
+    }
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit publication wipe: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    tracing::warn!(
+        conference_id = %id,
+        publications = publications.len(),
+        authorships = authorships.len(),
+        "Deleted all publications of conference"
+    );
+
+    if !publications.is_empty() {
+        events::publish_wipe("publications_wiped", id, publications.len() as i64);
+    }
+
+    Ok(Json(DeletedPublications {
+        conference_id: id,
+        publications: publications.len() as i64,
+        authorships: authorships.len() as i64,
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/conferences/{id}/committees",
+    tag = "conferences",
+    params(
+        ("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)"),
+        WipeConferenceQuery
+    ),
+    responses(
+        (status = 200, description = "All of the conference's committee roles removed", body = DeletedCommitteeRoles),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Conference not found"),
+        (status = 400, description = "Invalid ID format"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_conference_committees(
+    State(pool): State<Pool<Postgres>>,
+    Path(id_or_slug): Path<String>,
+    Query(query): Query<WipeConferenceQuery>,
+) -> Result<Json<DeletedCommitteeRoles>, ApiError> {
+    let id = resolve_conference(&pool, &id_or_slug).await?;
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    sqlx::query_scalar!("SELECT id FROM conferences WHERE id = $1 FOR UPDATE", id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock conference: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let roles = sqlx::query_scalar!("DELETE FROM committee_roles WHERE conference_id = $1 RETURNING id", id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to delete committee roles: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    if !roles.is_empty() {
+        record_change(
+            &mut tx,
+            "conference",
+            id,
+            "update",
+            query.modifier.as_deref(),
+            &serde_json::json!({ "committee_roles": roles.len() }),
+            &serde_json::json!({ "committee_roles": 0 }),
+        )
+        .await?;
+    }
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit committee wipe: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    tracing::warn!(conference_id = %id, committee_roles = roles.len(), "Deleted all committee roles of conference");
+
+    if !roles.is_empty() {
+        events::publish_wipe("committee_roles_wiped", id, roles.len() as i64);
+    }
+
+    Ok(Json(DeletedCommitteeRoles {
+        conference_id: id,
+        committee_roles: roles.len() as i64,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/conferences/{id}/history",
+    tag = "conferences",
+    params(("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)")),
+    responses(
+        (status = 200, description = "Audit trail of the conference, oldest first", body = Vec<EntityChange>),
+        (status = 404, description = "Conference not found and no recorded history"),
+        (status = 400, description = "Invalid ID format"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn conference_history(
+    State(pool): State<Pool<Postgres>>,
+    Path(id_or_slug): Path<String>,
+) -> Result<Json<Vec<EntityChange>>, StatusCode> {
+    let id = resolve_conference(&pool, &id_or_slug).await?;
+    let history = fetch_history(&pool, "conference", id).await?;
+
+    // Deleted conferences keep their history; only 404 when there is nothing at all
+    if history.is_empty() {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM conferences WHERE id = $1) as "exists!""#,
+            id
+        )
+        .fetch_one(&pool)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    if result.rows_affected() == 0 {
+        if !exists {
+            return Err(StatusCode::NOT_FOUND);
+        }
+    }
+
+    Ok(Json(history))
+}
+
+#[utoipa::path(
+    get,
+    path = "/conferences/{id}/proceedings.bib",
+    tag = "conferences",
+    params(("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)")),
+    responses(
+        (status = 200, description = "BibTeX: one `@inproceedings` per publication, each with a `crossref` to the `@proceedings` entry that closes the file", body = String, content_type = "application/x-bibtex"),
+        (status = 400, description = "Invalid ID format"),
+        (status = 404, description = "Conference not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn conference_proceedings_bib(
+    State(pool): State<Pool<Postgres>>,
+    Path(id_or_slug): Path<String>,
+) -> Result<Response, StatusCode> {
+    let id = resolve_conference(&pool, &id_or_slug).await?;
+
+    let conference = sqlx::query!(
+        r#"
+        SELECT
+            venue as "venue: Venue", year, city, country,
+            proceedings_publisher, proceedings_volume, proceedings_doi, proceedings_url
+        FROM conferences
+        WHERE id = $1
+        "#,
+        id
+    )
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch conference: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    let publications = sqlx::query!(
+        r#"
+        SELECT
+            p.canonical_key, p.title, p.pages, p.doi,
+            COALESCE(p.arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+            COALESCE(
+                ARRAY_AGG(au.published_as_name ORDER BY au.author_position) FILTER (WHERE au.id IS NOT NULL),
+                ARRAY[]::text[]
+            ) as "authors!"
+        FROM publications p
+        LEFT JOIN authorships au ON au.publication_id = p.id
+        WHERE p.conference_id = $1 AND p.deleted_at IS NULL
+        GROUP BY p.id
+        ORDER BY p.talk_date NULLS LAST, p.talk_time NULLS LAST, p.title
+        "#,
+        id
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch conference publications: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let year = conference.year.to_string();
+    let booktitle = format!("{} ({} {})", conference.venue.full_name(), conference.venue, year);
+    let address = [conference.city.as_deref(), conference.country.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ");
+    let proceedings = bibtex::Entry::new("proceedings", &format!("{}{}", conference.venue, year).to_lowercase())
+        .field("title", Some(&booktitle))
+        .field("booktitle", Some(&booktitle))
+        .raw_field("year", Some(&year))
+        .field("publisher", conference.proceedings_publisher.as_deref())
+        .field("volume", conference.proceedings_volume.as_deref())
+        .field("address", Some(&address))
+        .raw_field("doi", conference.proceedings_doi.as_deref())
+        .raw_field("url", conference.proceedings_url.as_deref());
+
+    // bibtex resolves a crossref only when the referenced entry comes after
+    // the entries citing it, so the @proceedings goes last
+    let mut out = String::new();
+    for publication in &publications {
+        let entry = bibtex::Entry::new("inproceedings", &publication.canonical_key)
+            .field("author", Some(&bibtex::author_list(&publication.authors)))
+            .field("title", Some(&publication.title))
+            .raw_field("year", Some(&year))
+            .field("pages", publication.pages.as_deref())
+            .raw_field("doi", publication.doi.as_deref())
+            .raw_field("eprint", publication.arxiv_ids.first().map(String::as_str))
+            .raw_field("archiveprefix", publication.arxiv_ids.first().map(|_| "arXiv"))
+            .raw_field("crossref", Some(proceedings.key()));
+        out.push_str(&entry.render());
+        out.push('\n');
+    }
+    out.push_str(&proceedings.render());
+
+    Ok(([(header::CONTENT_TYPE, "application/x-bibtex; charset=utf-8")], out).into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/conferences/{id}/new-authors",
+    tag = "conferences",
+    params(("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)")),
+    responses(
+        (status = 200, description = "First-timers: authors of the conference with no publication in an earlier year, at any venue", body = Vec<NewAuthor>),
+        (status = 400, description = "Invalid ID format"),
+        (status = 404, description = "Conference not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn conference_new_authors(
+    State(pool): State<Pool<Postgres>>,
+    Path(id_or_slug): Path<String>,
+) -> Result<Json<Vec<NewAuthor>>, StatusCode> {
+    let id = resolve_conference(&pool, &id_or_slug).await?;
+    let year = sqlx::query_scalar!("SELECT year FROM conferences WHERE id = $1", id)
+        .fetch_optional(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch conference: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    // Counted live rather than from `author_stats.first_year`, which lags
+    // until the next stats refresh. Another venue in the same year doesn't
+    // disqualify anyone: the edition still counts as their first.
+    let authors = sqlx::query_as!(
+        NewAuthor,
+        r#"
+        SELECT
+            a.id, a.full_name, a.affiliation,
+            COUNT(DISTINCT p.id) as "publication_count!"
+        FROM authorships au
+        JOIN publications p ON au.publication_id = p.id AND p.deleted_at IS NULL
+        JOIN authors a ON au.author_id = a.id AND a.deleted_at IS NULL
+        WHERE p.conference_id = $1
+          AND NOT EXISTS (
+              SELECT 1
+              FROM authorships earlier
+              JOIN publications ep ON earlier.publication_id = ep.id AND ep.deleted_at IS NULL
+              JOIN conferences ec ON ep.conference_id = ec.id
+              WHERE earlier.author_id = a.id AND ec.year < $2
+          )
+        GROUP BY a.id
+        ORDER BY a.family_name NULLS LAST, a.full_name, a.id
+        "#,
+        id,
+        year
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch new authors: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(authors))
+}
+
+#[utoipa::path(
+    get,
+    path = "/conferences/{id}/affiliations",
+    tag = "conferences",
+    params(("id" = String, Path, description = "Conference ID (UUID) or slug (e.g., QIP2024, QCRYPT2018, TQC2022)")),
+    responses(
+        (status = 200, description = "Affiliations of the conference's authors with distinct-author counts, most authors first", body = Vec<AffiliationCount>),
+        (status = 400, description = "Invalid ID format"),
+        (status = 404, description = "Conference not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn conference_affiliations(
+    State(pool): State<Pool<Postgres>>,
+    Path(id_or_slug): Path<String>,
+) -> Result<Json<Vec<AffiliationCount>>, StatusCode> {
+    let id = resolve_conference(&pool, &id_or_slug).await?;
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM conferences WHERE id = $1) as "exists!""#,
+        id
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to check conference: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if !exists {
         return Err(StatusCode::NOT_FOUND);
     }
 
-    Ok(StatusCode::NO_CONTENT)
+    // The affiliation printed on the paper, else the author's current one
+    let rows = sqlx::query!(
+        r#"
+        SELECT DISTINCT
+            au.author_id,
+            COALESCE(NULLIF(TRIM(au.affiliation), ''), NULLIF(TRIM(a.affiliation), '')) as "affiliation!"
+        FROM authorships au
+        JOIN publications p ON au.publication_id = p.id AND p.deleted_at IS NULL
+        JOIN authors a ON au.author_id = a.id AND a.deleted_at IS NULL
+        WHERE p.conference_id = $1
+          AND COALESCE(NULLIF(TRIM(au.affiliation), ''), NULLIF(TRIM(a.affiliation), '')) IS NOT NULL
+        "#,
+        id
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch conference affiliations: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let institutions = load_institutions(&pool).await?;
+    let candidates: Vec<_> = institutions.iter().map(|i| i.as_candidate()).collect();
+
+    // Group by matched institution, else by normalized spelling
+    #[derive(Default)]
+    struct Group {
+        authors: HashSet<Uuid>,
+        spellings: HashMap<String, HashSet<Uuid>>,
+    }
+    let mut groups: HashMap<(Option<Uuid>, String), Group> = HashMap::new();
+    for row in rows {
+        let institution_id = affiliation::best_match(&row.affiliation, &candidates);
+        let key = match institution_id {
+            Some(_) => String::new(),
+            None => affiliation::normalize(&row.affiliation),
+        };
+        if institution_id.is_none() && key.is_empty() {
+            continue;
+        }
+        let group = groups.entry((institution_id, key)).or_default();
+        group.authors.insert(row.author_id);
+        group.spellings.entry(row.affiliation).or_default().insert(row.author_id);
+    }
+
+    let mut counts: Vec<AffiliationCount> = groups
+        .into_iter()
+        .map(|((institution_id, _), group)| {
+            let mut variants: Vec<(String, usize)> = group
+                .spellings
+                .into_iter()
+                .map(|(spelling, authors)| (spelling, authors.len()))
+                .collect();
+            variants.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            let variants: Vec<String> = variants.into_iter().map(|(spelling, _)| spelling).collect();
+            let affiliation = institution_id
+                .and_then(|id| institutions.iter().find(|i| i.id == id))
+                .map(|i| i.name.clone())
+                .unwrap_or_else(|| variants[0].clone());
+            AffiliationCount {
+                affiliation,
+                institution_id,
+                author_count: group.authors.len() as i64,
+                variants,
+            }
+        })
+        .collect();
+    counts.sort_by(|a, b| {
+        b.author_count
+            .cmp(&a.author_count)
+            .then_with(|| a.affiliation.cmp(&b.affiliation))
+    });
+
+    Ok(Json(counts))
 }
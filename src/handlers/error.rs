@@ -0,0 +1,73 @@
+use axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
+use uuid::Uuid;
+
+/// PostgreSQL SQLSTATE for `unique_violation`.
+const PG_UNIQUE_VIOLATION: &str = "23505";
+
+/// Whether a query failed on a UNIQUE constraint.
+pub(crate) fn is_unique_violation(err: &sqlx::Error) -> bool {
+    err.as_database_error()
+        .is_some_and(|db_err| db_err.code().as_deref() == Some(PG_UNIQUE_VIOLATION))
+}
+
+/// Error with a JSON body (`{"error", "message"}`, the same shape the auth and
+/// rate-limit middleware use), for failures where the status code alone
+/// doesn't tell the client what to fix.
+///
+/// Handlers that return `Result<_, ApiError>` can still use `?` on the
+/// `Result<_, StatusCode>` helpers; those convert to a body with just the
+/// status reason.
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub message: String,
+    /// The record the error is about, e.g. the existing row behind a 409
+    pub id: Option<Uuid>,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self { status, message: message.into(), id: None }
+    }
+
+    pub fn with_id(mut self, id: Uuid) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, message)
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::CONFLICT, message)
+    }
+}
+
+impl From<StatusCode> for ApiError {
+    fn from(status: StatusCode) -> Self {
+        Self::new(status, status.canonical_reason().unwrap_or_default())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let mut body = json!({
+            "error": self.status.canonical_reason().unwrap_or_default(),
+            "message": self.message,
+        });
+        if let Some(id) = self.id {
+            body["id"] = json!(id);
+        }
+        (
+            self.status,
+            [(header::CONTENT_TYPE, "application/json")],
+            body.to_string(),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,34 @@
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+
+/// Weak validator for a response built from rows last modified at
+/// `last_modified`. `row_count` catches hard deletes, which leave no newer
+/// timestamp behind.
+pub(crate) fn weak_etag(last_modified: DateTime<Utc>, row_count: i64) -> String {
+    format!("W/\"{:x}-{:x}\"", last_modified.timestamp_micros(), row_count)
+}
+
+/// Whether `If-None-Match` already names `etag`. Uses the weak comparison
+/// RFC 9110 prescribes for this header, so `W/` prefixes are ignored.
+pub(crate) fn is_fresh(headers: &HeaderMap, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .any(|tag| tag.trim() == "*" || opaque(tag) == etag)
+}
+
+/// 304 for a client whose cached copy is still current.
+pub(crate) fn not_modified(etag: &str) -> Response {
+    (StatusCode::NOT_MODIFIED, [(header::ETAG, etag.to_string())]).into_response()
+}
+
+/// Attach `etag` to a full response.
+pub(crate) fn with_etag(response: impl IntoResponse, etag: &str) -> Response {
+    ([(header::ETAG, etag.to_string())], response).into_response()
+}
@@ -0,0 +1,158 @@
+//! Change notifications for downstream consumers (search index sync, live
+//! dashboards)
+//!
+//! Mutating handlers call `publish` once their transaction has committed.
+//! Each event goes to every `GET /events` subscriber through an in-process
+//! broadcast channel, and, when `WEBHOOK_URL` is set, is queued for a single
+//! delivery worker that POSTs events there as JSON in publish order. Publishing
+//! never waits on the receiver: once the bounded queue is full, further events
+//! are dropped with a warning. Delivery is best effort and failures are only
+//! logged.
+//!
+//! Subscriber streams end once `shutdown_token` is cancelled, so open SSE
+//! connections don't hold up a graceful shutdown.
+
+use std::convert::Infallible;
+use std::sync::OnceLock;
+
+use axum::response::sse::{Event, KeepAlive, Sse};
+use chrono::Utc;
+use futures_util::{Stream, StreamExt};
+use tokio::sync::{broadcast, mpsc, mpsc::error::TrySendError};
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::models::ChangeEvent;
+use crate::utils::http;
+
+/// Events buffered per SSE subscriber before a slow one starts missing them.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// Events waiting for webhook delivery before new ones are dropped.
+const WEBHOOK_QUEUE_CAPACITY: usize = 256;
+
+/// Process-wide channel feeding `GET /events`.
+fn channel() -> &'static broadcast::Sender<ChangeEvent> {
+    static CHANNEL: OnceLock<broadcast::Sender<ChangeEvent>> = OnceLock::new();
+    CHANNEL.get_or_init(|| broadcast::channel(CHANNEL_CAPACITY).0)
+}
+
+/// Process-wide token cancelled when the server starts shutting down; every
+/// `GET /events` stream finishes when it fires.
+pub fn shutdown_token() -> &'static CancellationToken {
+    static TOKEN: OnceLock<CancellationToken> = OnceLock::new();
+    TOKEN.get_or_init(CancellationToken::new)
+}
+
+/// Announce a committed change: `event` is `created`, `updated` or `deleted`.
+pub(crate) fn publish(event: &str, entity_type: &str, id: Uuid) {
+    send(ChangeEvent {
+        event: event.to_string(),
+        entity_type: entity_type.to_string(),
+        id,
+        count: None,
+        timestamp: Utc::now(),
+    });
+}
+
+/// Announce a committed bulk delete of a conference's rows (`event` is e.g.
+/// `publications_wiped`) as one event carrying the number removed.
+pub(crate) fn publish_wipe(event: &str, conference_id: Uuid, count: i64) {
+    send(ChangeEvent {
+        event: event.to_string(),
+        entity_type: "conference".to_string(),
+        id: conference_id,
+        count: Some(count),
+        timestamp: Utc::now(),
+    });
+}
+
+fn send(change: ChangeEvent) {
+    // No subscribers is fine; the event just isn't streamed
+    let _ = channel().send(change.clone());
+
+    let Some(url) = std::env::var("WEBHOOK_URL").ok().filter(|url| !url.trim().is_empty()) else {
+        return;
+    };
+    match webhook_queue().try_send((url, change)) {
+        Ok(()) => {}
+        Err(TrySendError::Full((_, change))) => tracing::warn!(
+            "Webhook queue is full; dropping {} {} {} event",
+            change.entity_type, change.id, change.event
+        ),
+        Err(TrySendError::Closed(_)) => {
+            tracing::error!("Webhook delivery worker has stopped; dropping change event")
+        }
+    }
+}
+
+/// Queue feeding the webhook delivery worker, started on first use.
+///
+/// The worker runs on its own thread and runtime so it outlives whichever
+/// request happened to start it, and delivers one event at a time, in order.
+fn webhook_queue() -> &'static mpsc::Sender<(String, ChangeEvent)> {
+    static QUEUE: OnceLock<mpsc::Sender<(String, ChangeEvent)>> = OnceLock::new();
+    QUEUE.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel(WEBHOOK_QUEUE_CAPACITY);
+        std::thread::Builder::new()
+            .name("webhook-delivery".to_string())
+            .spawn(move || {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .expect("webhook runtime builds")
+                    .block_on(deliver_webhooks(receiver))
+            })
+            .expect("failed to spawn webhook delivery thread");
+        sender
+    })
+}
+
+/// POST each queued event to its webhook URL, logging failures.
+async fn deliver_webhooks(mut queue: mpsc::Receiver<(String, ChangeEvent)>) {
+    while let Some((url, change)) = queue.recv().await {
+        let body = match serde_json::to_string(&change) {
+            Ok(body) => body,
+            Err(e) => {
+                tracing::error!("Failed to serialize change event: {:?}", e);
+                continue;
+            }
+        };
+        match http::post_json(&url, &body).await {
+            Ok(response) if (200..300).contains(&response.status) => {}
+            Ok(response) => tracing::warn!(
+                "Webhook for {} {} {} returned HTTP {}",
+                change.entity_type, change.id, change.event, response.status
+            ),
+            Err(e) => tracing::warn!(
+                "Webhook for {} {} {} failed: {}",
+                change.entity_type, change.id, change.event, e
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/events",
+    tag = "events",
+    responses(
+        (status = 200, description = "Server-Sent Events stream. Each committed create/update/delete is sent as an unnamed event whose data is a `ChangeEvent`; a `lagged` event (data: number of missed events) means the client fell behind and should refetch", content_type = "text/event-stream", body = ChangeEvent)
+    )
+)]
+pub async fn change_stream() -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = BroadcastStream::new(channel().subscribe()).map(|message| {
+        Ok(match message {
+            Ok(change) => Event::default().json_data(&change).unwrap_or_else(|e| {
+                tracing::error!("Failed to serialize change event: {:?}", e);
+                Event::default().comment("unserializable event")
+            }),
+            Err(BroadcastStreamRecvError::Lagged(missed)) => {
+                Event::default().event("lagged").data(missed.to_string())
+            }
+        })
+    });
+    let stream = stream.take_until(shutdown_token().cancelled());
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
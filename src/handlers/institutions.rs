@@ -0,0 +1,230 @@
+use axum::{extract::State, http::StatusCode, Json};
+use sqlx::{PgConnection, PgExecutor, Pool, Postgres};
+use uuid::Uuid;
+
+use super::error::{is_unique_violation, ApiError};
+use super::events;
+use crate::models::{CreateInstitution, Institution};
+use crate::utils::affiliation::{self, InstitutionNames};
+use crate::utils::country::validate_country_code;
+use crate::utils::{validate_text_len, MAX_NAME_LEN};
+
+/// An institution's id, canonical name, and every name it is matched by
+/// (canonical name first, then aliases).
+pub(crate) struct InstitutionRecord {
+    pub(crate) id: Uuid,
+    pub(crate) name: String,
+    pub(crate) names: Vec<String>,
+}
+
+impl InstitutionRecord {
+    pub(crate) fn as_candidate(&self) -> InstitutionNames<'_> {
+        InstitutionNames { id: self.id, names: &self.names }
+    }
+}
+
+/// Every institution, for matching affiliations against.
+pub(crate) async fn load_institutions(
+    executor: impl PgExecutor<'_>,
+) -> Result<Vec<InstitutionRecord>, StatusCode> {
+    sqlx::query_as!(
+        InstitutionRecord,
+        r#"SELECT id, name, array_prepend(name, aliases) as "names!" FROM institutions ORDER BY name"#
+    )
+    .fetch_all(executor)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch institutions: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+/// Canonical institution for a free-text affiliation, matched against every
+/// institution's name and aliases (see `affiliation::best_match`).
+pub(crate) async fn match_institution(
+    conn: &mut PgConnection,
+    affiliation: Option<&str>,
+) -> Result<Option<Uuid>, StatusCode> {
+    let Some(affiliation) = affiliation.filter(|a| !a.trim().is_empty()) else {
+        return Ok(None);
+    };
+
+    let institutions = load_institutions(conn).await?;
+    let candidates: Vec<InstitutionNames> = institutions.iter().map(InstitutionRecord::as_candidate).collect();
+
+    Ok(affiliation::best_match(affiliation, &candidates))
+}
+
+#[utoipa::path(
+    get,
+    path = "/institutions",
+    tag = "institutions",
+    responses(
+        (status = 200, description = "Institutions with their linked author counts, most authors first", body = Vec<Institution>),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn list_institutions(
+    State(pool): State<Pool<Postgres>>,
+) -> Result<Json<Vec<Institution>>, StatusCode> {
+    let institutions = sqlx::query_as!(
+        Institution,
+        r#"
+        SELECT
+            i.id, i.name, i.aliases, i.ror_id, i.country_code,
+            COUNT(a.id) as "author_count!",
+            i.created_at, i.updated_at
+        FROM institutions i
+        LEFT JOIN authors a ON a.institution_id = i.id AND a.deleted_at IS NULL
+        GROUP BY i.id
+        ORDER BY COUNT(a.id) DESC, i.name
+        "#
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch institutions: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(institutions))
+}
+
+#[utoipa::path(
+    post,
+    path = "/institutions",
+    tag = "institutions",
+    request_body = CreateInstitution,
+    responses(
+        (status = 201, description = "Institution created; live authors without an institution whose affiliation matches it are linked", body = Institution),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 409, description = "An institution with this name or ROR id already exists"),
+        (status = 422, description = "Empty name, invalid ROR id or country code"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_institution(
+    State(pool): State<Pool<Postgres>>,
+    Json(new_institution): Json<CreateInstitution>,
+) -> Result<(StatusCode, Json<Institution>), ApiError> {
+    let name = new_institution.name.trim();
+    if name.is_empty() {
+        return Err(ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, "Institution name must not be empty"));
+    }
+    validate_text_len(name, MAX_NAME_LEN)?;
+    let aliases: Vec<String> = new_institution
+        .aliases
+        .iter()
+        .map(|a| a.trim().to_string())
+        .filter(|a| !a.is_empty())
+        .collect();
+    for alias in &aliases {
+        validate_text_len(alias, MAX_NAME_LEN)?;
+    }
+    let ror_id = match new_institution.ror_id.as_deref() {
+        Some(ror_id) => Some(affiliation::normalize_ror_id(ror_id).ok_or_else(|| {
+            ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, format!("Invalid ROR id \"{}\"", ror_id))
+        })?),
+        None => None,
+    };
+    let country_code = new_institution
+        .country_code
+        .as_deref()
+        .map(validate_country_code)
+        .transpose()
+        .map_err(|message| ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, message))?;
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let institution = sqlx::query!(
+        r#"
+        INSERT INTO institutions (name, aliases, ror_id, country_code, creator, modifier)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, created_at, updated_at
+        "#,
+        name,
+        &aliases,
+        ror_id,
+        country_code,
+        new_institution.creator,
+        new_institution.modifier
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        if is_unique_violation(&e) {
+            return ApiError::conflict(format!("Institution \"{}\" already exists", name));
+        }
+        tracing::error!("Failed to create institution: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR.into()
+    })?;
+
+    // Link authors that match the new institution and aren't linked yet
+    let unlinked = sqlx::query!(
+        r#"
+        SELECT id, affiliation as "affiliation!"
+        FROM authors
+        WHERE institution_id IS NULL AND deleted_at IS NULL
+          AND affiliation IS NOT NULL AND affiliation <> ''
+        "#
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch unlinked authors: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let names: Vec<String> = std::iter::once(name.to_string()).chain(aliases.iter().cloned()).collect();
+    let candidate = [InstitutionNames { id: institution.id, names: &names }];
+    let matched: Vec<Uuid> = unlinked
+        .into_iter()
+        .filter(|a| affiliation::best_match(&a.affiliation, &candidate).is_some())
+        .map(|a| a.id)
+        .collect();
+
+    sqlx::query!(
+        r#"
+        UPDATE authors
+        SET institution_id = $1, modifier = $2, updated_at = NOW()
+        WHERE id = ANY($3)
+        "#,
+        institution.id,
+        new_institution.modifier,
+        &matched
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to link authors to institution: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit institution: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    for author_id in &matched {
+        events::publish("updated", "author", *author_id);
+    }
+
+    Ok((
+        StatusCode::CREATED,
+        Json(Institution {
+            id: institution.id,
+            name: name.to_string(),
+            aliases,
+            ror_id,
+            country_code: country_code.map(str::to_string),
+            author_count: matched.len() as i64,
+            created_at: institution.created_at,
+            updated_at: institution.updated_at,
+        }),
+    ))
+}
@@ -3,6 +3,18 @@ pub mod authors;
 pub mod publications;
 pub mod committees;
 pub mod authorships;
+pub mod stats;
+pub mod institutions;
+pub mod params;
+pub mod error;
+pub mod events;
+pub mod names;
+pub mod review;
+pub mod topics;
+pub(crate) mod audit;
+pub(crate) mod etag;
+pub(crate) mod page_size;
+pub(crate) mod resolve;
 pub mod web;
 
 pub use conferences::*;
@@ -10,3 +22,11 @@ pub use authors::*;
 pub use publications::*;
 pub use committees::*;
 pub use authorships::*;
+pub use stats::*;
+pub use institutions::*;
+pub use params::*;
+pub use error::*;
+pub use events::*;
+pub use names::*;
+pub use review::*;
+pub use topics::*;
@@ -0,0 +1,47 @@
+use axum::{extract::Query, http::StatusCode, Json};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::models::{NameVariants, NormalizedName};
+use crate::utils::{generate_name_variants, normalize_name, normalize_name_loose, validate_text_len, MAX_NAME_LEN};
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct NameQuery {
+    /// Name as it would be entered, e.g. `Albert Einstein`
+    pub name: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/utils/name-variants",
+    tag = "utils",
+    params(NameQuery),
+    responses(
+        (status = 200, description = "Normalized variants the name is matched under", body = NameVariants),
+        (status = 400, description = "Missing or overlong `name`")
+    )
+)]
+pub async fn name_variants(Query(query): Query<NameQuery>) -> Result<Json<NameVariants>, StatusCode> {
+    validate_text_len(&query.name, MAX_NAME_LEN)?;
+    let variants = generate_name_variants(&query.name);
+    Ok(Json(NameVariants { name: query.name, variants }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/utils/normalize",
+    tag = "utils",
+    params(NameQuery),
+    responses(
+        (status = 200, description = "Normalized and loose forms of the name", body = NormalizedName),
+        (status = 400, description = "Missing or overlong `name`")
+    )
+)]
+pub async fn normalized_name(Query(query): Query<NameQuery>) -> Result<Json<NormalizedName>, StatusCode> {
+    validate_text_len(&query.name, MAX_NAME_LEN)?;
+    Ok(Json(NormalizedName {
+        normalized: normalize_name(&query.name),
+        loose: normalize_name_loose(&query.name),
+        name: query.name,
+    }))
+}
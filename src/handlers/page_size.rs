@@ -0,0 +1,11 @@
+use axum::http::HeaderName;
+use axum::response::{IntoResponse, Response};
+
+/// Header carrying the `limit` a list endpoint actually applied, after
+/// defaulting and clamping to `MAX_PAGE_SIZE`.
+pub(crate) const X_PAGE_SIZE: HeaderName = HeaderName::from_static("x-page-size");
+
+/// Attach the effective page size to a list response.
+pub(crate) fn with_page_size(response: impl IntoResponse, limit: i64) -> Response {
+    ([(X_PAGE_SIZE, limit.to_string())], response).into_response()
+}
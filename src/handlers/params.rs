@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use super::error::ApiError;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct IncludeDeletedQuery {
+    /// Also return soft-deleted records (default: false)
+    pub include_deleted: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct UpdatedSinceQuery {
+    /// Only records changed at or after this RFC 3339 timestamp (e.g.
+    /// 2024-01-01T00:00:00Z), ordered by `(updated_at, id)`; for incremental sync
+    pub updated_since: Option<DateTime<Utc>>,
+    /// With `updated_since`, skip records changed at exactly that time whose id
+    /// is not after this one. Pass the last record's `updated_at` and `id` to
+    /// resume a sync without skipping or repeating rows
+    pub after_id: Option<Uuid>,
+}
+
+/// The id to resume an `updated_since` listing after; nil (before every id) when
+/// not given, so rows at exactly `updated_since` are included. 400 for
+/// `after_id` without `updated_since`.
+pub(crate) fn sync_after_id(
+    updated_since: Option<DateTime<Utc>>,
+    after_id: Option<Uuid>,
+) -> Result<Uuid, ApiError> {
+    match (updated_since, after_id) {
+        (None, Some(_)) => Err(ApiError::bad_request("after_id needs updated_since")),
+        (_, after_id) => Ok(after_id.unwrap_or_default()),
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SoftDeleteQuery {
+    /// Who is deleting or restoring the record; stored in `modifier` and the audit trail
+    pub modifier: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct IncludeQuery {
+    /// Comma-separated related data to embed (opt-in); see each endpoint for
+    /// the accepted values
+    pub include: Option<String>,
+}
+
+impl IncludeQuery {
+    /// The requested expansions, checked against those the endpoint supports
+    /// (400 naming the first unknown one).
+    pub(crate) fn parse(&self, supported: &[&'static str]) -> Result<Vec<&'static str>, ApiError> {
+        let mut expansions = Vec::new();
+        for value in self.include.iter().flat_map(|v| v.split(',')).map(str::trim).filter(|v| !v.is_empty()) {
+            let known = supported.iter().find(|s| s.eq_ignore_ascii_case(value)).ok_or_else(|| {
+                ApiError::bad_request(format!("Unknown include {:?}; expected one of {}", value, supported.join(", ")))
+            })?;
+            if !expansions.contains(known) {
+                expansions.push(*known);
+            }
+        }
+        Ok(expansions)
+    }
+}
@@ -1,6 +1,7 @@
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
 use serde::Deserialize;
@@ -8,85 +9,119 @@ use sqlx::{Pool, Postgres};
 use utoipa::IntoParams;
 use uuid::Uuid;
 
-use crate::models::{CreatePublication, PaperType, Publication, UpdatePublication};
+use super::page_size::with_page_size;
+use super::params::{sync_after_id, IncludeDeletedQuery, SoftDeleteQuery, UpdatedSinceQuery};
+use super::resolve::resolve_conference_filter;
+use super::topics::normalize_topic;
+use super::error::{is_unique_violation, ApiError};
+use super::events;
+use crate::models::{
+    AwardedPublication, CreatePublication, MergePublication, PaperType, Publication, PublicationDuplicateCluster,
+    RelatedPublication, UpdatePublication,
+};
 use crate::utils::{
-    clamp_pagination, parse_conference_slug, validate_optional_text_len, validate_optional_url,
-    validate_text_len, MAX_ABSTRACT_LEN, MAX_NAME_LEN, MAX_TITLE_LEN,
+    arxiv_key, canonical_venue, clamp_pagination, cluster_by_keys, doi, validate_optional_text_len, validate_optional_url,
+    validate_text_len, validate_venue, supplied, make_publication_key, next_free_key, CollapseWhitespace, UpdateMode, MAX_ABSTRACT_LEN, MAX_NAME_LEN, MAX_TITLE_LEN,
+    search_language, DEFAULT_SEARCH_LANGUAGE,
 };
+use crate::utils::jsonld::{self, ArticleAuthor};
 
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct PublicationQuery {
     /// Full-text search term
     pub search: Option<String>,
+    /// Text search configuration for `search`: english (default, stemmed), simple
+    /// (no stemming or stop words), french, german, italian, spanish, portuguese, dutch, russian
+    pub lang: Option<String>,
     /// Filter by conference ID (UUID) - use 'conference' for slug-based filtering
     pub conference_id: Option<Uuid>,
     /// Filter by conference slug (e.g., QIP2024, QCRYPT2018, TQC2022)
     pub conference: Option<String>,
     /// Filter by paper type
     pub paper_type: Option<String>,
+    /// Only publications tagged with this topic (e.g. error-correction; normalized like on add)
+    pub topic: Option<String>,
+    /// Only publications from this venue's conferences, across years (QIP, QCRYPT, TQC; case-insensitive)
+    pub venue: Option<String>,
+    /// Only publications from conferences held in or after this year (any venue)
+    pub year_from: Option<i32>,
+    /// Only publications from conferences held in or before this year (any venue)
+    pub year_to: Option<i32>,
+    /// `abstract_word_count` (shortest first) or `-abstract_word_count` (longest first);
+    /// publications without an abstract come last. Default: relevance for `search`,
+    /// otherwise session and title within a conference, newest first across all
+    pub sort: Option<String>,
     /// Maximum number of results (default: 100)
     pub limit: Option<i64>,
     /// Number of results to skip (default: 0)
     pub offset: Option<i64>,
+    /// Also return soft-deleted publications (default: false)
+    pub include_deleted: Option<bool>,
 }
 
-/// Resolve conference filter to UUID (from either conference_id or conference slug)
-async fn resolve_conference_filter(
-    pool: &Pool<Postgres>,
-    conference_id: Option<Uuid>,
-    conference_slug: Option<&str>,
-) -> Result<Option<Uuid>, StatusCode> {
-    // If conference_id is provided directly, use it
-    if let Some(id) = conference_id {
-        return Ok(Some(id));
-    }
-
-    // If conference slug is provided, resolve it
-    if let Some(slug) = conference_slug {
-        if let Some((venue, year)) = parse_conference_slug(slug) {
-            let result = sqlx::query_scalar!(
-                "SELECT id FROM conferences WHERE venue = $1 AND year = $2",
-                venue,
-                year
-            )
-            .fetch_optional(pool)
-            .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-            if let Some(id) = result {
-                return Ok(Some(id));
-            }
-            // Conference not found
-            return Err(StatusCode::NOT_FOUND);
-        }
-        // Invalid slug format
-        return Err(StatusCode::BAD_REQUEST);
+/// Parse `sort` into `Some(descending)`; `None` keeps the default order.
+fn parse_sort(sort: Option<&str>) -> Result<Option<bool>, String> {
+    match sort.map(str::trim) {
+        None => Ok(None),
+        Some("abstract_word_count") => Ok(Some(false)),
+        Some("-abstract_word_count") => Ok(Some(true)),
+        Some(other) => Err(format!(
+            "Unknown sort {:?}; expected abstract_word_count or -abstract_word_count",
+            other
+        )),
     }
+}
 
-    Ok(None)
+/// Canonicalize a client-supplied DOI (see `utils::doi`); 422 if it isn't one.
+fn canonical_doi(value: Option<String>) -> Result<Option<String>, StatusCode> {
+    value
+        .map(|raw| {
+            doi::normalize(&raw).ok_or_else(|| {
+                tracing::warn!(doi = %raw, "Rejected invalid DOI");
+                StatusCode::UNPROCESSABLE_ENTITY
+            })
+        })
+        .transpose()
 }
 
 #[utoipa::path(
     get,
     path = "/publications",
     tag = "publications",
-    params(PublicationQuery),
+    params(PublicationQuery, UpdatedSinceQuery),
     responses(
-        (status = 200, description = "List of publications", body = Vec<Publication>),
+        (status = 200, description = "List of publications", body = Vec<Publication>, headers(("x-page-size" = i64, description = "Effective `limit` after clamping to `MAX_PAGE_SIZE`"))),
+        (status = 400, description = "Negative limit or offset, unknown lang, unknown sort, invalid topic, unknown venue, year_from after year_to, or `after_id` without `updated_since`"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn list_publications(
     State(pool): State<Pool<Postgres>>,
     Query(query): Query<PublicationQuery>,
-) -> Result<Json<Vec<Publication>>, StatusCode> {
-    let (limit, offset) = clamp_pagination(query.limit, query.offset);
+    Query(sync): Query<UpdatedSinceQuery>,
+) -> Result<Response, ApiError> {
+    let (limit, offset) = clamp_pagination(query.limit, query.offset).map_err(ApiError::bad_request)?;
+    let include_deleted = query.include_deleted.unwrap_or(false);
+    let after_id = sync_after_id(sync.updated_since, sync.after_id)?;
+    let lang = search_language(query.lang.as_deref()).map_err(ApiError::bad_request)?;
+    let sort_desc = parse_sort(query.sort.as_deref()).map_err(ApiError::bad_request)?;
+    let topic = query
+        .topic
+        .as_deref()
+        .map(|t| normalize_topic(t, StatusCode::BAD_REQUEST))
+        .transpose()?;
+    let venue = query.venue.as_deref().map(validate_venue).transpose().map_err(ApiError::bad_request)?;
+    if let (Some(from), Some(to)) = (query.year_from, query.year_to) {
+        if from > to {
+            return Err(ApiError::bad_request(format!("year_from ({}) is after year_to ({})", from, to)));
+        }
+    }
 
     // Resolve conference filter (supports both UUID and slug like QIP2024)
     let conf_id = resolve_conference_filter(&pool, query.conference_id, query.conference.as_deref()).await?;
 
     // Build dynamic query based on filters
-    let publications = if let Some(search) = &query.search {
+    let publications = if let Some(search) = query.search.as_ref().filter(|_| lang == DEFAULT_SEARCH_LANGUAGE) {
         // Full-text search
         sqlx::query_as!(
             Publication,
@@ -94,21 +129,90 @@ pub async fn list_publications(
             SELECT
                 id, conference_id, canonical_key, doi,
                 COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
-                title, abstract as "abstract_text",
+                title, abstract as "abstract_text", abstract_word_count,
                 paper_type as "paper_type: PaperType",
                 pages, session_name, presentation_url, video_url, youtube_id,
                 award, award_date, published_date,
                 presenter_author_id, is_proceedings_track,
                 talk_date, talk_time, duration_minutes,
-                created_at, updated_at
+                talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+                created_at, updated_at, deleted_at
             FROM publications
             WHERE search_vector @@ plainto_tsquery('english', $1)
-            ORDER BY ts_rank(search_vector, plainto_tsquery('english', $1)) DESC
+              AND ($4 OR deleted_at IS NULL)
+              AND ($5::timestamptz IS NULL OR (updated_at, id) > ($5, $11))
+              AND ($7::text IS NULL OR EXISTS (SELECT 1 FROM publication_topics t WHERE t.publication_id = publications.id AND t.topic = $7))
+              AND ($8::int IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.year >= $8))
+              AND ($9::int IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.year <= $9))
+              AND ($10::text IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.venue = $10))
+            ORDER BY CASE WHEN $5::timestamptz IS NOT NULL THEN updated_at END,
+                     CASE WHEN $5::timestamptz IS NOT NULL THEN id END,
+                     CASE WHEN $6::bool IS FALSE THEN abstract_word_count END ASC NULLS LAST,
+                     CASE WHEN $6::bool THEN abstract_word_count END DESC NULLS LAST,
+                     ts_rank(search_vector, plainto_tsquery('english', $1)) DESC
+            LIMIT $2 OFFSET $3
+            "#,
+            search,
+            limit,
+            offset,
+            include_deleted,
+            sync.updated_since,
+            sort_desc,
+            topic,
+            query.year_from,
+            query.year_to,
+            venue,
+            after_id
+        )
+        .fetch_all(&pool)
+        .await
+    } else if let Some(search) = &query.search {
+        // Other configurations aren't indexed: the vector is built per row, with
+        // the same title/abstract weights as `search_vector`
+        sqlx::query_as!(
+            Publication,
+            r#"
+            SELECT
+                id, conference_id, canonical_key, doi,
+                COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+                title, abstract as "abstract_text", abstract_word_count,
+                paper_type as "paper_type: PaperType",
+                pages, session_name, presentation_url, video_url, youtube_id,
+                award, award_date, published_date,
+                presenter_author_id, is_proceedings_track,
+                talk_date, talk_time, duration_minutes,
+                talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+                created_at, updated_at, deleted_at
+            FROM publications,
+                 LATERAL (SELECT plainto_tsquery($6::regconfig, $1) as q) tsq,
+                 LATERAL (SELECT setweight(to_tsvector($6::regconfig, title), 'A') ||
+                                 setweight(to_tsvector($6::regconfig, COALESCE(abstract, '')), 'B') as v) doc
+            WHERE doc.v @@ tsq.q
+              AND ($4 OR deleted_at IS NULL)
+              AND ($5::timestamptz IS NULL OR (updated_at, id) > ($5, $12))
+              AND ($8::text IS NULL OR EXISTS (SELECT 1 FROM publication_topics t WHERE t.publication_id = publications.id AND t.topic = $8))
+              AND ($9::int IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.year >= $9))
+              AND ($10::int IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.year <= $10))
+              AND ($11::text IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.venue = $11))
+            ORDER BY CASE WHEN $5::timestamptz IS NOT NULL THEN updated_at END,
+                     CASE WHEN $5::timestamptz IS NOT NULL THEN id END,
+                     CASE WHEN $7::bool IS FALSE THEN abstract_word_count END ASC NULLS LAST,
+                     CASE WHEN $7::bool THEN abstract_word_count END DESC NULLS LAST,
+                     ts_rank(doc.v, tsq.q) DESC
             LIMIT $2 OFFSET $3
             "#,
             search,
             limit,
-            offset
+            offset,
+            include_deleted,
+            sync.updated_since,
+            lang as &str,
+            sort_desc,
+            topic,
+            query.year_from,
+            query.year_to,
+            venue,
+            after_id
         )
         .fetch_all(&pool)
         .await
@@ -119,21 +223,40 @@ pub async fn list_publications(
             SELECT
                 id, conference_id, canonical_key, doi,
                 COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
-                title, abstract as "abstract_text",
+                title, abstract as "abstract_text", abstract_word_count,
                 paper_type as "paper_type: PaperType",
                 pages, session_name, presentation_url, video_url, youtube_id,
                 award, award_date, published_date,
                 presenter_author_id, is_proceedings_track,
                 talk_date, talk_time, duration_minutes,
-                created_at, updated_at
+                talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+                created_at, updated_at, deleted_at
             FROM publications
             WHERE conference_id = $1
-            ORDER BY session_name, title
+              AND ($4 OR deleted_at IS NULL)
+              AND ($5::timestamptz IS NULL OR (updated_at, id) > ($5, $11))
+              AND ($7::text IS NULL OR EXISTS (SELECT 1 FROM publication_topics t WHERE t.publication_id = publications.id AND t.topic = $7))
+              AND ($8::int IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.year >= $8))
+              AND ($9::int IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.year <= $9))
+              AND ($10::text IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.venue = $10))
+            ORDER BY CASE WHEN $5::timestamptz IS NOT NULL THEN updated_at END,
+                     CASE WHEN $5::timestamptz IS NOT NULL THEN id END,
+                     CASE WHEN $6::bool IS FALSE THEN abstract_word_count END ASC NULLS LAST,
+                     CASE WHEN $6::bool THEN abstract_word_count END DESC NULLS LAST,
+                     session_name, title
             LIMIT $2 OFFSET $3
             "#,
             cid,
             limit,
-            offset
+            offset,
+            include_deleted,
+            sync.updated_since,
+            sort_desc,
+            topic,
+            query.year_from,
+            query.year_to,
+            venue,
+            after_id
         )
         .fetch_all(&pool)
         .await
@@ -144,19 +267,38 @@ pub async fn list_publications(
             SELECT
                 id, conference_id, canonical_key, doi,
                 COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
-                title, abstract as "abstract_text",
+                title, abstract as "abstract_text", abstract_word_count,
                 paper_type as "paper_type: PaperType",
                 pages, session_name, presentation_url, video_url, youtube_id,
                 award, award_date, published_date,
                 presenter_author_id, is_proceedings_track,
                 talk_date, talk_time, duration_minutes,
-                created_at, updated_at
+                talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+                created_at, updated_at, deleted_at
             FROM publications
-            ORDER BY created_at DESC
+            WHERE ($3 OR deleted_at IS NULL)
+              AND ($4::timestamptz IS NULL OR (updated_at, id) > ($4, $10))
+              AND ($6::text IS NULL OR EXISTS (SELECT 1 FROM publication_topics t WHERE t.publication_id = publications.id AND t.topic = $6))
+              AND ($7::int IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.year >= $7))
+              AND ($8::int IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.year <= $8))
+              AND ($9::text IS NULL OR conference_id IN (SELECT c.id FROM conferences c WHERE c.venue = $9))
+            ORDER BY CASE WHEN $4::timestamptz IS NOT NULL THEN updated_at END,
+                     CASE WHEN $4::timestamptz IS NOT NULL THEN id END,
+                     CASE WHEN $5::bool IS FALSE THEN abstract_word_count END ASC NULLS LAST,
+                     CASE WHEN $5::bool THEN abstract_word_count END DESC NULLS LAST,
+                     created_at DESC
             LIMIT $1 OFFSET $2
             "#,
             limit,
-            offset
+            offset,
+            include_deleted,
+            sync.updated_since,
+            sort_desc,
+            topic,
+            query.year_from,
+            query.year_to,
+            venue,
+            after_id
         )
         .fetch_all(&pool)
         .await
@@ -166,46 +308,133 @@ pub async fn list_publications(
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
-    Ok(Json(publications))
+    Ok(with_page_size(Json(publications), limit))
 }
 
 #[utoipa::path(
     get,
     path = "/publications/{id}",
     tag = "publications",
-    params(("id" = Uuid, Path, description = "Publication ID")),
+    params(("id" = Uuid, Path, description = "Publication ID"), IncludeDeletedQuery),
     responses(
         (status = 200, description = "Publication found", body = Publication),
-        (status = 404, description = "Publication not found")
+        (status = 404, description = "Publication not found"),
+        (status = 500, description = "Internal server error")
     )
 )]
 pub async fn get_publication(
     State(pool): State<Pool<Postgres>>,
-    Path(id): Path<Uuid>,
-) -> Result<Json<Publication>, StatusCode> {
+    Path(id): Path<String>,
+    Query(query): Query<IncludeDeletedQuery>,
+) -> Result<Response, StatusCode> {
+    // The router can't match a suffix after a path parameter, so
+    // `/publications/{id}.jsonld` arrives here
+    if let Some(id) = id.strip_suffix(".jsonld") {
+        let id = id.parse().map_err(|_| StatusCode::BAD_REQUEST)?;
+        return get_publication_jsonld(State(pool), Path(id)).await;
+    }
+    let id: Uuid = id.parse().map_err(|_| StatusCode::BAD_REQUEST)?;
+
     let publication = sqlx::query_as!(
         Publication,
         r#"
         SELECT
             id, conference_id, canonical_key, doi,
             COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
-            title, abstract as "abstract_text",
+            title, abstract as "abstract_text", abstract_word_count,
             paper_type as "paper_type: PaperType",
             pages, session_name, presentation_url, video_url, youtube_id,
             award, award_date, published_date,
             presenter_author_id, is_proceedings_track,
             talk_date, talk_time, duration_minutes,
-            created_at, updated_at
+            talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+            created_at, updated_at, deleted_at
         FROM publications
-        WHERE id = $1
+        WHERE id = $1 AND ($2 OR deleted_at IS NULL)
+        "#,
+        id,
+        query.include_deleted.unwrap_or(false)
+    )
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch publication: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(Json(publication).into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/publications/{id}.jsonld",
+    tag = "publications",
+    params(("id" = Uuid, Path, description = "Publication ID")),
+    responses(
+        (status = 200, description = "schema.org ScholarlyArticle (application/ld+json)", body = Object),
+        (status = 404, description = "Publication not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_publication_jsonld(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, StatusCode> {
+    let publication = sqlx::query!(
+        r#"
+        SELECT
+            p.title,
+            COALESCE(p.arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+            p.doi,
+            COALESCE(p.published_date, p.talk_date, c.start_date) as published_date
+        FROM publications p
+        JOIN conferences c ON p.conference_id = c.id
+        WHERE p.id = $1 AND p.deleted_at IS NULL
         "#,
         id
     )
-    .fetch_one(&pool)
+    .fetch_optional(&pool)
     .await
-    .map_err(|_| StatusCode::NOT_FOUND)?;
+    .map_err(|e| {
+        tracing::error!("Failed to fetch publication: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
 
-    Ok(Json(publication))
+    let authors = sqlx::query!(
+        r#"
+        SELECT au.published_as_name as name, a.orcid
+        FROM authorships au
+        JOIN authors a ON au.author_id = a.id
+        WHERE au.publication_id = $1
+        ORDER BY au.author_position
+        "#,
+        id
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch publication authors: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let authors: Vec<ArticleAuthor> = authors
+        .iter()
+        .map(|a| ArticleAuthor {
+            name: &a.name,
+            orcid: a.orcid.as_deref(),
+        })
+        .collect();
+    let article = jsonld::scholarly_article(
+        &publication.title,
+        &authors,
+        publication.published_date,
+        &publication.arxiv_ids,
+        publication.doi.as_deref(),
+    );
+
+    Ok(([(header::CONTENT_TYPE, "application/ld+json")], Json(article)).into_response())
 }
 
 #[utoipa::path(
@@ -216,6 +445,7 @@ pub async fn get_publication(
     responses(
         (status = 201, description = "Publication created", body = Publication),
         (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 422, description = "Invalid DOI, or `canonical_key` omitted for an unknown conference"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -224,10 +454,12 @@ pub async fn get_publication(
 )]
 pub async fn create_publication(
     State(pool): State<Pool<Postgres>>,
-    Json(new_pub): Json<CreatePublication>,
+    Json(mut new_pub): Json<CreatePublication>,
 ) -> Result<(StatusCode, Json<Publication>), StatusCode> {
+    new_pub.title.collapse_whitespace();
+    new_pub.session_name.collapse_whitespace();
     validate_text_len(&new_pub.title, MAX_TITLE_LEN)?;
-    validate_text_len(&new_pub.canonical_key, MAX_NAME_LEN)?;
+    validate_optional_text_len(new_pub.canonical_key.as_deref(), MAX_NAME_LEN)?;
     validate_optional_text_len(new_pub.abstract_text.as_deref(), MAX_ABSTRACT_LEN)?;
     validate_optional_text_len(new_pub.doi.as_deref(), MAX_NAME_LEN)?;
     validate_optional_text_len(new_pub.session_name.as_deref(), MAX_TITLE_LEN)?;
@@ -237,65 +469,113 @@ pub async fn create_publication(
     validate_optional_url(new_pub.presentation_url.as_deref())?;
     validate_optional_url(new_pub.video_url.as_deref())?;
 
+    let doi = canonical_doi(new_pub.doi)?;
     let arxiv_ids = new_pub.arxiv_ids.unwrap_or_default();
     let paper_type = new_pub.paper_type.unwrap_or(PaperType::Regular);
     let is_proceedings_track = new_pub.is_proceedings_track.unwrap_or(false);
+    let generated = new_pub.canonical_key.is_none();
+    let mut canonical_key = match new_pub.canonical_key.take() {
+        Some(key) => key,
+        None => generate_canonical_key(&pool, new_pub.conference_id, &new_pub.title).await?,
+    };
 
-    let publication = sqlx::query_as!(
-        Publication,
-        r#"
-        INSERT INTO publications (
-            conference_id, canonical_key, doi, arxiv_ids,
-            title, abstract, paper_type,
-            pages, session_name, presentation_url, video_url, youtube_id,
-            award, award_date, published_date,
-            presenter_author_id, is_proceedings_track,
-            talk_date, talk_time, duration_minutes,
-            creator, modifier
+    let mut attempts = 0;
+    let publication = loop {
+        attempts += 1;
+        let result = sqlx::query_as!(
+            Publication,
+            r#"
+            INSERT INTO publications (
+                conference_id, canonical_key, doi, arxiv_ids,
+                title, abstract, paper_type,
+                pages, session_name, presentation_url, video_url, youtube_id,
+                award, award_date, published_date,
+                presenter_author_id, is_proceedings_track,
+                talk_date, talk_time, duration_minutes,
+                creator, modifier
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)
+            RETURNING
+                id, conference_id, canonical_key, doi,
+                COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+                title, abstract as "abstract_text", abstract_word_count,
+                paper_type as "paper_type: PaperType",
+                pages, session_name, presentation_url, video_url, youtube_id,
+                award, award_date, published_date,
+                presenter_author_id, is_proceedings_track,
+                talk_date, talk_time, duration_minutes,
+                talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+                created_at, updated_at, deleted_at
+            "#,
+            new_pub.conference_id,
+            canonical_key,
+            doi,
+            &arxiv_ids,
+            new_pub.title,
+            new_pub.abstract_text,
+            paper_type as PaperType,
+            new_pub.pages,
+            new_pub.session_name,
+            new_pub.presentation_url,
+            new_pub.video_url,
+            new_pub.youtube_id,
+            new_pub.award,
+            new_pub.award_date,
+            new_pub.published_date,
+            new_pub.presenter_author_id,
+            is_proceedings_track,
+            new_pub.talk_date,
+            new_pub.talk_time,
+            new_pub.duration_minutes,
+            new_pub.creator,
+            new_pub.modifier
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)
-        RETURNING
-            id, conference_id, canonical_key, doi,
-            COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
-            title, abstract as "abstract_text",
-            paper_type as "paper_type: PaperType",
-            pages, session_name, presentation_url, video_url, youtube_id,
-            award, award_date, published_date,
-            presenter_author_id, is_proceedings_track,
-            talk_date, talk_time, duration_minutes,
-            created_at, updated_at
-        "#,
-        new_pub.conference_id,
-        new_pub.canonical_key,
-        new_pub.doi,
-        &arxiv_ids,
-        new_pub.title,
-        new_pub.abstract_text,
-        paper_type as PaperType,
-        new_pub.pages,
-        new_pub.session_name,
-        new_pub.presentation_url,
-        new_pub.video_url,
-        new_pub.youtube_id,
-        new_pub.award,
-        new_pub.award_date,
-        new_pub.published_date,
-        new_pub.presenter_author_id,
-        is_proceedings_track,
-        new_pub.talk_date,
-        new_pub.talk_time,
-        new_pub.duration_minutes,
-        new_pub.creator,
-        new_pub.modifier
+        .fetch_one(&pool)
+        .await;
+        match result {
+            Ok(publication) => break publication,
+            // A concurrent create took the generated key; pick the next one
+            Err(e) if generated && attempts < 3 && is_unique_violation(&e) => {
+                canonical_key = generate_canonical_key(&pool, new_pub.conference_id, &new_pub.title).await?;
+            }
+            Err(e) => {
+                tracing::error!("Failed to create publication: {:?}", e);
+                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+        }
+    };
+
+    events::publish("created", "publication", publication.id);
+
+    Ok((StatusCode::CREATED, Json(publication)))
+}
+
+/// First unused `canonical_key` built from the conference and title. 422 if
+/// the conference doesn't exist, since there is nothing to build the key from.
+async fn generate_canonical_key(pool: &Pool<Postgres>, conference_id: Uuid, title: &str) -> Result<String, StatusCode> {
+    let conference = sqlx::query!("SELECT venue, year FROM conferences WHERE id = $1", conference_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch conference for canonical key: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+    let base = make_publication_key(&conference.venue, conference.year, title);
+
+    // Soft-deleted publications keep their keys, so they count as taken
+    let taken = sqlx::query_scalar!(
+        "SELECT canonical_key FROM publications WHERE canonical_key = $1 OR canonical_key LIKE $1 || '-%'",
+        base
     )
-    .fetch_one(&pool)
+    .fetch_all(pool)
     .await
     .map_err(|e| {
-        tracing::error!("Failed to create publication: {:?}", e);
+        tracing::error!("Failed to fetch canonical keys: {:?}", e);
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
-    Ok((StatusCode::CREATED, Json(publication)))
+    Ok(next_free_key(&base, &taken))
 }
 
 #[utoipa::path(
@@ -305,9 +585,10 @@ pub async fn create_publication(
     params(("id" = Uuid, Path, description = "Publication ID")),
     request_body = UpdatePublication,
     responses(
-        (status = 200, description = "Publication updated", body = Publication),
+        (status = 200, description = "Publication replaced", body = Publication),
         (status = 401, description = "Unauthorized - missing or invalid token"),
         (status = 404, description = "Publication not found"),
+        (status = 422, description = "Required field `title` missing, invalid DOI, or presenter is not one of the publication's authors"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -318,16 +599,52 @@ pub async fn update_publication(
     State(pool): State<Pool<Postgres>>,
     Path(id): Path<Uuid>,
     Json(update): Json<UpdatePublication>,
-) -> Result<Json<Publication>, StatusCode> {
+) -> Result<Json<Publication>, ApiError> {
+    apply_publication_update(&pool, id, update, UpdateMode::Replace).await
+}
+
+#[utoipa::path(
+    patch,
+    path = "/publications/{id}",
+    tag = "publications",
+    params(("id" = Uuid, Path, description = "Publication ID")),
+    request_body = UpdatePublication,
+    responses(
+        (status = 200, description = "Publication updated", body = Publication),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Publication not found"),
+        (status = 422, description = "Invalid DOI, or presenter is not one of the publication's authors"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn patch_publication(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Json(update): Json<UpdatePublication>,
+) -> Result<Json<Publication>, ApiError> {
+    apply_publication_update(&pool, id, update, UpdateMode::Merge).await
+}
+
+async fn apply_publication_update(
+    pool: &Pool<Postgres>,
+    id: Uuid,
+    mut update: UpdatePublication,
+    mode: UpdateMode,
+) -> Result<Json<Publication>, ApiError> {
+    update.title.collapse_whitespace();
+    update.session_name.collapse_whitespace();
     validate_optional_text_len(update.title.as_deref(), MAX_TITLE_LEN)?;
-    validate_optional_text_len(update.abstract_text.as_deref(), MAX_ABSTRACT_LEN)?;
-    validate_optional_text_len(update.doi.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.session_name.as_deref(), MAX_TITLE_LEN)?;
-    validate_optional_text_len(update.award.as_deref(), MAX_TITLE_LEN)?;
-    validate_optional_text_len(update.youtube_id.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_text_len(update.pages.as_deref(), MAX_NAME_LEN)?;
-    validate_optional_url(update.presentation_url.as_deref())?;
-    validate_optional_url(update.video_url.as_deref())?;
+    validate_optional_text_len(supplied(&update.abstract_text), MAX_ABSTRACT_LEN)?;
+    validate_optional_text_len(supplied(&update.doi), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&update.session_name), MAX_TITLE_LEN)?;
+    validate_optional_text_len(supplied(&update.award), MAX_TITLE_LEN)?;
+    validate_optional_text_len(supplied(&update.youtube_id), MAX_NAME_LEN)?;
+    validate_optional_text_len(supplied(&update.pages), MAX_NAME_LEN)?;
+    validate_optional_url(supplied(&update.presentation_url))?;
+    validate_optional_url(supplied(&update.video_url))?;
 
     // First fetch the existing publication
     let existing = sqlx::query_as!(
@@ -336,26 +653,54 @@ pub async fn update_publication(
         SELECT
             id, conference_id, canonical_key, doi,
             COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
-            title, abstract as "abstract_text",
+            title, abstract as "abstract_text", abstract_word_count,
             paper_type as "paper_type: PaperType",
             pages, session_name, presentation_url, video_url, youtube_id,
             award, award_date, published_date,
             presenter_author_id, is_proceedings_track,
             talk_date, talk_time, duration_minutes,
-            created_at, updated_at
+            talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+            created_at, updated_at, deleted_at
         FROM publications
-        WHERE id = $1
+        WHERE id = $1 AND deleted_at IS NULL
         "#,
         id
     )
-    .fetch_optional(&pool)
+    .fetch_optional(pool)
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     .ok_or(StatusCode::NOT_FOUND)?;
 
-    let arxiv_ids = update.arxiv_ids.unwrap_or(existing.arxiv_ids);
+    let doi = update.doi.map(canonical_doi).transpose()?;
+    let arxiv_ids = mode.or_default(update.arxiv_ids, existing.arxiv_ids, Vec::new());
+    let presenter_author_id = mode.optional(update.presenter_author_id, existing.presenter_author_id);
+
+    // The `ensure_presenter_is_author` trigger would reject this too, but only
+    // as an opaque 500; check up front so the client learns what was wrong.
+    if let Some(presenter_id) = presenter_author_id.filter(|p| Some(*p) != existing.presenter_author_id) {
+        let is_author = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM authorships WHERE publication_id = $1 AND author_id = $2
+            ) as "exists!"
+            "#,
+            id,
+            presenter_id
+        )
+        .fetch_one(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check presenter authorship: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+        if !is_author {
+            return Err(ApiError::new(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("presenter_author_id {} is not one of the publication's authors", presenter_id),
+            ));
+        }
+    }
 
-    // Update with provided values or keep existing
     let publication = sqlx::query_as!(
         Publication,
         r#"
@@ -385,42 +730,45 @@ pub async fn update_publication(
         RETURNING
             id, conference_id, canonical_key, doi,
             COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
-            title, abstract as "abstract_text",
+            title, abstract as "abstract_text", abstract_word_count,
             paper_type as "paper_type: PaperType",
             pages, session_name, presentation_url, video_url, youtube_id,
             award, award_date, published_date,
             presenter_author_id, is_proceedings_track,
             talk_date, talk_time, duration_minutes,
-            created_at, updated_at
+            talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+            created_at, updated_at, deleted_at
         "#,
-        update.doi.or(existing.doi),
+        mode.optional(doi, existing.doi),
         &arxiv_ids,
-        update.title.unwrap_or(existing.title),
-        update.abstract_text.or(existing.abstract_text),
-        update.paper_type.unwrap_or(existing.paper_type) as PaperType,
-        update.pages.or(existing.pages),
-        update.session_name.or(existing.session_name),
-        update.presentation_url.or(existing.presentation_url),
-        update.video_url.or(existing.video_url),
-        update.youtube_id.or(existing.youtube_id),
-        update.award.or(existing.award),
-        update.award_date.or(existing.award_date),
-        update.published_date.or(existing.published_date),
-        update.presenter_author_id.or(existing.presenter_author_id),
-        update.is_proceedings_track.unwrap_or(existing.is_proceedings_track),
-        update.talk_date.or(existing.talk_date),
-        update.talk_time.or(existing.talk_time),
-        update.duration_minutes.or(existing.duration_minutes),
+        mode.required(update.title, existing.title)?,
+        mode.optional(update.abstract_text, existing.abstract_text),
+        mode.or_default(update.paper_type, existing.paper_type, PaperType::Regular) as PaperType,
+        mode.optional(update.pages, existing.pages),
+        mode.optional(update.session_name, existing.session_name),
+        mode.optional(update.presentation_url, existing.presentation_url),
+        mode.optional(update.video_url, existing.video_url),
+        mode.optional(update.youtube_id, existing.youtube_id),
+        mode.optional(update.award, existing.award),
+        mode.optional(update.award_date, existing.award_date),
+        mode.optional(update.published_date, existing.published_date),
+        presenter_author_id,
+        mode.or_default(update.is_proceedings_track, existing.is_proceedings_track, false),
+        mode.optional(update.talk_date, existing.talk_date),
+        mode.optional(update.talk_time, existing.talk_time),
+        mode.optional(update.duration_minutes, existing.duration_minutes),
         update.modifier,
         id
     )
-    .fetch_one(&pool)
+    .fetch_one(pool)
     .await
     .map_err(|e| {
         tracing::error!("Failed to update publication: {:?}", e);
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
+    events::publish("updated", "publication", id);
+
     Ok(Json(publication))
 }
 
@@ -428,11 +776,11 @@ pub async fn update_publication(
     delete,
     path = "/publications/{id}",
     tag = "publications",
-    params(("id" = Uuid, Path, description = "Publication ID")),
+    params(("id" = Uuid, Path, description = "Publication ID"), SoftDeleteQuery),
     responses(
-        (status = 204, description = "Publication deleted"),
+        (status = 204, description = "Publication soft-deleted"),
         (status = 401, description = "Unauthorized - missing or invalid token"),
-        (status = 404, description = "Publication not found"),
+        (status = 404, description = "Publication not found or already deleted"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -442,15 +790,549 @@ pub async fn update_publication(
 pub async fn delete_publication(
     State(pool): State<Pool<Postgres>>,
     Path(id): Path<Uuid>,
+    Query(query): Query<SoftDeleteQuery>,
 ) -> Result<StatusCode, StatusCode> {
-    let result = sqlx::query!("DELETE FROM publications WHERE id = $1", id)
-        .execute(&pool)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    validate_optional_text_len(query.modifier.as_deref(), MAX_NAME_LEN)?;
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE publications
+        SET deleted_at = NOW(), modifier = COALESCE($2, modifier), updated_at = NOW()
+        WHERE id = $1 AND deleted_at IS NULL
+        "#,
+        id,
+        query.modifier
+    )
+    .execute(&pool)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     if result.rows_affected() == 0 {
         return Err(StatusCode::NOT_FOUND);
     }
 
+    events::publish("deleted", "publication", id);
+
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[utoipa::path(
+    post,
+    path = "/publications/{id}/restore",
+    tag = "publications",
+    params(("id" = Uuid, Path, description = "Publication ID"), SoftDeleteQuery),
+    responses(
+        (status = 200, description = "Publication restored", body = Publication),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Publication not found or not deleted"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn restore_publication(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<SoftDeleteQuery>,
+) -> Result<Json<Publication>, StatusCode> {
+    validate_optional_text_len(query.modifier.as_deref(), MAX_NAME_LEN)?;
+
+    let publication = sqlx::query_as!(
+        Publication,
+        r#"
+        UPDATE publications
+        SET deleted_at = NULL, modifier = COALESCE($2, modifier), updated_at = NOW()
+        WHERE id = $1 AND deleted_at IS NOT NULL
+        RETURNING
+            id, conference_id, canonical_key, doi,
+            COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+            title, abstract as "abstract_text", abstract_word_count,
+            paper_type as "paper_type: PaperType",
+            pages, session_name, presentation_url, video_url, youtube_id,
+            award, award_date, published_date,
+            presenter_author_id, is_proceedings_track,
+            talk_date, talk_time, duration_minutes,
+            talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+            created_at, updated_at, deleted_at
+        "#,
+        id,
+        query.modifier
+    )
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to restore publication: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    events::publish("updated", "publication", id);
+
+    Ok(Json(publication))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct DuplicatesQuery {
+    /// Conference slug (e.g., QIP2024, qip-2024)
+    pub conference: Option<String>,
+    /// Conference ID (UUID), alternative to `conference`
+    pub conference_id: Option<Uuid>,
+}
+
+/// Identifiers that mark two publications as the same paper
+fn duplicate_keys(publication: &Publication) -> Vec<String> {
+    publication
+        .doi
+        .iter()
+        .map(|d| format!("doi:{}", d.to_lowercase()))
+        .chain(publication.arxiv_ids.iter().map(|a| format!("arxiv:{}", arxiv_key(a))))
+        .collect()
+}
+
+#[utoipa::path(
+    get,
+    path = "/publications/duplicates",
+    tag = "publications",
+    params(DuplicatesQuery),
+    responses(
+        (status = 200, description = "Clusters of publications sharing a DOI or arXiv id", body = Vec<PublicationDuplicateCluster>),
+        (status = 400, description = "Missing or malformed conference"),
+        (status = 404, description = "Conference not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn publication_duplicates(
+    State(pool): State<Pool<Postgres>>,
+    Query(query): Query<DuplicatesQuery>,
+) -> Result<Json<Vec<PublicationDuplicateCluster>>, StatusCode> {
+    let conf_id = resolve_conference_filter(&pool, query.conference_id, query.conference.as_deref())
+        .await?
+        .ok_or(StatusCode::BAD_REQUEST)?;
+
+    let publications = sqlx::query_as!(
+        Publication,
+        r#"
+        SELECT
+            id, conference_id, canonical_key, doi,
+            COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+            title, abstract as "abstract_text", abstract_word_count,
+            paper_type as "paper_type: PaperType",
+            pages, session_name, presentation_url, video_url, youtube_id,
+            award, award_date, published_date,
+            presenter_author_id, is_proceedings_track,
+            talk_date, talk_time, duration_minutes,
+            talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+            created_at, updated_at, deleted_at
+        FROM publications
+        WHERE conference_id = $1 AND deleted_at IS NULL
+          AND (doi IS NOT NULL OR cardinality(arxiv_ids) > 0)
+        ORDER BY canonical_key
+        "#,
+        conf_id
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch publications for duplicate check: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let keys: Vec<Vec<String>> = publications.iter().map(duplicate_keys).collect();
+    let clusters = cluster_by_keys(&keys);
+
+    let mut slots: Vec<Option<Publication>> = publications.into_iter().map(Some).collect();
+    let result = clusters
+        .into_iter()
+        .map(|members| {
+            let mut shared_keys: Vec<String> = Vec::new();
+            for (n, &i) in members.iter().enumerate() {
+                for key in &keys[i] {
+                    let seen_elsewhere = members[n + 1..].iter().any(|&j| keys[j].contains(key));
+                    if seen_elsewhere && !shared_keys.contains(key) {
+                        shared_keys.push(key.clone());
+                    }
+                }
+            }
+            shared_keys.sort();
+
+            PublicationDuplicateCluster {
+                shared_keys,
+                publications: members.iter().filter_map(|&i| slots[i].take()).collect(),
+            }
+        })
+        .collect();
+
+    Ok(Json(result))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RelatedQuery {
+    /// Leave out publications from the same conference (default: true)
+    pub other_conferences: Option<bool>,
+    /// Maximum number of results (default: 100)
+    pub limit: Option<i64>,
+    /// Number of results to skip (default: 0)
+    pub offset: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/publications/{id}/related",
+    tag = "publications",
+    params(("id" = Uuid, Path, description = "Publication ID"), RelatedQuery),
+    responses(
+        (status = 200, description = "Live publications sharing at least one author, most shared authors first, then newest conference", body = Vec<RelatedPublication>, headers(("x-page-size" = i64, description = "Effective `limit` after clamping to `MAX_PAGE_SIZE`"))),
+        (status = 400, description = "Negative limit or offset"),
+        (status = 404, description = "Publication not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn related_publications(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<RelatedQuery>,
+) -> Result<Response, ApiError> {
+    let (limit, offset) = clamp_pagination(query.limit, query.offset).map_err(ApiError::bad_request)?;
+
+    let conference_id = sqlx::query_scalar!(
+        "SELECT conference_id FROM publications WHERE id = $1 AND deleted_at IS NULL",
+        id
+    )
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to look up publication: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    let ranked = sqlx::query!(
+        r#"
+        SELECT
+            p.id,
+            COUNT(*) as "shared_author_count!",
+            ARRAY_AGG(theirs.author_id ORDER BY theirs.author_position) as "shared_author_ids!"
+        FROM authorships ours
+        JOIN authorships theirs ON theirs.author_id = ours.author_id AND theirs.publication_id <> ours.publication_id
+        JOIN publications p ON p.id = theirs.publication_id AND p.deleted_at IS NULL
+        JOIN conferences c ON c.id = p.conference_id
+        WHERE ours.publication_id = $1
+          AND NOT ($2 AND p.conference_id = $3)
+        GROUP BY p.id, c.year, c.start_date, p.title
+        ORDER BY COUNT(*) DESC, c.year DESC, c.start_date DESC NULLS LAST, p.title, p.id
+        LIMIT $4 OFFSET $5
+        "#,
+        id,
+        query.other_conferences.unwrap_or(true),
+        conference_id,
+        limit,
+        offset
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to rank related publications: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let ids: Vec<Uuid> = ranked.iter().map(|r| r.id).collect();
+    let mut publications = sqlx::query_as!(
+        Publication,
+        r#"
+        SELECT
+            id, conference_id, canonical_key, doi,
+            COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+            title, abstract as "abstract_text", abstract_word_count,
+            paper_type as "paper_type: PaperType",
+            pages, session_name, presentation_url, video_url, youtube_id,
+            award, award_date, published_date,
+            presenter_author_id, is_proceedings_track,
+            talk_date, talk_time, duration_minutes,
+            talk_datetime_utc(conference_id, talk_date, talk_time) as talk_datetime_utc,
+            created_at, updated_at, deleted_at
+        FROM publications
+        WHERE id = ANY($1)
+        "#,
+        &ids
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch related publications: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // Back into rank order
+    let related: Vec<RelatedPublication> = ranked
+        .into_iter()
+        .filter_map(|r| {
+            let i = publications.iter().position(|p| p.id == r.id)?;
+            Some(RelatedPublication {
+                publication: publications.swap_remove(i),
+                shared_author_count: r.shared_author_count,
+                shared_author_ids: r.shared_author_ids,
+            })
+        })
+        .collect();
+
+    Ok(with_page_size(Json(related), limit))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AwardQuery {
+    /// Restrict to one venue (QIP, QCRYPT, TQC; case-insensitive). All venues when omitted.
+    pub venue: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/awards",
+    tag = "publications",
+    params(AwardQuery),
+    responses(
+        (status = 200, description = "Award-winning publications, oldest conference first", body = Vec<AwardedPublication>),
+        (status = 400, description = "Unknown venue"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn list_awards(
+    State(pool): State<Pool<Postgres>>,
+    Query(query): Query<AwardQuery>,
+) -> Result<Json<Vec<AwardedPublication>>, StatusCode> {
+    let venue = match query.venue.as_deref() {
+        Some(v) => Some(canonical_venue(v).ok_or(StatusCode::BAD_REQUEST)?),
+        None => None,
+    };
+
+    let awards = sqlx::query_as!(
+        AwardedPublication,
+        r#"
+        SELECT
+            p.id as publication_id,
+            c.id as conference_id,
+            c.venue || c.year::text as "conference!",
+            c.venue, c.year,
+            p.title,
+            ARRAY(
+                SELECT au.published_as_name
+                FROM authorships au
+                WHERE au.publication_id = p.id
+                ORDER BY au.author_position
+            ) as "authors!",
+            p.award as "award!",
+            p.award_date
+        FROM publications p
+        JOIN conferences c ON p.conference_id = c.id
+        WHERE p.award IS NOT NULL AND p.award <> ''
+          AND p.deleted_at IS NULL
+          AND ($1::text IS NULL OR c.venue = $1)
+        ORDER BY c.year, c.venue, p.award_date NULLS LAST, p.title
+        "#,
+        venue
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch awards: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(awards))
+}
+
+#[utoipa::path(
+    post,
+    path = "/publications/{id}/merge",
+    tag = "publications",
+    params(("id" = Uuid, Path, description = "ID of the publication to keep")),
+    request_body = MergePublication,
+    responses(
+        (status = 200, description = "Kept publication after the merge", body = Publication),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Kept or source publication not found"),
+        (status = 422, description = "source_id is the kept publication"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn merge_publication(
+    State(pool): State<Pool<Postgres>>,
+    Path(keep_id): Path<Uuid>,
+    Json(payload): Json<MergePublication>,
+) -> Result<Json<Publication>, ApiError> {
+    validate_text_len(&payload.modifier, MAX_NAME_LEN)?;
+    let source_id = payload.source_id;
+    if source_id == keep_id {
+        return Err(ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "source_id must differ from the publication being kept",
+        ));
+    }
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let locked = sqlx::query!(
+        r#"
+        SELECT id, COALESCE(arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!"
+        FROM publications WHERE id = ANY($1) AND deleted_at IS NULL
+        FOR UPDATE
+        "#,
+        &[keep_id, source_id][..]
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let mut arxiv_ids: Vec<String> = Vec::new();
+    for (id, role) in [(keep_id, "Kept"), (source_id, "Source")] {
+        let row = locked.iter().find(|r| r.id == id).ok_or_else(|| {
+            ApiError::new(
+                StatusCode::NOT_FOUND,
+                format!("{} publication {} not found", role, id),
+            )
+        })?;
+        // One entry per paper: 2101.00001 and 2101.00001v2 are the same preprint,
+        // and the kept paper's spelling wins
+        for arxiv_id in &row.arxiv_ids {
+            if !arxiv_ids.iter().any(|a| arxiv_key(a) == arxiv_key(arxiv_id)) {
+                arxiv_ids.push(arxiv_id.clone());
+            }
+        }
+    }
+
+    // Authors already on the kept paper keep their existing authorship
+    let dropped_authorships = sqlx::query_scalar!(
+        r#"
+        DELETE FROM authorships
+        WHERE publication_id = $2
+          AND author_id IN (SELECT author_id FROM authorships WHERE publication_id = $1)
+        RETURNING id
+        "#,
+        keep_id,
+        source_id
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to drop overlapping authorships: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // The rest are appended after the kept paper's authors, in their old order
+    let moved_authorships = sqlx::query_scalar!(
+        r#"
+        UPDATE authorships a SET
+            publication_id = $1,
+            author_position = (m.max_pos + s.rn)::int,
+            modifier = $3,
+            updated_at = NOW()
+        FROM (
+            SELECT id, ROW_NUMBER() OVER (ORDER BY author_position) AS rn
+            FROM authorships WHERE publication_id = $2
+        ) s,
+        (SELECT COALESCE(MAX(author_position), 0) AS max_pos FROM authorships WHERE publication_id = $1) m
+        WHERE a.id = s.id
+        RETURNING a.id
+        "#,
+        keep_id,
+        source_id,
+        payload.modifier
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to move authorships: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // The kept paper gets the union of both papers' topics
+    sqlx::query!(
+        r#"
+        INSERT INTO publication_topics (publication_id, topic, creator)
+        SELECT $1, topic, $3 FROM publication_topics WHERE publication_id = $2
+        ON CONFLICT DO NOTHING
+        "#,
+        keep_id,
+        source_id,
+        payload.modifier
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to copy publication topics: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let publication = sqlx::query_as!(
+        Publication,
+        r#"
+        UPDATE publications k SET
+            doi = COALESCE(k.doi, s.doi),
+            arxiv_ids = $4,
+            abstract = COALESCE(k.abstract, s.abstract),
+            presentation_url = COALESCE(k.presentation_url, s.presentation_url),
+            video_url = COALESCE(k.video_url, s.video_url),
+            youtube_id = COALESCE(k.youtube_id, s.youtube_id),
+            presenter_author_id = COALESCE(k.presenter_author_id, s.presenter_author_id),
+            modifier = $3,
+            updated_at = NOW()
+        FROM publications s
+        WHERE k.id = $1 AND s.id = $2
+        RETURNING
+            k.id, k.conference_id, k.canonical_key, k.doi,
+            COALESCE(k.arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+            k.title, k.abstract as "abstract_text", k.abstract_word_count,
+            k.paper_type as "paper_type: PaperType",
+            k.pages, k.session_name, k.presentation_url, k.video_url, k.youtube_id,
+            k.award, k.award_date, k.published_date,
+            k.presenter_author_id, k.is_proceedings_track,
+            k.talk_date, k.talk_time, k.duration_minutes,
+            talk_datetime_utc(k.conference_id, k.talk_date, k.talk_time) as talk_datetime_utc,
+            k.created_at, k.updated_at, k.deleted_at
+        "#,
+        keep_id,
+        source_id,
+        payload.modifier,
+        &arxiv_ids
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to merge publication fields: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    sqlx::query!(
+        "UPDATE publications SET deleted_at = NOW(), modifier = $2, updated_at = NOW() WHERE id = $1",
+        source_id,
+        payload.modifier
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to delete merged publication: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit publication merge: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    for authorship_id in dropped_authorships {
+        events::publish("deleted", "authorship", authorship_id);
+    }
+    for authorship_id in moved_authorships {
+        events::publish("updated", "authorship", authorship_id);
+    }
+    events::publish("updated", "publication", keep_id);
+    events::publish("deleted", "publication", source_id);
+
+    Ok(Json(publication))
+}
@@ -0,0 +1,45 @@
+use axum::http::StatusCode;
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+use crate::utils::parse_conference_slug;
+
+/// Resolve a conference reference — a UUID or a slug such as `QIP2024` or
+/// `qip-2024` — to the conference id.
+///
+/// A UUID is returned as is, without checking that the conference exists;
+/// a slug that parses but matches no conference is `NOT_FOUND`, and anything
+/// else is `BAD_REQUEST`.
+pub(crate) async fn resolve_conference(pool: &Pool<Postgres>, id_or_slug: &str) -> Result<Uuid, StatusCode> {
+    if let Ok(id) = Uuid::parse_str(id_or_slug) {
+        return Ok(id);
+    }
+
+    let (venue, year) = parse_conference_slug(id_or_slug).ok_or(StatusCode::BAD_REQUEST)?;
+    sqlx::query_scalar!(
+        "SELECT id FROM conferences WHERE venue = $1 AND year = $2",
+        venue,
+        year
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to resolve conference slug: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// Resolve the optional `conference_id` / `conference` (slug) pair that list
+/// endpoints filter by; `conference_id` wins when both are given.
+pub(crate) async fn resolve_conference_filter(
+    pool: &Pool<Postgres>,
+    conference_id: Option<Uuid>,
+    conference_slug: Option<&str>,
+) -> Result<Option<Uuid>, StatusCode> {
+    match (conference_id, conference_slug) {
+        (Some(id), _) => Ok(Some(id)),
+        (None, Some(slug)) => resolve_conference(pool, slug).await.map(Some),
+        (None, None) => Ok(None),
+    }
+}
@@ -0,0 +1,569 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Response,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use sqlx::{Pool, Postgres, Transaction};
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use super::audit::{record_change, snapshot};
+use super::error::ApiError;
+use super::events;
+use super::page_size::with_page_size;
+use crate::models::{
+    Author, AuthorReviewItem, ResolveAuthorReview, ReviewAuthor, ReviewDecision, ReviewScan, ReviewStatus,
+};
+use crate::utils::{
+    clamp_pagination, name_similarity, validate_optional_text_len, validate_text_len, MAX_NAME_LEN, MAX_TITLE_LEN,
+};
+
+/// Trigram similarity of two `normalized_name`s for the pair to be scored at
+/// all; cheap and index-backed, so it only has to be generous
+const REVIEW_TRIGRAM_THRESHOLD: f64 = 0.5;
+
+/// Minimum `name_similarity` for a pair to enter the queue
+pub const MIN_REVIEW_SCORE: f64 = 0.85;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ReviewQuery {
+    /// Also list deferred pairs, after the pending ones (default: false)
+    pub include_deferred: Option<bool>,
+    /// Maximum number of results (default: 100)
+    pub limit: Option<i64>,
+    /// Number of results to skip (default: 0)
+    pub offset: Option<i64>,
+}
+
+/// Flat row behind `AuthorReviewItem`; both sides of the pair come from one join.
+struct ReviewRow {
+    id: Uuid,
+    score: f64,
+    status: ReviewStatus,
+    note: Option<String>,
+    resolved_by: Option<String>,
+    resolved_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    author_id: Uuid,
+    author_name: String,
+    author_affiliation: Option<String>,
+    author_orcid: Option<String>,
+    author_publications: i64,
+    author_roles: i64,
+    other_id: Uuid,
+    other_name: String,
+    other_affiliation: Option<String>,
+    other_orcid: Option<String>,
+    other_publications: i64,
+    other_roles: i64,
+}
+
+impl From<ReviewRow> for AuthorReviewItem {
+    fn from(row: ReviewRow) -> Self {
+        AuthorReviewItem {
+            id: row.id,
+            score: row.score,
+            status: row.status,
+            note: row.note,
+            resolved_by: row.resolved_by,
+            resolved_at: row.resolved_at,
+            created_at: row.created_at,
+            author: ReviewAuthor {
+                id: row.author_id,
+                full_name: row.author_name,
+                affiliation: row.author_affiliation,
+                orcid: row.author_orcid,
+                publication_count: row.author_publications,
+                committee_role_count: row.author_roles,
+            },
+            other_author: ReviewAuthor {
+                id: row.other_id,
+                full_name: row.other_name,
+                affiliation: row.other_affiliation,
+                orcid: row.other_orcid,
+                publication_count: row.other_publications,
+                committee_role_count: row.other_roles,
+            },
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/review/authors",
+    tag = "review",
+    params(ReviewQuery),
+    responses(
+        (status = 200, description = "Undecided pairs of live authors that may be the same person, most similar first; only pairs found by the last `POST /review/authors/scan`", body = Vec<AuthorReviewItem>, headers(("x-page-size" = i64, description = "Effective `limit` after clamping to `MAX_PAGE_SIZE`"))),
+        (status = 400, description = "Negative limit/offset"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn list_author_reviews(
+    State(pool): State<Pool<Postgres>>,
+    Query(query): Query<ReviewQuery>,
+) -> Result<Response, ApiError> {
+    let (limit, offset) = clamp_pagination(query.limit, query.offset).map_err(ApiError::bad_request)?;
+
+    let statuses: Vec<String> = if query.include_deferred.unwrap_or(false) {
+        vec!["pending".into(), "deferred".into()]
+    } else {
+        vec!["pending".into()]
+    };
+    let items = sqlx::query_as!(
+        ReviewRow,
+        r#"
+        SELECT
+            q.id, q.score, q.status as "status: ReviewStatus", q.note, q.resolved_by, q.resolved_at, q.created_at,
+            a.id as author_id, a.full_name as author_name, a.affiliation as author_affiliation, a.orcid as author_orcid,
+            (SELECT COUNT(*) FROM authorships au JOIN publications p ON p.id = au.publication_id AND p.deleted_at IS NULL
+             WHERE au.author_id = a.id) as "author_publications!",
+            (SELECT COUNT(*) FROM committee_roles cr WHERE cr.author_id = a.id) as "author_roles!",
+            b.id as other_id, b.full_name as other_name, b.affiliation as other_affiliation, b.orcid as other_orcid,
+            (SELECT COUNT(*) FROM authorships au JOIN publications p ON p.id = au.publication_id AND p.deleted_at IS NULL
+             WHERE au.author_id = b.id) as "other_publications!",
+            (SELECT COUNT(*) FROM committee_roles cr WHERE cr.author_id = b.id) as "other_roles!"
+        FROM review_queue q
+        JOIN authors a ON a.id = q.author_id AND a.deleted_at IS NULL
+        JOIN authors b ON b.id = q.other_author_id AND b.deleted_at IS NULL
+        WHERE q.status = ANY($1)
+        ORDER BY q.status = 'deferred', q.score DESC, q.created_at, q.id
+        LIMIT $2 OFFSET $3
+        "#,
+        &statuses,
+        limit,
+        offset
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch author review queue: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let items: Vec<AuthorReviewItem> = items.into_iter().map(Into::into).collect();
+    Ok(with_page_size(Json(items), limit))
+}
+
+#[utoipa::path(
+    post,
+    path = "/review/authors/scan",
+    tag = "review",
+    responses(
+        (status = 200, description = "Look-alike pairs of live authors detected and queued as pending", body = ReviewScan),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn scan_author_reviews(State(pool): State<Pool<Postgres>>) -> Result<Json<ReviewScan>, StatusCode> {
+    let added = enqueue_author_pairs(&pool).await?;
+    Ok(Json(ReviewScan { added }))
+}
+
+/// Add newly detected look-alike pairs to the queue as `pending` and return
+/// how many were added. Pairs already in the queue, whatever their status, are
+/// left alone, which is what keeps a decided pair from coming back. Two
+/// different ORCID iDs rule a pair out.
+///
+/// Compares every live author with every other through the trigram index, so
+/// it only runs on an authenticated scan, never on a listing.
+async fn enqueue_author_pairs(pool: &Pool<Postgres>) -> Result<u64, StatusCode> {
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    sqlx::query(&format!("SET LOCAL pg_trgm.similarity_threshold = {}", REVIEW_TRIGRAM_THRESHOLD))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to set trigram threshold: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let candidates = sqlx::query!(
+        r#"
+        SELECT a.id, a.full_name, b.id as other_id, b.full_name as other_name
+        FROM authors a
+        JOIN authors b ON a.id < b.id AND a.normalized_name % b.normalized_name
+        WHERE a.deleted_at IS NULL AND b.deleted_at IS NULL
+          AND (a.orcid IS NULL OR b.orcid IS NULL)
+          AND NOT EXISTS (
+              SELECT 1 FROM review_queue q WHERE q.author_id = a.id AND q.other_author_id = b.id
+          )
+        "#
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to detect duplicate authors: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let (mut ids, mut other_ids, mut scores) = (Vec::new(), Vec::new(), Vec::new());
+    for pair in candidates {
+        let score = name_similarity(&pair.full_name, &pair.other_name);
+        if score >= MIN_REVIEW_SCORE {
+            ids.push(pair.id);
+            other_ids.push(pair.other_id);
+            scores.push(score);
+        }
+    }
+
+    let mut added = 0;
+    if !ids.is_empty() {
+        // Concurrent scans may detect the same pair
+        added = sqlx::query!(
+            r#"
+            INSERT INTO review_queue (author_id, other_author_id, score)
+            SELECT * FROM UNNEST($1::uuid[], $2::uuid[], $3::float8[])
+            ON CONFLICT (author_id, other_author_id) DO NOTHING
+            "#,
+            &ids,
+            &other_ids,
+            &scores
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to enqueue duplicate authors: {:?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .rows_affected();
+    }
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit review queue update: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    Ok(added)
+}
+
+#[utoipa::path(
+    post,
+    path = "/review/authors/{id}/resolve",
+    tag = "review",
+    params(("id" = Uuid, Path, description = "Review queue entry ID")),
+    request_body = ResolveAuthorReview,
+    responses(
+        (status = 200, description = "Decision recorded (and, for `merge`, the authors merged)", body = AuthorReviewItem),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Review queue entry not found"),
+        (status = 409, description = "Pair already merged or kept separate, or one of the authors has been deleted"),
+        (status = 422, description = "`merge` without a `keep_author_id` from the pair"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn resolve_author_review(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ResolveAuthorReview>,
+) -> Result<Json<AuthorReviewItem>, ApiError> {
+    validate_text_len(&payload.modifier, MAX_NAME_LEN)?;
+    validate_optional_text_len(payload.note.as_deref(), MAX_TITLE_LEN)?;
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let entry = sqlx::query!(
+        r#"
+        SELECT author_id, other_author_id, status as "status: ReviewStatus"
+        FROM review_queue
+        WHERE id = $1
+        FOR UPDATE
+        "#,
+        id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    let resolved_as = match entry.status {
+        ReviewStatus::Merged => Some("merged"),
+        ReviewStatus::KeptSeparate => Some("kept separate"),
+        ReviewStatus::Pending | ReviewStatus::Deferred => None,
+    };
+    if let Some(resolved_as) = resolved_as {
+        return Err(ApiError::conflict(format!("Review entry {} was already {}", id, resolved_as)));
+    }
+
+    let mut merged = None;
+    let status = match payload.decision {
+        ReviewDecision::KeepSeparate => ReviewStatus::KeptSeparate,
+        ReviewDecision::Defer => ReviewStatus::Deferred,
+        ReviewDecision::Merge => {
+            let keep_id = payload
+                .keep_author_id
+                .filter(|k| *k == entry.author_id || *k == entry.other_author_id)
+                .ok_or_else(|| {
+                    ApiError::new(
+                        StatusCode::UNPROCESSABLE_ENTITY,
+                        "merge requires keep_author_id to be one of the pair's authors",
+                    )
+                })?;
+            let drop_id = if keep_id == entry.author_id { entry.other_author_id } else { entry.author_id };
+            merge_authors(&mut tx, keep_id, drop_id, &payload.modifier).await?;
+            merged = Some((keep_id, drop_id));
+            ReviewStatus::Merged
+        }
+    };
+
+    sqlx::query!(
+        r#"
+        UPDATE review_queue
+        SET status = $2, note = COALESCE($3, note), resolved_by = $4, resolved_at = NOW()
+        WHERE id = $1
+        "#,
+        id,
+        status as ReviewStatus,
+        payload.note,
+        payload.modifier
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to record review decision: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit review decision: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    if let Some((keep_id, drop_id)) = merged {
+        events::publish("updated", "author", keep_id);
+        events::publish("deleted", "author", drop_id);
+    }
+
+    let item = sqlx::query_as!(
+        ReviewRow,
+        r#"
+        SELECT
+            q.id, q.score, q.status as "status: ReviewStatus", q.note, q.resolved_by, q.resolved_at, q.created_at,
+            a.id as author_id, a.full_name as author_name, a.affiliation as author_affiliation, a.orcid as author_orcid,
+            (SELECT COUNT(*) FROM authorships au JOIN publications p ON p.id = au.publication_id AND p.deleted_at IS NULL
+             WHERE au.author_id = a.id) as "author_publications!",
+            (SELECT COUNT(*) FROM committee_roles cr WHERE cr.author_id = a.id) as "author_roles!",
+            b.id as other_id, b.full_name as other_name, b.affiliation as other_affiliation, b.orcid as other_orcid,
+            (SELECT COUNT(*) FROM authorships au JOIN publications p ON p.id = au.publication_id AND p.deleted_at IS NULL
+             WHERE au.author_id = b.id) as "other_publications!",
+            (SELECT COUNT(*) FROM committee_roles cr WHERE cr.author_id = b.id) as "other_roles!"
+        FROM review_queue q
+        JOIN authors a ON a.id = q.author_id
+        JOIN authors b ON b.id = q.other_author_id
+        WHERE q.id = $1
+        "#,
+        id
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch review entry: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(item.into()))
+}
+
+/// Fold `drop_id` into `keep_id`: move its authorships, presented talks,
+/// committee roles and name variants (skipping any the kept author already
+/// has), record its spelling as a variant, fill the kept author's empty
+/// fields from it, and soft-delete it.
+async fn merge_authors(
+    tx: &mut Transaction<'_, Postgres>,
+    keep_id: Uuid,
+    drop_id: Uuid,
+    modifier: &str,
+) -> Result<(), ApiError> {
+    let authors = sqlx::query_as!(
+        Author,
+        r#"
+        SELECT
+            id, full_name, family_name, given_name,
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
+        FROM authors
+        WHERE id = ANY($1)
+        FOR UPDATE
+        "#,
+        &[keep_id, drop_id][..]
+    )
+    .fetch_all(&mut **tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let find = |id: Uuid| authors.iter().find(|a| a.id == id && a.deleted_at.is_none());
+    let (Some(keep), Some(drop)) = (find(keep_id), find(drop_id)) else {
+        return Err(ApiError::conflict("One of the pair's authors has been deleted; the pair can't be merged"));
+    };
+
+    let fail = |what: &'static str| {
+        move |e: sqlx::Error| {
+            tracing::error!("Failed to {} during author merge: {:?}", what, e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+
+    // Papers both are on keep the kept author's authorship
+    sqlx::query!(
+        r#"
+        DELETE FROM authorships
+        WHERE author_id = $2
+          AND publication_id IN (SELECT publication_id FROM authorships WHERE author_id = $1)
+        "#,
+        keep_id,
+        drop_id
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(fail("drop overlapping authorships"))?;
+    sqlx::query!(
+        "UPDATE authorships SET author_id = $1, modifier = $3, updated_at = NOW() WHERE author_id = $2",
+        keep_id,
+        drop_id,
+        modifier
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(fail("move authorships"))?;
+
+    // After the authorships, so ensure_presenter_is_author accepts the kept author
+    sqlx::query!(
+        "UPDATE publications SET presenter_author_id = $1, modifier = $3, updated_at = NOW() WHERE presenter_author_id = $2",
+        keep_id,
+        drop_id,
+        modifier
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(fail("move presented talks"))?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM committee_roles d
+        USING committee_roles k
+        WHERE d.author_id = $2 AND k.author_id = $1
+          AND d.conference_id = k.conference_id AND d.committee = k.committee AND d.position = k.position
+        "#,
+        keep_id,
+        drop_id
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(fail("drop overlapping committee roles"))?;
+    sqlx::query!(
+        "UPDATE committee_roles SET author_id = $1, modifier = $3, updated_at = NOW() WHERE author_id = $2",
+        keep_id,
+        drop_id,
+        modifier
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(fail("move committee roles"))?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM author_name_variants
+        WHERE author_id = $2
+          AND normalized_variant IN (SELECT normalized_variant FROM author_name_variants WHERE author_id = $1)
+        "#,
+        keep_id,
+        drop_id
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(fail("drop overlapping name variants"))?;
+    sqlx::query!("UPDATE author_name_variants SET author_id = $1 WHERE author_id = $2", keep_id, drop_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(fail("move name variants"))?;
+    if drop.normalized_name != keep.normalized_name {
+        sqlx::query!(
+            r#"
+            INSERT INTO author_name_variants (author_id, variant_name, normalized_variant, variant_type, notes, creator)
+            VALUES ($1, $2, $3, 'merged', $4, $5)
+            ON CONFLICT (author_id, normalized_variant) DO NOTHING
+            "#,
+            keep_id,
+            drop.full_name,
+            drop.normalized_name,
+            format!("Merged from author {}", drop_id),
+            modifier
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(fail("record merged spelling"))?;
+    }
+
+    // The ORCID is unique, so it has to leave the merged author before the kept one takes it
+    let deleted_at = sqlx::query_scalar!(
+        r#"
+        UPDATE authors
+        SET orcid = NULL, deleted_at = NOW(), modifier = $2, updated_at = NOW()
+        WHERE id = $1
+        RETURNING deleted_at
+        "#,
+        drop_id,
+        modifier
+    )
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(fail("delete merged author"))?;
+
+    let kept = sqlx::query_as!(
+        Author,
+        r#"
+        UPDATE authors SET
+            family_name = COALESCE(family_name, $2),
+            given_name = COALESCE(given_name, $3),
+            orcid = COALESCE(orcid, $4),
+            homepage_url = COALESCE(homepage_url, $5),
+            affiliation = COALESCE(affiliation, $6),
+            institution_id = COALESCE(institution_id, $7),
+            modifier = $8,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING
+            id, full_name, family_name, given_name,
+            normalized_name, orcid, homepage_url, affiliation, institution_id,
+            created_at, updated_at, deleted_at
+        "#,
+        keep_id,
+        drop.family_name,
+        drop.given_name,
+        drop.orcid,
+        drop.homepage_url,
+        drop.affiliation,
+        drop.institution_id,
+        modifier
+    )
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(fail("fill kept author"))?;
+
+    record_change(
+        tx,
+        "author",
+        drop_id,
+        "delete",
+        Some(modifier),
+        &serde_json::json!({ "deleted_at": null, "orcid": drop.orcid, "merged_into": null }),
+        &serde_json::json!({ "deleted_at": deleted_at, "orcid": null, "merged_into": keep_id }),
+    )
+    .await?;
+    record_change(tx, "author", keep_id, "update", Some(modifier), &snapshot(keep), &snapshot(&kept)).await?;
+
+    Ok(())
+}
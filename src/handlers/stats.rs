@@ -0,0 +1,105 @@
+use axum::{extract::State, http::StatusCode, Json};
+use sqlx::{Pool, Postgres};
+
+use crate::models::{GlobalStats, LatestConference, VenueStats};
+
+#[utoipa::path(
+    get,
+    path = "/stats",
+    tag = "stats",
+    responses(
+        (status = 200, description = "Database-wide totals with a per-venue breakdown", body = GlobalStats),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn global_stats(
+    State(pool): State<Pool<Postgres>>,
+) -> Result<Json<GlobalStats>, StatusCode> {
+    // Same totals as the home page
+    let totals = sqlx::query!(
+        r#"
+        SELECT
+            (SELECT COUNT(DISTINCT id) FROM author_stats) as "total_authors!",
+            (SELECT COUNT(*) FROM publications WHERE deleted_at IS NULL) as "total_publications!",
+            (SELECT COUNT(*) FROM conferences) as "total_conferences!",
+            (SELECT COUNT(*) FROM committee_roles) as "total_committee_roles!"
+        "#
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch global stats: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let venues = sqlx::query!(
+        r#"
+        SELECT
+            v.venue as "venue!",
+            (SELECT COUNT(*) FROM conferences c WHERE c.venue = v.venue) as "conference_count!",
+            (SELECT COUNT(*)
+             FROM publications p
+             JOIN conferences c ON p.conference_id = c.id
+             WHERE c.venue = v.venue AND p.deleted_at IS NULL) as "publication_count!",
+            (SELECT COUNT(*)
+             FROM committee_roles cr
+             JOIN conferences c ON cr.conference_id = c.id
+             WHERE c.venue = v.venue) as "committee_role_count!",
+            (SELECT COUNT(*) FROM (
+                SELECT au.author_id
+                FROM authorships au
+                JOIN publications p ON au.publication_id = p.id AND p.deleted_at IS NULL
+                JOIN conferences c ON p.conference_id = c.id
+                JOIN authors a ON au.author_id = a.id AND a.deleted_at IS NULL
+                WHERE c.venue = v.venue
+                UNION
+                SELECT cr.author_id
+                FROM committee_roles cr
+                JOIN conferences c ON cr.conference_id = c.id
+                JOIN authors a ON cr.author_id = a.id AND a.deleted_at IS NULL
+                WHERE c.venue = v.venue
+             ) people) as "author_count!",
+            latest.id as "latest_id?",
+            latest.year as "latest_year?",
+            latest.start_date as latest_start_date
+        FROM (SELECT DISTINCT venue FROM conferences) v
+        LEFT JOIN LATERAL (
+            SELECT id, year, start_date
+            FROM conferences c
+            WHERE c.venue = v.venue
+            ORDER BY year DESC
+            LIMIT 1
+        ) latest ON true
+        ORDER BY v.venue
+        "#
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch per-venue stats: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .into_iter()
+    .map(|row| VenueStats {
+        latest_conference: row.latest_id.zip(row.latest_year).map(|(id, year)| LatestConference {
+            id,
+            slug: format!("{}{}", row.venue, year),
+            year,
+            start_date: row.latest_start_date,
+        }),
+        venue: row.venue,
+        conference_count: row.conference_count,
+        publication_count: row.publication_count,
+        committee_role_count: row.committee_role_count,
+        author_count: row.author_count,
+    })
+    .collect();
+
+    Ok(Json(GlobalStats {
+        total_authors: totals.total_authors,
+        total_publications: totals.total_publications,
+        total_conferences: totals.total_conferences,
+        total_committee_roles: totals.total_committee_roles,
+        venues,
+    }))
+}
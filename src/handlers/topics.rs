@@ -0,0 +1,248 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Response,
+    Json,
+};
+use serde::Deserialize;
+use sqlx::{PgExecutor, Pool, Postgres};
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use super::error::ApiError;
+use super::events;
+use super::page_size::with_page_size;
+use crate::models::{AddTopics, TopicCount};
+use crate::utils::{clamp_pagination, topic, validate_text_len, MAX_NAME_LEN};
+
+/// Normalize a client-supplied topic (see `utils::topic`), naming it in the error.
+pub(crate) fn normalize_topic(raw: &str, status: StatusCode) -> Result<String, ApiError> {
+    topic::normalize(raw).ok_or_else(|| {
+        ApiError::new(
+            status,
+            format!(
+                "Invalid topic \"{}\": use letters, digits, spaces or hyphens, at most {} characters",
+                raw,
+                topic::MAX_TOPIC_LEN
+            ),
+        )
+    })
+}
+
+/// 404 unless the publication exists and isn't soft-deleted.
+async fn ensure_publication(executor: impl PgExecutor<'_>, id: Uuid) -> Result<(), ApiError> {
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM publications WHERE id = $1 AND deleted_at IS NULL) as "exists!""#,
+        id
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to check publication: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    if !exists {
+        return Err(StatusCode::NOT_FOUND.into());
+    }
+    Ok(())
+}
+
+async fn publication_topics(executor: impl PgExecutor<'_>, id: Uuid) -> Result<Vec<String>, ApiError> {
+    sqlx::query_scalar!(
+        "SELECT topic FROM publication_topics WHERE publication_id = $1 ORDER BY topic",
+        id
+    )
+    .fetch_all(executor)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch publication topics: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR.into()
+    })
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TopicQuery {
+    /// Maximum number of results (default: 100)
+    pub limit: Option<i64>,
+    /// Number of results to skip (default: 0)
+    pub offset: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/topics",
+    tag = "topics",
+    params(TopicQuery),
+    responses(
+        (status = 200, description = "Topics on live publications, most used first", body = Vec<TopicCount>, headers(("x-page-size" = i64, description = "Effective `limit` after clamping to `MAX_PAGE_SIZE`"))),
+        (status = 400, description = "Negative limit or offset"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn list_topics(
+    State(pool): State<Pool<Postgres>>,
+    Query(query): Query<TopicQuery>,
+) -> Result<Response, ApiError> {
+    let (limit, offset) = clamp_pagination(query.limit, query.offset).map_err(ApiError::bad_request)?;
+
+    let topics = sqlx::query_as!(
+        TopicCount,
+        r#"
+        SELECT t.topic, COUNT(*) as "publication_count!"
+        FROM publication_topics t
+        JOIN publications p ON p.id = t.publication_id AND p.deleted_at IS NULL
+        GROUP BY t.topic
+        ORDER BY COUNT(*) DESC, t.topic
+        LIMIT $1 OFFSET $2
+        "#,
+        limit,
+        offset
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to fetch topics: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(with_page_size(Json(topics), limit))
+}
+
+#[utoipa::path(
+    get,
+    path = "/publications/{id}/topics",
+    tag = "topics",
+    params(("id" = Uuid, Path, description = "Publication ID")),
+    responses(
+        (status = 200, description = "The publication's topics, alphabetically", body = Vec<String>),
+        (status = 404, description = "Publication not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_publication_topics(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<Vec<String>>, ApiError> {
+    ensure_publication(&pool, id).await?;
+    Ok(Json(publication_topics(&pool, id).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/publications/{id}/topics",
+    tag = "topics",
+    params(("id" = Uuid, Path, description = "Publication ID")),
+    request_body = AddTopics,
+    responses(
+        (status = 200, description = "Topics added; returns all of the publication's topics, alphabetically", body = Vec<String>),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Publication not found"),
+        (status = 422, description = "Empty topic list or a topic that isn't a valid slug"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn add_publication_topics(
+    State(pool): State<Pool<Postgres>>,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<AddTopics>,
+) -> Result<Json<Vec<String>>, ApiError> {
+    validate_text_len(&payload.creator, MAX_NAME_LEN)?;
+    if payload.topics.is_empty() {
+        return Err(ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, "topics must not be empty"));
+    }
+    let topics = payload
+        .topics
+        .iter()
+        .map(|t| normalize_topic(t, StatusCode::UNPROCESSABLE_ENTITY))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        tracing::error!("Failed to begin transaction: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    ensure_publication(&mut *tx, id).await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO publication_topics (publication_id, topic, creator)
+        SELECT $1, topic, $3 FROM UNNEST($2::text[]) AS topic
+        ON CONFLICT DO NOTHING
+        "#,
+        id,
+        &topics,
+        payload.creator
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to add publication topics: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let all = publication_topics(&mut *tx, id).await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Failed to commit publication topics: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    events::publish("updated", "publication", id);
+
+    Ok(Json(all))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/publications/{id}/topics/{topic}",
+    tag = "topics",
+    params(
+        ("id" = Uuid, Path, description = "Publication ID"),
+        ("topic" = String, Path, description = "Topic to remove; normalized like on add")
+    ),
+    responses(
+        (status = 204, description = "Topic removed"),
+        (status = 401, description = "Unauthorized - missing or invalid token"),
+        (status = 404, description = "Publication not found or doesn't have this topic"),
+        (status = 422, description = "Topic isn't a valid slug"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_publication_topic(
+    State(pool): State<Pool<Postgres>>,
+    Path((id, raw_topic)): Path<(Uuid, String)>,
+) -> Result<StatusCode, ApiError> {
+    let topic = normalize_topic(&raw_topic, StatusCode::UNPROCESSABLE_ENTITY)?;
+
+    let result = sqlx::query!(
+        r#"
+        DELETE FROM publication_topics t
+        USING publications p
+        WHERE t.publication_id = $1 AND t.topic = $2
+          AND p.id = t.publication_id AND p.deleted_at IS NULL
+        "#,
+        id,
+        topic
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to delete publication topic: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    if result.rows_affected() == 0 {
+        return Err(StatusCode::NOT_FOUND.into());
+    }
+
+    events::publish("updated", "publication", id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
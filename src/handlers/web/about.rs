@@ -12,7 +12,7 @@ pub async fn about() -> Result<Response, StatusCode> {
     match template.render() {
         Ok(html) => Ok(Html(html).into_response()),
         Err(e) => {
-            eprintln!("Template error: {}", e);
+            tracing::error!(error = ?e, "Failed to render about page");
             Err(StatusCode::INTERNAL_SERVER_ERROR)
         }
     }
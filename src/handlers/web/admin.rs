@@ -1,67 +1,208 @@
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum::http::StatusCode;
-use axum::response::{Html, IntoResponse, Response};
+use axum::Json;
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
+use std::time::Instant;
+use uuid::Uuid;
 
-/// Admin endpoint to refresh all materialized views.
-///
-/// Uses `REFRESH MATERIALIZED VIEW CONCURRENTLY` so readers are not blocked during
-/// the refresh. CONCURRENTLY requires every view to have at least one UNIQUE index;
-/// `author_stats` and `conference_stats` got theirs at creation, and `coauthor_pairs`
-/// got one in migration 20260505000000.
-pub async fn refresh_stats(State(pool): State<PgPool>) -> Result<Response, StatusCode> {
-    sqlx::query("REFRESH MATERIALIZED VIEW CONCURRENTLY author_stats")
-        .execute(&pool)
-        .await
-        .map_err(|e| {
-            tracing::error!(error = ?e, "Failed to refresh author_stats");
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
+use crate::handlers::resolve::resolve_conference;
 
-    sqlx::query("REFRESH MATERIALIZED VIEW CONCURRENTLY conference_stats")
-        .execute(&pool)
-        .await
-        .map_err(|e| {
-            tracing::error!(error = ?e, "Failed to refresh conference_stats");
-            StatusCode::INTERNAL_SERVER_ERROR
-        })?;
+#[derive(Debug, Deserialize)]
+pub struct RefreshStatsQuery {
+    /// Only recompute this conference (UUID or slug) and the people in it
+    pub conference: Option<String>,
+}
+
+/// Time taken to refresh one stats table
+#[derive(Debug, Serialize)]
+pub struct ViewRefresh {
+    pub view: &'static str,
+    pub duration_ms: u64,
+}
+
+/// Result of `POST /admin/refresh-stats`
+#[derive(Debug, Serialize)]
+pub struct RefreshStatsReport {
+    /// Set for a targeted refresh
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conference_id: Option<Uuid>,
+    /// Authors recomputed by a targeted refresh
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_count: Option<usize>,
+    pub views: Vec<ViewRefresh>,
+    /// Wall-clock time for the whole refresh; less than the sum of the views
+    /// because they run in parallel
+    pub total_ms: u64,
+}
 
-    sqlx::query("REFRESH MATERIALIZED VIEW CONCURRENTLY coauthor_pairs")
-        .execute(&pool)
+/// Run `refresh_<view>($1)`; a NULL id list recomputes every row.
+async fn refresh_view(
+    pool: &PgPool,
+    view: &'static str,
+    ids: Option<&[Uuid]>,
+) -> Result<ViewRefresh, StatusCode> {
+    let started = Instant::now();
+    sqlx::query(&format!("SELECT refresh_{}($1)", view))
+        .bind(ids)
+        .execute(pool)
         .await
         .map_err(|e| {
-            tracing::error!(error = ?e, "Failed to refresh coauthor_pairs");
+            tracing::error!(error = ?e, view, "Failed to refresh stats");
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
 
-    let html = r#"<!DOCTYPE html>
-<html>
-<head>
-    <meta charset="UTF-8">
-    <meta http-equiv="refresh" content="2;url=/">
-    <title>Refreshing Statistics - QuantumDB</title>
-    <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/@picocss/pico@2/css/pico.min.css">
-</head>
-<body>
-    <main class="container">
-        <article>
-            <header>
-                <h1>Statistics Refreshed</h1>
-            </header>
-            <p>All materialized views have been successfully refreshed:</p>
-            <ul>
-                <li>Author statistics</li>
-                <li>Conference statistics</li>
-                <li>Coauthor pairs</li>
-            </ul>
-            <p>Redirecting to homepage in 2 seconds...</p>
-            <footer>
-                <a href="/" role="button">Go to Homepage</a>
-            </footer>
-        </article>
-    </main>
-</body>
-</html>"#;
-
-    Ok(Html(html).into_response())
+    Ok(ViewRefresh {
+        view,
+        duration_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+/// Admin endpoint to recompute the stats tables.
+///
+/// `author_stats`, `conference_stats`, `coauthor_pairs` and `committee_pairs`
+/// are summary tables filled by the `refresh_*` SQL functions (migrations
+/// 20261015000300 and 20261015001000). Each refresh swaps rows inside one
+/// transaction, so readers are never blocked and never see a half-filled
+/// table. They only read the base tables, never each other, so they run in
+/// parallel on separate pool connections.
+///
+/// With `?conference=`, only that conference's row, its authors and committee
+/// members, and their coauthor and committee pairs are recomputed — enough after importing
+/// or editing one edition.
+///
+/// POST-only: as a GET, link prefetchers and crawlers were triggering refreshes.
+pub async fn refresh_stats(
+    State(pool): State<PgPool>,
+    Query(params): Query<RefreshStatsQuery>,
+) -> Result<Json<RefreshStatsReport>, StatusCode> {
+    let started = Instant::now();
+
+    let conference_id = match params.conference.as_deref() {
+        Some(conference) => Some(resolve_conference(&pool, conference).await?),
+        None => None,
+    };
+
+    let author_ids = match conference_id {
+        Some(conference_id) => Some(
+            sqlx::query_scalar!(
+                r#"
+                SELECT au.author_id as "author_id!"
+                FROM authorships au
+                JOIN publications p ON au.publication_id = p.id
+                WHERE p.conference_id = $1
+                UNION
+                SELECT author_id FROM committee_roles WHERE conference_id = $1
+                "#,
+                conference_id
+            )
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = ?e, "Failed to collect conference authors");
+                StatusCode::INTERNAL_SERVER_ERROR
+            })?,
+        ),
+        None => None,
+    };
+    let conference_ids = conference_id.map(|id| vec![id]);
+
+    let (authors, conferences, coauthors, committees) = tokio::try_join!(
+        refresh_view(&pool, "author_stats", author_ids.as_deref()),
+        refresh_view(&pool, "conference_stats", conference_ids.as_deref()),
+        refresh_view(&pool, "coauthor_pairs", author_ids.as_deref()),
+        refresh_view(&pool, "committee_pairs", author_ids.as_deref()),
+    )?;
+
+    Ok(Json(RefreshStatsReport {
+        conference_id,
+        author_count: author_ids.map(|ids| ids.len()),
+        views: vec![authors, conferences, coauthors, committees],
+        total_ms: started.elapsed().as_millis() as u64,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReindexSearchQuery {
+    /// Only check this conference's (UUID or slug) publications
+    pub conference: Option<String>,
+}
+
+/// Result of `POST /admin/reindex-search`
+#[derive(Debug, Serialize)]
+pub struct ReindexSearchReport {
+    /// Set when only one conference was checked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conference_id: Option<Uuid>,
+    /// Publications checked (soft-deleted ones included)
+    pub checked: i64,
+    /// Publications whose `search_vector` didn't match their title and
+    /// abstract, and were recomputed
+    pub recomputed: i64,
+    pub duration_ms: u64,
+}
+
+/// Admin endpoint to check and repair `publications.search_vector`.
+///
+/// The column is `GENERATED ALWAYS ... STORED` (migration 20251228160003), so
+/// Postgres computes it on every insert and update, `COPY` included; it can't
+/// be written directly. This compares each vector with the column's own
+/// expression and rewrites the rows that differ, which makes Postgres
+/// recompute them (e.g. after a restore from a dump made with a different
+/// text search configuration). A healthy table reports `recomputed: 0`.
+///
+/// With `?conference=`, only that conference's publications are checked.
+pub async fn reindex_search(
+    State(pool): State<PgPool>,
+    Query(params): Query<ReindexSearchQuery>,
+) -> Result<Json<ReindexSearchReport>, StatusCode> {
+    let started = Instant::now();
+
+    let conference_id = match params.conference.as_deref() {
+        Some(conference) => Some(resolve_conference(&pool, conference).await?),
+        None => None,
+    };
+
+    let report = sqlx::query!(
+        r#"
+        WITH scope AS (
+            SELECT id,
+                   search_vector IS DISTINCT FROM (
+                       setweight(to_tsvector('english', title), 'A') ||
+                       setweight(to_tsvector('english', COALESCE(abstract, '')), 'B')
+                   ) as stale
+            FROM publications
+            WHERE $1::uuid IS NULL OR conference_id = $1
+        ),
+        recomputed AS (
+            -- A no-op assignment is enough for Postgres to regenerate the column
+            UPDATE publications p
+            SET title = p.title
+            FROM scope
+            WHERE p.id = scope.id AND scope.stale
+            RETURNING p.id
+        )
+        SELECT
+            (SELECT COUNT(*) FROM scope) as "checked!",
+            (SELECT COUNT(*) FROM recomputed) as "recomputed!"
+        "#,
+        conference_id
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to reindex publication search vectors");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    if report.recomputed > 0 {
+        tracing::warn!(recomputed = report.recomputed, "Recomputed stale publication search vectors");
+    }
+
+    Ok(Json(ReindexSearchReport {
+        conference_id,
+        checked: report.checked,
+        recomputed: report.recomputed,
+        duration_ms: started.elapsed().as_millis() as u64,
+    }))
 }
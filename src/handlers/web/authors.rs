@@ -5,13 +5,21 @@ use axum::response::{Html, IntoResponse, Response};
 use serde::Deserialize;
 use sqlx::PgPool;
 
-use crate::models::{PaperType, CommitteeType, CommitteePosition};
+use super::links::{publication_links, ExternalLink};
+use super::pager::{encode_query_value, page_offset, Pager};
+use crate::handlers::etag::{is_fresh, not_modified, weak_etag, with_etag};
+use crate::utils::jsonld;
+
+/// Rows per page of the web authors list
+const AUTHORS_PAGE_SIZE: i64 = 100;
 
 #[derive(Template)]
 #[template(path = "authors_list.html")]
 struct AuthorsListTemplate {
     authors: Vec<AuthorListItem>,
     search_term: String,
+    min_publications: i64,
+    pager: Pager,
 }
 
 #[derive(Template)]
@@ -19,6 +27,7 @@ struct AuthorsListTemplate {
 struct AuthorsTablePartialTemplate {
     authors: Vec<AuthorListItem>,
     search_term: String,
+    pager: Pager,
 }
 
 struct AuthorListItem {
@@ -40,6 +49,8 @@ struct AuthorDetailTemplate {
     committee_roles: Vec<CommitteeRoleItem>,
     coauthors: Vec<CoauthorItem>,
     contribution: ContributionGraph,
+    /// schema.org `Person` for the page head
+    json_ld: String,
 }
 
 // ─── Contribution-over-time SVG layout ──────────────────────────────────────
@@ -347,16 +358,12 @@ fn build_contribution_graph(
 }
 
 struct AuthorDetail {
-    slug: String,
     full_name: String,
     initials: String,
-    family_name: String,
-    given_name: String,
     affiliation: String,
     orcid: String,
     homepage_url: String,
     google_scholar_id: String,
-    publication_count: i64,
     committee_role_count: i64,
     leadership_count: i64,
     venues: String,
@@ -391,16 +398,21 @@ struct PublicationItem {
     conference_slug: String,
     paper_type: String,
     coauthors: Vec<CoauthorRef>,
-    arxiv_ids: Vec<String>,
+    title_url: String,
+    extra_links: Vec<ExternalLink>,
     abstract_text: String,
     video_url: String,
     presenter_is_self: bool,
+    self_corresponding: bool,
+    self_equal_contribution: bool,
 }
 
 struct CoauthorRef {
     slug: String,
     name: String,
     is_speaker: bool,
+    is_corresponding: bool,
+    is_equal_contribution: bool,
 }
 
 struct CommitteeRoleItem {
@@ -422,6 +434,22 @@ struct CoauthorItem {
 pub struct AuthorSearchParams {
     #[serde(default)]
     search: String,
+    /// 1-based page number
+    page: Option<i64>,
+    /// Only list authors with at least this many talks
+    min_publications: Option<i64>,
+}
+
+/// `/authors` URL for another page of the current search
+fn authors_page_url(search: &str, min_publications: i64, page: i64) -> String {
+    let mut url = format!("/authors?page={}", page);
+    if !search.is_empty() {
+        url.push_str(&format!("&search={}", encode_query_value(search)));
+    }
+    if min_publications > 0 {
+        url.push_str(&format!("&min_publications={}", min_publications));
+    }
+    url
 }
 
 pub async fn authors_list(
@@ -430,8 +458,29 @@ pub async fn authors_list(
     headers: HeaderMap,
 ) -> Result<Response, StatusCode> {
     let search_pattern = format!("%{}%", params.search);
+    let min_publications = params.min_publications.unwrap_or(0).max(0);
+    let (page, offset) = page_offset(params.page, AUTHORS_PAGE_SIZE);
 
-    let authors = sqlx::query!(
+    let total = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "count!"
+        FROM authors a
+        LEFT JOIN author_stats ast ON a.id = ast.id
+        WHERE (a.full_name ILIKE $1 OR a.normalized_name ILIKE $1)
+          AND a.deleted_at IS NULL
+          AND COALESCE(ast.publication_count, 0) >= $2
+        "#,
+        search_pattern,
+        min_publications
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to count authors");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let authors: Vec<AuthorListItem> = sqlx::query!(
         r#"
         SELECT
             a.slug as "slug!",
@@ -443,15 +492,21 @@ pub async fn authors_list(
             COALESCE(ast.last_year::text, '') as "last_year!"
         FROM authors a
         LEFT JOIN author_stats ast ON a.id = ast.id
-        WHERE a.full_name ILIKE $1 OR a.normalized_name ILIKE $1
-        ORDER BY a.full_name
+        WHERE (a.full_name ILIKE $1 OR a.normalized_name ILIKE $1)
+          AND a.deleted_at IS NULL
+          AND COALESCE(ast.publication_count, 0) >= $2
+        ORDER BY a.full_name, a.id
+        LIMIT $3 OFFSET $4
         "#,
-        search_pattern
+        search_pattern,
+        min_publications,
+        AUTHORS_PAGE_SIZE,
+        offset
     )
     .fetch_all(&pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error: {}", e);
+        tracing::error!(error = ?e, "Failed to fetch authors");
         StatusCode::INTERNAL_SERVER_ERROR
     })?
     .into_iter()
@@ -466,6 +521,10 @@ pub async fn authors_list(
     })
     .collect();
 
+    let pager = Pager::new(total, page, AUTHORS_PAGE_SIZE, authors.len(), |page| {
+        authors_page_url(&params.search, min_publications, page)
+    });
+
     // Check if this is an HTMX request
     let is_htmx = headers.get("hx-request").is_some();
 
@@ -474,6 +533,7 @@ pub async fn authors_list(
         let template = AuthorsTablePartialTemplate {
             authors,
             search_term: params.search,
+            pager,
         };
         template.render()
     } else {
@@ -481,6 +541,8 @@ pub async fn authors_list(
         let template = AuthorsListTemplate {
             authors,
             search_term: params.search,
+            min_publications,
+            pager,
         };
         template.render()
     };
@@ -488,7 +550,7 @@ pub async fn authors_list(
     match html {
         Ok(html) => Ok(Html(html).into_response()),
         Err(e) => {
-            eprintln!("Template error: {}", e);
+            tracing::error!(error = ?e, "Failed to render authors page");
             Err(StatusCode::INTERNAL_SERVER_ERROR)
         }
     }
@@ -497,7 +559,55 @@ pub async fn authors_list(
 pub async fn author_detail(
     Path(slug): Path<String>,
     State(pool): State<PgPool>,
+    headers: HeaderMap,
 ) -> Result<Response, StatusCode> {
+    // Everything the page shows: the author, their talks, the coauthors and
+    // conferences on them, their committee roles, and the stats tables
+    let fingerprint = sqlx::query!(
+        r#"
+        SELECT
+            GREATEST(
+                a.updated_at,
+                (SELECT MAX(GREATEST(au.updated_at, p.updated_at, c.updated_at))
+                 FROM authorships au
+                 JOIN publications p ON p.id = au.publication_id
+                 JOIN conferences c ON c.id = p.conference_id
+                 WHERE au.author_id = a.id),
+                (SELECT MAX(GREATEST(au2.updated_at, a2.updated_at))
+                 FROM authorships au
+                 JOIN authorships au2 ON au2.publication_id = au.publication_id
+                 JOIN authors a2 ON a2.id = au2.author_id
+                 WHERE au.author_id = a.id),
+                (SELECT MAX(GREATEST(cr.updated_at, c.updated_at))
+                 FROM committee_roles cr
+                 JOIN conferences c ON c.id = cr.conference_id
+                 WHERE cr.author_id = a.id),
+                (SELECT MAX(refreshed_at) FROM stats_refreshes
+                 WHERE stats_table IN ('author_stats', 'coauthor_pairs'))
+            ) as "last_modified!",
+            (SELECT COUNT(*)
+             FROM authorships au
+             JOIN authorships au2 ON au2.publication_id = au.publication_id
+             WHERE au.author_id = a.id)
+            + (SELECT COUNT(*) FROM committee_roles cr WHERE cr.author_id = a.id) as "row_count!"
+        FROM authors a
+        WHERE a.slug = $1 AND a.deleted_at IS NULL
+        "#,
+        slug
+    )
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to fetch author fingerprint");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    let etag = weak_etag(fingerprint.last_modified, fingerprint.row_count);
+    if is_fresh(&headers, &etag) {
+        return Ok(not_modified(&etag));
+    }
+
     // Get author with stats
     let author = sqlx::query!(
         r#"
@@ -519,14 +629,14 @@ pub async fn author_detail(
             COALESCE(ast.last_year::text, '') as "last_year!"
         FROM authors a
         LEFT JOIN author_stats ast ON a.id = ast.id
-        WHERE a.slug = $1
+        WHERE a.slug = $1 AND a.deleted_at IS NULL
         "#,
         slug
     )
     .fetch_optional(&pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error: {}", e);
+        tracing::error!(error = ?e, "Failed to fetch author");
         StatusCode::INTERNAL_SERVER_ERROR
     })?
     .ok_or(StatusCode::NOT_FOUND)?;
@@ -554,17 +664,29 @@ pub async fn author_detail(
                 array_agg(COALESCE(a2.id = p.presenter_author_id, false) ORDER BY au2.author_position) FILTER (WHERE a2.id IS NOT NULL),
                 ARRAY[]::boolean[]
             ) as "coauthor_is_speaker!",
+            COALESCE(
+                array_agg(au2.is_corresponding ORDER BY au2.author_position) FILTER (WHERE a2.id IS NOT NULL),
+                ARRAY[]::boolean[]
+            ) as "coauthor_is_corresponding!",
+            COALESCE(
+                array_agg(au2.is_equal_contribution ORDER BY au2.author_position) FILTER (WHERE a2.id IS NOT NULL),
+                ARRAY[]::boolean[]
+            ) as "coauthor_is_equal_contribution!",
             COALESCE(p.presenter_author_id = $1, false) as "presenter_is_self!",
+            au.is_corresponding as self_corresponding,
+            au.is_equal_contribution as self_equal_contribution,
             COALESCE(p.arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+            p.doi,
             COALESCE(p.abstract, '') as "abstract_text!",
             COALESCE(p.video_url, '') as "video_url!"
         FROM authorships au
         JOIN publications p ON au.publication_id = p.id
         JOIN conferences c ON p.conference_id = c.id
         LEFT JOIN authorships au2 ON p.id = au2.publication_id AND au2.author_id != $1
-        LEFT JOIN authors a2 ON au2.author_id = a2.id
-        WHERE au.author_id = $1
-        GROUP BY p.id, p.title, c.venue, c.year, p.paper_type, p.arxiv_ids, p.abstract, p.video_url
+        LEFT JOIN authors a2 ON au2.author_id = a2.id AND a2.deleted_at IS NULL
+        WHERE au.author_id = $1 AND p.deleted_at IS NULL
+        GROUP BY p.id, p.title, c.venue, c.year, p.paper_type, p.arxiv_ids, p.doi, p.abstract, p.video_url,
+                 au.is_corresponding, au.is_equal_contribution
         ORDER BY c.year DESC, c.venue
         "#,
         author_id
@@ -572,7 +694,7 @@ pub async fn author_detail(
     .fetch_all(&pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error fetching publications: {}", e);
+        tracing::error!(error = ?e, "Failed to fetch author publications");
         StatusCode::INTERNAL_SERVER_ERROR
     })?
     .into_iter()
@@ -582,12 +704,17 @@ pub async fn author_detail(
             .into_iter()
             .zip(row.coauthor_names)
             .zip(row.coauthor_is_speaker)
-            .map(|((slug, name), is_speaker)| CoauthorRef {
+            .zip(row.coauthor_is_corresponding)
+            .zip(row.coauthor_is_equal_contribution)
+            .map(|((((slug, name), is_speaker), is_corresponding), is_equal_contribution)| CoauthorRef {
                 slug,
                 name,
                 is_speaker,
+                is_corresponding,
+                is_equal_contribution,
             })
             .collect();
+        let (title_url, extra_links) = publication_links(&row.arxiv_ids, row.doi.as_deref());
         PublicationItem {
             title: row.title,
             conference_venue: row.conference_venue,
@@ -595,10 +722,13 @@ pub async fn author_detail(
             conference_slug: row.conference_slug,
             paper_type: row.paper_type,
             coauthors,
-            arxiv_ids: row.arxiv_ids,
+            title_url,
+            extra_links,
             abstract_text: row.abstract_text,
             video_url: row.video_url,
             presenter_is_self: row.presenter_is_self,
+            self_corresponding: row.self_corresponding,
+            self_equal_contribution: row.self_equal_contribution,
         }
     })
     .collect();
@@ -623,7 +753,7 @@ pub async fn author_detail(
     .fetch_all(&pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error fetching committee roles: {}", e);
+        tracing::error!(error = ?e, "Failed to fetch author committee roles");
         StatusCode::INTERNAL_SERVER_ERROR
     })?
     .into_iter()
@@ -660,7 +790,7 @@ pub async fn author_detail(
     .fetch_all(&pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error fetching coauthors: {}", e);
+        tracing::error!(error = ?e, "Failed to fetch coauthors");
         StatusCode::INTERNAL_SERVER_ERROR
     })?
     .into_iter()
@@ -679,18 +809,30 @@ pub async fn author_detail(
 
     let initials = compute_initials(&author.full_name);
 
+    let profiles: Vec<String> = [
+        (!author.homepage_url.is_empty()).then(|| author.homepage_url.clone()),
+        (!author.google_scholar_id.is_empty()).then(|| {
+            format!("https://scholar.google.com/citations?user={}", author.google_scholar_id)
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    let json_ld = jsonld::to_script_body(&jsonld::person(
+        &author.full_name,
+        Some(&author.affiliation),
+        Some(&author.orcid),
+        &profiles,
+    ));
+
     let template = AuthorDetailTemplate {
         author: AuthorDetail {
-            slug: author.slug,
             full_name: author.full_name,
             initials,
-            family_name: author.family_name,
-            given_name: author.given_name,
             affiliation: author.affiliation,
             orcid: author.orcid,
             homepage_url: author.homepage_url,
             google_scholar_id: author.google_scholar_id,
-            publication_count: author.publication_count,
             committee_role_count: author.committee_role_count,
             leadership_count: author.leadership_count,
             venues: author.venues,
@@ -702,12 +844,13 @@ pub async fn author_detail(
         committee_roles,
         coauthors,
         contribution,
+        json_ld,
     };
 
     match template.render() {
-        Ok(html) => Ok(Html(html).into_response()),
+        Ok(html) => Ok(with_etag(Html(html), &etag)),
         Err(e) => {
-            eprintln!("Template error: {}", e);
+            tracing::error!(error = ?e, "Failed to render author page");
             Err(StatusCode::INTERNAL_SERVER_ERROR)
         }
     }
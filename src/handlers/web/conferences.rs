@@ -4,7 +4,9 @@ use axum::http::{StatusCode, HeaderMap};
 use axum::response::{Html, IntoResponse, Response};
 use serde::Deserialize;
 use sqlx::{PgPool, FromRow};
-use uuid::Uuid;
+
+use super::links::{publication_links, ExternalLink};
+use crate::handlers::etag::{is_fresh, not_modified, weak_etag, with_etag};
 
 #[derive(Template)]
 #[template(path = "conferences_list.html")]
@@ -51,7 +53,6 @@ struct ConferenceDetailTemplate {
 }
 
 struct ConferenceDetail {
-    slug: String,
     venue: String,
     year: i32,
     location: String,
@@ -80,7 +81,8 @@ struct PublicationItem {
     talk_date: String,
     talk_time: String,
     duration_minutes: String,
-    arxiv_ids: Vec<String>,
+    title_url: String,
+    extra_links: Vec<ExternalLink>,
     abstract_text: String,
     video_url: String,
 }
@@ -89,6 +91,8 @@ struct AuthorInfo {
     slug: String,
     name: String,
     is_speaker: bool,
+    is_corresponding: bool,
+    is_equal_contribution: bool,
 }
 
 #[derive(Clone)]
@@ -117,12 +121,13 @@ pub async fn conferences_list(
     State(pool): State<PgPool>,
     headers: HeaderMap,
 ) -> Result<Response, StatusCode> {
-    // Parse venues parameter (comma-separated list)
-    let venue_list: Vec<&str> = if params.venues.is_empty() {
-        vec![]
-    } else {
-        params.venues.split(',').collect()
-    };
+    // Parse venues parameter (comma-separated list, any case; stored venues are uppercase)
+    let venue_list: Vec<String> = params
+        .venues
+        .split(',')
+        .map(|v| v.trim().to_uppercase())
+        .filter(|v| !v.is_empty())
+        .collect();
     
     // Build dynamic query based on filter params
     let where_clause = if venue_list.is_empty() {
@@ -169,7 +174,7 @@ pub async fn conferences_list(
         .fetch_all(&pool)
         .await
         .map_err(|e| {
-            eprintln!("Database error: {}", e);
+            tracing::error!(error = ?e, "Failed to fetch conferences");
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
 
@@ -211,7 +216,7 @@ pub async fn conferences_list(
     match html {
         Ok(html) => Ok(Html(html).into_response()),
         Err(e) => {
-            eprintln!("Template error: {}", e);
+            tracing::error!(error = ?e, "Failed to render conferences page");
             Err(StatusCode::INTERNAL_SERVER_ERROR)
         }
     }
@@ -220,10 +225,57 @@ pub async fn conferences_list(
 pub async fn conference_detail(
     Path(slug): Path<String>,
     State(pool): State<PgPool>,
+    headers: HeaderMap,
 ) -> Result<Response, StatusCode> {
-    // Slug formats accepted: "qip-2024" (canonical) and legacy "QIP2024".
+    // Slug formats accepted: "qip-2024" (canonical) and legacy "QIP2024", in
+    // any case. A slug that doesn't parse is malformed rather than missing,
+    // as in the API.
     let (venue, year) = crate::utils::parse_conference_slug(&slug)
-        .ok_or(StatusCode::NOT_FOUND)?;
+        .ok_or(StatusCode::BAD_REQUEST)?;
+
+    // Everything the page shows: the conference, its talks and their authors,
+    // its committee, and the conference_stats row. Checked before the page
+    // queries so an unchanged page costs one query.
+    let fingerprint = sqlx::query!(
+        r#"
+        SELECT
+            GREATEST(
+                c.updated_at,
+                (SELECT MAX(p.updated_at) FROM publications p WHERE p.conference_id = c.id),
+                (SELECT MAX(GREATEST(au.updated_at, a.updated_at))
+                 FROM publications p
+                 JOIN authorships au ON au.publication_id = p.id
+                 JOIN authors a ON a.id = au.author_id
+                 WHERE p.conference_id = c.id),
+                (SELECT MAX(GREATEST(cr.updated_at, a.updated_at))
+                 FROM committee_roles cr
+                 JOIN authors a ON a.id = cr.author_id
+                 WHERE cr.conference_id = c.id),
+                (SELECT refreshed_at FROM stats_refreshes WHERE stats_table = 'conference_stats')
+            ) as "last_modified!",
+            (SELECT COUNT(*)
+             FROM publications p
+             JOIN authorships au ON au.publication_id = p.id
+             WHERE p.conference_id = c.id)
+            + (SELECT COUNT(*) FROM committee_roles cr WHERE cr.conference_id = c.id) as "row_count!"
+        FROM conferences c
+        WHERE c.venue = $1 AND c.year = $2
+        "#,
+        venue,
+        year
+    )
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to fetch conference fingerprint");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .ok_or(StatusCode::NOT_FOUND)?;
+
+    let etag = weak_etag(fingerprint.last_modified, fingerprint.row_count);
+    if is_fresh(&headers, &etag) {
+        return Ok(not_modified(&etag));
+    }
 
     // Now fetch conference with a single query
     let conference = sqlx::query!(
@@ -260,7 +312,7 @@ pub async fn conference_detail(
     .fetch_optional(&pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error: {}", e);
+        tracing::error!(error = ?e, "Failed to fetch conference");
         StatusCode::INTERNAL_SERVER_ERROR
     })?
     .ok_or(StatusCode::NOT_FOUND)?;
@@ -286,10 +338,11 @@ pub async fn conference_detail(
             p.duration_minutes,
             p.presenter_author_id,
             COALESCE(p.arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+            p.doi,
             COALESCE(p.abstract, '') as "abstract_text!",
             COALESCE(p.video_url, '') as "video_url!"
         FROM publications p
-        WHERE p.conference_id = $1
+        WHERE p.conference_id = $1 AND p.deleted_at IS NULL
         ORDER BY
             COALESCE(p.talk_date, '9999-12-31'::date),
             COALESCE(p.talk_time, '23:59:59'::time),
@@ -301,7 +354,7 @@ pub async fn conference_detail(
     .fetch_all(&pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error fetching publications: {}", e);
+        tracing::error!(error = ?e, "Failed to fetch conference publications");
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -313,10 +366,12 @@ pub async fn conference_detail(
             SELECT
                 a.slug as "slug!",
                 a.full_name,
-                COALESCE(a.id = $2, false) as "is_speaker!"
+                COALESCE(a.id = $2, false) as "is_speaker!",
+                au.is_corresponding,
+                au.is_equal_contribution
             FROM authorships au
             JOIN authors a ON au.author_id = a.id
-            WHERE au.publication_id = $1
+            WHERE au.publication_id = $1 AND a.deleted_at IS NULL
             ORDER BY au.author_position
             "#,
             pub_record.id,
@@ -325,7 +380,7 @@ pub async fn conference_detail(
         .fetch_all(&pool)
         .await
         .map_err(|e| {
-            eprintln!("Database error fetching authors: {}", e);
+            tracing::error!(error = ?e, "Failed to fetch publication authors");
             StatusCode::INTERNAL_SERVER_ERROR
         })?
         .into_iter()
@@ -333,9 +388,13 @@ pub async fn conference_detail(
             slug: row.slug,
             name: row.full_name,
             is_speaker: row.is_speaker,
+            is_corresponding: row.is_corresponding,
+            is_equal_contribution: row.is_equal_contribution,
         })
         .collect();
 
+        let (title_url, extra_links) =
+            publication_links(&pub_record.arxiv_ids, pub_record.doi.as_deref());
         publications.push(PublicationItem {
             title: pub_record.title,
             paper_type: pub_record.paper_type,
@@ -344,7 +403,8 @@ pub async fn conference_detail(
             talk_date: pub_record.talk_date.map(|d| d.to_string()).unwrap_or_default(),
             talk_time: pub_record.talk_time.map(|t| t.format("%H:%M").to_string()).unwrap_or_default(),
             duration_minutes: pub_record.duration_minutes.map(|d| d.to_string()).unwrap_or_default(),
-            arxiv_ids: pub_record.arxiv_ids,
+            title_url,
+            extra_links,
             abstract_text: pub_record.abstract_text,
             video_url: pub_record.video_url,
         });
@@ -362,7 +422,7 @@ pub async fn conference_detail(
             a.full_name as "author_name!"
         FROM committee_roles cr
         JOIN authors a ON cr.author_id = a.id
-        WHERE cr.conference_id = $1
+        WHERE cr.conference_id = $1 AND a.deleted_at IS NULL
         ORDER BY cr.committee, cr.position, a.full_name
         "#,
         conference_id
@@ -370,7 +430,7 @@ pub async fn conference_detail(
     .fetch_all(&pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error fetching committees: {}", e);
+        tracing::error!(error = ?e, "Failed to fetch conference committees");
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -410,7 +470,6 @@ pub async fn conference_detail(
 
     let template = ConferenceDetailTemplate {
         conference: ConferenceDetail {
-            slug: conference.slug.unwrap_or_default(),
             venue: conference.venue,
             year: conference.year,
             location,
@@ -435,9 +494,9 @@ pub async fn conference_detail(
     };
 
     match template.render() {
-        Ok(html) => Ok(Html(html).into_response()),
+        Ok(html) => Ok(with_etag(Html(html), &etag)),
         Err(e) => {
-            eprintln!("Template error: {}", e);
+            tracing::error!(error = ?e, "Failed to render conference page");
             Err(StatusCode::INTERNAL_SERVER_ERROR)
         }
     }
@@ -0,0 +1,102 @@
+use askama::Template;
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::{SecondsFormat, Utc};
+use sqlx::PgPool;
+
+use super::links::{base_url, publication_links, ExternalLink};
+
+/// Entries in `/feed.atom`; readers poll, so older additions have long been seen
+const FEED_SIZE: i64 = 50;
+
+#[derive(Template)]
+#[template(path = "feed.xml")]
+struct FeedTemplate {
+    base: String,
+    self_url: String,
+    updated: String,
+    entries: Vec<FeedEntry>,
+}
+
+struct FeedEntry {
+    id: String,
+    title: String,
+    added: String,
+    authors: Vec<String>,
+    url: String,
+    extra_links: Vec<ExternalLink>,
+    venue: String,
+    conference: String,
+}
+
+/// Atom feed of the most recently added publications, newest first. Entries
+/// link to the arXiv abstract or DOI, or to the conference page without one.
+pub async fn feed(State(pool): State<PgPool>) -> Result<Response, StatusCode> {
+    let base = base_url();
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            p.id,
+            p.title,
+            p.created_at,
+            COALESCE(p.arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+            p.doi,
+            c.venue,
+            c.year,
+            LOWER(c.venue) || '-' || c.year::text as "conference_slug!",
+            COALESCE(
+                (SELECT array_agg(au.published_as_name ORDER BY au.author_position)
+                 FROM authorships au
+                 WHERE au.publication_id = p.id),
+                ARRAY[]::text[]
+            ) as "authors!"
+        FROM publications p
+        JOIN conferences c ON p.conference_id = c.id
+        WHERE p.deleted_at IS NULL
+        ORDER BY p.created_at DESC, p.id
+        LIMIT $1
+        "#,
+        FEED_SIZE
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to fetch feed entries");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let updated = rows.first().map(|row| row.created_at).unwrap_or_else(Utc::now);
+    let entries = rows
+        .into_iter()
+        .map(|row| {
+            let (title_url, extra_links) = publication_links(&row.arxiv_ids, row.doi.as_deref());
+            let conference_url = format!("{}/conferences/{}", base, row.conference_slug);
+            FeedEntry {
+                id: row.id.to_string(),
+                title: row.title,
+                added: row.created_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+                authors: row.authors,
+                url: if title_url.is_empty() { conference_url } else { title_url },
+                extra_links,
+                conference: format!("{} {}", row.venue, row.year),
+                venue: row.venue,
+            }
+        })
+        .collect();
+
+    let template = FeedTemplate {
+        self_url: format!("{}/feed.atom", base),
+        base: base.to_string(),
+        updated: updated.to_rfc3339_opts(SecondsFormat::Secs, true),
+        entries,
+    };
+    match template.render() {
+        Ok(xml) => Ok(([(header::CONTENT_TYPE, "application/atom+xml")], xml).into_response()),
+        Err(e) => {
+            tracing::error!(error = ?e, "Failed to render Atom feed");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
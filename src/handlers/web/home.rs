@@ -28,7 +28,7 @@ pub async fn home(State(pool): State<PgPool>) -> Result<Response, StatusCode> {
         r#"
         SELECT 
             (SELECT COUNT(DISTINCT id) FROM author_stats) as "total_authors!",
-            (SELECT COUNT(*) FROM publications) as "total_publications!",
+            (SELECT COUNT(*) FROM publications WHERE deleted_at IS NULL) as "total_publications!",
             (SELECT COUNT(*) FROM conferences) as "total_conferences!",
             (SELECT COUNT(*) FROM committee_roles) as "total_committee_roles!"
         "#
@@ -36,7 +36,7 @@ pub async fn home(State(pool): State<PgPool>) -> Result<Response, StatusCode> {
     .fetch_one(&pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error fetching stats: {}", e);
+        tracing::error!(error = ?e, "Failed to fetch site stats");
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -58,7 +58,7 @@ pub async fn home(State(pool): State<PgPool>) -> Result<Response, StatusCode> {
     .fetch_all(&pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error fetching conferences: {}", e);
+        tracing::error!(error = ?e, "Failed to fetch recent conferences");
         StatusCode::INTERNAL_SERVER_ERROR
     })?
     .into_iter()
@@ -90,7 +90,7 @@ pub async fn home(State(pool): State<PgPool>) -> Result<Response, StatusCode> {
     match template.render() {
         Ok(html) => Ok(Html(html).into_response()),
         Err(e) => {
-            eprintln!("Template error: {}", e);
+            tracing::error!(error = ?e, "Failed to render home page");
             Err(StatusCode::INTERNAL_SERVER_ERROR)
         }
     }
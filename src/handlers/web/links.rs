@@ -0,0 +1,82 @@
+use std::sync::OnceLock;
+
+use crate::utils::{arxiv, doi};
+
+/// Site root used when `PUBLIC_BASE_URL` is unset, matching the default `BIND_ADDR`
+pub(crate) const DEFAULT_PUBLIC_BASE_URL: &str = "http://localhost:3000";
+
+/// An outbound link shown next to a publication title
+pub(crate) struct ExternalLink {
+    pub(crate) label: String,
+    pub(crate) url: String,
+}
+
+/// Links for a publication: the title links to its first arXiv abstract page
+/// (or the DOI resolver without one), the remaining ids become extra links.
+/// Ids that don't parse as arXiv ids or DOIs are dropped rather than linked
+/// somewhere broken.
+pub(crate) fn publication_links(
+    arxiv_ids: &[String],
+    doi_value: Option<&str>,
+) -> (String, Vec<ExternalLink>) {
+    let mut links: Vec<ExternalLink> = arxiv_ids
+        .iter()
+        .filter_map(|id| {
+            let id = arxiv::normalize(id)?;
+            Some(ExternalLink {
+                url: arxiv::abs_url(&id)?,
+                label: format!("arXiv:{}", id),
+            })
+        })
+        .collect();
+    links.extend(doi_value.and_then(|d| {
+        let d = doi::normalize(d)?;
+        Some(ExternalLink {
+            url: doi::url(&d)?,
+            label: format!("doi:{}", d),
+        })
+    }));
+
+    if links.is_empty() {
+        return (String::new(), links);
+    }
+    let title_url = links.remove(0).url;
+    (title_url, links)
+}
+
+/// Absolute site root for sitemap and feed URLs: `PUBLIC_BASE_URL`, read
+/// once, or `DEFAULT_PUBLIC_BASE_URL` when unset. Never derived from the
+/// request's `Host` or `X-Forwarded-Proto`, which any client can set and
+/// which would then end up in cached sitemaps and feed ids.
+pub(crate) fn base_url() -> &'static str {
+    static BASE: OnceLock<String> = OnceLock::new();
+    BASE.get_or_init(|| {
+        let configured = std::env::var("PUBLIC_BASE_URL").ok();
+        if configured.as_deref().is_none_or(|url| url.trim().is_empty()) {
+            tracing::warn!("PUBLIC_BASE_URL is not set; sitemap and feed links use {}", DEFAULT_PUBLIC_BASE_URL);
+        }
+        resolve_base_url(configured.as_deref())
+    })
+}
+
+/// `configured` without trailing slashes, or the default when missing or blank.
+fn resolve_base_url(configured: Option<&str>) -> String {
+    configured
+        .map(|url| url.trim().trim_end_matches('/'))
+        .filter(|url| !url.is_empty())
+        .unwrap_or(DEFAULT_PUBLIC_BASE_URL)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_base_url() {
+        assert_eq!(resolve_base_url(Some("https://quantumdb.org/")), "https://quantumdb.org");
+        assert_eq!(resolve_base_url(Some(" https://example.org/db ")), "https://example.org/db");
+        assert_eq!(resolve_base_url(Some("  ")), DEFAULT_PUBLIC_BASE_URL);
+        assert_eq!(resolve_base_url(None), DEFAULT_PUBLIC_BASE_URL);
+    }
+}
@@ -1,11 +1,19 @@
 pub mod home;
 pub mod authors;
 pub mod conferences;
+pub mod publications;
 pub mod admin;
 pub mod about;
+pub mod sitemap;
+pub mod feed;
+mod links;
+mod pager;
 
 pub use home::*;
 pub use authors::*;
 pub use conferences::*;
+pub use publications::*;
 pub use admin::*;
 pub use about::*;
+pub use sitemap::*;
+pub use feed::*;
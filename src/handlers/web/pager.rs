@@ -0,0 +1,47 @@
+/// "Showing 101–200 of 1234" plus prev/next links (empty when there is none)
+pub(crate) struct Pager {
+    pub(crate) total: i64,
+    pub(crate) first_shown: i64,
+    pub(crate) last_shown: i64,
+    pub(crate) prev_url: String,
+    pub(crate) next_url: String,
+}
+
+/// Clamped 1-based page number and the row offset it starts at
+pub(crate) fn page_offset(page: Option<i64>, page_size: i64) -> (i64, i64) {
+    let page = page.unwrap_or(1).max(1);
+    (page, (page - 1).saturating_mul(page_size))
+}
+
+impl Pager {
+    /// Pager for `page` of `page_size` rows, `shown` of which were fetched;
+    /// `page_url` builds the link to another page of the same listing
+    pub(crate) fn new(
+        total: i64,
+        page: i64,
+        page_size: i64,
+        shown: usize,
+        page_url: impl Fn(i64) -> String,
+    ) -> Self {
+        let offset = (page - 1).saturating_mul(page_size);
+        let shown = shown as i64;
+        Pager {
+            total,
+            first_shown: if shown > 0 { offset + 1 } else { 0 },
+            last_shown: offset + shown,
+            prev_url: if page > 1 { page_url(page - 1) } else { String::new() },
+            next_url: if offset + shown < total { page_url(page + 1) } else { String::new() },
+        }
+    }
+}
+
+/// Percent-encode a query-string value
+pub(crate) fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -0,0 +1,182 @@
+use askama::Template;
+use axum::extract::{Query, State};
+use axum::http::{StatusCode, HeaderMap};
+use axum::response::{Html, IntoResponse, Response};
+use serde::Deserialize;
+use sqlx::PgPool;
+
+use super::links::{publication_links, ExternalLink};
+use super::pager::{encode_query_value, page_offset, Pager};
+
+/// Rows per page of the web publications list
+const PUBLICATIONS_PAGE_SIZE: i64 = 100;
+
+#[derive(Template)]
+#[template(path = "publications_list.html")]
+struct PublicationsListTemplate {
+    publications: Vec<PublicationListItem>,
+    search_term: String,
+    pager: Pager,
+}
+
+#[derive(Template)]
+#[template(path = "publications_table_partial.html")]
+struct PublicationsTablePartialTemplate {
+    publications: Vec<PublicationListItem>,
+    search_term: String,
+    pager: Pager,
+}
+
+struct PublicationListItem {
+    title: String,
+    paper_type: String,
+    conference_slug: String,
+    conference_label: String,
+    title_url: String,
+    extra_links: Vec<ExternalLink>,
+    award: String,
+    authors: Vec<AuthorRef>,
+}
+
+struct AuthorRef {
+    slug: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+pub struct PublicationSearchParams {
+    #[serde(default)]
+    search: String,
+    /// 1-based page number
+    page: Option<i64>,
+}
+
+/// `/publications` URL for another page of the current search
+fn publications_page_url(search: &str, page: i64) -> String {
+    let mut url = format!("/publications?page={}", page);
+    if !search.is_empty() {
+        url.push_str(&format!("&search={}", encode_query_value(search)));
+    }
+    url
+}
+
+pub async fn publications_list(
+    Query(params): Query<PublicationSearchParams>,
+    State(pool): State<PgPool>,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    let search = params.search.trim();
+    let (page, offset) = page_offset(params.page, PUBLICATIONS_PAGE_SIZE);
+
+    let total = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "count!"
+        FROM publications p
+        WHERE p.deleted_at IS NULL
+          AND ($1 = '' OR p.search_vector @@ plainto_tsquery('english', $1))
+        "#,
+        search
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to count publications");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    // Same full-text index as the API's `?search=`; without a term, newest first
+    let publications = sqlx::query!(
+        r#"
+        SELECT
+            p.title,
+            p.paper_type::text as "paper_type!",
+            LOWER(c.venue) || '-' || c.year::text as "conference_slug!",
+            c.venue,
+            c.year,
+            COALESCE(p.arxiv_ids, ARRAY[]::text[]) as "arxiv_ids!",
+            p.doi,
+            COALESCE(p.award, '') as "award!",
+            COALESCE(
+                array_agg(a.slug ORDER BY au.author_position) FILTER (WHERE a.id IS NOT NULL),
+                ARRAY[]::text[]
+            ) as "author_slugs!",
+            COALESCE(
+                array_agg(a.full_name ORDER BY au.author_position) FILTER (WHERE a.id IS NOT NULL),
+                ARRAY[]::text[]
+            ) as "author_names!"
+        FROM publications p
+        JOIN conferences c ON p.conference_id = c.id
+        LEFT JOIN authorships au ON au.publication_id = p.id
+        LEFT JOIN authors a ON au.author_id = a.id AND a.deleted_at IS NULL
+        WHERE p.deleted_at IS NULL
+          AND ($1 = '' OR p.search_vector @@ plainto_tsquery('english', $1))
+        GROUP BY p.id, c.id
+        ORDER BY
+            CASE WHEN $1 = '' THEN 0 ELSE ts_rank(p.search_vector, plainto_tsquery('english', $1)) END DESC,
+            c.year DESC, c.venue, p.title, p.id
+        LIMIT $2 OFFSET $3
+        "#,
+        search,
+        PUBLICATIONS_PAGE_SIZE,
+        offset
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to search publications");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?
+    .into_iter()
+    .map(|row| {
+        let (title_url, extra_links) = publication_links(&row.arxiv_ids, row.doi.as_deref());
+        PublicationListItem {
+            title: row.title,
+            paper_type: row.paper_type,
+            conference_slug: row.conference_slug,
+            conference_label: format!("{} {}", row.venue, row.year),
+            title_url,
+            extra_links,
+            award: row.award,
+            authors: row
+                .author_slugs
+                .into_iter()
+                .zip(row.author_names)
+                .map(|(slug, name)| AuthorRef { slug, name })
+                .collect(),
+        }
+    })
+    .collect::<Vec<_>>();
+
+    let pager = Pager::new(total, page, PUBLICATIONS_PAGE_SIZE, publications.len(), |page| {
+        publications_page_url(search, page)
+    });
+
+    // Check if this is an HTMX request
+    let is_htmx = headers.get("hx-request").is_some();
+
+    let html = if is_htmx {
+        // Return partial template for HTMX requests
+        let template = PublicationsTablePartialTemplate {
+            publications,
+            search_term: params.search,
+            pager,
+        };
+        template.render()
+    } else {
+        // Return full page for regular requests
+        let template = PublicationsListTemplate {
+            publications,
+            search_term: params.search,
+            pager,
+        };
+        template.render()
+    };
+
+    match html {
+        Ok(html) => Ok(Html(html).into_response()),
+        Err(e) => {
+            tracing::error!(error = ?e, "Failed to render publications page");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use askama::Template;
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use sqlx::PgPool;
+
+use super::links::base_url;
+
+#[derive(Template)]
+#[template(path = "sitemap.xml")]
+struct SitemapTemplate {
+    urls: Vec<SitemapUrl>,
+}
+
+struct SitemapUrl {
+    loc: String,
+    lastmod: String,
+}
+
+/// `sitemap.xml` listing every conference and live author page.
+pub async fn sitemap(State(pool): State<PgPool>) -> Result<Response, StatusCode> {
+    let base = base_url();
+
+    let conferences = sqlx::query!(
+        r#"
+        SELECT
+            LOWER(venue) || '-' || year::text as "slug!",
+            updated_at::date::text as "lastmod!"
+        FROM conferences
+        ORDER BY year DESC, venue
+        "#
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to fetch conferences for sitemap");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let authors = sqlx::query!(
+        r#"
+        SELECT slug, updated_at::date::text as "lastmod!"
+        FROM authors
+        WHERE deleted_at IS NULL
+        ORDER BY slug
+        "#
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "Failed to fetch authors for sitemap");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let mut urls = vec![
+        SitemapUrl { loc: format!("{}/", base), lastmod: String::new() },
+        SitemapUrl { loc: format!("{}/conferences", base), lastmod: String::new() },
+        SitemapUrl { loc: format!("{}/authors", base), lastmod: String::new() },
+        SitemapUrl { loc: format!("{}/publications", base), lastmod: String::new() },
+    ];
+    urls.extend(conferences.into_iter().map(|row| SitemapUrl {
+        loc: format!("{}/conferences/{}", base, row.slug),
+        lastmod: row.lastmod,
+    }));
+    urls.extend(authors.into_iter().map(|row| SitemapUrl {
+        loc: format!("{}/authors/{}", base, row.slug),
+        lastmod: row.lastmod,
+    }));
+
+    match (SitemapTemplate { urls }).render() {
+        Ok(xml) => Ok(([(header::CONTENT_TYPE, "application/xml")], xml).into_response()),
+        Err(e) => {
+            tracing::error!(error = ?e, "Failed to render sitemap");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
@@ -8,7 +8,7 @@ pub use models::{
     Author, CreateAuthor, UpdateAuthor,
     Authorship, CreateAuthorship, UpdateAuthorship,
     CommitteeRole, CommitteeType, CommitteePosition, CreateCommitteeRole, UpdateCommitteeRole,
-    Conference, CreateConference, UpdateConference,
+    Conference, CreateConference, UpdateConference, Venue,
     Publication, PaperType, CreatePublication, UpdatePublication,
     normalize_name,
 };
@@ -16,4 +16,5 @@ pub use handlers::*;
 pub use utils::{
     parse_conference_slug, make_conference_slug,
     normalize_name_loose, name_similarity, split_name, extract_initials, generate_name_variants,
+    split_name_with_order, is_ambiguous_name_order, NameOrder, normalize_name_translit, jaro_winkler,
 };
@@ -5,7 +5,8 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use axum::{
-    http::{header, HeaderValue, Method},
+    body::Body,
+    http::{header, HeaderValue, Method, Request},
     middleware,
     response::Json,
     routing::get,
@@ -13,59 +14,130 @@ use axum::{
 };
 use tower_governor::{governor::GovernorConfigBuilder, GovernorLayer};
 use tower_http::{
-    cors::CorsLayer,
+    cors::{AllowOrigin, CorsLayer},
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
     services::ServeDir,
     set_header::SetResponseHeaderLayer,
+    trace::{DefaultOnResponse, TraceLayer},
+    LatencyUnit,
 };
-use tracing::{info, Level};
+use tracing::{info, warn, Level};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
-use quantumdb::{handlers, middleware::auth_middleware, models::*};
+use quantumdb::{
+    handlers,
+    middleware::{auth_middleware, idempotency::prune_expired_keys, idempotency_middleware, rate_limit_error, write_limit_period, TokenKeyExtractor, DEFAULT_WRITE_LIMIT_PER_MINUTE},
+    models::*,
+};
+
+/// How long in-flight requests get to finish after a shutdown signal before
+/// the server stops waiting for them.
+const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
 
 #[derive(OpenApi)]
 #[openapi(
     info(
         title = "QuantumDB API",
         version = "0.1.0",
-        description = "REST API for tracking quantum computing conferences (QIP, QCrypt, TQC), publications, authors, and committee memberships. Write operations (POST, PUT, DELETE) and admin endpoints require Bearer token authentication."
+        description = "REST API for tracking quantum computing conferences (QIP, QCrypt, TQC), publications, authors, and committee memberships. Write operations (POST, PUT, PATCH, DELETE) and admin endpoints require Bearer token authentication."
     ),
     servers(
         (url = "/api/v1", description = "API v1 endpoints")
     ),
     paths(
         handlers::list_conferences,
+        handlers::conference_trends,
+        handlers::compare_conferences,
+        handlers::conference_geo,
+        handlers::conference_index,
         handlers::get_conference,
+        handlers::conference_chairs,
+        handlers::conference_committee_anomalies,
+        handlers::conference_history,
+        handlers::conference_affiliations,
+        handlers::conference_new_authors,
+        handlers::conference_proceedings_bib,
         handlers::create_conference,
         handlers::update_conference,
+        handlers::patch_conference,
         handlers::delete_conference,
+        handlers::delete_conference_publications,
+        handlers::delete_conference_committees,
         handlers::list_authors,
+        handlers::top_authors,
+        handlers::orphan_authors,
+        handlers::author_autocomplete,
         handlers::get_author,
+        handlers::get_author_by_orcid,
+        handlers::author_vcard,
+        handlers::author_timeline,
+        handlers::author_publications,
+        handlers::author_talks,
+        handlers::author_committee_timeline,
+        handlers::author_committee_collaborators,
+        handlers::author_history,
         handlers::create_author,
         handlers::update_author,
+        handlers::patch_author,
         handlers::delete_author,
+        handlers::restore_author,
+        handlers::enrich_author_from_orcid,
         handlers::list_publications,
         handlers::get_publication,
+        handlers::get_publication_jsonld,
         handlers::create_publication,
         handlers::update_publication,
+        handlers::patch_publication,
         handlers::delete_publication,
+        handlers::restore_publication,
+        handlers::publication_duplicates,
+        handlers::related_publications,
+        handlers::list_awards,
+        handlers::merge_publication,
         handlers::list_committee_roles,
         handlers::get_committee_role,
         handlers::create_committee_role,
         handlers::update_committee_role,
+        handlers::patch_committee_role,
         handlers::delete_committee_role,
         handlers::list_authorships,
         handlers::get_authorship,
         handlers::create_authorship,
         handlers::update_authorship,
+        handlers::reorder_authorships,
+        handlers::patch_authorship,
         handlers::delete_authorship,
+        handlers::global_stats,
+        handlers::list_institutions,
+        handlers::create_institution,
+        handlers::change_stream,
+        handlers::name_variants,
+        handlers::normalized_name,
+        handlers::list_author_reviews,
+        handlers::scan_author_reviews,
+        handlers::resolve_author_review,
+        handlers::list_topics,
+        handlers::get_publication_topics,
+        handlers::add_publication_topics,
+        handlers::delete_publication_topic,
     ),
     components(schemas(
-        Conference, CreateConference, UpdateConference,
-        Author, CreateAuthor, UpdateAuthor,
+        Conference, CreateConference, UpdateConference, Venue, AcceptanceTrend,
+        ConferenceComparison, ConferenceComparisonRow, ConferenceIndexEntry, ConferenceCountryCount, VenueConferenceCount,
+        DeletedPublications, DeletedCommitteeRoles,
+        Author, CreateAuthor, UpdateAuthor, AuthorTimelineYear, AuthorPublication, AuthorTalk, NewAuthor, TopAuthor, AuthorList, AuthorPage, ScoredAuthor, AuthorWithStats, AuthorStatsSummary, AuthorSuggestion, OrcidEnrichment,
+        NameVariants, NormalizedName,
         Publication, CreatePublication, UpdatePublication, PaperType,
-        CommitteeRole, CreateCommitteeRole, UpdateCommitteeRole, CommitteeType, CommitteePosition,
-        Authorship, CreateAuthorship, UpdateAuthorship,
+        PublicationDuplicateCluster, MergePublication, AwardedPublication, RelatedPublication,
+        CommitteeRole, CommitteeRoleAuthor, CommitteeRoleWithAuthor, CreateCommitteeRole, UpdateCommitteeRole, CommitteeType, CommitteePosition,
+        CommitteeTimelineEntry, CommitteeCollaborator, ConferenceChair, CommitteeChairs, CommitteeAnomaly, AnomalySeverity,
+        EntityChange, ChangeEvent,
+        Authorship, CreateAuthorship, UpdateAuthorship, ReorderAuthorships,
+        GlobalStats, VenueStats, LatestConference,
+        Institution, CreateInstitution, AffiliationCount,
+        ReviewStatus, ReviewDecision, ReviewAuthor, AuthorReviewItem, ResolveAuthorReview, ReviewScan,
+        TopicCount, AddTopics,
     )),
     modifiers(&SecurityAddon),
     tags(
@@ -74,6 +146,12 @@ use quantumdb::{handlers, middleware::auth_middleware, models::*};
         (name = "publications", description = "Publication management"),
         (name = "committees", description = "Committee role management"),
         (name = "authorships", description = "Authorship (author-publication links) management"),
+        (name = "stats", description = "Aggregate statistics"),
+        (name = "institutions", description = "Canonical institutions for affiliation matching"),
+        (name = "events", description = "Live stream of data changes"),
+        (name = "utils", description = "Previews of how names are normalized for matching"),
+        (name = "review", description = "Curator queue of suspected duplicate authors"),
+        (name = "topics", description = "Research-area tags on publications"),
     )
 )]
 struct ApiDoc;
@@ -103,7 +181,15 @@ impl utoipa::Modify for SecurityAddon {
 async fn main() -> Result<(), sqlx::Error> {
     dotenv().ok();
     let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    let pool = PgPoolOptions::new().connect(&url).await?;
+    let bind_addr = std::env::var("BIND_ADDR").unwrap_or_else(|_| "0.0.0.0:3000".to_string());
+    let max_connections = std::env::var("DATABASE_MAX_CONNECTIONS")
+        .ok()
+        .map(|v| v.parse::<u32>().expect("DATABASE_MAX_CONNECTIONS must be a positive integer"))
+        .unwrap_or(10);
+    let pool = PgPoolOptions::new()
+        .max_connections(max_connections)
+        .connect(&url)
+        .await?;
 
     tracing_subscriber::fmt().with_max_level(Level::INFO).init();
 
@@ -111,24 +197,79 @@ async fn main() -> Result<(), sqlx::Error> {
     let api_routes = Router::new()
         // Conference routes (read-only)
         .route("/conferences", get(handlers::list_conferences))
+        .route("/conferences/trends", get(handlers::conference_trends))
+        .route("/conferences/compare", get(handlers::compare_conferences))
+        .route("/conferences/geo", get(handlers::conference_geo))
+        .route("/conferences/index.json", get(handlers::conference_index))
         .route("/conferences/{id}", get(handlers::get_conference))
+        .route("/conferences/{id}/chairs", get(handlers::conference_chairs))
+        .route("/conferences/{id}/committee-anomalies", get(handlers::conference_committee_anomalies))
+        .route("/conferences/{id}/history", get(handlers::conference_history))
+        .route("/conferences/{id}/affiliations", get(handlers::conference_affiliations))
+        .route("/conferences/{id}/new-authors", get(handlers::conference_new_authors))
+        .route("/conferences/{id}/proceedings.bib", get(handlers::conference_proceedings_bib))
         // Author routes (read-only)
         .route("/authors", get(handlers::list_authors))
+        .route("/authors/top", get(handlers::top_authors))
+        .route("/authors/orphans", get(handlers::orphan_authors))
+        .route("/authors/autocomplete", get(handlers::author_autocomplete))
+        .route("/authors/by-orcid/{orcid}", get(handlers::get_author_by_orcid))
         .route("/authors/{id}", get(handlers::get_author))
+        .route("/authors/{id}/timeline", get(handlers::author_timeline))
+        .route("/authors/{id}/publications", get(handlers::author_publications))
+        .route("/authors/{id}/talks", get(handlers::author_talks))
+        .route("/authors/{id}/vcard", get(handlers::author_vcard))
+        .route("/authors/{id}/committee-timeline", get(handlers::author_committee_timeline))
+        .route("/authors/{id}/committee-collaborators", get(handlers::author_committee_collaborators))
+        .route("/authors/{id}/history", get(handlers::author_history))
         // Publication routes (read-only)
         .route("/publications", get(handlers::list_publications))
+        .route("/publications/duplicates", get(handlers::publication_duplicates))
         .route("/publications/{id}", get(handlers::get_publication))
+        .route("/publications/{id}/related", get(handlers::related_publications))
+        .route("/publications/{id}/topics", get(handlers::get_publication_topics))
+        .route("/awards", get(handlers::list_awards))
+        .route("/topics", get(handlers::list_topics))
         // Committee routes (read-only)
         .route("/committees", get(handlers::list_committee_roles))
         .route("/committees/{id}", get(handlers::get_committee_role))
         // Authorship routes (read-only)
         .route("/authorships", get(handlers::list_authorships))
         .route("/authorships/{id}", get(handlers::get_authorship))
+        // Aggregate statistics
+        .route("/stats", get(handlers::global_stats))
+        // Institutions
+        .route("/institutions", get(handlers::list_institutions))
+        // Name matching previews
+        .route("/utils/name-variants", get(handlers::name_variants))
+        .route("/utils/normalize", get(handlers::normalized_name))
+        // Duplicate-author review queue
+        .route("/review/authors", get(handlers::list_author_reviews))
+        // Change stream (Server-Sent Events)
+        .route("/events", get(handlers::change_stream))
         // OpenAPI spec endpoint
         .route("/openapi.json", get(|| async { Json(ApiDoc::openapi()) }))
         // Swagger UI (will be served at /api/v1/swagger-ui/)
         .merge(SwaggerUi::new("/swagger-ui").url("/api/v1/openapi.json", ApiDoc::openapi()));
 
+    // Per-token write limit: RATE_LIMIT_PER_MINUTE requests of burst per API token,
+    // refilled evenly over a minute. Only the protected (write) routes are limited;
+    // reads are covered by the per-IP limiter below.
+    let write_limit_per_minute = std::env::var("RATE_LIMIT_PER_MINUTE")
+        .ok()
+        .map(|v| v.parse::<u32>().expect("RATE_LIMIT_PER_MINUTE must be a positive integer"))
+        .unwrap_or(DEFAULT_WRITE_LIMIT_PER_MINUTE)
+        .max(1);
+    let write_governor_conf = Arc::new(
+        GovernorConfigBuilder::default()
+            .key_extractor(TokenKeyExtractor)
+            .period(write_limit_period(write_limit_per_minute))
+            .burst_size(write_limit_per_minute)
+            .error_handler(rate_limit_error)
+            .finish()
+            .expect("write rate limit config is valid"),
+    );
+
     // Protected API routes (require authentication)
     let protected_api_routes = Router::new()
         // Conference write operations
@@ -136,15 +277,27 @@ async fn main() -> Result<(), sqlx::Error> {
         .route(
             "/conferences/{id}",
             axum::routing::put(handlers::update_conference)
+                .patch(handlers::patch_conference)
                 .delete(handlers::delete_conference),
         )
+        .route(
+            "/conferences/{id}/publications",
+            axum::routing::delete(handlers::delete_conference_publications),
+        )
+        .route(
+            "/conferences/{id}/committees",
+            axum::routing::delete(handlers::delete_conference_committees),
+        )
         // Author write operations
         .route("/authors", axum::routing::post(handlers::create_author))
         .route(
             "/authors/{id}",
             axum::routing::put(handlers::update_author)
+                .patch(handlers::patch_author)
                 .delete(handlers::delete_author),
         )
+        .route("/authors/{id}/restore", axum::routing::post(handlers::restore_author))
+        .route("/authors/{id}/enrich/orcid", axum::routing::post(handlers::enrich_author_from_orcid))
         // Publication write operations
         .route(
             "/publications",
@@ -153,8 +306,29 @@ async fn main() -> Result<(), sqlx::Error> {
         .route(
             "/publications/{id}",
             axum::routing::put(handlers::update_publication)
+                .patch(handlers::patch_publication)
                 .delete(handlers::delete_publication),
         )
+        .route(
+            "/publications/{id}/restore",
+            axum::routing::post(handlers::restore_publication),
+        )
+        .route(
+            "/publications/{id}/merge",
+            axum::routing::post(handlers::merge_publication),
+        )
+        .route(
+            "/publications/{id}/authorships/order",
+            axum::routing::put(handlers::reorder_authorships),
+        )
+        .route(
+            "/publications/{id}/topics",
+            axum::routing::post(handlers::add_publication_topics),
+        )
+        .route(
+            "/publications/{id}/topics/{topic}",
+            axum::routing::delete(handlers::delete_publication_topic),
+        )
         // Committee write operations
         .route(
             "/committees",
@@ -163,6 +337,7 @@ async fn main() -> Result<(), sqlx::Error> {
         .route(
             "/committees/{id}",
             axum::routing::put(handlers::update_committee_role)
+                .patch(handlers::patch_committee_role)
                 .delete(handlers::delete_committee_role),
         )
         // Authorship write operations
@@ -173,8 +348,18 @@ async fn main() -> Result<(), sqlx::Error> {
         .route(
             "/authorships/{id}",
             axum::routing::put(handlers::update_authorship)
+                .patch(handlers::patch_authorship)
                 .delete(handlers::delete_authorship),
         )
+        // Institution write operations
+        .route("/institutions", axum::routing::post(handlers::create_institution))
+        // Review queue decisions
+        .route("/review/authors/scan", axum::routing::post(handlers::scan_author_reviews))
+        .route("/review/authors/{id}/resolve", axum::routing::post(handlers::resolve_author_review))
+        // Innermost, so replays are only served to authenticated callers
+        .layer(middleware::from_fn_with_state(pool.clone(), idempotency_middleware))
+        // Rate limit sits inside auth so only valid tokens get a bucket
+        .layer(GovernorLayer { config: write_governor_conf.clone() })
         // Apply authentication middleware to all protected routes
         .layer(middleware::from_fn(auth_middleware));
 
@@ -185,21 +370,28 @@ async fn main() -> Result<(), sqlx::Error> {
         .route("/authors/{id}", get(handlers::web::author_detail))
         .route("/conferences", get(handlers::web::conferences_list))
         .route("/conferences/{slug}", get(handlers::web::conference_detail))
+        .route("/publications", get(handlers::web::publications_list))
         .route("/about", get(handlers::web::about))
+        .route("/sitemap.xml", get(handlers::web::sitemap))
+        .route("/feed.atom", get(handlers::web::feed))
         .route("/health", get(health));
 
     // Protected web routes (admin operations)
     let protected_web_routes = Router::new()
-        .route("/admin/refresh-stats", get(handlers::web::refresh_stats))
+        .route(
+            "/admin/refresh-stats",
+            axum::routing::post(handlers::web::refresh_stats),
+        )
+        .route(
+            "/admin/reindex-search",
+            axum::routing::post(handlers::web::reindex_search),
+        )
         .layer(middleware::from_fn(auth_middleware));
 
-    // CORS: allow GET on read-only endpoints from any origin (read API is public);
-    // write endpoints additionally require a Bearer token, which CORS does not protect
-    // against — the token check is the real boundary. Tighten origins via env if needed.
-    let cors = CorsLayer::new()
-        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
-        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE])
-        .allow_origin(tower_http::cors::Any);
+    // CORS for the JSON API only, from `ALLOWED_ORIGINS` (comma-separated origins,
+    // or `*` for any). Unset means no cross-origin access. The Bearer token remains
+    // the real boundary for writes; CORS only controls which browser apps may call us.
+    let cors = cors_layer(std::env::var("ALLOWED_ORIGINS").ok().as_deref());
 
     // Per-IP rate limit: 10 req/sec sustained (period = 100ms) with bursts up to 100.
     // A normal browser page-load fans out a few parallel requests; this absorbs that
@@ -216,11 +408,23 @@ async fn main() -> Result<(), sqlx::Error> {
     // Background task: periodically prune the limiter's per-IP state so memory doesn't
     // grow unbounded. The crate's docs explicitly recommend this.
     let governor_limiter = governor_conf.limiter().clone();
+    let write_governor_limiter = write_governor_conf.limiter().clone();
     tokio::spawn(async move {
         let mut ticker = tokio::time::interval(Duration::from_secs(60));
         loop {
             ticker.tick().await;
             governor_limiter.retain_recent();
+            write_governor_limiter.retain_recent();
+        }
+    });
+
+    // Background task: drop idempotency keys past their 24 hours, off the request path
+    let prune_pool = pool.clone();
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(15 * 60));
+        loop {
+            ticker.tick().await;
+            prune_expired_keys(&prune_pool).await;
         }
     });
 
@@ -243,32 +447,217 @@ async fn main() -> Result<(), sqlx::Error> {
             HeaderValue::from_static("geolocation=(), microphone=(), camera=()"),
         ));
 
+    // Access log: one INFO line per response with status and latency, inside a span
+    // carrying method, path and request id. The id is taken from an incoming
+    // `x-request-id` header or generated, and echoed back on the response.
+    let request_tracing = tower::ServiceBuilder::new()
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(|request: &Request<Body>| {
+                    let request_id = request
+                        .headers()
+                        .get("x-request-id")
+                        .and_then(|v| v.to_str().ok())
+                        .unwrap_or("-");
+                    tracing::info_span!(
+                        "request",
+                        method = %request.method(),
+                        path = %request.uri().path(),
+                        request_id = %request_id,
+                    )
+                })
+                .on_response(
+                    DefaultOnResponse::new()
+                        .level(Level::INFO)
+                        .latency_unit(LatencyUnit::Millis),
+                ),
+        )
+        .layer(PropagateRequestIdLayer::x_request_id());
+
     let app = Router::new()
         .merge(web_routes)
         .merge(protected_web_routes)
-        .nest("/api/v1", api_routes.merge(protected_api_routes))
+        .nest("/api/v1", api_routes.merge(protected_api_routes).layer(cors))
         .nest_service("/static", ServeDir::new("static"))
         .layer(GovernorLayer { config: governor_conf })
-        .layer(cors)
         .layer(security_headers)
-        // Database pool state
-        .with_state(pool);
+        .layer(request_tracing)
+        // Database pool state (kept so it can be closed on shutdown)
+        .with_state(pool.clone());
 
-    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+    let listener = tokio::net::TcpListener::bind(&bind_addr)
+        .await
+        .unwrap_or_else(|e| panic!("failed to bind {}: {}", bind_addr, e));
 
-    info!("Server is running on http://0.0.0.0:3000");
-    info!("Web interface available at http://0.0.0.0:3000/");
-    info!("API documentation at http://0.0.0.0:3000/api/v1/swagger-ui/");
+    info!("Server is running on http://{} (pool size {})", bind_addr, max_connections);
+    info!("Web interface available at http://{}/", bind_addr);
+    info!("API documentation at http://{}/api/v1/swagger-ui/", bind_addr);
     // `with_connect_info` exposes the peer SocketAddr to the rate-limiter middleware
     // so it can key on client IP. Required by tower_governor's default extractor.
-    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
-        .await
-        .unwrap();
+    // On SIGTERM/Ctrl-C, stop accepting connections and let in-flight requests
+    // finish before closing the pool, so restarts don't cut off mutations.
+    // A request that outlives SHUTDOWN_DRAIN_TIMEOUT is abandoned.
+    let server = axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .with_graceful_shutdown(shutdown_signal());
+    let drain_deadline = async {
+        handlers::shutdown_token().cancelled().await;
+        tokio::time::sleep(SHUTDOWN_DRAIN_TIMEOUT).await;
+    };
+    tokio::select! {
+        result = server => result.unwrap(),
+        _ = drain_deadline => warn!(
+            "In-flight requests still running after {:?}, shutting down anyway",
+            SHUTDOWN_DRAIN_TIMEOUT
+        ),
+    }
+
+    info!("Server stopped, closing database pool");
+    pool.close().await;
 
     Ok(())
 }
 
+/// Build the API CORS layer from an `ALLOWED_ORIGINS` value. Preflight `OPTIONS`
+/// requests for the listed methods are answered by the layer itself.
+fn cors_layer(allowed_origins: Option<&str>) -> CorsLayer {
+    let cors = CorsLayer::new()
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::PATCH, Method::DELETE])
+        .allow_headers([
+            header::AUTHORIZATION,
+            header::CONTENT_TYPE,
+            header::HeaderName::from_static(quantumdb::middleware::idempotency::IDEMPOTENCY_KEY_HEADER),
+        ])
+        // Response headers browser clients read: page size, ETags for
+        // conditional requests, and how long to back off after a 429
+        .expose_headers([
+            header::HeaderName::from_static("x-page-size"),
+            header::ETAG,
+            header::RETRY_AFTER,
+        ]);
+
+    match allowed_origins.map(str::trim).filter(|s| !s.is_empty()) {
+        Some("*") => cors.allow_origin(AllowOrigin::any()),
+        Some(list) => {
+            let origins: Vec<HeaderValue> = list
+                .split(',')
+                .map(str::trim)
+                .filter(|o| !o.is_empty())
+                .map(|o| {
+                    HeaderValue::from_str(o)
+                        .unwrap_or_else(|_| panic!("invalid origin in ALLOWED_ORIGINS: {}", o))
+                })
+                .collect();
+            info!("CORS enabled for {} origin(s)", origins.len());
+            cors.allow_origin(AllowOrigin::list(origins))
+        }
+        None => cors,
+    }
+}
+
+/// Resolves when the process receives Ctrl-C or (on Unix) SIGTERM, cancelling
+/// the shutdown token so open `/events` streams end and the drain can finish
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl-C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    info!("Shutdown signal received, draining in-flight requests");
+    handlers::shutdown_token().cancel();
+}
+
 // Health check endpoint
 async fn health() -> &'static str {
     "OK"
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The TypeScript client is generated from this spec, so every schema a
+    /// path refers to must be registered in `components(schemas(...))`.
+    #[test]
+    fn openapi_refs_resolve() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let schemas = spec["components"]["schemas"].as_object().unwrap();
+
+        fn collect_refs(value: &serde_json::Value, refs: &mut Vec<String>) {
+            match value {
+                serde_json::Value::Object(map) => {
+                    if let Some(serde_json::Value::String(r)) = map.get("$ref") {
+                        refs.push(r.clone());
+                    }
+                    map.values().for_each(|v| collect_refs(v, refs));
+                }
+                serde_json::Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+                _ => {}
+            }
+        }
+
+        let mut refs = Vec::new();
+        collect_refs(&spec, &mut refs);
+        let missing: Vec<&String> = refs
+            .iter()
+            .filter(|r| {
+                r.strip_prefix("#/components/schemas/")
+                    .is_none_or(|name| !schemas.contains_key(name))
+            })
+            .collect();
+        assert!(missing.is_empty(), "unresolved $refs: {:?}", missing);
+    }
+
+    #[tokio::test]
+    async fn cors_exposes_response_headers() {
+        use tower::ServiceExt;
+
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(cors_layer(Some("https://example.org")));
+        let request = axum::http::Request::builder()
+            .uri("/")
+            .header(header::ORIGIN, "https://example.org")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let exposed = response.headers()[header::ACCESS_CONTROL_EXPOSE_HEADERS].to_str().unwrap().to_string();
+        for name in ["x-page-size", "etag", "retry-after"] {
+            assert!(exposed.contains(name), "{} not in {}", name, exposed);
+        }
+    }
+
+    #[test]
+    fn openapi_publication_schema_is_complete() {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let properties = &spec["components"]["schemas"]["Publication"]["properties"];
+        for field in [
+            "presenter_author_id",
+            "is_proceedings_track",
+            "talk_date",
+            "talk_time",
+            "talk_datetime_utc",
+            "duration_minutes",
+            "deleted_at",
+        ] {
+            assert!(properties.get(field).is_some(), "Publication schema lacks {}", field);
+        }
+    }
+}
@@ -68,7 +68,7 @@ pub async fn auth_middleware(headers: HeaderMap, request: Request, next: Next) -
             .filter(|s| !s.is_empty())
             .collect::<Vec<String>>(),
         Err(_) => {
-            eprintln!("ERROR: API_TOKENS environment variable not set");
+            tracing::error!("API_TOKENS environment variable not set");
             return (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 [(axum::http::header::CONTENT_TYPE, "application/json")],
@@ -109,7 +109,7 @@ fn unauthorized_json(message: &str) -> Response {
 
 // Pull `token=...` out of a raw query string. Tokens generated by
 // tools/generate_token.sh are URL-safe (alphanumeric + `-`/`_`), so no decoding.
-fn extract_query_token(query: &str) -> Option<String> {
+pub(crate) fn extract_query_token(query: &str) -> Option<String> {
     query
         .split('&')
         .find_map(|pair| pair.strip_prefix("token=").map(|v| v.to_string()))
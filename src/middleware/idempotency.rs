@@ -0,0 +1,299 @@
+use axum::{
+    body::{to_bytes, Body, HttpBody},
+    extract::{Request, State},
+    http::{header, HeaderMap, HeaderName, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::{json, Map, Value};
+use sqlx::{Pool, Postgres};
+use tower_governor::key_extractor::KeyExtractor;
+
+use super::rate_limit::TokenKeyExtractor;
+
+/// Request header carrying the client-chosen key
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Set to `true` on a response replayed from a stored key
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// Longest accepted key; UUIDs and content hashes fit comfortably
+const MAX_KEY_LEN: usize = 255;
+
+/// Largest request body buffered for hashing, matching axum's default `Json` limit
+const MAX_REQUEST_BODY: usize = 2 * 1024 * 1024;
+
+/// Largest response body stored for replay; bigger responses (or ones of
+/// unknown length) are passed through and the key released, like a 5xx
+const MAX_STORED_RESPONSE: usize = 2 * 1024 * 1024;
+
+/// Response headers stored and replayed alongside the body and content type
+const REPLAYED_HEADERS: [HeaderName; 3] = [
+    header::LOCATION,
+    header::ETAG,
+    HeaderName::from_static("x-page-size"),
+];
+
+/// Makes POST requests that carry an `Idempotency-Key` header safe to retry.
+///
+/// The first request with a key runs normally and its response is stored
+/// (for 24 hours, per API token). A repeat with the same key, path and body
+/// gets that response back with `Idempotent-Replayed: true` and nothing is
+/// written again. Reusing a key for a different request is a 422; a repeat
+/// that arrives while the first is still running is a 409. 5xx responses
+/// are not stored, so those retries run again; neither are bodies over
+/// `MAX_STORED_RESPONSE`. Expired keys are removed by `prune_expired_keys`.
+///
+/// The handler runs in its own task so that a client timing out mid-request
+/// doesn't cancel it and leave the key unresolved.
+pub async fn idempotency_middleware(State(pool): State<Pool<Postgres>>, request: Request, next: Next) -> Response {
+    if request.method() != Method::POST {
+        return next.run(request).await;
+    }
+    let key = match request.headers().get(IDEMPOTENCY_KEY_HEADER) {
+        None => return next.run(request).await,
+        Some(value) => match value.to_str().map(str::trim) {
+            Ok(key) if !key.is_empty() && key.len() <= MAX_KEY_LEN => key.to_string(),
+            _ => {
+                return error_json(
+                    StatusCode::BAD_REQUEST,
+                    &format!("Idempotency-Key must be 1 to {} visible ASCII characters", MAX_KEY_LEN),
+                )
+            }
+        },
+    };
+    // Never fails; requests without a token (AUTH_DISABLED=1) share one scope
+    let client = TokenKeyExtractor.extract(&request).unwrap_or_default();
+    let path = request.uri().path_and_query().map(|p| p.to_string()).unwrap_or_default();
+
+    let (parts, body) = request.into_parts();
+    let body = match to_bytes(body, MAX_REQUEST_BODY).await {
+        Ok(body) => body,
+        Err(_) => return error_json(StatusCode::PAYLOAD_TOO_LARGE, "Request body is too large"),
+    };
+
+    // Claim the key. A placeholder left by a server that died mid-request is
+    // taken over once it is clearly abandoned, and an expired key that
+    // hasn't been pruned yet counts as unused.
+    let claimed = sqlx::query_scalar!(
+        r#"
+        INSERT INTO idempotency_keys (client, key, path, request_hash)
+        VALUES (md5($1), $2, $3, md5($4::bytea))
+        ON CONFLICT (client, key) DO UPDATE
+            SET path = EXCLUDED.path, request_hash = EXCLUDED.request_hash, created_at = NOW(),
+                status = NULL, content_type = NULL, body = NULL, headers = NULL
+            WHERE (idempotency_keys.status IS NULL
+                   AND idempotency_keys.created_at < NOW() - INTERVAL '10 minutes')
+               OR idempotency_keys.created_at < NOW() - INTERVAL '24 hours'
+        RETURNING key
+        "#,
+        client,
+        key,
+        path,
+        body.as_ref()
+    )
+    .fetch_optional(&pool)
+    .await;
+
+    match claimed {
+        Ok(Some(_)) => {}
+        Ok(None) => return replay(&pool, &client, &key, &path, &body).await,
+        Err(e) => {
+            tracing::error!("Failed to claim idempotency key: {:?}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+
+    let request = Request::from_parts(parts, Body::from(body));
+    let task = tokio::spawn(async move {
+        let response = next.run(request).await;
+        store(&pool, &client, &key, response).await
+    });
+    task.await.unwrap_or_else(|e| {
+        tracing::error!("Idempotent request task failed: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR.into_response()
+    })
+}
+
+/// Save the first response under the key and pass it on; on a 5xx, or a body
+/// too large to store, release the key instead so the retry runs again.
+async fn store(pool: &Pool<Postgres>, client: &str, key: &str, response: Response) -> Response {
+    let storable = response
+        .body()
+        .size_hint()
+        .upper()
+        .is_some_and(|len| len <= MAX_STORED_RESPONSE as u64);
+    if response.status().is_server_error() || !storable {
+        if !storable {
+            tracing::warn!("Response for idempotency key is too large to store; releasing the key");
+        }
+        release(pool, client, key).await;
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, MAX_STORED_RESPONSE).await {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::error!("Failed to buffer response for idempotency key: {:?}", e);
+            release(pool, client, key).await;
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let content_type = parts.headers.get(header::CONTENT_TYPE).and_then(|v| v.to_str().ok());
+    let result = sqlx::query!(
+        r#"
+        UPDATE idempotency_keys
+        SET status = $3, content_type = $4, body = $5, headers = $6
+        WHERE client = md5($1) AND key = $2
+        "#,
+        client,
+        key,
+        parts.status.as_u16() as i16,
+        content_type,
+        body.as_ref(),
+        replayed_headers(&parts.headers)
+    )
+    .execute(pool)
+    .await;
+    if let Err(e) = result {
+        tracing::error!("Failed to record idempotency key: {:?}", e);
+    }
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// Drop a claimed key without storing a response.
+async fn release(pool: &Pool<Postgres>, client: &str, key: &str) {
+    if let Err(e) = sqlx::query!("DELETE FROM idempotency_keys WHERE client = md5($1) AND key = $2", client, key)
+        .execute(pool)
+        .await
+    {
+        tracing::error!("Failed to release idempotency key: {:?}", e);
+    }
+}
+
+/// Delete keys past their 24 hours. Run periodically by a background task in
+/// `main` rather than on each request.
+pub async fn prune_expired_keys(pool: &Pool<Postgres>) {
+    if let Err(e) = sqlx::query!("DELETE FROM idempotency_keys WHERE created_at < NOW() - INTERVAL '24 hours'")
+        .execute(pool)
+        .await
+    {
+        tracing::error!("Failed to prune idempotency keys: {:?}", e);
+    }
+}
+
+/// The `REPLAYED_HEADERS` present on a response, as a JSON object.
+fn replayed_headers(headers: &HeaderMap) -> Value {
+    let stored: Map<String, Value> = REPLAYED_HEADERS
+        .iter()
+        .filter_map(|name| {
+            let value = headers.get(name)?.to_str().ok()?;
+            Some((name.to_string(), Value::from(value)))
+        })
+        .collect();
+    Value::Object(stored)
+}
+
+/// Put headers saved by `replayed_headers` back on a response.
+fn restore_headers(headers: &mut HeaderMap, stored: &Value) {
+    for name in &REPLAYED_HEADERS {
+        let value = stored.get(name.as_str()).and_then(Value::as_str);
+        if let Some(value) = value.and_then(|v| HeaderValue::from_str(v).ok()) {
+            headers.insert(name.clone(), value);
+        }
+    }
+}
+
+/// Answer a request whose key was already claimed.
+async fn replay(pool: &Pool<Postgres>, client: &str, key: &str, path: &str, body: &[u8]) -> Response {
+    let stored = sqlx::query!(
+        r#"
+        SELECT path, request_hash = md5($3::bytea) as "same_body!", status, content_type, body, headers
+        FROM idempotency_keys
+        WHERE client = md5($1) AND key = $2
+        "#,
+        client,
+        key,
+        body
+    )
+    .fetch_optional(pool)
+    .await;
+
+    let stored = match stored {
+        Ok(Some(stored)) => stored,
+        // Released by a 5xx between our claim attempt and this lookup
+        Ok(None) => {
+            return error_json(StatusCode::CONFLICT, "The request with this Idempotency-Key failed; retry it")
+        }
+        Err(e) => {
+            tracing::error!("Failed to look up idempotency key: {:?}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    if stored.path != path || !stored.same_body {
+        return error_json(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Idempotency-Key was already used for a different request",
+        );
+    }
+    let Some(status) = stored.status.and_then(|s| StatusCode::from_u16(s as u16).ok()) else {
+        return error_json(StatusCode::CONFLICT, "A request with this Idempotency-Key is still in progress");
+    };
+
+    let mut response = (status, stored.body.unwrap_or_default()).into_response();
+    if let Some(content_type) = stored.content_type.and_then(|c| HeaderValue::from_str(&c).ok()) {
+        response.headers_mut().insert(header::CONTENT_TYPE, content_type);
+    }
+    if let Some(headers) = &stored.headers {
+        restore_headers(response.headers_mut(), headers);
+    }
+    response
+        .headers_mut()
+        .insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+fn error_json(status: StatusCode, message: &str) -> Response {
+    (
+        status,
+        [(header::CONTENT_TYPE, "application/json")],
+        json!({
+            "error": status.canonical_reason().unwrap_or("Error"),
+            "message": message,
+        })
+        .to_string(),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replayed_headers_round_trip() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::LOCATION, HeaderValue::from_static("/api/v1/publications/abc"));
+        headers.insert(header::ETAG, HeaderValue::from_static("\"v1\""));
+        headers.insert("x-page-size", HeaderValue::from_static("50"));
+        headers.insert(header::SET_COOKIE, HeaderValue::from_static("session=1"));
+
+        let stored = replayed_headers(&headers);
+        assert_eq!(
+            stored,
+            json!({"location": "/api/v1/publications/abc", "etag": "\"v1\"", "x-page-size": "50"})
+        );
+
+        let mut replayed = HeaderMap::new();
+        restore_headers(&mut replayed, &stored);
+        assert_eq!(replayed.len(), 3);
+        assert_eq!(replayed[header::LOCATION], "/api/v1/publications/abc");
+        assert_eq!(replayed[header::ETAG], "\"v1\"");
+        assert_eq!(replayed["x-page-size"], "50");
+        assert!(replayed.get(header::SET_COOKIE).is_none());
+    }
+}
@@ -1,3 +1,7 @@
 pub mod auth;
+pub mod idempotency;
+pub mod rate_limit;
 
 pub use auth::auth_middleware;
+pub use idempotency::idempotency_middleware;
+pub use rate_limit::{rate_limit_error, write_limit_period, TokenKeyExtractor, DEFAULT_WRITE_LIMIT_PER_MINUTE};
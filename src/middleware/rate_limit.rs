@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    http::{header, Request, Response, StatusCode},
+};
+use serde_json::json;
+use tower_governor::{key_extractor::KeyExtractor, GovernorError};
+
+use super::auth::extract_query_token;
+
+/// Write requests allowed per token per minute when `RATE_LIMIT_PER_MINUTE` is unset
+pub const DEFAULT_WRITE_LIMIT_PER_MINUTE: u32 = 600;
+
+/// How often the write limiter refills one request, for `per_minute` requests a
+/// minute. Nanosecond resolution, so it stays non-zero (which the limiter
+/// requires) for any `u32` limit.
+pub fn write_limit_period(per_minute: u32) -> Duration {
+    Duration::from_nanos(60_000_000_000 / u64::from(per_minute.max(1)))
+}
+
+/// Keys the write limiter on the caller's API token, so one busy scraper can't
+/// starve other clients sharing an IP (or be spread across several IPs).
+///
+/// Reads the token the same way `auth_middleware` does (Bearer header, then
+/// `?token=`). Requests without one — only possible with `AUTH_DISABLED=1` —
+/// share a single bucket.
+#[derive(Debug, Clone, Copy)]
+pub struct TokenKeyExtractor;
+
+impl KeyExtractor for TokenKeyExtractor {
+    type Key = String;
+
+    fn extract<T>(&self, req: &Request<T>) -> Result<Self::Key, GovernorError> {
+        let bearer = req
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(|t| t.trim().to_string());
+
+        Ok(bearer
+            .or_else(|| req.uri().query().and_then(extract_query_token))
+            .unwrap_or_default())
+    }
+}
+
+/// Error handler for the write limiter: 429 with `Retry-After` (set by
+/// tower_governor) and a JSON body matching the auth middleware's error shape.
+pub fn rate_limit_error(error: GovernorError) -> Response<Body> {
+    let (wait_time, headers) = match error {
+        GovernorError::TooManyRequests { wait_time, headers } => (wait_time, headers),
+        mut other => return other.as_response(),
+    };
+
+    let body = json!({
+        "error": "Too Many Requests",
+        "message": format!("Write rate limit exceeded. Retry in {}s.", wait_time),
+    })
+    .to_string();
+
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+    if let Some(headers) = headers {
+        response.headers_mut().extend(headers);
+    }
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_governor::governor::GovernorConfigBuilder;
+
+    #[test]
+    fn test_write_limit_period() {
+        assert_eq!(write_limit_period(600), Duration::from_millis(100));
+        assert_eq!(write_limit_period(0), Duration::from_secs(60));
+        // Above 60000 a minute a millisecond period would round down to zero
+        for per_minute in [60_001, 1_000_000, u32::MAX] {
+            let period = write_limit_period(per_minute);
+            assert!(!period.is_zero(), "{} per minute", per_minute);
+            assert!(GovernorConfigBuilder::default()
+                .period(period)
+                .burst_size(per_minute)
+                .finish()
+                .is_some());
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// One entry in a record's audit trail
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct EntityChange {
+    pub id: Uuid,
+    /// `author` or `conference`
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    /// `create`, `update`, `delete` or `restore`
+    pub action: String,
+    pub modifier: Option<String>,
+    pub changed_at: DateTime<Utc>,
+    /// Changed fields as `{"field": {"old": ..., "new": ...}}`
+    pub diff: serde_json::Value,
+}
+
+/// A create, update or delete of a core record, as POSTed to `WEBHOOK_URL`
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ChangeEvent {
+    /// `created`, `updated` or `deleted` (restores and merges count as
+    /// updates); for a conference, also `publications_wiped` or
+    /// `committee_roles_wiped` when its rows were bulk-deleted
+    pub event: String,
+    /// `conference`, `author`, `publication`, `committee_role` or `authorship`
+    pub entity_type: String,
+    pub id: Uuid,
+    /// Rows removed, on `*_wiped` events only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
+    pub timestamp: DateTime<Utc>,
+}
@@ -1,8 +1,10 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use super::PaperType;
+
 /// Author response model
 #[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
 pub struct Author {
@@ -14,8 +16,12 @@ pub struct Author {
     pub orcid: Option<String>,
     pub homepage_url: Option<String>,
     pub affiliation: Option<String>,
+    /// Canonical institution matched from `affiliation` (best effort; see `GET /institutions`)
+    pub institution_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set when the author has been soft-deleted (only visible with `include_deleted=true`)
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 /// Request model for creating a new author
@@ -35,12 +41,30 @@ pub struct CreateAuthor {
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateAuthor {
     pub full_name: Option<String>,
-    pub family_name: Option<String>,
-    pub given_name: Option<String>,
-    pub orcid: Option<String>,
-    pub homepage_url: Option<String>,
-    pub affiliation: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub family_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub given_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub orcid: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub homepage_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub affiliation: Option<Option<String>>,
     pub modifier: String,
+    /// The `updated_at` the client last saw. When set, the update is rejected
+    /// with 409 Conflict if the author has been modified since.
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Result of `POST /authors/{id}/enrich/orcid`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrcidEnrichment {
+    /// The author after enrichment
+    pub author: Author,
+    /// Fields that were changed, e.g. `["given_name", "affiliation"]`; empty
+    /// when the ORCID record had nothing new
+    pub updated_fields: Vec<String>,
 }
 
 /// Author name variant for tracking alternative names
@@ -55,5 +79,150 @@ pub struct AuthorNameVariant {
     pub created_at: DateTime<Utc>,
 }
 
+/// One of an author's publications, with where they appear in its byline
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct AuthorPublication {
+    pub publication_id: Uuid,
+    pub title: String,
+    pub conference_id: Uuid,
+    pub venue: String,
+    pub year: i32,
+    /// Name as printed on the paper
+    pub published_as_name: String,
+    /// 1-indexed position in the author list
+    pub author_position: i32,
+    /// Number of authors on the paper
+    pub author_count: i64,
+}
+
+/// A talk an author gave: a publication with them as presenter
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct AuthorTalk {
+    pub publication_id: Uuid,
+    pub title: String,
+    pub paper_type: PaperType,
+    pub conference_id: Uuid,
+    pub venue: String,
+    pub year: i32,
+    pub session_name: Option<String>,
+    pub talk_date: Option<NaiveDate>,
+    pub talk_time: Option<NaiveTime>,
+    /// `talk_date` and `talk_time` in the conference's timezone, as UTC; null if any is unknown
+    pub talk_datetime_utc: Option<DateTime<Utc>>,
+    pub duration_minutes: Option<i32>,
+}
+
+/// An author whose first publication in the database is at a given conference
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct NewAuthor {
+    pub id: Uuid,
+    pub full_name: String,
+    pub affiliation: Option<String>,
+    /// Their papers at this conference
+    pub publication_count: i64,
+}
+
+/// One year of an author's publication history
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct AuthorTimelineYear {
+    pub year: i32,
+    pub publication_count: i64,
+    /// Running total of publications up to and including this year
+    pub cumulative_count: i64,
+    /// Venues the author published at that year
+    pub venues: Vec<String>,
+}
+
+/// One page of `GET /authors?cursor=`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthorPage {
+    pub authors: Vec<Author>,
+    /// Pass as `cursor` to fetch the next page; absent on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Counts from `author_stats`, embedded with `include=stats`. An author the
+/// stats haven't been refreshed for yet gets zero counts and no years.
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct AuthorStatsSummary {
+    pub publication_count: i64,
+    pub committee_role_count: i64,
+    pub first_year: Option<i32>,
+    pub last_year: Option<i32>,
+    pub venues: Vec<String>,
+}
+
+/// An author with `include=stats`: the `Author` fields plus its counts
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthorWithStats {
+    #[serde(flatten)]
+    pub author: Author,
+    #[serde(flatten)]
+    pub stats: AuthorStatsSummary,
+}
+
+/// An author from `GET /authors?fuzzy=true`, with how well it matched
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ScoredAuthor {
+    #[serde(flatten)]
+    pub author: Author,
+    /// `name_similarity` of the search term and `full_name`, 0.0 to 1.0
+    pub score: f64,
+}
+
+/// Body of `GET /authors`, whose shape depends on the query
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum AuthorList {
+    /// The default: a plain array
+    Authors(Vec<Author>),
+    /// With `cursor`
+    Page(AuthorPage),
+    /// With `fuzzy=true`, best match first
+    Scored(Vec<ScoredAuthor>),
+    /// With `include=stats`
+    WithStats(Vec<AuthorWithStats>),
+}
+
+/// A live author from `GET /authors/autocomplete`: just enough to render a suggestion
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct AuthorSuggestion {
+    pub id: Uuid,
+    pub full_name: String,
+    pub affiliation: Option<String>,
+}
+
+/// One entry of the author leaderboard
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct TopAuthor {
+    pub id: Uuid,
+    pub full_name: String,
+    /// Most recent affiliation seen on a paper or committee role
+    pub affiliation: Option<String>,
+    pub publication_count: i64,
+    pub committee_role_count: i64,
+    /// Chair and co-chair roles
+    pub leadership_count: i64,
+}
+
 // Re-export normalize_name from utils for backwards compatibility
 pub use crate::utils::normalize_name;
+
+/// Response of `GET /utils/name-variants`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NameVariants {
+    pub name: String,
+    /// Normalized forms the name is matched under: standard, loose, reordered
+    /// and initialed variants
+    pub variants: Vec<String>,
+}
+
+/// Response of `GET /utils/normalize`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NormalizedName {
+    pub name: String,
+    /// Matching form: lowercase, accents stripped, whitespace collapsed
+    pub normalized: String,
+    /// `normalized` with hyphens, apostrophes and periods also removed
+    pub loose: String,
+}
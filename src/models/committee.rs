@@ -1,10 +1,12 @@
+use std::str::FromStr;
+
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Committee type enum matching the database
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "committee_type")]
 pub enum CommitteeType {
     OC,    // Organizing Committee
@@ -13,6 +15,21 @@ pub enum CommitteeType {
     Local, // Local Organizers
 }
 
+impl FromStr for CommitteeType {
+    type Err = ();
+
+    /// Parse a query-string value (case-insensitive), e.g. `pc` or `Local`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_uppercase().as_str() {
+            "OC" => Ok(CommitteeType::OC),
+            "PC" => Ok(CommitteeType::PC),
+            "SC" => Ok(CommitteeType::SC),
+            "LOCAL" => Ok(CommitteeType::Local),
+            _ => Err(()),
+        }
+    }
+}
+
 /// Committee position enum matching the database
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "committee_position")]
@@ -31,6 +48,21 @@ pub enum CommitteePosition {
     Member,
 }
 
+impl FromStr for CommitteePosition {
+    type Err = ();
+
+    /// Parse a query-string value (case-insensitive), e.g. `chair` or `co_chair`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "chair" => Ok(CommitteePosition::Chair),
+            "co_chair" => Ok(CommitteePosition::CoChair),
+            "area_chair" => Ok(CommitteePosition::AreaChair),
+            "member" => Ok(CommitteePosition::Member),
+            _ => Err(()),
+        }
+    }
+}
+
 /// Committee role response model
 #[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
 pub struct CommitteeRole {
@@ -48,6 +80,21 @@ pub struct CommitteeRole {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Author name embedded in a committee role by `include=author`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommitteeRoleAuthor {
+    pub id: Uuid,
+    pub full_name: String,
+}
+
+/// A committee role with `include=author`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommitteeRoleWithAuthor {
+    #[serde(flatten)]
+    pub role: CommitteeRole,
+    pub author: CommitteeRoleAuthor,
+}
+
 /// Request model for creating a committee role
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateCommitteeRole {
@@ -69,10 +116,76 @@ pub struct CreateCommitteeRole {
 pub struct UpdateCommitteeRole {
     pub committee: Option<CommitteeType>,
     pub position: Option<CommitteePosition>,
-    pub role_title: Option<String>,
-    pub term_start: Option<NaiveDate>,
-    pub term_end: Option<NaiveDate>,
-    pub affiliation: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub role_title: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub term_start: Option<Option<NaiveDate>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub term_end: Option<Option<NaiveDate>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub affiliation: Option<Option<String>>,
     pub metadata: Option<serde_json::Value>,
     pub modifier: String,
 }
+
+/// One committee role in an author's service timeline
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct CommitteeTimelineEntry {
+    pub committee_role_id: Uuid,
+    pub conference_id: Uuid,
+    pub venue: String,
+    pub year: i32,
+    pub committee: CommitteeType,
+    pub position: CommitteePosition,
+    pub role_title: Option<String>,
+    /// Length in years of the unbroken run of service on this venue's committee
+    /// (same committee type) that this role belongs to.
+    pub consecutive_years: i64,
+}
+
+/// Someone who served on the same committees as a given author
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct CommitteeCollaborator {
+    pub author_id: Uuid,
+    pub full_name: String,
+    /// Committees (conference + committee type) both served on
+    pub served_together_count: i64,
+    /// Most recent conference year they shared a committee
+    pub last_year: i32,
+}
+
+/// A chair or co-chair with the author resolved, for conference landing pages
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct ConferenceChair {
+    pub committee_role_id: Uuid,
+    pub author_id: Uuid,
+    pub full_name: String,
+    pub position: CommitteePosition,
+    pub role_title: Option<String>,
+    /// Affiliation recorded on the role, falling back to the author's current one
+    pub affiliation: Option<String>,
+}
+
+/// Chairs of one committee of a conference
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommitteeChairs {
+    pub committee: CommitteeType,
+    pub chairs: Vec<ConferenceChair>,
+}
+
+/// How sure a committee anomaly is to be a data error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AnomalySeverity {
+    /// Contradictory data, e.g. one person as both chair and member
+    Error,
+    /// Unusual but possible, e.g. no PC recorded yet
+    Warning,
+}
+
+/// One heuristic data-quality finding about a conference's committees
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommitteeAnomaly {
+    pub severity: AnomalySeverity,
+    pub message: String,
+}
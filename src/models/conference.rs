@@ -1,13 +1,81 @@
+use std::fmt;
+use std::str::FromStr;
+
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize, Serializer};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+/// Declares `Venue` and its name tables from one list, so the enum, its
+/// database/JSON names and `utils::VENUES` can't drift apart.
+macro_rules! venues {
+    ($($variant:ident => $name:literal, $full_name:literal;)+) => {
+        /// Conference series. Stored as text in `conferences.venue`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type, ToSchema)]
+        #[sqlx(type_name = "text")]
+        pub enum Venue {
+            $(
+                #[serde(rename = $name)]
+                #[sqlx(rename = $name)]
+                $variant,
+            )+
+        }
+
+        impl Venue {
+            pub const ALL: &'static [Venue] = &[$(Venue::$variant),+];
+
+            /// Canonical uppercase names, in declaration order (`utils::VENUES`)
+            pub const NAMES: &'static [&'static str] = &[$($name),+];
+
+            /// Canonical uppercase name, as stored
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(Venue::$variant => $name,)+
+                }
+            }
+
+            /// Full series name, as used in proceedings titles
+            pub fn full_name(self) -> &'static str {
+                match self {
+                    $(Venue::$variant => $full_name,)+
+                }
+            }
+        }
+    };
+}
+
+// Longest name first: slug parsing tries them in this order
+venues! {
+    Qcrypt => "QCRYPT", "International Conference on Quantum Cryptography";
+    Qip => "QIP", "Conference on Quantum Information Processing";
+    Tqc => "TQC", "Conference on the Theory of Quantum Computation, Communication and Cryptography";
+}
+
+impl fmt::Display for Venue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Venue {
+    type Err = String;
+
+    /// Case-insensitive, e.g. `qip` or ` QCrypt `; the error lists the accepted values
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let canonical = crate::utils::validate_venue(s)?;
+        Venue::ALL
+            .iter()
+            .copied()
+            .find(|v| v.as_str() == canonical)
+            .ok_or_else(|| format!("Venue {} has no Venue variant", canonical))
+    }
+}
+
 /// Conference response model (matches database schema)
 #[derive(Debug, sqlx::FromRow, ToSchema)]
 pub struct Conference {
     pub id: Uuid,
-    pub venue: String,
+    pub venue: Venue,
     pub year: i32,
     pub start_date: Option<NaiveDate>,
     pub end_date: Option<NaiveDate>,
@@ -43,7 +111,7 @@ pub struct Conference {
 impl Conference {
     /// Get the human-friendly slug (e.g., QIP2024, QCRYPT2018)
     pub fn slug(&self) -> String {
-        format!("{}{}", self.venue.to_uppercase(), self.year)
+        format!("{}{}", self.venue, self.year)
     }
 }
 
@@ -127,32 +195,140 @@ pub struct CreateConference {
 pub struct UpdateConference {
     pub venue: Option<String>,
     pub year: Option<i32>,
-    pub start_date: Option<NaiveDate>,
-    pub end_date: Option<NaiveDate>,
-    pub city: Option<String>,
-    pub country: Option<String>,
-    pub country_code: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub start_date: Option<Option<NaiveDate>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub end_date: Option<Option<NaiveDate>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub city: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub country: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub country_code: Option<Option<String>>,
     pub is_virtual: Option<bool>,
     pub is_hybrid: Option<bool>,
-    pub timezone: Option<String>,
-    pub venue_name: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub timezone: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub venue_name: Option<Option<String>>,
     /// Original conference website URL
-    pub website_url: Option<String>,
-    pub proceedings_url: Option<String>,
-    pub proceedings_publisher: Option<String>,
-    pub proceedings_volume: Option<String>,
-    pub proceedings_doi: Option<String>,
-    pub submission_count: Option<i32>,
-    pub acceptance_count: Option<i32>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub website_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub proceedings_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub proceedings_publisher: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub proceedings_volume: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub proceedings_doi: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub submission_count: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub acceptance_count: Option<Option<i32>>,
     /// Static archive root URL (e.g., https://qip.iaqi.org/2024/)
-    pub archive_url: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub archive_url: Option<Option<String>>,
     /// Archive URL for local organizing committee page
-    pub archive_organizers_url: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub archive_organizers_url: Option<Option<String>>,
     /// Archive URL for program committee page
-    pub archive_pc_url: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub archive_pc_url: Option<Option<String>>,
     /// Archive URL for steering committee page
-    pub archive_steering_url: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub archive_steering_url: Option<Option<String>>,
     /// Archive URL for conference program/schedule page
-    pub archive_program_url: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub archive_program_url: Option<Option<String>>,
     pub modifier: String,
 }
+
+/// One row of a venue's acceptance-rate trend
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct AcceptanceTrend {
+    pub venue: String,
+    pub year: i32,
+    pub submission_count: Option<i32>,
+    pub acceptance_count: Option<i32>,
+    /// Accepted / submitted as a percentage, rounded to one decimal.
+    /// Null unless both counts are known and submissions are non-zero.
+    pub acceptance_rate: Option<f64>,
+}
+
+/// Compact listing of one conference edition, for crawlers and static mirrors
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct ConferenceIndexEntry {
+    /// Canonical slug, e.g. `qip-2024`
+    pub slug: String,
+    pub venue: String,
+    pub year: i32,
+    /// From `conference_stats`; as fresh as the last stats refresh
+    pub publication_count: i64,
+}
+
+/// Conferences held in one country, for `GET /conferences/geo`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConferenceCountryCount {
+    /// ISO 3166-1 alpha-2
+    pub country_code: String,
+    /// Most common `country` name recorded with this code
+    pub country: Option<String>,
+    pub conference_count: i64,
+    /// Per-venue breakdown, venues in alphabetical order
+    pub venues: Vec<VenueConferenceCount>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VenueConferenceCount {
+    pub venue: String,
+    pub conference_count: i64,
+}
+
+/// Headline numbers for one conference edition, used by the comparison endpoint
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct ConferenceComparisonRow {
+    pub id: Uuid,
+    /// Human-friendly slug (e.g., QIP2024)
+    pub slug: String,
+    pub venue: String,
+    pub year: i32,
+    pub publication_count: i64,
+    pub award_count: i64,
+    pub submission_count: Option<i32>,
+    pub acceptance_count: Option<i32>,
+    /// Accepted / submitted as a percentage, rounded to one decimal
+    pub acceptance_rate: Option<f64>,
+    /// Distinct people on any committee
+    pub committee_member_count: i64,
+    pub pc_member_count: i64,
+    pub oc_member_count: i64,
+    pub sc_member_count: i64,
+    pub local_member_count: i64,
+    /// Distinct authors across the edition's publications
+    pub unique_author_count: i64,
+}
+
+/// Side-by-side comparison of several conference editions
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConferenceComparison {
+    /// Found conferences, in the order requested
+    pub conferences: Vec<ConferenceComparisonRow>,
+    /// Requested slugs that are malformed or match no conference
+    pub not_found: Vec<String>,
+}
+
+/// Result of `DELETE /conferences/{id}/publications`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeletedPublications {
+    pub conference_id: Uuid,
+    pub publications: i64,
+    pub authorships: i64,
+}
+
+/// Result of `DELETE /conferences/{id}/committees`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeletedCommitteeRoles {
+    pub conference_id: Uuid,
+    pub committee_roles: i64,
+}
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Canonical institution with the number of live authors linked to it
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct Institution {
+    pub id: Uuid,
+    pub name: String,
+    /// Other spellings matched to this institution (acronyms, former names)
+    pub aliases: Vec<String>,
+    /// Research Organization Registry id (e.g., 042nb2s44)
+    pub ror_id: Option<String>,
+    pub country_code: Option<String>,
+    pub author_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Request model for creating an institution
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateInstitution {
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Bare id or `https://ror.org/...` URL
+    pub ror_id: Option<String>,
+    pub country_code: Option<String>,
+    pub creator: String,
+    pub modifier: String,
+}
+
+/// Authors of one conference sharing an affiliation
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AffiliationCount {
+    /// The matched institution's name, or else the most common spelling
+    pub affiliation: String,
+    /// Set when the affiliation matched a canonical institution
+    pub institution_id: Option<Uuid>,
+    /// Distinct authors with this affiliation on the conference's publications
+    pub author_count: i64,
+    /// Spellings grouped under this entry, most common first
+    pub variants: Vec<String>,
+}
@@ -2,8 +2,18 @@ pub mod conference;
 pub mod author;
 pub mod publication;
 pub mod committee;
+pub mod audit;
+pub mod stats;
+pub mod institution;
+pub mod review;
+pub mod topic;
 
 pub use conference::*;
 pub use author::*;
 pub use publication::*;
 pub use committee::*;
+pub use audit::*;
+pub use stats::*;
+pub use institution::*;
+pub use review::*;
+pub use topic::*;
@@ -5,7 +5,7 @@ use uuid::Uuid;
 
 /// Paper type enum matching the database
 /// Types represent what appears in conference programs, not selection mechanism
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "paper_type", rename_all = "snake_case")]
 #[serde(rename_all = "lowercase")]
 pub enum PaperType {
@@ -43,6 +43,8 @@ pub struct Publication {
     #[sqlx(rename = "abstract")]
     #[serde(rename = "abstract")]
     pub abstract_text: Option<String>,
+    /// Words in the abstract; null when there is no abstract
+    pub abstract_word_count: Option<i32>,
     pub paper_type: PaperType,
     pub pages: Option<String>,
     pub session_name: Option<String>,
@@ -62,17 +64,24 @@ pub struct Publication {
     pub talk_date: Option<NaiveDate>,
     /// Time when the talk started (if known)
     pub talk_time: Option<NaiveTime>,
+    /// When the talk started in UTC, from `talk_date` and `talk_time` in the
+    /// conference's timezone; null if any of the three is unknown
+    pub talk_datetime_utc: Option<DateTime<Utc>>,
     /// Duration of the talk in minutes (if known)
     pub duration_minutes: Option<i32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set when the publication has been soft-deleted (only visible with `include_deleted=true`)
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 /// Request model for creating a publication
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreatePublication {
     pub conference_id: Uuid,
-    pub canonical_key: String,
+    /// Unique key; when omitted one is generated from venue, year and title
+    /// (e.g. `qip2024-quantum-error-correction`, with `-2`, `-3`, … on collision)
+    pub canonical_key: Option<String>,
     pub doi: Option<String>,
     pub arxiv_ids: Option<Vec<String>>,
     pub title: String,
@@ -104,30 +113,86 @@ pub struct CreatePublication {
 /// Request model for updating a publication
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdatePublication {
-    pub doi: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub doi: Option<Option<String>>,
     pub arxiv_ids: Option<Vec<String>>,
     pub title: Option<String>,
-    #[serde(rename = "abstract")]
-    pub abstract_text: Option<String>,
+    #[serde(rename = "abstract", default, deserialize_with = "crate::utils::nullable")]
+    pub abstract_text: Option<Option<String>>,
     pub paper_type: Option<PaperType>,
-    pub pages: Option<String>,
-    pub session_name: Option<String>,
-    pub presentation_url: Option<String>,
-    pub video_url: Option<String>,
-    pub youtube_id: Option<String>,
-    pub award: Option<String>,
-    pub award_date: Option<NaiveDate>,
-    pub published_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub pages: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub session_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub presentation_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub video_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub youtube_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub award: Option<Option<String>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub award_date: Option<Option<NaiveDate>>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub published_date: Option<Option<NaiveDate>>,
     /// Author who presented the talk (must be one of the authors)
-    pub presenter_author_id: Option<Uuid>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub presenter_author_id: Option<Option<Uuid>>,
     /// Whether this is in the formal proceedings track
     pub is_proceedings_track: Option<bool>,
     /// Date when the talk was given
-    pub talk_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub talk_date: Option<Option<NaiveDate>>,
     /// Time when the talk started
-    pub talk_time: Option<NaiveTime>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub talk_time: Option<Option<NaiveTime>>,
     /// Duration of the talk in minutes
-    pub duration_minutes: Option<i32>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub duration_minutes: Option<Option<i32>>,
+    pub modifier: String,
+}
+
+/// Publications that look like the same paper imported more than once
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicationDuplicateCluster {
+    /// Identifiers shared within the cluster, e.g. `doi:10.4230/...` or `arxiv:2301.12345`
+    pub shared_keys: Vec<String>,
+    pub publications: Vec<Publication>,
+}
+
+/// A publication sharing authors with another one, for `GET /publications/{id}/related`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RelatedPublication {
+    #[serde(flatten)]
+    pub publication: Publication,
+    pub shared_author_count: i64,
+    /// The shared authors, in this publication's author order
+    pub shared_author_ids: Vec<Uuid>,
+}
+
+/// One award-winning publication, as listed by `GET /awards`
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct AwardedPublication {
+    pub publication_id: Uuid,
+    pub conference_id: Uuid,
+    /// Human-friendly conference slug (e.g., QIP2024)
+    pub conference: String,
+    pub venue: String,
+    pub year: i32,
+    pub title: String,
+    /// Names as published, in author order
+    pub authors: Vec<String>,
+    /// Award name, e.g. "Best Student Paper"
+    pub award: String,
+    pub award_date: Option<NaiveDate>,
+}
+
+/// Request model for merging a duplicate publication into the one being kept
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MergePublication {
+    /// Publication to fold into the kept one; soft-deleted afterwards
+    pub source_id: Uuid,
     pub modifier: String,
 }
 
@@ -140,6 +205,10 @@ pub struct Authorship {
     pub author_position: i32,
     pub published_as_name: String,
     pub affiliation: Option<String>,
+    pub is_corresponding: bool,
+    /// Co-first author, or otherwise marked as contributing equally
+    pub is_equal_contribution: bool,
+    pub contribution_note: Option<String>,
     pub metadata: serde_json::Value,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -153,6 +222,11 @@ pub struct CreateAuthorship {
     pub author_position: i32,
     pub published_as_name: String,
     pub affiliation: Option<String>,
+    #[serde(default)]
+    pub is_corresponding: bool,
+    #[serde(default)]
+    pub is_equal_contribution: bool,
+    pub contribution_note: Option<String>,
     pub metadata: Option<serde_json::Value>,
     pub creator: String,
     pub modifier: String,
@@ -163,7 +237,20 @@ pub struct CreateAuthorship {
 pub struct UpdateAuthorship {
     pub author_position: Option<i32>,
     pub published_as_name: Option<String>,
-    pub affiliation: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub affiliation: Option<Option<String>>,
+    pub is_corresponding: Option<bool>,
+    pub is_equal_contribution: Option<bool>,
+    #[serde(default, deserialize_with = "crate::utils::nullable")]
+    pub contribution_note: Option<Option<String>>,
     pub metadata: Option<serde_json::Value>,
     pub modifier: String,
 }
+
+/// Request model for rewriting a publication's author order
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReorderAuthorships {
+    /// Every current author of the publication, first author first
+    pub author_ids: Vec<Uuid>,
+    pub modifier: String,
+}
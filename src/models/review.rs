@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Where a suspected duplicate pair stands in the review queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewStatus {
+    Pending,
+    Deferred,
+    Merged,
+    KeptSeparate,
+}
+
+/// A curator's decision on a suspected duplicate pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewDecision {
+    /// Same person: fold the other author into `keep_author_id`
+    Merge,
+    /// Different people; the pair is not surfaced again
+    KeepSeparate,
+    /// Undecided; the pair moves behind the pending ones
+    Defer,
+}
+
+/// One side of a suspected duplicate pair, with what links to it
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReviewAuthor {
+    pub id: Uuid,
+    pub full_name: String,
+    pub affiliation: Option<String>,
+    pub orcid: Option<String>,
+    pub publication_count: i64,
+    pub committee_role_count: i64,
+}
+
+/// A review queue entry for two authors that may be the same person
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthorReviewItem {
+    pub id: Uuid,
+    /// `name_similarity` of the two names when the pair was detected
+    pub score: f64,
+    pub status: ReviewStatus,
+    pub note: Option<String>,
+    pub resolved_by: Option<String>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub author: ReviewAuthor,
+    pub other_author: ReviewAuthor,
+}
+
+/// Result of `POST /review/authors/scan`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReviewScan {
+    /// Pairs newly added to the queue as `pending`
+    pub added: u64,
+}
+
+/// Request body for `POST /review/authors/{id}/resolve`
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ResolveAuthorReview {
+    pub decision: ReviewDecision,
+    /// Required for `merge`: which of the two authors survives
+    pub keep_author_id: Option<Uuid>,
+    pub note: Option<String>,
+    pub modifier: String,
+}
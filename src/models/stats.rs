@@ -0,0 +1,39 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Most recent edition of a venue
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LatestConference {
+    pub id: Uuid,
+    /// Human-friendly slug (e.g., QIP2024)
+    pub slug: String,
+    pub year: i32,
+    pub start_date: Option<NaiveDate>,
+}
+
+/// Totals for one venue
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VenueStats {
+    pub venue: String,
+    pub conference_count: i64,
+    /// Live (not deleted) publications
+    pub publication_count: i64,
+    pub committee_role_count: i64,
+    /// Distinct people who authored a publication or served on a committee
+    pub author_count: i64,
+    pub latest_conference: Option<LatestConference>,
+}
+
+/// Database-wide totals, the JSON counterpart of the home page numbers
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GlobalStats {
+    /// From `author_stats`; as fresh as the last stats refresh
+    pub total_authors: i64,
+    pub total_publications: i64,
+    pub total_conferences: i64,
+    pub total_committee_roles: i64,
+    /// One entry per venue with at least one conference, alphabetically
+    pub venues: Vec<VenueStats>,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A topic with the number of live publications tagged with it
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct TopicCount {
+    /// Normalized slug, e.g. error-correction
+    pub topic: String,
+    pub publication_count: i64,
+}
+
+/// Request model for tagging a publication with topics
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddTopics {
+    /// Topics to add; normalized to slugs ("Error Correction" becomes error-correction).
+    /// Topics the publication already has are kept as they are
+    pub topics: Vec<String>,
+    pub creator: String,
+}
@@ -0,0 +1,214 @@
+//! Affiliation normalization and institution matching
+//!
+//! Affiliations are free text on authors, authorships and committee roles, so
+//! "MIT", "M.I.T." and "Massachusetts Institute of Technology" are three
+//! different strings. `normalize` gives a comparison key that absorbs
+//! punctuation, case and diacritics; `best_match` maps an affiliation onto one
+//! of the curated `institutions` by name, alias or acronym.
+
+use uuid::Uuid;
+
+use super::country::normalize_country_code;
+use super::normalize_name;
+
+/// Words skipped when forming an acronym ("Institute of Technology" → "it").
+const ACRONYM_STOPWORDS: &[&str] = &["of", "the", "and", "for", "de", "la", "du", "der", "fur", "a"];
+
+/// Country abbreviations seen in affiliations that aren't ISO alpha-2 codes,
+/// as normalized keys. Never matched against acronyms: "UK" is not the
+/// University of Kent.
+const COUNTRY_ABBREVIATIONS: &[&str] = &["uk", "usa", "uae", "prc", "roc", "rok", "drc", "ussr"];
+
+/// Comparison key for an affiliation: lowercase ASCII, dots dropped so
+/// initialisms collapse ("M.I.T." → "mit"), other punctuation as spaces, `&`
+/// as "and", and a leading "the" removed.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::affiliation;
+///
+/// assert_eq!(affiliation::normalize("M.I.T."), "mit");
+/// assert_eq!(affiliation::normalize("  The University of Tokyo "), "university of tokyo");
+/// assert_eq!(affiliation::normalize("Université Paris-Saclay"), "universite paris saclay");
+/// assert_eq!(affiliation::normalize("Centrum Wiskunde & Informatica"), "centrum wiskunde and informatica");
+/// ```
+pub fn normalize(affiliation: &str) -> String {
+    let folded: String = normalize_name(affiliation)
+        .replace('&', " and ")
+        .chars()
+        .filter(|c| !matches!(c, '.' | '\'' | '’'))
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { ' ' })
+        .collect();
+    let mut words: Vec<&str> = folded.split_whitespace().collect();
+    if words.len() > 1 && words[0] == "the" {
+        words.remove(0);
+    }
+    words.join(" ")
+}
+
+/// Initials of the significant words of a multi-word name, e.g.
+/// "Massachusetts Institute of Technology" → "mit". `None` for names too
+/// short to have a meaningful acronym.
+pub fn acronym(name: &str) -> Option<String> {
+    let key = normalize(name);
+    let initials: String = key
+        .split_whitespace()
+        .filter(|w| !ACRONYM_STOPWORDS.contains(w))
+        .filter_map(|w| w.chars().next())
+        .collect();
+    (initials.len() >= 2 && key.contains(' ')).then_some(initials)
+}
+
+/// An institution as seen by the matcher: its id and every known name
+/// (canonical name first, then aliases).
+pub struct InstitutionNames<'a> {
+    pub id: Uuid,
+    pub names: &'a [String],
+}
+
+/// Best-effort canonical institution for a free-text affiliation.
+///
+/// The affiliation is split on commas and semicolons ("Dept. of Physics, MIT,
+/// Cambridge, USA") and each part compared, in order, against the normalized
+/// names and aliases of every institution. Failing that, the whole
+/// affiliation and its first part are compared against their acronyms; later
+/// parts are usually places, and a country such as "UK" or "USA" is never
+/// taken for an acronym. The first hit wins. `None` when nothing matches;
+/// ambiguity is resolved by order rather than reported, so callers should
+/// treat the result as a suggestion.
+pub fn best_match(affiliation: &str, institutions: &[InstitutionNames]) -> Option<Uuid> {
+    let parts: Vec<String> = affiliation
+        .split([',', ';'])
+        .map(normalize)
+        .filter(|p| !p.is_empty())
+        .collect();
+    if parts.is_empty() {
+        return None;
+    }
+    let whole = normalize(affiliation);
+
+    let by_name = parts.iter().find_map(|part| {
+        institutions
+            .iter()
+            .find(|inst| inst.names.iter().any(|n| normalize(n) == *part))
+    });
+    let by_acronym = || {
+        [whole.as_str(), parts[0].as_str()]
+            .into_iter()
+            .filter(|key| !is_country(key))
+            .find_map(|key| {
+                institutions
+                    .iter()
+                    .find(|inst| inst.names.iter().any(|n| acronym(n).as_deref() == Some(key)))
+            })
+    };
+    by_name.or_else(by_acronym).map(|inst| inst.id)
+}
+
+/// Whether a normalized affiliation part is just a country code or abbreviation.
+fn is_country(key: &str) -> bool {
+    COUNTRY_ABBREVIATIONS.contains(&key) || key.len() == 2 && normalize_country_code(key).is_some()
+}
+
+/// Canonicalize a ROR id (`https://ror.org/042nb2s44`, `ror.org/042nb2s44`
+/// or bare `042nb2s44`) to its bare, lowercase 9-character form, or `None` if
+/// it isn't shaped like one (a leading `0`, six base-32 characters, two check
+/// digits).
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::affiliation;
+///
+/// assert_eq!(affiliation::normalize_ror_id("https://ror.org/042NB2S44"), Some("042nb2s44".to_string()));
+/// assert_eq!(affiliation::normalize_ror_id("MIT"), None);
+/// ```
+pub fn normalize_ror_id(ror_id: &str) -> Option<String> {
+    let id = ror_id.trim().to_ascii_lowercase();
+    let id = id
+        .strip_prefix("https://ror.org/")
+        .or_else(|| id.strip_prefix("http://ror.org/"))
+        .or_else(|| id.strip_prefix("ror.org/"))
+        .unwrap_or(&id);
+    // `is_ascii` first: the length check and slices below count bytes
+    let shaped = id.is_ascii()
+        && id.len() == 9
+        && id.starts_with('0')
+        && id[1..7].chars().all(|c| c.is_ascii_digit() || c.is_ascii_lowercase() && !"ilou".contains(c))
+        && id[7..].chars().all(|c| c.is_ascii_digit());
+    shaped.then(|| id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_acronym() {
+        assert_eq!(acronym("Massachusetts Institute of Technology"), Some("mit".to_string()));
+        assert_eq!(acronym("Centrum Wiskunde & Informatica"), Some("cwi".to_string()));
+        assert_eq!(acronym("QuSoft"), None);
+    }
+
+    #[test]
+    fn test_best_match_spellings() {
+        let mit = names(&["Massachusetts Institute of Technology"]);
+        let caltech = names(&["California Institute of Technology", "Caltech"]);
+        let institutions = [
+            InstitutionNames { id: Uuid::from_u128(1), names: &mit },
+            InstitutionNames { id: Uuid::from_u128(2), names: &caltech },
+        ];
+        for affiliation in ["MIT", "M.I.T.", "massachusetts institute of technology", "MIT, Cambridge, USA"] {
+            assert_eq!(best_match(affiliation, &institutions), Some(Uuid::from_u128(1)), "{}", affiliation);
+        }
+        // Acronyms are only trusted up front; later parts need a name or alias
+        assert_eq!(best_match("Dept. of Physics, MIT, Cambridge, USA", &institutions), None);
+        assert_eq!(best_match("Caltech", &institutions), Some(Uuid::from_u128(2)));
+        assert_eq!(best_match("University of Waterloo", &institutions), None);
+        assert_eq!(best_match(" , ", &institutions), None);
+    }
+
+    #[test]
+    fn test_best_match_prefers_names_over_acronyms() {
+        // "cit" is an acronym of the first institution and the alias of the second
+        let first = names(&["California Institute of Technology"]);
+        let second = names(&["Chiba Institute", "CIT"]);
+        let institutions = [
+            InstitutionNames { id: Uuid::from_u128(1), names: &first },
+            InstitutionNames { id: Uuid::from_u128(2), names: &second },
+        ];
+        assert_eq!(best_match("CIT", &institutions), Some(Uuid::from_u128(2)));
+    }
+
+    #[test]
+    fn test_best_match_ignores_country_acronyms() {
+        let kent = names(&["University of Kent"]);
+        let south_alabama = names(&["University of South Alabama"]);
+        let institutions = [
+            InstitutionNames { id: Uuid::from_u128(1), names: &kent },
+            InstitutionNames { id: Uuid::from_u128(2), names: &south_alabama },
+        ];
+        for affiliation in ["Cambridge, UK", "DAMTP, Cambridge, U.K.", "Stanford University, Stanford, CA, USA", "UK", "USA"] {
+            assert_eq!(best_match(affiliation, &institutions), None, "{}", affiliation);
+        }
+        assert_eq!(best_match("University of Kent, Canterbury, UK", &institutions), Some(Uuid::from_u128(1)));
+    }
+
+    #[test]
+    fn test_normalize_ror_id() {
+        assert_eq!(normalize_ror_id("042nb2s44"), Some("042nb2s44".to_string()));
+        assert_eq!(normalize_ror_id("ror.org/05a28rw58"), Some("05a28rw58".to_string()));
+        assert_eq!(normalize_ror_id("142nb2s44"), None);
+        assert_eq!(normalize_ror_id("042nb2s4"), None);
+    }
+
+    #[test]
+    fn test_normalize_ror_id_non_ascii() {
+        // Nine bytes, but slicing at byte 7 would split the "é"
+        assert_eq!(normalize_ror_id("0abcdeé8"), None);
+        assert_eq!(normalize_ror_id("https://ror.org/0abcdeé8"), None);
+    }
+}
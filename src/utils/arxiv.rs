@@ -0,0 +1,105 @@
+//! arXiv identifier canonicalization
+//!
+//! Scraped programmes give arXiv ids as `arXiv:2301.12345v2`, abs/pdf URLs or
+//! bare ids. The canonical form drops the prefix and the version, so it can be
+//! compared across sources and turned into a stable abs-page link.
+
+/// URL and URI prefixes stripped before validation (matched case-insensitively).
+const PREFIXES: &[&str] = &[
+    "https://arxiv.org/abs/",
+    "http://arxiv.org/abs/",
+    "https://arxiv.org/pdf/",
+    "http://arxiv.org/pdf/",
+    "arxiv.org/abs/",
+    "arxiv:",
+];
+
+/// Canonicalize an arXiv id, or `None` if it isn't shaped like one.
+///
+/// Accepts new-style (`YYMM.NNNN[N]`) and old-style (`archive[.XX]/YYMMNNN`)
+/// ids; returns them without prefix or version, old-style archives lowercased.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::arxiv;
+///
+/// assert_eq!(arxiv::normalize("arXiv:2301.12345v2"), Some("2301.12345".to_string()));
+/// assert_eq!(arxiv::normalize("https://arxiv.org/abs/quant-ph/0101001v1"), Some("quant-ph/0101001".to_string()));
+/// assert_eq!(arxiv::normalize("10.1103/PhysRevA.52.R2493"), None);
+/// ```
+pub fn normalize(id: &str) -> Option<String> {
+    let mut rest = id.trim();
+    for prefix in PREFIXES {
+        if rest.get(..prefix.len()).is_some_and(|head| head.eq_ignore_ascii_case(prefix)) {
+            rest = rest[prefix.len()..].trim_start();
+            break;
+        }
+    }
+    let rest = rest.strip_suffix(".pdf").unwrap_or(rest).to_lowercase();
+
+    let unversioned = match rest.rfind('v') {
+        Some(pos) if pos + 1 < rest.len() && rest[pos + 1..].bytes().all(|b| b.is_ascii_digit()) => &rest[..pos],
+        _ => rest.as_str(),
+    };
+
+    let digits = |s: &str, lens: &[usize]| lens.contains(&s.len()) && s.bytes().all(|b| b.is_ascii_digit());
+    let valid = match unversioned.split_once('/') {
+        // archive[.subject-class]/YYMMNNN
+        Some((archive, number)) => {
+            let (name, class) = archive.split_once('.').unwrap_or((archive, "ab"));
+            !name.is_empty()
+                && name.bytes().all(|b| b.is_ascii_lowercase() || b == b'-')
+                && class.len() == 2
+                && class.bytes().all(|b| b.is_ascii_lowercase())
+                && digits(number, &[7])
+        }
+        // YYMM.NNNN (to 2014) or YYMM.NNNNN
+        None => unversioned
+            .split_once('.')
+            .is_some_and(|(yymm, number)| digits(yymm, &[4]) && digits(number, &[4, 5])),
+    };
+
+    valid.then(|| unversioned.to_string())
+}
+
+/// Link to the abstract page for an arXiv id, if it is a valid one.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::arxiv;
+///
+/// assert_eq!(arxiv::abs_url("arXiv:2301.12345v2"), Some("https://arxiv.org/abs/2301.12345".to_string()));
+/// ```
+pub fn abs_url(id: &str) -> Option<String> {
+    normalize(id).map(|id| format!("https://arxiv.org/abs/{}", id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_style() {
+        assert_eq!(normalize("2301.12345"), Some("2301.12345".to_string()));
+        assert_eq!(normalize("0704.0001"), Some("0704.0001".to_string()));
+        assert_eq!(normalize(" 2301.12345V3 "), Some("2301.12345".to_string()));
+        assert_eq!(normalize("ARXIV: 2301.12345"), Some("2301.12345".to_string()));
+        assert_eq!(normalize("https://arxiv.org/pdf/2301.12345v1.pdf"), Some("2301.12345".to_string()));
+    }
+
+    #[test]
+    fn test_old_style() {
+        assert_eq!(normalize("quant-ph/9705052"), Some("quant-ph/9705052".to_string()));
+        assert_eq!(normalize("math.CO/0101001v2"), Some("math.co/0101001".to_string()));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(normalize(""), None);
+        assert_eq!(normalize("2301.123"), None);
+        assert_eq!(normalize("2301.12345v"), None);
+        assert_eq!(normalize("quant-ph/12345"), None);
+        assert_eq!(normalize("see paper"), None);
+        assert_eq!(normalize("arXiv:ünïcode"), None);
+    }
+}
@@ -0,0 +1,122 @@
+//! BibTeX entry formatting
+//!
+//! Field values are written in braces with LaTeX special characters escaped;
+//! non-ASCII text is left as UTF-8, which biber and modern bibtex builds read.
+//! Fields whose value is empty are dropped so that entries don't carry blank
+//! `doi = {}` lines that reference managers then import.
+
+/// One BibTeX entry, e.g. `@inproceedings{key, ...}`.
+#[derive(Debug)]
+pub struct Entry {
+    entry_type: &'static str,
+    key: String,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Entry {
+    pub fn new(entry_type: &'static str, key: &str) -> Self {
+        Entry {
+            entry_type,
+            key: citation_key(key),
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Add a text field, escaped. Blank values are skipped.
+    pub fn field(mut self, name: &'static str, value: Option<&str>) -> Self {
+        if let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) {
+            self.fields.push((name, escape(value)));
+        }
+        self
+    }
+
+    /// Add a field whose value must not be escaped (DOIs, URLs, keys).
+    pub fn raw_field(mut self, name: &'static str, value: Option<&str>) -> Self {
+        if let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) {
+            self.fields.push((name, value.replace(['{', '}'], "")));
+        }
+        self
+    }
+
+    /// Render the entry, fields in insertion order.
+    ///
+    /// # Examples
+    /// ```
+    /// use quantumdb::utils::bibtex::Entry;
+    ///
+    /// let entry = Entry::new("inproceedings", "qip2024-toric-codes")
+    ///     .field("title", Some("Toric codes & friends"))
+    ///     .field("pages", None)
+    ///     .raw_field("crossref", Some("qip2024"));
+    /// assert_eq!(
+    ///     entry.render(),
+    ///     "@inproceedings{qip2024-toric-codes,\n  title = {Toric codes \\& friends},\n  crossref = {qip2024},\n}\n"
+    /// );
+    /// ```
+    pub fn render(&self) -> String {
+        let mut out = format!("@{}{{{},\n", self.entry_type, self.key);
+        for (name, value) in &self.fields {
+            out.push_str(&format!("  {} = {{{}}},\n", name, value));
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Join author names the way BibTeX expects them in `author`.
+pub fn author_list<S: AsRef<str>>(names: &[S]) -> String {
+    names.iter().map(AsRef::as_ref).collect::<Vec<_>>().join(" and ")
+}
+
+/// Keep only characters BibTeX accepts in citation keys.
+fn citation_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.' | '/'))
+        .collect()
+}
+
+/// Escape LaTeX special characters in a field value.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' | '}' | '&' | '%' | '$' | '#' | '_' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '\n' | '\r' => escaped.push(' '),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_specials() {
+        assert_eq!(escape("50% of $n$ #1_a {x}"), "50\\% of \\$n\\$ \\#1\\_a \\{x\\}");
+        assert_eq!(escape("a\\b~c^d"), "a\\textbackslash{}b\\textasciitilde{}c\\textasciicircum{}d");
+        assert_eq!(escape("Zürich"), "Zürich");
+    }
+
+    #[test]
+    fn test_citation_key_strips_invalid_characters() {
+        assert_eq!(Entry::new("misc", "qip 2024,{x}").key(), "qip2024x");
+    }
+
+    #[test]
+    fn test_author_list() {
+        assert_eq!(author_list(&["Alice Smith", "Bob Jones"]), "Alice Smith and Bob Jones");
+        assert_eq!(author_list::<&str>(&[]), "");
+    }
+}
@@ -0,0 +1,115 @@
+//! Grouping records that share identifiers
+//!
+//! Used for duplicate detection: two records belong to the same cluster when
+//! they share at least one key, transitively (A~B via a DOI and B~C via an
+//! arXiv id puts A, B and C together).
+
+use std::collections::HashMap;
+
+/// Group items by shared keys; `keys[i]` are the identifiers of item `i`.
+///
+/// Returns only clusters with two or more members, each as ascending item
+/// indices, ordered by their first member.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::cluster_by_keys;
+///
+/// let keys = vec![
+///     vec!["doi:10.1/a".to_string()],
+///     vec!["arxiv:2301.00001".to_string()],
+///     vec!["doi:10.1/a".to_string(), "arxiv:2301.00002".to_string()],
+/// ];
+/// assert_eq!(cluster_by_keys(&keys), vec![vec![0, 2]]);
+/// ```
+pub fn cluster_by_keys(keys: &[Vec<String>]) -> Vec<Vec<usize>> {
+    fn find(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    let mut parent: Vec<usize> = (0..keys.len()).collect();
+    let mut first_seen: HashMap<&str, usize> = HashMap::new();
+
+    for (i, item_keys) in keys.iter().enumerate() {
+        for key in item_keys {
+            match first_seen.get(key.as_str()) {
+                Some(&j) => {
+                    let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+                    parent[a.max(b)] = a.min(b);
+                }
+                None => {
+                    first_seen.insert(key, i);
+                }
+            }
+        }
+    }
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut group_of_root: HashMap<usize, usize> = HashMap::new();
+    for i in 0..keys.len() {
+        let root = find(&mut parent, i);
+        let g = *group_of_root.entry(root).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[g].push(i);
+    }
+
+    groups.retain(|g| g.len() > 1);
+    groups
+}
+
+/// Comparison key for an arXiv identifier, so `arXiv:2301.12345v2` and
+/// `2301.12345` compare equal: the `arxiv::normalize` form when the id parses,
+/// otherwise just trimmed and lowercased.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::arxiv_key;
+///
+/// assert_eq!(arxiv_key("2301.12345v2"), "2301.12345");
+/// assert_eq!(arxiv_key(" quant-ph/0101001 "), "quant-ph/0101001");
+/// ```
+pub fn arxiv_key(id: &str) -> String {
+    super::arxiv::normalize(id).unwrap_or_else(|| id.trim().to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(items: &[&[&str]]) -> Vec<Vec<String>> {
+        items
+            .iter()
+            .map(|k| k.iter().map(|s| s.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_no_shared_keys() {
+        assert!(cluster_by_keys(&keys(&[&["a"], &["b"], &[]])).is_empty());
+    }
+
+    #[test]
+    fn test_transitive_clusters() {
+        let k = keys(&[&["a"], &["x"], &["a", "b"], &["y"], &["b"], &["x"]]);
+        assert_eq!(cluster_by_keys(&k), vec![vec![0, 2, 4], vec![1, 5]]);
+    }
+
+    #[test]
+    fn test_repeated_key_on_one_item() {
+        assert!(cluster_by_keys(&keys(&[&["a", "a"], &["b"]])).is_empty());
+    }
+
+    #[test]
+    fn test_arxiv_key() {
+        assert_eq!(arxiv_key("2301.12345"), "2301.12345");
+        assert_eq!(arxiv_key("2301.12345V10"), "2301.12345");
+        assert_eq!(arxiv_key("quant-ph/0101001v1"), "quant-ph/0101001");
+        assert_eq!(arxiv_key("2301.12345v"), "2301.12345v");
+    }
+}
@@ -1,16 +1,27 @@
-/// Conference slug utilities
-///
-/// Canonical slug format: `{lower-venue}-{year}` (e.g. `qip-2024`, `qcrypt-2018`,
-/// `tqc-2022`). Permanent and human-readable.
-///
-/// `parse_conference_slug` is permissive — it also accepts the legacy compact
-/// uppercase form (e.g. `QIP2024`) and mixed-case variants, so REST clients
-/// using either style continue to work. `make_conference_slug` always emits the
-/// canonical lowercase-hyphen form.
+//! Conference slug utilities
+//!
+//! Canonical slug format: `{lower-venue}-{year}` (e.g. `qip-2024`, `qcrypt-2018`,
+//! `tqc-2022`). Permanent and human-readable.
+//!
+//! `parse_conference_slug` is permissive — it also accepts the legacy compact
+//! uppercase form (e.g. `QIP2024`) and mixed-case variants, so REST clients
+//! using either style continue to work. `make_conference_slug` always emits the
+//! canonical lowercase-hyphen form.
+//!
+//! Publications without a natural `canonical_key` get one built the same way
+//! from the conference and title (`make_publication_key`).
+
+use chrono::Datelike;
 
-/// Valid venue prefixes (uppercase canonical form). Longest first so the parser
+/// The known venues, in canonical uppercase form: the single list behind slug
+/// parsing, venue filters and write validation. Longest first so the parser
 /// matches `QCRYPT` before `QIP` when no separator is present.
-const VENUES: &[&str] = &["QCRYPT", "QIP", "TQC"];
+///
+/// Generated with the `models::Venue` enum by the `venues!` list in
+/// `src/models/conference.rs`. Adding a venue means adding it there, widening
+/// the `conferences_venue_check` constraint (the API tests compare the two)
+/// and `VENUES` in `tools/scrapers/_lib.py`.
+pub const VENUES: &[&str] = crate::models::Venue::NAMES;
 
 /// Parse a conference slug into `(venue, year)` components.
 ///
@@ -37,7 +48,7 @@ pub fn parse_conference_slug(slug: &str) -> Option<(String, i32)> {
             // Allow optional separator between venue and year.
             let year_str = rest.trim_start_matches(|c: char| !c.is_ascii_digit());
             if let Ok(year) = year_str.parse::<i32>() {
-                if (1990..=2100).contains(&year) {
+                if (MIN_CONFERENCE_YEAR..=2100).contains(&year) {
                     return Some((venue.to_string(), year));
                 }
             }
@@ -47,6 +58,72 @@ pub fn parse_conference_slug(slug: &str) -> Option<(String, i32)> {
     None
 }
 
+/// Map a client-supplied venue (any case, surrounding whitespace allowed) to its
+/// canonical uppercase form, or `None` if it is not a known venue.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::canonical_venue;
+///
+/// assert_eq!(canonical_venue("qip"), Some("QIP"));
+/// assert_eq!(canonical_venue(" QCrypt "), Some("QCRYPT"));
+/// assert_eq!(canonical_venue("AQIS"), None);
+/// ```
+pub fn canonical_venue(venue: &str) -> Option<&'static str> {
+    let upper = venue.trim().to_uppercase();
+    VENUES.iter().copied().find(|v| *v == upper)
+}
+
+/// Validate a venue on write: the canonical form when known, otherwise a
+/// message listing the accepted values.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::validate_venue;
+///
+/// assert_eq!(validate_venue("qip"), Ok("QIP"));
+/// assert_eq!(
+///     validate_venue("AQIS"),
+///     Err("Unknown venue \"AQIS\"; expected one of QCRYPT, QIP, TQC".to_string())
+/// );
+/// ```
+pub fn validate_venue(venue: &str) -> Result<&'static str, String> {
+    canonical_venue(venue)
+        .ok_or_else(|| format!("Unknown venue {:?}; expected one of {}", venue, VENUES.join(", ")))
+}
+
+/// Earliest conference year accepted on write (and by `parse_conference_slug`).
+pub const MIN_CONFERENCE_YEAR: i32 = 1990;
+
+/// How far ahead of the current year a conference may be scheduled.
+const YEARS_AHEAD: i32 = 5;
+
+/// Latest conference year accepted on write: five years past the current one.
+pub fn max_conference_year() -> i32 {
+    chrono::Utc::now().year() + YEARS_AHEAD
+}
+
+/// Validate a conference year on write against `MIN_CONFERENCE_YEAR..=max_year`,
+/// so typos like `202` or `20224` are caught before they break slugs and sorting.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::validate_conference_year;
+///
+/// assert_eq!(validate_conference_year(2024, 2031), Ok(()));
+/// assert_eq!(
+///     validate_conference_year(20224, 2031),
+///     Err("Conference year 20224 is outside 1990..=2031".to_string())
+/// );
+/// ```
+pub fn validate_conference_year(year: i32, max_year: i32) -> Result<(), String> {
+    if (MIN_CONFERENCE_YEAR..=max_year).contains(&year) {
+        Ok(())
+    } else {
+        Err(format!("Conference year {} is outside {}..={}", year, MIN_CONFERENCE_YEAR, max_year))
+    }
+}
+
 /// Generate the canonical slug from venue and year.
 ///
 /// # Examples
@@ -60,6 +137,64 @@ pub fn make_conference_slug(venue: &str, year: i32) -> String {
     format!("{}-{}", venue.to_lowercase(), year)
 }
 
+/// Longest title part of a generated publication key, cut at a word boundary.
+const MAX_TITLE_SLUG_LEN: usize = 60;
+
+/// Title part of a generated key when the title has no ASCII words, so the
+/// key never equals the bare conference form used by the `@proceedings` entry.
+const UNTITLED_SLUG: &str = "untitled";
+
+/// Generate a publication `canonical_key` from its conference and title:
+/// the compact lowercase conference form, then the title's ASCII words
+/// (`untitled` without any).
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::make_publication_key;
+///
+/// assert_eq!(
+///     make_publication_key("QIP", 2024, "Quantum Error-Correction: a Primer"),
+///     "qip2024-quantum-error-correction-a-primer"
+/// );
+/// assert_eq!(make_publication_key("TQC", 2019, "Schrödinger's Ψ"), "tqc2019-schrodinger-s");
+/// assert_eq!(make_publication_key("QCRYPT", 2011, "∑"), "qcrypt2011-untitled");
+/// ```
+pub fn make_publication_key(venue: &str, year: i32, title: &str) -> String {
+    let mut slug = String::new();
+    for word in super::normalize_name(title).split(|c: char| !c.is_ascii_alphanumeric()).filter(|w| !w.is_empty()) {
+        if !slug.is_empty() && slug.len() + 1 + word.len() > MAX_TITLE_SLUG_LEN {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word[..word.len().min(MAX_TITLE_SLUG_LEN)]);
+    }
+    if slug.is_empty() {
+        slug.push_str(UNTITLED_SLUG);
+    }
+    format!("{}{}-{}", venue.to_lowercase(), year, slug)
+}
+
+/// First of `base`, `base-2`, `base-3`, … not among the `taken` keys.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::next_free_key;
+///
+/// assert_eq!(next_free_key("qip2024-qec", &[]), "qip2024-qec");
+/// assert_eq!(next_free_key("qip2024-qec", &["qip2024-qec".into(), "qip2024-qec-2".into()]), "qip2024-qec-3");
+/// ```
+pub fn next_free_key(base: &str, taken: &[String]) -> String {
+    if !taken.iter().any(|key| key == base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|key| !taken.contains(key))
+        .expect("unbounded counter")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,10 +240,61 @@ mod tests {
         assert_eq!(parse_conference_slug("qip-2200"), None); // too far future
     }
 
+    #[test]
+    fn test_canonical_venue() {
+        assert_eq!(canonical_venue("QIP"), Some("QIP"));
+        assert_eq!(canonical_venue("qcrypt"), Some("QCRYPT"));
+        assert_eq!(canonical_venue("Tqc"), Some("TQC"));
+        assert_eq!(canonical_venue(""), None);
+        assert_eq!(canonical_venue("QIP2024"), None);
+    }
+
+    #[test]
+    fn test_validate_venue() {
+        assert_eq!(validate_venue(" tqc "), Ok("TQC"));
+        let message = validate_venue("INVALID").unwrap_err();
+        assert!(VENUES.iter().all(|v| message.contains(v)));
+    }
+
+    #[test]
+    fn test_venue_enum_matches_list() {
+        use crate::models::Venue;
+
+        let names: Vec<&str> = Venue::ALL.iter().map(|v| v.as_str()).collect();
+        assert_eq!(names, VENUES);
+        for venue in Venue::ALL {
+            assert_eq!(venue.as_str().parse::<Venue>(), Ok(*venue));
+        }
+        assert_eq!("qcrypt".parse::<Venue>(), Ok(Venue::Qcrypt));
+        assert!("AQIS".parse::<Venue>().is_err());
+    }
+
+    #[test]
+    fn test_publication_key_never_bare_conference() {
+        // "tqc2024" alone is the conference's own @proceedings key
+        for title in ["", "∑", "  ", "—", "量子"] {
+            assert_eq!(make_publication_key("TQC", 2024, title), "tqc2024-untitled");
+        }
+    }
+
     #[test]
     fn test_make_slug() {
         assert_eq!(make_conference_slug("QIP", 2024), "qip-2024");
         assert_eq!(make_conference_slug("qcrypt", 2018), "qcrypt-2018");
         assert_eq!(make_conference_slug("TQC", 2022), "tqc-2022");
     }
+
+    #[test]
+    fn test_make_publication_key() {
+        assert_eq!(make_publication_key("QIP", 2024, "  Quantum   error correction "), "qip2024-quantum-error-correction");
+        let long = make_publication_key("QIP", 2024, &"entanglement ".repeat(20));
+        assert!(long.len() <= "qip2024-".len() + MAX_TITLE_SLUG_LEN);
+        assert!(long.ends_with("-entanglement"));
+    }
+
+    #[test]
+    fn test_next_free_key_skips_gaps_in_order() {
+        let taken = vec!["k".to_string(), "k-3".to_string()];
+        assert_eq!(next_free_key("k", &taken), "k-2");
+    }
 }
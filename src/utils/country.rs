@@ -0,0 +1,67 @@
+//! ISO 3166-1 alpha-2 country codes
+
+/// Officially assigned ISO 3166-1 alpha-2 codes, sorted for binary search.
+/// Mirrored by `COUNTRY_CODES` in `tools/scrapers/_lib.py` for the importers.
+const ALPHA2_CODES: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// Canonicalize a client-supplied country code: surrounding whitespace and
+/// dots are dropped and letters uppercased, so "us" and "U.S." both become
+/// "US". `None` unless the result is an assigned ISO 3166-1 alpha-2 code
+/// (alpha-3 codes such as "USA" and the common "UK" are rejected rather than
+/// guessed at).
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::country::normalize_country_code;
+///
+/// assert_eq!(normalize_country_code(" U.S. "), Some("US"));
+/// assert_eq!(normalize_country_code("ch"), Some("CH"));
+/// assert_eq!(normalize_country_code("USA"), None);
+/// assert_eq!(normalize_country_code("XX"), None);
+/// ```
+pub fn normalize_country_code(code: &str) -> Option<&'static str> {
+    let code: String = code.trim().chars().filter(|c| *c != '.').collect::<String>().to_ascii_uppercase();
+    ALPHA2_CODES.binary_search(&code.as_str()).ok().map(|i| ALPHA2_CODES[i])
+}
+
+/// Validate a country code on write: the canonical code when valid, otherwise
+/// a message naming the expected format.
+pub fn validate_country_code(code: &str) -> Result<&'static str, String> {
+    normalize_country_code(code)
+        .ok_or_else(|| format!("Invalid country code {:?}; expected ISO 3166-1 alpha-2 (e.g. US, GB, CH)", code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_are_sorted_and_unique() {
+        assert!(ALPHA2_CODES.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(ALPHA2_CODES.len(), 249);
+    }
+
+    #[test]
+    fn test_rejects_reserved_and_malformed_codes() {
+        for code in ["UK", "EU", "", "U", "GBR", "1A", "ÜS"] {
+            assert_eq!(normalize_country_code(code), None, "{}", code);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Opaque keyset-pagination cursors
+//!
+//! A cursor carries the sort key of the last row a client saw, so the next
+//! page starts strictly after it no matter what was inserted or deleted in
+//! between. It is the key serialized as JSON and hex-encoded: URL-safe, and
+//! clients have no reason to look inside.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Encode a sort key as a cursor.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::cursor;
+///
+/// let c = cursor::encode(&("Smith", 7));
+/// assert_eq!(cursor::decode::<(String, i32)>(&c), Some(("Smith".to_string(), 7)));
+/// ```
+pub fn encode<T: Serialize>(key: &T) -> String {
+    serde_json::to_vec(key)
+        .unwrap_or_default()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Decode a cursor made by `encode`, or `None` if it was tampered with or is
+/// for a different key shape.
+pub fn decode<T: DeserializeOwned>(cursor: &str) -> Option<T> {
+    if !cursor.len().is_multiple_of(2) {
+        return None;
+    }
+    let bytes = (0..cursor.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(cursor.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let key = ("Müller".to_string(), "Ana".to_string(), 42u32);
+        assert_eq!(decode::<(String, String, u32)>(&encode(&key)), Some(key));
+    }
+
+    #[test]
+    fn test_rejects_garbage() {
+        assert_eq!(decode::<(String, u32)>(""), None);
+        assert_eq!(decode::<(String, u32)>("abc"), None);
+        assert_eq!(decode::<(String, u32)>("zz"), None);
+        assert_eq!(decode::<(String, u32)>("é1"), None);
+        // Valid hex and JSON, wrong shape
+        assert_eq!(decode::<(String, u32)>(&encode(&[1, 2, 3])), None);
+    }
+}
@@ -0,0 +1,88 @@
+use serde_json::{Map, Value};
+
+/// Fields that change on every write and would only add noise to a diff.
+const IGNORED_FIELDS: &[&str] = &["created_at", "updated_at"];
+
+/// Field-level diff between two JSON snapshots of the same record.
+///
+/// Returns an object mapping each changed field to `{"old": ..., "new": ...}`.
+/// Pass `Value::Null` for `before` on create or `after` on delete; every
+/// non-null field of the other side is then reported. `created_at` and
+/// `updated_at` are skipped.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::json_diff;
+/// use serde_json::json;
+///
+/// let diff = json_diff(
+///     &json!({"affiliation": "CWI", "orcid": null}),
+///     &json!({"affiliation": "QuSoft", "orcid": null}),
+/// );
+/// assert_eq!(diff, json!({"affiliation": {"old": "CWI", "new": "QuSoft"}}));
+/// ```
+pub fn json_diff(before: &Value, after: &Value) -> Value {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    let mut diff = Map::new();
+    for key in before.keys().chain(after.keys().filter(|k| !before.contains_key(*k))) {
+        if IGNORED_FIELDS.contains(&key.as_str()) {
+            continue;
+        }
+        let old = before.get(key).unwrap_or(&Value::Null);
+        let new = after.get(key).unwrap_or(&Value::Null);
+        if old != new {
+            let mut change = Map::new();
+            change.insert("old".to_string(), old.clone());
+            change.insert("new".to_string(), new.clone());
+            diff.insert(key.clone(), Value::Object(change));
+        }
+    }
+
+    Value::Object(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn unchanged_fields_are_omitted() {
+        let row = json!({"full_name": "Alice", "affiliation": "CWI"});
+        assert_eq!(json_diff(&row, &row), json!({}));
+    }
+
+    #[test]
+    fn reports_changed_fields() {
+        let diff = json_diff(
+            &json!({"full_name": "Alice", "affiliation": null}),
+            &json!({"full_name": "Alice", "affiliation": "CWI"}),
+        );
+        assert_eq!(diff, json!({"affiliation": {"old": null, "new": "CWI"}}));
+    }
+
+    #[test]
+    fn create_and_delete_report_every_non_null_field() {
+        let row = json!({"full_name": "Alice", "orcid": null});
+        assert_eq!(
+            json_diff(&Value::Null, &row),
+            json!({"full_name": {"old": null, "new": "Alice"}})
+        );
+        assert_eq!(
+            json_diff(&row, &Value::Null),
+            json!({"full_name": {"old": "Alice", "new": null}})
+        );
+    }
+
+    #[test]
+    fn ignores_timestamps() {
+        let diff = json_diff(
+            &json!({"updated_at": "2024-01-01T00:00:00Z", "created_at": "a"}),
+            &json!({"updated_at": "2024-06-01T00:00:00Z", "created_at": "b"}),
+        );
+        assert_eq!(diff, json!({}));
+    }
+}
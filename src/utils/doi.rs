@@ -0,0 +1,104 @@
+//! DOI canonicalization
+//!
+//! DOIs arrive from scrapers and clients as bare DOIs, `doi:` URIs or resolver
+//! URLs. We store the bare, lowercased form (`10.1234/abc`) so duplicates and
+//! Crossref lookups match regardless of formatting. DOIs are case-insensitive,
+//! so lowercasing loses nothing.
+
+/// Resolver and URI prefixes stripped before validation (matched case-insensitively).
+const PREFIXES: &[&str] = &[
+    "https://doi.org/",
+    "http://doi.org/",
+    "https://dx.doi.org/",
+    "http://dx.doi.org/",
+    "doi.org/",
+    "doi:",
+];
+
+/// Canonicalize a DOI, or `None` if it isn't shaped like one.
+///
+/// Strips resolver URLs and `doi:`, lowercases, and checks the
+/// `10.<registrant>/<suffix>` shape (registrant digits, optionally dotted;
+/// suffix non-empty without whitespace).
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::doi;
+///
+/// assert_eq!(doi::normalize("https://doi.org/10.1103/PhysRevA.52.R2493"), Some("10.1103/physreva.52.r2493".to_string()));
+/// assert_eq!(doi::normalize("doi:10.4230/LIPIcs.TQC.2022.1"), Some("10.4230/lipics.tqc.2022.1".to_string()));
+/// assert_eq!(doi::normalize("arXiv:2301.12345"), None);
+/// ```
+pub fn normalize(doi: &str) -> Option<String> {
+    let mut rest = doi.trim();
+    for prefix in PREFIXES {
+        if rest.get(..prefix.len()).is_some_and(|head| head.eq_ignore_ascii_case(prefix)) {
+            rest = rest[prefix.len()..].trim_start();
+            break;
+        }
+    }
+
+    let (registrant, suffix) = rest.strip_prefix("10.")?.split_once('/')?;
+    let registrant_ok = !registrant.is_empty()
+        && registrant
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()));
+    if !registrant_ok || suffix.is_empty() || suffix.chars().any(char::is_whitespace) {
+        return None;
+    }
+
+    Some(rest.to_lowercase())
+}
+
+/// Resolver link for a DOI, if it is a valid one.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::doi;
+///
+/// assert_eq!(doi::url("doi:10.4230/LIPIcs.TQC.2022.1"), Some("https://doi.org/10.4230/lipics.tqc.2022.1".to_string()));
+/// ```
+pub fn url(doi: &str) -> Option<String> {
+    normalize(doi).map(|doi| format!("https://doi.org/{}", doi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bare_doi() {
+        assert_eq!(normalize("10.1234/x"), Some("10.1234/x".to_string()));
+        assert_eq!(normalize("  10.1234/ABC-def  "), Some("10.1234/abc-def".to_string()));
+    }
+
+    #[test]
+    fn test_strips_prefixes() {
+        for input in [
+            "https://doi.org/10.1234/x",
+            "http://dx.doi.org/10.1234/x",
+            "HTTPS://DOI.ORG/10.1234/X",
+            "doi.org/10.1234/x",
+            "doi:10.1234/x",
+            "DOI: 10.1234/x",
+        ] {
+            assert_eq!(normalize(input), Some("10.1234/x".to_string()), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_dotted_registrant() {
+        assert_eq!(normalize("10.1000.10/abc"), Some("10.1000.10/abc".to_string()));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(normalize(""), None);
+        assert_eq!(normalize("10.1234"), None); // no suffix
+        assert_eq!(normalize("10.1234/"), None);
+        assert_eq!(normalize("10.abc/x"), None);
+        assert_eq!(normalize("11.1234/x"), None);
+        assert_eq!(normalize("10.1234/has space"), None);
+        assert_eq!(normalize("https://example.com/10.1234/x"), None);
+    }
+}
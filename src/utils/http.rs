@@ -0,0 +1,176 @@
+//! Outbound HTTP for enrichment lookups and webhooks
+//!
+//! Enrichment talks to a handful of public JSON APIs (ORCID) a few times per
+//! admin request, and webhooks send one small POST per change. Both share one
+//! reqwest client (TLS via rustls with the webpki root store). `TIMEOUT`
+//! bounds each request as a whole, body included; up to `MAX_REDIRECTS`
+//! redirects are followed, but never from https to plain http.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use reqwest::{header, redirect, Client, Url};
+
+/// Overall deadline for a single request: connecting, redirects, and reading
+/// the whole body.
+pub const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Responses larger than this are cut off and reported as an error.
+const MAX_RESPONSE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Redirects followed before a request is reported as failed.
+const MAX_REDIRECTS: usize = 5;
+
+/// Status code and body of a completed request.
+#[derive(Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+/// `GET` an `http://` or `https://` URL with the given `Accept` header.
+///
+/// Errors (bad URL, connection, TLS, timeout, truncated response) are
+/// returned as a message suitable for logs; any HTTP status, including
+/// 4xx/5xx, is `Ok`.
+pub async fn get(url: &str, accept: &str) -> Result<HttpResponse, String> {
+    send(client().get(url).header(header::ACCEPT, accept), url).await
+}
+
+/// `POST` a JSON body to an `http://` or `https://` URL. Errors as for `get`.
+pub async fn post_json(url: &str, body: &str) -> Result<HttpResponse, String> {
+    let request = client()
+        .post(url)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(body.to_string());
+    send(request, url).await
+}
+
+fn client() -> &'static Client {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        Client::builder()
+            .timeout(TIMEOUT)
+            .redirect(redirect::Policy::custom(follow_redirect))
+            .user_agent(concat!("quantumdb/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .expect("HTTP client config is valid")
+    })
+}
+
+/// Redirect policy: like browsers, 301/302/303 turn a `POST` into a body-less
+/// `GET` and 307/308 repeat it as is (reqwest handles that part).
+fn follow_redirect(attempt: redirect::Attempt) -> redirect::Action {
+    if attempt.previous().len() > MAX_REDIRECTS {
+        return attempt.error("Too many redirects");
+    }
+    match attempt.previous().last() {
+        Some(from) if is_downgrade(from, attempt.url()) => {
+            attempt.error("Refusing to follow a redirect from https to http")
+        }
+        _ => attempt.follow(),
+    }
+}
+
+/// Whether following `from` -> `to` would drop TLS.
+fn is_downgrade(from: &Url, to: &Url) -> bool {
+    from.scheme() == "https" && to.scheme() != "https"
+}
+
+/// Send the request and read the body, up to `MAX_RESPONSE_BYTES`.
+async fn send(request: reqwest::RequestBuilder, url: &str) -> Result<HttpResponse, String> {
+    let failed = |e: reqwest::Error| format!("Request to {} failed: {}", url, describe(&e));
+    let mut response = request.send().await.map_err(failed)?;
+    let status = response.status().as_u16();
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(failed)? {
+        if body.len() + chunk.len() > MAX_RESPONSE_BYTES {
+            return Err(format!("Response from {} is too large", url));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(HttpResponse { status, body })
+}
+
+/// An error and its causes on one line; reqwest's own message is only the
+/// outermost layer ("error sending request").
+fn describe(error: &dyn std::error::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    #[tokio::test]
+    async fn test_rejects_unsupported_urls() {
+        assert!(get("ftp://example.org/", "*/*").await.is_err());
+        assert!(get("http://:80/", "*/*").await.is_err());
+        assert!(get("http://example.org:port/", "*/*").await.is_err());
+    }
+
+    #[test]
+    fn test_is_downgrade() {
+        let url = |s: &str| Url::parse(s).unwrap();
+        assert!(is_downgrade(&url("https://example.org/a"), &url("http://example.org/a")));
+        assert!(!is_downgrade(&url("https://example.org/a"), &url("https://other.org/b")));
+        assert!(!is_downgrade(&url("http://example.org/a"), &url("https://example.org/a")));
+        assert!(!is_downgrade(&url("http://example.org/a"), &url("http://[::1]:8080/b")));
+    }
+
+    /// Serve one canned response per connection, returning each request's first line.
+    fn serve(responses: Vec<&'static str>) -> (u16, std::thread::JoinHandle<Vec<String>>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = std::thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|response| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut request = Vec::new();
+                    let mut buf = [0; 1024];
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        let n = stream.read(&mut buf).unwrap();
+                        request.extend_from_slice(&buf[..n]);
+                    }
+                    stream.write_all(response.as_bytes()).unwrap();
+                    String::from_utf8_lossy(&request).lines().next().unwrap_or_default().to_string()
+                })
+                .collect()
+        });
+        (port, handle)
+    }
+
+    #[tokio::test]
+    async fn test_follows_redirects() {
+        let (port, server) = serve(vec![
+            "HTTP/1.1 303 See Other\r\nLocation: /final?ok=1\r\nConnection: close\r\n\r\n",
+            "HTTP/1.1 200 OK\r\nConnection: close\r\n\r\ndone",
+        ]);
+        let response = post_json(&format!("http://127.0.0.1:{}/start", port), "{}").await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, b"done");
+        assert_eq!(server.join().unwrap(), vec!["POST /start HTTP/1.1", "GET /final?ok=1 HTTP/1.1"]);
+
+        let loop_response = "HTTP/1.1 307 Temporary Redirect\r\nLocation: /again\r\nConnection: close\r\n\r\n";
+        let (port, server) = serve(vec![loop_response; MAX_REDIRECTS + 1]);
+        let error = get(&format!("http://127.0.0.1:{}/", port), "*/*").await.unwrap_err();
+        assert!(error.contains("Too many redirects"), "{}", error);
+        server.join().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_truncated_body_is_an_error() {
+        let (port, server) = serve(vec!["HTTP/1.1 200 OK\r\nContent-Length: 100\r\nConnection: close\r\n\r\nshort"]);
+        assert!(get(&format!("http://127.0.0.1:{}/", port), "*/*").await.is_err());
+        server.join().unwrap();
+    }
+}
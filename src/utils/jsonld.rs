@@ -0,0 +1,165 @@
+//! schema.org JSON-LD for people and publications
+//!
+//! Search engines and scholarly indexers (Google Scholar, Semantic Scholar)
+//! read `Person` and `ScholarlyArticle` descriptions embedded in pages. The
+//! builders here only map fields we already store; identifiers that don't
+//! normalize are left out of `sameAs` rather than published broken.
+
+use chrono::NaiveDate;
+use serde_json::{json, Map, Value};
+
+use crate::utils::{arxiv, doi, orcid};
+
+const CONTEXT: &str = "https://schema.org";
+
+/// An author of a publication, as it appears in `ScholarlyArticle.author`.
+pub struct ArticleAuthor<'a> {
+    pub name: &'a str,
+    pub orcid: Option<&'a str>,
+}
+
+/// `Person` description. `same_as` lists further profile URLs (homepage,
+/// Google Scholar); the ORCID profile is added to it when set.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::jsonld;
+///
+/// let person = jsonld::person("Alice Smith", Some("QuSoft"), Some("0000-0001-2345-6789"), &[]);
+/// assert_eq!(person["@type"], "Person");
+/// assert_eq!(person["affiliation"]["name"], "QuSoft");
+/// assert_eq!(person["sameAs"][0], "https://orcid.org/0000-0001-2345-6789");
+/// ```
+pub fn person(
+    name: &str,
+    affiliation: Option<&str>,
+    orcid: Option<&str>,
+    same_as: &[String],
+) -> Value {
+    let mut person = Map::new();
+    person.insert("@context".into(), json!(CONTEXT));
+    person.insert("@type".into(), json!("Person"));
+    person.insert("name".into(), json!(name));
+    if let Some(affiliation) = affiliation.filter(|a| !a.trim().is_empty()) {
+        person.insert(
+            "affiliation".into(),
+            json!({"@type": "Organization", "name": affiliation}),
+        );
+    }
+
+    let mut links: Vec<String> = orcid.and_then(orcid::url).into_iter().collect();
+    links.extend(same_as.iter().filter(|l| !l.is_empty()).cloned());
+    if !links.is_empty() {
+        person.insert("sameAs".into(), json!(links));
+    }
+    Value::Object(person)
+}
+
+/// `ScholarlyArticle` description: title, authors in byline order,
+/// `datePublished`, and the arXiv abstract pages and DOI resolver link as
+/// `sameAs`. The DOI is also given as a `PropertyValue` identifier.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::jsonld::{self, ArticleAuthor};
+///
+/// let authors = [ArticleAuthor { name: "Alice Smith", orcid: None }];
+/// let article = jsonld::scholarly_article("Quantum widgets", &authors, None, &["2301.12345".into()], Some("doi:10.1/X"));
+/// assert_eq!(article["headline"], "Quantum widgets");
+/// assert_eq!(article["sameAs"][0], "https://arxiv.org/abs/2301.12345");
+/// assert_eq!(article["sameAs"][1], "https://doi.org/10.1/x");
+/// ```
+pub fn scholarly_article(
+    title: &str,
+    authors: &[ArticleAuthor],
+    date_published: Option<NaiveDate>,
+    arxiv_ids: &[String],
+    doi_value: Option<&str>,
+) -> Value {
+    let mut article = Map::new();
+    article.insert("@context".into(), json!(CONTEXT));
+    article.insert("@type".into(), json!("ScholarlyArticle"));
+    article.insert("name".into(), json!(title));
+    article.insert("headline".into(), json!(title));
+    article.insert(
+        "author".into(),
+        Value::Array(
+            authors
+                .iter()
+                .map(|a| {
+                    let mut author = person(a.name, None, a.orcid, &[]);
+                    if let Some(fields) = author.as_object_mut() {
+                        fields.remove("@context");
+                    }
+                    author
+                })
+                .collect(),
+        ),
+    );
+    if let Some(date) = date_published {
+        article.insert("datePublished".into(), json!(date.to_string()));
+    }
+
+    let doi_value = doi_value.and_then(doi::normalize);
+    let mut links: Vec<String> = arxiv_ids
+        .iter()
+        .filter_map(|id| arxiv::abs_url(&arxiv::normalize(id)?))
+        .collect();
+    links.extend(doi_value.as_deref().and_then(doi::url));
+    if !links.is_empty() {
+        article.insert("sameAs".into(), json!(links));
+    }
+    if let Some(doi_value) = doi_value {
+        article.insert(
+            "identifier".into(),
+            json!({"@type": "PropertyValue", "propertyID": "DOI", "value": doi_value}),
+        );
+    }
+    Value::Object(article)
+}
+
+/// Serialize for a `<script type="application/ld+json">` element. `<` is
+/// escaped so a title containing `</script>` can't end the element early.
+pub fn to_script_body(value: &Value) -> String {
+    value.to_string().replace('<', "\\u003c")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_person_skips_empty_fields() {
+        let person = person("Bob", Some(""), Some(" "), &[String::new()]);
+        assert_eq!(person, json!({"@context": CONTEXT, "@type": "Person", "name": "Bob"}));
+    }
+
+    #[test]
+    fn test_article_drops_unparseable_ids() {
+        let article = scholarly_article(
+            "T",
+            &[],
+            NaiveDate::from_ymd_opt(2024, 1, 15),
+            &["not-an-id".into()],
+            Some("nonsense"),
+        );
+        assert_eq!(article["datePublished"], "2024-01-15");
+        assert!(article.get("sameAs").is_none());
+        assert!(article.get("identifier").is_none());
+    }
+
+    #[test]
+    fn test_article_authors_have_no_context() {
+        let authors = [ArticleAuthor { name: "A", orcid: Some("0000-0002-1825-0097") }];
+        let article = scholarly_article("T", &authors, None, &[], None);
+        assert!(article["author"][0].get("@context").is_none());
+        assert_eq!(article["author"][0]["sameAs"][0], "https://orcid.org/0000-0002-1825-0097");
+    }
+
+    #[test]
+    fn test_script_body_escapes_closing_tag() {
+        let body = to_script_body(&json!({"name": "</script><b>"}));
+        assert!(!body.contains("</script>"));
+        assert_eq!(serde_json::from_str::<Value>(&body).unwrap()["name"], "</script><b>");
+    }
+}
@@ -1,9 +1,29 @@
+pub mod affiliation;
+pub mod arxiv;
+pub mod bibtex;
+pub mod cluster;
 pub mod conference;
+pub mod country;
+pub mod cursor;
+pub mod diff;
+pub mod doi;
+pub mod http;
+pub mod jsonld;
 pub mod normalize;
+pub mod orcid;
 pub mod pagination;
+mod pinyin;
+pub mod search;
+pub mod topic;
+pub mod update;
 pub mod validation;
+pub mod vcard;
 
+pub use cluster::*;
 pub use conference::*;
+pub use diff::*;
 pub use normalize::*;
 pub use pagination::*;
+pub use search::*;
+pub use update::*;
 pub use validation::*;
@@ -10,6 +10,8 @@
 
 use unicode_normalization::UnicodeNormalization;
 
+use super::pinyin::pinyin;
+
 /// Normalize a name for matching purposes.
 ///
 /// Transformations applied:
@@ -49,6 +51,41 @@ pub fn normalize_name(name: &str) -> String {
         .join(" ")
 }
 
+/// Trim and collapse runs of whitespace to a single space, keeping case and
+/// accents. This is the display-form cleanup applied to names, titles and
+/// affiliations on write; `normalize_name` is the matching form.
+///
+/// # Examples
+///
+/// ```
+/// use quantumdb::utils::collapse_whitespace;
+///
+/// assert_eq!(collapse_whitespace("  José \t García\n"), "José García");
+/// ```
+pub fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// In-place `collapse_whitespace` for request fields, including optional and
+/// tri-state (`nullable`) ones. Absent values and `null` are left alone.
+pub trait CollapseWhitespace {
+    fn collapse_whitespace(&mut self);
+}
+
+impl CollapseWhitespace for String {
+    fn collapse_whitespace(&mut self) {
+        *self = collapse_whitespace(self);
+    }
+}
+
+impl<T: CollapseWhitespace> CollapseWhitespace for Option<T> {
+    fn collapse_whitespace(&mut self) {
+        if let Some(value) = self {
+            value.collapse_whitespace();
+        }
+    }
+}
+
 /// Replace special characters that don't decompose via Unicode NFD.
 ///
 /// Some characters like Ł, Ø, Æ are distinct letters, not accented versions,
@@ -116,6 +153,44 @@ pub fn normalize_name_loose(name: &str) -> String {
         .join(" ")
 }
 
+/// Normalize a name after romanizing Chinese characters to pinyin.
+///
+/// An unspaced Hanzi name is read family-first, so "张伟" becomes
+/// "zhang wei" and "欧阳娜娜" becomes "ouyang nana"; given-name syllables
+/// are joined as in the usual romanized spelling ("张小明" → "zhang xiaoming").
+/// Only the common characters in the built-in table are romanized (others are
+/// kept), and Hangul/kana are not transliterated.
+///
+/// # Examples
+///
+/// ```
+/// use quantumdb::utils::normalize_name_translit;
+///
+/// assert_eq!(normalize_name_translit("张伟"), "zhang wei");
+/// assert_eq!(normalize_name_translit("張小明"), "zhang xiaoming");
+/// assert_eq!(normalize_name_translit("José García"), "jose garcia");
+/// ```
+pub fn normalize_name_translit(name: &str) -> String {
+    let romanize = |s: &str| -> String {
+        s.chars()
+            .map(|c| pinyin(c).map_or_else(|| c.to_string(), str::to_string))
+            .collect()
+    };
+
+    let tokens: Vec<&str> = name.split_whitespace().collect();
+    let words: Vec<String> = match tokens[..] {
+        [single] => match split_cjk_surname(single) {
+            Some((family, given)) if single.chars().all(is_han) => {
+                vec![romanize(family), romanize(given)]
+            }
+            _ => vec![romanize(single)],
+        },
+        _ => tokens.iter().map(|t| romanize(t)).collect(),
+    };
+
+    normalize_name(&words.join(" "))
+}
+
 /// Extract initials from a name.
 ///
 /// Returns uppercase initials from each word in the name.
@@ -154,7 +229,10 @@ fn is_combining_mark(c: char) -> bool {
 /// Compare two names for potential match, returning a similarity score.
 ///
 /// Returns a value between 0.0 (no match) and 1.0 (exact match).
-/// Uses normalized forms for comparison.
+/// Uses normalized forms for comparison. Words are matched exactly or, to
+/// tolerate typos, by Jaro-Winkler similarity of at least
+/// [`TYPO_WORD_SIMILARITY`]; a score that relies on a typo match is capped at
+/// 0.9 so it ranks below exact and punctuation-only matches.
 ///
 /// # Examples
 ///
@@ -167,8 +245,20 @@ fn is_combining_mark(c: char) -> bool {
 /// assert!(name_similarity("Alice Smith", "Bob Smith") > 0.3);
 /// // No common words
 /// assert!(name_similarity("John Doe", "Alice Smith") < 0.1);
+/// // Single-character typo
+/// assert!(name_similarity("Jon Smith", "John Smith") > 0.8);
 /// ```
 pub fn name_similarity(name1: &str, name2: &str) -> f64 {
+    // Hanzi on one side only: compare in pinyin. Homophones share a spelling,
+    // so such a match is never treated as certain.
+    if name1.chars().any(is_han) != name2.chars().any(is_han) {
+        let translit1 = normalize_name_translit(name1);
+        let translit2 = normalize_name_translit(name2);
+        if !translit1.chars().any(is_han) && !translit2.chars().any(is_han) {
+            return name_similarity(&translit1, &translit2).min(0.9);
+        }
+    }
+
     let norm1 = normalize_name(name1);
     let norm2 = normalize_name(name2);
 
@@ -184,25 +274,133 @@ pub fn name_similarity(name1: &str, name2: &str) -> f64 {
         return 0.95;
     }
 
-    // Calculate Jaccard similarity on words
-    let words1: std::collections::HashSet<&str> = norm1.split_whitespace().collect();
-    let words2: std::collections::HashSet<&str> = norm2.split_whitespace().collect();
+    // Jaccard similarity on words, where a typo match counts fractionally
+    let mut words1: Vec<&str> = loose1.split_whitespace().collect();
+    let mut words2: Vec<&str> = loose2.split_whitespace().collect();
+    words1.sort_unstable();
+    words1.dedup();
+    words2.sort_unstable();
+    words2.dedup();
+
+    let mut unmatched: Vec<&str> = words2.iter().copied().filter(|w| !words1.contains(w)).collect();
+    let mut matched = words1.iter().filter(|w| words2.contains(w)).count();
+    let mut score = matched as f64;
+    let mut fuzzy = false;
+
+    for word in words1.iter().filter(|w| !words2.contains(w)) {
+        let best = unmatched
+            .iter()
+            .enumerate()
+            .map(|(i, other)| (i, jaro_winkler(word, other)))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((i, sim)) = best.filter(|(_, sim)| *sim >= TYPO_WORD_SIMILARITY) {
+            unmatched.swap_remove(i);
+            matched += 1;
+            score += sim;
+            fuzzy = true;
+        }
+    }
 
-    let intersection = words1.intersection(&words2).count();
-    let union = words1.union(&words2).count();
+    let union = words1.len() + words2.len() - matched;
 
     if union == 0 {
         return 0.0;
     }
 
-    intersection as f64 / union as f64
+    let similarity = score / union as f64;
+    if fuzzy {
+        similarity.min(0.9)
+    } else {
+        similarity
+    }
+}
+
+/// Minimum Jaro-Winkler similarity for two differing words to count as the
+/// same word with a typo ("jon"/"john" ≈ 0.93, "smith"/"smyth" ≈ 0.89).
+pub const TYPO_WORD_SIMILARITY: f64 = 0.85;
+
+/// Jaro-Winkler similarity of two strings, from 0.0 (nothing in common) to
+/// 1.0 (identical), comparing characters as given (normalize first).
+///
+/// # Examples
+///
+/// ```
+/// use quantumdb::utils::jaro_winkler;
+///
+/// assert_eq!(jaro_winkler("smith", "smith"), 1.0);
+/// assert!(jaro_winkler("martha", "marhta") > 0.96);
+/// assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+/// ```
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0usize;
+
+    for (i, ca) in a.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(b.len());
+        for j in lo..hi {
+            if !b_matched[j] && b[j] == *ca {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let a_seq = a.iter().zip(&a_matched).filter(|(_, m)| **m).map(|(c, _)| c);
+    let b_seq = b.iter().zip(&b_matched).filter(|(_, m)| **m).map(|(c, _)| c);
+    let transpositions = a_seq.zip(b_seq).filter(|(x, y)| x != y).count() / 2;
+
+    let m = matches as f64;
+    let jaro = (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0;
+
+    let prefix = a.iter().zip(&b).take(4).take_while(|(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// Generational suffixes, compared case-insensitively without a trailing period.
+const NAME_SUFFIXES: &[&str] = &["jr", "sr", "ii", "iii", "iv"];
+
+fn is_name_suffix(word: &str) -> bool {
+    NAME_SUFFIXES.contains(&word.trim_end_matches('.').to_lowercase().as_str())
+}
+
+/// Move trailing suffix words ("Jr.", "III") from `words` onto the front of
+/// `suffixes`, always leaving at least one word behind.
+fn take_suffixes<'a>(words: &mut Vec<&'a str>, suffixes: &mut Vec<&'a str>) {
+    while words.len() > 1 && words.last().is_some_and(|w| is_name_suffix(w)) {
+        suffixes.insert(0, words.pop().unwrap());
+    }
 }
 
 /// Split a full name into (given_name, family_name) components.
 ///
 /// Uses common heuristics:
-/// - For Western names: last word is family name, rest is given name
+/// - A single comma means "Family, Given" (as in BibTeX and many scraped lists)
+/// - Otherwise Western order: last word is family name, rest is given name
 /// - Handles common prefixes like "van", "von", "de", "la"
+/// - Suffixes like "Jr." or "III" are kept with the family name
+///
+/// Comma-free names are assumed to be given-name first; use
+/// [`split_name_with_order`] when the convention is known to differ, and
+/// [`is_ambiguous_name_order`] to find names where the guess is unsafe.
 ///
 /// # Examples
 ///
@@ -211,16 +409,102 @@ pub fn name_similarity(name1: &str, name2: &str) -> f64 {
 ///
 /// assert_eq!(split_name("John Smith"), (Some("John".into()), Some("Smith".into())));
 /// assert_eq!(split_name("Ludwig van Beethoven"), (Some("Ludwig".into()), Some("van Beethoven".into())));
+/// assert_eq!(split_name("García, José"), (Some("José".into()), Some("García".into())));
 /// ```
 pub fn split_name(full_name: &str) -> (Option<String>, Option<String>) {
-    let parts: Vec<&str> = full_name.split_whitespace().collect();
+    split_name_with_order(full_name, NameOrder::GivenFirst)
+}
+
+/// Order of the given and family names in a comma-free full name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameOrder {
+    /// "Wei Zhang", "John Smith"
+    GivenFirst,
+    /// "Zhang Wei", "Nguyen Van A", "张伟"
+    FamilyFirst,
+}
+
+/// Split a full name like [`split_name`], but with the name order supplied by
+/// the caller (e.g. from ORCID data, which records given and family names
+/// separately).
+///
+/// A "Family, Given" comma always wins over `order`. With `FamilyFirst`, a
+/// name written in Han or Hangul without spaces is split after its surname
+/// (one character, or a known two-character compound surname).
+///
+/// # Examples
+///
+/// ```
+/// use quantumdb::utils::{split_name_with_order, NameOrder};
+///
+/// assert_eq!(
+///     split_name_with_order("Zhang Wei", NameOrder::FamilyFirst),
+///     (Some("Wei".into()), Some("Zhang".into()))
+/// );
+/// assert_eq!(
+///     split_name_with_order("张伟", NameOrder::FamilyFirst),
+///     (Some("伟".into()), Some("张".into()))
+/// );
+/// ```
+pub fn split_name_with_order(full_name: &str, order: NameOrder) -> (Option<String>, Option<String>) {
+    let mut segments: Vec<&str> = full_name
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    // "John Smith, Jr." - a trailing segment made only of suffixes
+    let mut suffixes: Vec<&str> = Vec::new();
+    while segments.len() > 1
+        && segments
+            .last()
+            .is_some_and(|seg| seg.split_whitespace().all(is_name_suffix))
+    {
+        let seg = segments.pop().unwrap();
+        suffixes.splice(0..0, seg.split_whitespace());
+    }
+
+    let with_suffixes = |family: String, suffixes: &[&str]| {
+        if suffixes.is_empty() {
+            family
+        } else {
+            format!("{} {}", family, suffixes.join(" "))
+        }
+    };
+
+    if let [family, given] = segments[..] {
+        let mut given_words: Vec<&str> = given.split_whitespace().collect();
+        take_suffixes(&mut given_words, &mut suffixes);
+        let family = family.split_whitespace().collect::<Vec<_>>().join(" ");
+        return (
+            Some(given_words.join(" ")),
+            Some(with_suffixes(family, &suffixes)),
+        );
+    }
+
+    let mut parts: Vec<&str> = segments.iter().flat_map(|s| s.split_whitespace()).collect();
 
     if parts.is_empty() {
         return (None, None);
     }
 
+    take_suffixes(&mut parts, &mut suffixes);
+
+    if order == NameOrder::FamilyFirst {
+        if let [single] = parts[..] {
+            if let Some((family, given)) = split_cjk_surname(single) {
+                return (Some(given.to_string()), Some(with_suffixes(family.to_string(), &suffixes)));
+            }
+        }
+    }
+
     if parts.len() == 1 {
-        return (None, Some(parts[0].to_string()));
+        return (None, Some(with_suffixes(parts[0].to_string(), &suffixes)));
+    }
+
+    if order == NameOrder::FamilyFirst {
+        let family = with_suffixes(parts[0].to_string(), &suffixes);
+        return (Some(parts[1..].join(" ")), Some(family));
     }
 
     // Common family name prefixes
@@ -244,11 +528,89 @@ pub fn split_name(full_name: &str) -> (Option<String>, Option<String>) {
         None
     };
 
-    let family = Some(parts[family_start..].join(" "));
+    let family = Some(with_suffixes(parts[family_start..].join(" "), &suffixes));
 
     (given, family)
 }
 
+/// Two-character Chinese and Korean surnames; everything else is taken as a
+/// one-character surname.
+const COMPOUND_CJK_SURNAMES: &[&str] = &[
+    "欧阳", "司马", "诸葛", "上官", "皇甫", "司徒", "东方", "令狐", "夏侯", "慕容",
+    "歐陽", "諸葛", "東方", "남궁", "황보", "선우", "제갈",
+];
+
+/// Romanized surnames common enough in China, Korea and Vietnam that a
+/// comma-free name starting with one may well be written family-first.
+const EAST_ASIAN_SURNAMES: &[&str] = &[
+    // Chinese (pinyin)
+    "wang", "li", "zhang", "liu", "chen", "yang", "huang", "zhao", "wu", "zhou",
+    "xu", "sun", "ma", "zhu", "hu", "guo", "he", "lin", "gao", "luo", "zheng",
+    "liang", "xie", "song", "tang", "han", "feng", "deng", "cao", "peng", "zeng",
+    "xiao", "tian", "dong", "pan", "yuan", "cai", "jiang", "yu", "du", "ye",
+    "cheng", "wei", "su", "lu", "ding", "ren", "shen", "yao", "fan", "fang",
+    "shi", "jin", "qian", "dai", "xia", "zhong", "tan", "qin", "meng",
+    // Korean
+    "kim", "lee", "park", "choi", "jung", "kang", "cho", "yoon", "jang", "lim", "shin",
+    // Vietnamese
+    "nguyen", "tran", "le", "pham", "hoang", "huynh", "phan", "vu", "vo", "dang",
+    "bui", "do", "ho", "ngo", "duong",
+];
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}' |  // Hiragana, Katakana
+        '\u{3400}'..='\u{4DBF}' |  // CJK Unified Ideographs Extension A
+        '\u{4E00}'..='\u{9FFF}' |  // CJK Unified Ideographs
+        '\u{AC00}'..='\u{D7AF}'    // Hangul Syllables
+    )
+}
+
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}')
+}
+
+/// Split an unspaced Han/Hangul name into (surname, given name).
+fn split_cjk_surname(name: &str) -> Option<(&str, &str)> {
+    if name.chars().count() < 2 || !name.chars().all(is_cjk) {
+        return None;
+    }
+    let len = COMPOUND_CJK_SURNAMES
+        .iter()
+        .find(|s| name.starts_with(**s) && name.len() > s.len())
+        .map_or_else(|| name.chars().next().unwrap().len_utf8(), |s| s.len());
+    Some(name.split_at(len))
+}
+
+/// Whether the given/family order of a comma-free name can't be inferred.
+///
+/// True for names in CJK script and for romanized names that start with a
+/// common Chinese, Korean or Vietnamese surname ("Zhang Wei" could be either
+/// order). Such names should be split with an explicit [`NameOrder`] or left
+/// for review instead of trusting [`split_name`].
+///
+/// # Examples
+///
+/// ```
+/// use quantumdb::utils::is_ambiguous_name_order;
+///
+/// assert!(is_ambiguous_name_order("Zhang Wei"));
+/// assert!(is_ambiguous_name_order("Nguyễn Văn A"));
+/// assert!(!is_ambiguous_name_order("Zhang, Wei"));
+/// assert!(!is_ambiguous_name_order("John Smith"));
+/// ```
+pub fn is_ambiguous_name_order(full_name: &str) -> bool {
+    if full_name.contains(',') {
+        return false;
+    }
+    if full_name.chars().any(is_cjk) {
+        return true;
+    }
+    let normalized = normalize_name_loose(full_name);
+    let words: Vec<&str> = normalized.split_whitespace().collect();
+    words.len() >= 2 && EAST_ASIAN_SURNAMES.contains(&words[0])
+}
+
 /// Generate potential name variants for fuzzy matching.
 ///
 /// Returns a list of normalized variants that might match this name:
@@ -256,6 +618,9 @@ pub fn split_name(full_name: &str) -> (Option<String>, Option<String>) {
 /// - Loose normalization (no punctuation)
 /// - Initials + family name
 /// - Family name only
+///
+/// For names whose order is ambiguous (see [`is_ambiguous_name_order`]) the
+/// family-first reading contributes its family name and initials variants too.
 pub fn generate_name_variants(full_name: &str) -> Vec<String> {
     let mut variants = Vec::new();
 
@@ -269,23 +634,30 @@ pub fn generate_name_variants(full_name: &str) -> Vec<String> {
         variants.push(loose);
     }
 
-    // Split into given/family
-    let (given, family) = split_name(full_name);
+    let mut orders = vec![NameOrder::GivenFirst];
+    if is_ambiguous_name_order(full_name) {
+        orders.push(NameOrder::FamilyFirst);
+    }
+
+    for order in orders {
+        // Split into given/family
+        let (given, family) = split_name_with_order(full_name, order);
 
-    // Family name only
-    if let Some(ref fam) = family {
-        let norm_family = normalize_name(fam);
-        if !variants.contains(&norm_family) {
-            variants.push(norm_family);
+        // Family name only
+        if let Some(ref fam) = family {
+            let norm_family = normalize_name(fam);
+            if !variants.contains(&norm_family) {
+                variants.push(norm_family);
+            }
         }
-    }
 
-    // Initials + family name (e.g., "A. Einstein")
-    if let (Some(ref giv), Some(ref fam)) = (&given, &family) {
-        let initials = extract_initials(giv);
-        let variant = format!("{} {}", initials.to_lowercase(), normalize_name(fam));
-        if !variants.contains(&variant) {
-            variants.push(variant);
+        // Initials + family name (e.g., "A. Einstein")
+        if let (Some(ref giv), Some(ref fam)) = (&given, &family) {
+            let initials = extract_initials(giv);
+            let variant = format!("{} {}", initials.to_lowercase(), normalize_name(fam));
+            if !variants.contains(&variant) {
+                variants.push(variant);
+            }
         }
     }
 
@@ -323,6 +695,19 @@ mod tests {
         assert_eq!(normalize_name("Alice  Bob  Carol"), "alice bob carol");
     }
 
+    #[test]
+    fn test_collapse_whitespace() {
+        assert_eq!(collapse_whitespace("Quantum   error\u{a0}correction "), "Quantum error correction");
+        assert_eq!(collapse_whitespace(" \t\n"), "");
+
+        let mut field: Option<Option<String>> = Some(Some("  Alice  Bob ".into()));
+        field.collapse_whitespace();
+        assert_eq!(field, Some(Some("Alice Bob".into())));
+        let mut cleared: Option<Option<String>> = Some(None);
+        cleared.collapse_whitespace();
+        assert_eq!(cleared, Some(None));
+    }
+
     #[test]
     fn test_normalize_loose() {
         assert_eq!(normalize_name_loose("O'Brien"), "obrien");
@@ -358,6 +743,123 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_split_name_comma() {
+        assert_eq!(
+            split_name("Smith, John"),
+            (Some("John".into()), Some("Smith".into()))
+        );
+        assert_eq!(
+            split_name("García, José"),
+            (Some("José".into()), Some("García".into()))
+        );
+        assert_eq!(
+            split_name("van der Berg, Anna"),
+            (Some("Anna".into()), Some("van der Berg".into()))
+        );
+        assert_eq!(
+            split_name("  Smith ,John  "),
+            (Some("John".into()), Some("Smith".into()))
+        );
+    }
+
+    #[test]
+    fn test_split_name_suffix() {
+        assert_eq!(
+            split_name("King, Martin Luther Jr."),
+            (Some("Martin Luther".into()), Some("King Jr.".into()))
+        );
+        assert_eq!(
+            split_name("Martin Luther King Jr."),
+            (Some("Martin Luther".into()), Some("King Jr.".into()))
+        );
+        assert_eq!(
+            split_name("John Smith, Jr."),
+            (Some("John".into()), Some("Smith Jr.".into()))
+        );
+        assert_eq!(
+            split_name("Henry Ford III"),
+            (Some("Henry".into()), Some("Ford III".into()))
+        );
+        // A lone suffix-like word is still a family name
+        assert_eq!(split_name("Jr."), (None, Some("Jr.".into())));
+    }
+
+    #[test]
+    fn test_split_name_with_order() {
+        assert_eq!(
+            split_name_with_order("Zhang Wei", NameOrder::FamilyFirst),
+            (Some("Wei".into()), Some("Zhang".into()))
+        );
+        assert_eq!(
+            split_name_with_order("Nguyen Van A", NameOrder::FamilyFirst),
+            (Some("Van A".into()), Some("Nguyen".into()))
+        );
+        assert_eq!(
+            split_name_with_order("Zhang Wei", NameOrder::GivenFirst),
+            (Some("Zhang".into()), Some("Wei".into()))
+        );
+        // The comma is explicit and overrides the hint
+        assert_eq!(
+            split_name_with_order("Zhang, Wei", NameOrder::GivenFirst),
+            (Some("Wei".into()), Some("Zhang".into()))
+        );
+        assert_eq!(
+            split_name_with_order("欧阳娜娜", NameOrder::FamilyFirst),
+            (Some("娜娜".into()), Some("欧阳".into()))
+        );
+        assert_eq!(
+            split_name_with_order("김민수", NameOrder::FamilyFirst),
+            (Some("민수".into()), Some("김".into()))
+        );
+        assert_eq!(
+            split_name_with_order("张", NameOrder::FamilyFirst),
+            (None, Some("张".into()))
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_name_order() {
+        assert!(is_ambiguous_name_order("Zhang Wei"));
+        assert!(is_ambiguous_name_order("Kim Min-su"));
+        assert!(is_ambiguous_name_order("Nguyen Van A"));
+        assert!(is_ambiguous_name_order("张伟"));
+        assert!(!is_ambiguous_name_order("Zhang, Wei"));
+        assert!(!is_ambiguous_name_order("Min-su Kim"));
+        assert!(!is_ambiguous_name_order("Alice Smith"));
+        assert!(!is_ambiguous_name_order("Zhang"));
+    }
+
+    #[test]
+    fn test_generate_variants_ambiguous_order() {
+        let variants = generate_name_variants("Zhang Wei");
+        assert!(variants.contains(&"wei".to_string()));
+        assert!(variants.contains(&"z wei".to_string()));
+        assert!(variants.contains(&"zhang".to_string()));
+        assert!(variants.contains(&"w zhang".to_string()));
+    }
+
+    #[test]
+    fn test_normalize_translit() {
+        assert_eq!(normalize_name_translit("张伟"), "zhang wei");
+        assert_eq!(normalize_name_translit("欧阳娜娜"), "ouyang nana");
+        assert_eq!(normalize_name_translit("陈 晓明"), "chen xiaoming");
+        // Unknown characters are kept rather than guessed
+        assert_eq!(normalize_name_translit("张龘"), "zhang 龘");
+        assert_eq!(normalize_name_translit("Zhang Wei"), "zhang wei");
+    }
+
+    #[test]
+    fn test_name_similarity_translit() {
+        let sim = name_similarity("张伟", "Zhang Wei");
+        assert!(sim > 0.85 && sim < 1.0);
+        assert!(name_similarity("张伟", "Wei Zhang") > 0.85);
+        assert!(name_similarity("張偉", "Wei Zhang") > 0.85);
+        assert!(name_similarity("张伟", "Li Wei") < 0.5);
+        // Nothing to compare for unknown characters
+        assert!(name_similarity("龘", "Zhang Wei") < 0.1);
+    }
+
     #[test]
     fn test_name_similarity() {
         // Exact match after normalization
@@ -374,6 +876,35 @@ mod tests {
         assert!(name_similarity("Alice", "Bob") < 0.1);
     }
 
+    #[test]
+    fn test_jaro_winkler() {
+        assert_eq!(jaro_winkler("", ""), 1.0);
+        assert_eq!(jaro_winkler("abc", ""), 0.0);
+        assert!((jaro_winkler("martha", "marhta") - 0.961).abs() < 0.001);
+        assert!((jaro_winkler("dwayne", "duane") - 0.84).abs() < 0.001);
+        assert!((jaro_winkler("dixon", "dicksonx") - 0.813).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_name_similarity_typos() {
+        for (a, b) in [
+            ("Jon Smith", "John Smith"),
+            ("John Smtih", "John Smith"),
+            ("Alice Smyth", "Alice Smith"),
+            ("Schrodinger", "Schroedinger"),
+            ("Michele Mosca", "Michelle Mosca"),
+        ] {
+            let sim = name_similarity(a, b);
+            assert!(sim > 0.8 && sim <= 0.9, "{} vs {}: {}", a, b, sim);
+        }
+
+        // Typo matches rank below punctuation-only differences
+        assert!(name_similarity("Jon Smith", "John Smith") < name_similarity("O'Brien", "OBrien"));
+        // Dissimilar words still don't count
+        assert!(name_similarity("Alice Smith", "Bob Smith") < 0.5);
+        assert!(name_similarity("J. Smith", "John Smith") < 0.8);
+    }
+
     #[test]
     fn test_generate_variants() {
         let variants = generate_name_variants("Albert Einstein");
@@ -0,0 +1,189 @@
+//! ORCID iD canonicalization
+//!
+//! ORCID iDs are stored in the hyphenated form `0000-0002-1825-0097` (the
+//! `valid_orcid` constraint on `authors`). Clients also send profile URLs or
+//! lowercase check characters; `normalize` maps those onto the stored form.
+//!
+//! `parse_record` reads the profile fields we enrich authors from out of a
+//! public API v3.0 record (`GET {ORCID_API_URL}/{orcid}/record`).
+
+use serde_json::Value;
+
+/// URL prefixes stripped before validation (matched case-insensitively).
+const PREFIXES: &[&str] = &["https://orcid.org/", "http://orcid.org/", "orcid.org/"];
+
+/// Canonicalize an ORCID iD, or `None` if it isn't shaped like one: four
+/// groups of four digits, the last character possibly the check character `X`.
+///
+/// Only the shape is checked, matching the database constraint; the ISO 7064
+/// checksum is not.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::orcid;
+///
+/// assert_eq!(orcid::normalize("https://orcid.org/0000-0002-1694-233x"), Some("0000-0002-1694-233X".to_string()));
+/// assert_eq!(orcid::normalize("0000-0002-1825-0097"), Some("0000-0002-1825-0097".to_string()));
+/// assert_eq!(orcid::normalize("0000000218250097"), None);
+/// ```
+pub fn normalize(orcid: &str) -> Option<String> {
+    let mut rest = orcid.trim();
+    for prefix in PREFIXES {
+        if rest.get(..prefix.len()).is_some_and(|head| head.eq_ignore_ascii_case(prefix)) {
+            rest = &rest[prefix.len()..];
+            break;
+        }
+    }
+    // Byte lengths and offsets below assume one byte per character
+    if !rest.is_ascii() {
+        return None;
+    }
+    let rest = rest.to_ascii_uppercase();
+
+    let groups: Vec<&str> = rest.split('-').collect();
+    let shaped = groups.len() == 4
+        && groups.iter().all(|g| g.len() == 4)
+        && rest[..rest.len() - 1].chars().all(|c| c.is_ascii_digit() || c == '-')
+        && rest.ends_with(|c: char| c.is_ascii_digit() || c == 'X');
+    shaped.then_some(rest)
+}
+
+/// Public profile link for an ORCID iD, if it is a valid one.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::orcid;
+///
+/// assert_eq!(orcid::url("0000-0002-1825-0097"), Some("https://orcid.org/0000-0002-1825-0097".to_string()));
+/// ```
+pub fn url(orcid: &str) -> Option<String> {
+    normalize(orcid).map(|orcid| format!("https://orcid.org/{}", orcid))
+}
+
+/// ORCID public API base used when `ORCID_API_URL` is unset.
+pub const DEFAULT_API_URL: &str = "https://pub.orcid.org/v3.0";
+
+/// Author fields found in a public ORCID record. Each is `None` when the
+/// record doesn't have it or the researcher hasn't made it public.
+#[derive(Debug, Default, PartialEq)]
+pub struct OrcidProfile {
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    /// First listed researcher URL
+    pub homepage_url: Option<String>,
+    /// Organization of the most recent employment without an end date
+    pub affiliation: Option<String>,
+}
+
+/// Pull an `OrcidProfile` out of an ORCID v3.0 `record` document.
+///
+/// ORCID lists employments most recent first, so the first one still open
+/// (no `end-date`) is taken as the current affiliation.
+pub fn parse_record(record: &Value) -> OrcidProfile {
+    let text = |value: &Value| {
+        value
+            .as_str()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    let name = &record["person"]["name"];
+
+    let homepage_url = record["person"]["researcher-urls"]["researcher-url"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find_map(|url| text(&url["url"]["value"]));
+
+    let affiliation = record["activities-summary"]["employments"]["affiliation-group"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .flat_map(|group| group["summaries"].as_array().into_iter().flatten())
+        .map(|summary| &summary["employment-summary"])
+        .filter(|employment| employment["end-date"].is_null())
+        .find_map(|employment| text(&employment["organization"]["name"]));
+
+    OrcidProfile {
+        given_name: text(&name["given-names"]["value"]),
+        family_name: text(&name["family-name"]["value"]),
+        homepage_url,
+        affiliation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_strips_prefixes() {
+        for input in [
+            "https://orcid.org/0000-0002-1825-0097",
+            "HTTP://ORCID.ORG/0000-0002-1825-0097",
+            "orcid.org/0000-0002-1825-0097",
+            "  0000-0002-1825-0097 ",
+        ] {
+            assert_eq!(normalize(input), Some("0000-0002-1825-0097".to_string()), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_rejects_malformed() {
+        for input in ["", "invalid-orcid", "0000-0002-1825-009", "0000-0002-1825-00977", "0000-000X-1825-0097", "0000-0002-1825-009Y"] {
+            assert_eq!(normalize(input), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_rejects_non_ascii() {
+        // "00é" is four bytes, so it used to pass the group-length check and
+        // panic slicing off the check character
+        for input in ["0000-0002-1825-00é", "0000-0002-1825-é7", "０000-0002-1825-0097"] {
+            assert_eq!(normalize(input), None, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_record() {
+        let record = json!({
+            "person": {
+                "name": {
+                    "given-names": {"value": "Josiah"},
+                    "family-name": {"value": " Carberry "}
+                },
+                "researcher-urls": {"researcher-url": [
+                    {"url": {"value": "https://carberry.example.edu"}},
+                    {"url": {"value": "https://example.org/second"}}
+                ]}
+            },
+            "activities-summary": {"employments": {"affiliation-group": [
+                {"summaries": [{"employment-summary": {
+                    "organization": {"name": "Wesleyan University"},
+                    "end-date": {"year": {"value": "2020"}}
+                }}]},
+                {"summaries": [{"employment-summary": {
+                    "organization": {"name": "Brown University"},
+                    "end-date": null
+                }}]}
+            ]}}
+        });
+        assert_eq!(
+            parse_record(&record),
+            OrcidProfile {
+                given_name: Some("Josiah".to_string()),
+                family_name: Some("Carberry".to_string()),
+                homepage_url: Some("https://carberry.example.edu".to_string()),
+                affiliation: Some("Brown University".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_private_record() {
+        // Private sections come back as null
+        let record = json!({"person": {"name": null, "researcher-urls": {"researcher-url": []}}, "activities-summary": null});
+        assert_eq!(parse_record(&record), OrcidProfile::default());
+    }
+}
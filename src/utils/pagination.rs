@@ -1,49 +1,92 @@
+use std::sync::OnceLock;
+
 /// Default page size when the client does not specify `limit`.
 pub const DEFAULT_LIMIT: i64 = 100;
 
-/// Hard upper bound on `limit`. Larger values are clamped down to this.
+/// Upper bound on `limit` unless `MAX_PAGE_SIZE` says otherwise.
 /// Prevents `?limit=999999999` from forcing a full-table fetch + serialise.
-pub const MAX_LIMIT: i64 = 1000;
+pub const DEFAULT_MAX_PAGE_SIZE: i64 = 500;
+
+/// Largest `limit` a list endpoint serves: `MAX_PAGE_SIZE` from the
+/// environment, read once, or `DEFAULT_MAX_PAGE_SIZE` when unset or not a
+/// positive integer.
+pub fn max_page_size() -> i64 {
+    static MAX: OnceLock<i64> = OnceLock::new();
+    *MAX.get_or_init(|| {
+        std::env::var("MAX_PAGE_SIZE")
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .filter(|&max: &i64| max > 0)
+            .unwrap_or(DEFAULT_MAX_PAGE_SIZE)
+    })
+}
 
-/// Clamp client-supplied pagination parameters to safe ranges.
+/// Check and clamp client-supplied pagination parameters.
 ///
-/// - `limit` is clamped to `1..=MAX_LIMIT`, defaulting to `DEFAULT_LIMIT` when absent.
-/// - `offset` is clamped to `>= 0`, defaulting to `0` when absent.
-pub fn clamp_pagination(limit: Option<i64>, offset: Option<i64>) -> (i64, i64) {
-    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
-    let offset = offset.unwrap_or(0).max(0);
-    (limit, offset)
+/// - A negative `limit` or `offset` is rejected with a message for the client.
+/// - `limit` is clamped to `1..=max_page_size()`, defaulting to `DEFAULT_LIMIT` when absent.
+/// - `offset` defaults to `0` when absent.
+pub fn clamp_pagination(limit: Option<i64>, offset: Option<i64>) -> Result<(i64, i64), String> {
+    clamp_pagination_to(limit, offset, max_page_size())
+}
+
+/// `clamp_pagination` against an explicit ceiling.
+pub fn clamp_pagination_to(
+    limit: Option<i64>,
+    offset: Option<i64>,
+    max: i64,
+) -> Result<(i64, i64), String> {
+    for (name, value) in [("limit", limit), ("offset", offset)] {
+        if let Some(value) = value.filter(|v| *v < 0) {
+            return Err(format!("{} must not be negative (got {})", name, value));
+        }
+    }
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, max);
+    Ok((limit, offset.unwrap_or(0)))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const MAX: i64 = DEFAULT_MAX_PAGE_SIZE;
+
     #[test]
     fn defaults_when_none() {
-        assert_eq!(clamp_pagination(None, None), (DEFAULT_LIMIT, 0));
+        assert_eq!(clamp_pagination_to(None, None, MAX), Ok((DEFAULT_LIMIT, 0)));
     }
 
     #[test]
     fn clamps_huge_limit() {
-        assert_eq!(clamp_pagination(Some(i64::MAX), None), (MAX_LIMIT, 0));
-        assert_eq!(clamp_pagination(Some(999_999_999), None), (MAX_LIMIT, 0));
+        assert_eq!(clamp_pagination_to(Some(i64::MAX), None, MAX), Ok((MAX, 0)));
+        assert_eq!(clamp_pagination_to(Some(999_999_999), None, MAX), Ok((MAX, 0)));
+    }
+
+    #[test]
+    fn default_limit_respects_small_ceiling() {
+        assert_eq!(clamp_pagination_to(None, None, 25), Ok((25, 0)));
     }
 
     #[test]
-    fn clamps_negative_limit_to_one() {
-        assert_eq!(clamp_pagination(Some(-5), None), (1, 0));
-        assert_eq!(clamp_pagination(Some(0), None), (1, 0));
+    fn clamps_zero_limit_to_one() {
+        assert_eq!(clamp_pagination_to(Some(0), None, MAX), Ok((1, 0)));
     }
 
     #[test]
-    fn clamps_negative_offset_to_zero() {
-        assert_eq!(clamp_pagination(None, Some(-100)), (DEFAULT_LIMIT, 0));
+    fn rejects_negative_values() {
+        assert_eq!(
+            clamp_pagination_to(Some(-5), None, MAX),
+            Err("limit must not be negative (got -5)".to_string())
+        );
+        assert_eq!(
+            clamp_pagination_to(None, Some(-1), MAX),
+            Err("offset must not be negative (got -1)".to_string())
+        );
     }
 
     #[test]
     fn passes_valid_values() {
-        assert_eq!(clamp_pagination(Some(50), Some(200)), (50, 200));
-        assert_eq!(clamp_pagination(Some(MAX_LIMIT), Some(0)), (MAX_LIMIT, 0));
+        assert_eq!(clamp_pagination_to(Some(50), Some(200), MAX), Ok((50, 200)));
+        assert_eq!(clamp_pagination_to(Some(MAX), Some(0), MAX), Ok((MAX, 0)));
     }
 }
@@ -0,0 +1,136 @@
+//! Conservative Hanzi → pinyin table for author name matching
+//!
+//! Covers the common Chinese surnames and given-name characters (simplified
+//! and traditional forms), without tones and using each character's usual
+//! reading in names (曾 → zeng, not ceng). Characters not listed are left
+//! untouched by the transliteration, so an unknown name never matches a
+//! romanized one by accident.
+
+/// Sorted by code point so lookups can binary search.
+const PINYIN: &[(char, &str)] = &[
+    ('一', "yi"), ('丁', "ding"), ('万', "wan"), ('上', "shang"), ('世', "shi"), ('东', "dong"),
+    ('严', "yan"), ('中', "zhong"), ('丹', "dan"), ('丽', "li"), ('义', "yi"), ('之', "zhi"),
+    ('乔', "qiao"), ('于', "yu"), ('云', "yun"), ('亚', "ya"), ('亞', "ya"), ('亮', "liang"),
+    ('仁', "ren"), ('仇', "qiu"), ('付', "fu"), ('仲', "zhong"), ('任', "ren"), ('伍', "wu"),
+    ('伟', "wei"), ('伦', "lun"), ('伯', "bo"), ('何', "he"), ('余', "yu"), ('佟', "tong"),
+    ('佩', "pei"), ('佳', "jia"), ('來', "lai"), ('侯', "hou"), ('俊', "jun"), ('俞', "yu"),
+    ('信', "xin"), ('倩', "qian"), ('倪', "ni"), ('倫', "lun"), ('偉', "wei"), ('健', "jian"),
+    ('傅', "fu"), ('傑', "jie"), ('储', "chu"), ('儲', "chu"), ('元', "yuan"), ('光', "guang"),
+    ('全', "quan"), ('兰', "lan"), ('关', "guan"), ('冀', "ji"), ('冉', "ran"), ('军', "jun"),
+    ('冬', "dong"), ('冯', "feng"), ('冰', "bing"), ('冷', "leng"), ('冼', "xian"), ('凌', "ling"),
+    ('凤', "feng"), ('凯', "kai"), ('凱', "kai"), ('刘', "liu"), ('刚', "gang"), ('利', "li"),
+    ('剛', "gang"), ('劉', "liu"), ('力', "li"), ('劳', "lao"), ('勇', "yong"), ('勝', "sheng"),
+    ('勞', "lao"), ('包', "bao"), ('匡', "kuang"), ('华', "hua"), ('卓', "zhuo"), ('南', "nan"),
+    ('博', "bo"), ('卜', "bu"), ('卞', "bian"), ('卢', "lu"), ('卫', "wei"), ('原', "yuan"),
+    ('双', "shuang"), ('古', "gu"), ('史', "shi"), ('叶', "ye"), ('司', "si"), ('吉', "ji"),
+    ('向', "xiang"), ('吕', "lu"), ('吳', "wu"), ('吴', "wu"), ('呂', "lu"), ('周', "zhou"),
+    ('和', "he"), ('哲', "zhe"), ('唐', "tang"), ('商', "shang"), ('喜', "xi"), ('喬', "qiao"),
+    ('喻', "yu"), ('嘉', "jia"), ('嚴', "yan"), ('国', "guo"), ('國', "guo"), ('坤', "kun"),
+    ('士', "shi"), ('夏', "xia"), ('大', "da"), ('天', "tian"), ('奚', "xi"), ('妍', "yan"),
+    ('姚', "yao"), ('姜', "jiang"), ('姬', "ji"), ('娄', "lou"), ('娜', "na"), ('娟', "juan"),
+    ('婁', "lou"), ('婧', "jing"), ('婷', "ting"), ('媛', "yuan"), ('子', "zi"), ('孔', "kong"),
+    ('孙', "sun"), ('孟', "meng"), ('季', "ji"), ('学', "xue"), ('孫', "sun"), ('學', "xue"),
+    ('宁', "ning"), ('宇', "yu"), ('安', "an"), ('宋', "song"), ('宏', "hong"), ('宗', "zong"),
+    ('官', "guan"), ('宝', "bao"), ('家', "jia"), ('容', "rong"), ('寇', "kou"), ('寧', "ning"),
+    ('寶', "bao"), ('封', "feng"), ('小', "xiao"), ('少', "shao"), ('尚', "shang"), ('尤', "you"),
+    ('尹', "yin"), ('屈', "qu"), ('屠', "tu"), ('山', "shan"), ('岑', "cen"), ('岩', "yan"),
+    ('岳', "yue"), ('峰', "feng"), ('崔', "cui"), ('川', "chuan"), ('左', "zuo"), ('巫', "wu"),
+    ('巴', "ba"), ('帆', "fan"), ('师', "shi"), ('師', "shi"), ('席', "xi"), ('常', "chang"),
+    ('平', "ping"), ('庄', "zhuang"), ('庆', "qing"), ('应', "ying"), ('庞', "pang"), ('康', "kang"),
+    ('廖', "liao"), ('建', "jian"), ('张', "zhang"), ('張', "zhang"), ('強', "qiang"), ('强', "qiang"),
+    ('彤', "tong"), ('彬', "bin"), ('彭', "peng"), ('徐', "xu"), ('徒', "tu"), ('德', "de"),
+    ('志', "zhi"), ('忠', "zhong"), ('思', "si"), ('怡', "yi"), ('恆', "heng"), ('恒', "heng"),
+    ('悅', "yue"), ('悦', "yue"), ('惠', "hui"), ('愛', "ai"), ('慧', "hui"), ('慶', "qing"),
+    ('應', "ying"), ('成', "cheng"), ('戚', "qi"), ('戴', "dai"), ('房', "fang"), ('振', "zhen"),
+    ('敏', "min"), ('敖', "ao"), ('文', "wen"), ('斌', "bin"), ('新', "xin"), ('方', "fang"),
+    ('施', "shi"), ('旭', "xu"), ('时', "shi"), ('昊', "hao"), ('明', "ming"), ('易', "yi"),
+    ('昕', "xin"), ('星', "xing"), ('春', "chun"), ('時', "shi"), ('晏', "yan"), ('晓', "xiao"),
+    ('晗', "han"), ('晨', "chen"), ('景', "jing"), ('晴', "qing"), ('晶', "jing"), ('智', "zhi"),
+    ('曉', "xiao"), ('曲', "qu"), ('曹', "cao"), ('曾', "zeng"), ('月', "yue"), ('朱', "zhu"),
+    ('权', "quan"), ('李', "li"), ('杜', "du"), ('来', "lai"), ('杨', "yang"), ('杰', "jie"),
+    ('東', "dong"), ('松', "song"), ('林', "lin"), ('柏', "bai"), ('查', "zha"), ('柯', "ke"),
+    ('柳', "liu"), ('柴', "chai"), ('栾', "luan"), ('桂', "gui"), ('桑', "sang"), ('梁', "liang"),
+    ('梅', "mei"), ('梓', "zi"), ('森', "sen"), ('楊', "yang"), ('楚', "chu"), ('楠', "nan"),
+    ('楼', "lou"), ('榮', "rong"), ('樊', "fan"), ('樓', "lou"), ('權', "quan"), ('欒', "luan"),
+    ('欣', "xin"), ('欧', "ou"), ('歐', "ou"), ('正', "zheng"), ('武', "wu"), ('段', "duan"),
+    ('殷', "yin"), ('毅', "yi"), ('毕', "bi"), ('毛', "mao"), ('民', "min"), ('永', "yong"),
+    ('江', "jiang"), ('池', "chi"), ('汤', "tang"), ('汪', "wang"), ('沈', "shen"), ('沙', "sha"),
+    ('波', "bo"), ('泽', "ze"), ('洁', "jie"), ('洋', "yang"), ('浩', "hao"), ('海', "hai"),
+    ('涂', "tu"), ('涛', "tao"), ('涵', "han"), ('清', "qing"), ('温', "wen"), ('游', "you"),
+    ('湯', "tang"), ('源', "yuan"), ('溫', "wen"), ('滕', "teng"), ('潔', "jie"), ('潘', "pan"),
+    ('澤', "ze"), ('濤', "tao"), ('焦', "jiao"), ('然', "ran"), ('熊', "xiong"), ('燕', "yan"),
+    ('爱', "ai"), ('牛', "niu"), ('玉', "yu"), ('王', "wang"), ('玮', "wei"), ('玲', "ling"),
+    ('珍', "zhen"), ('班', "ban"), ('琦', "qi"), ('琪', "qi"), ('琳', "lin"), ('琴', "qin"),
+    ('瑋', "wei"), ('瑞', "rui"), ('瑤', "yao"), ('瑶', "yao"), ('璇', "xuan"), ('璐', "lu"),
+    ('甄', "zhen"), ('甘', "gan"), ('生', "sheng"), ('田', "tian"), ('申', "shen"), ('畢', "bi"),
+    ('白', "bai"), ('皮', "pi"), ('盛', "sheng"), ('盧', "lu"), ('睿', "rui"), ('瞿', "qu"),
+    ('石', "shi"), ('磊', "lei"), ('祁', "qi"), ('祖', "zu"), ('祝', "zhu"), ('祥', "xiang"),
+    ('福', "fu"), ('禹', "yu"), ('秀', "xiu"), ('秋', "qiu"), ('秦', "qin"), ('程', "cheng"),
+    ('穆', "mu"), ('穎', "ying"), ('窦', "dou"), ('竇', "dou"), ('立', "li"), ('章', "zhang"),
+    ('童', "tong"), ('符', "fu"), ('简', "jian"), ('管', "guan"), ('簡', "jian"), ('米', "mi"),
+    ('紀', "ji"), ('紅', "hong"), ('繼', "ji"), ('红', "hong"), ('纪', "ji"), ('继', "ji"),
+    ('罗', "luo"), ('羅', "luo"), ('美', "mei"), ('群', "qun"), ('義', "yi"), ('翁', "weng"),
+    ('翔', "xiang"), ('翟', "zhai"), ('耿', "geng"), ('聂', "nie"), ('聞', "wen"), ('聶', "nie"),
+    ('肖', "xiao"), ('胜', "sheng"), ('胡', "hu"), ('胥', "xu"), ('臧', "zang"), ('舒', "shu"),
+    ('舟', "zhou"), ('航', "hang"), ('良', "liang"), ('艳', "yan"), ('艷', "yan"), ('艺', "yi"),
+    ('艾', "ai"), ('芬', "fen"), ('花', "hua"), ('芳', "fang"), ('苏', "su"), ('苗', "miao"),
+    ('若', "ruo"), ('英', "ying"), ('范', "fan"), ('茜', "qian"), ('荣', "rong"), ('莉', "li"),
+    ('莊', "zhuang"), ('莫', "mo"), ('華', "hua"), ('菲', "fei"), ('萍', "ping"), ('萧', "xiao"),
+    ('萬', "wan"), ('萱', "xuan"), ('葉', "ye"), ('葛', "ge"), ('董', "dong"), ('蒋', "jiang"),
+    ('蒙', "meng"), ('蒲', "pu"), ('蓝', "lan"), ('蔡', "cai"), ('蔣', "jiang"), ('蕊', "rui"),
+    ('蕭', "xiao"), ('蕾', "lei"), ('薇', "wei"), ('薛', "xue"), ('藍', "lan"), ('藝', "yi"),
+    ('蘇', "su"), ('蘭', "lan"), ('虞', "yu"), ('衛', "wei"), ('袁', "yuan"), ('裴', "pei"),
+    ('褚', "chu"), ('覃', "qin"), ('詩', "shi"), ('詹', "zhan"), ('談', "tan"), ('謝', "xie"),
+    ('譚', "tan"), ('诗', "shi"), ('谈', "tan"), ('谢', "xie"), ('谭', "tan"), ('谷', "gu"),
+    ('貴', "gui"), ('費', "fei"), ('賀', "he"), ('賈', "jia"), ('賢', "xian"), ('贤', "xian"),
+    ('贵', "gui"), ('费', "fei"), ('贺', "he"), ('贾', "jia"), ('赵', "zhao"), ('超', "chao"),
+    ('越', "yue"), ('趙', "zhao"), ('路', "lu"), ('車', "che"), ('軍', "jun"), ('軒', "xuan"),
+    ('輝', "hui"), ('车', "che"), ('轩', "xuan"), ('辉', "hui"), ('辛', "xin"), ('辰', "chen"),
+    ('边', "bian"), ('达', "da"), ('进', "jin"), ('远', "yuan"), ('连', "lian"), ('迟', "chi"),
+    ('連', "lian"), ('進', "jin"), ('逸', "yi"), ('達', "da"), ('遠', "yuan"), ('遲', "chi"),
+    ('邊', "bian"), ('邓', "deng"), ('邢', "xing"), ('邱', "qiu"), ('邵', "shao"), ('邹', "zou"),
+    ('郁', "yu"), ('郎', "lang"), ('郑', "zheng"), ('郝', "hao"), ('郭', "guo"), ('鄒', "zou"),
+    ('鄧', "deng"), ('鄭', "zheng"), ('金', "jin"), ('鈺', "yu"), ('銘', "ming"), ('鋒', "feng"),
+    ('錢', "qian"), ('鍾', "zhong"), ('鑫', "xin"), ('钟', "zhong"), ('钰', "yu"), ('钱', "qian"),
+    ('铭', "ming"), ('锋', "feng"), ('長', "chang"), ('长', "chang"), ('閆', "yan"), ('閔', "min"),
+    ('關', "guan"), ('闫', "yan"), ('闵', "min"), ('闻', "wen"), ('阮', "ruan"), ('阳', "yang"),
+    ('陆', "lu"), ('陈', "chen"), ('陳', "chen"), ('陶', "tao"), ('陸', "lu"), ('陽', "yang"),
+    ('隋', "sui"), ('雙', "shuang"), ('雨', "yu"), ('雪', "xue"), ('雯', "wen"), ('雲', "yun"),
+    ('雷', "lei"), ('霍', "huo"), ('霞', "xia"), ('露', "lu"), ('青', "qing"), ('静', "jing"),
+    ('靜', "jing"), ('靳', "jin"), ('鞠', "ju"), ('韋', "wei"), ('韓', "han"), ('韜', "tao"),
+    ('韦', "wei"), ('韩', "han"), ('韬', "tao"), ('項', "xiang"), ('顏', "yan"), ('顧', "gu"),
+    ('项', "xiang"), ('顾', "gu"), ('颖', "ying"), ('颜', "yan"), ('飛', "fei"), ('飞', "fei"),
+    ('饒', "rao"), ('饶', "rao"), ('馬', "ma"), ('馮', "feng"), ('駱', "luo"), ('駿', "jun"),
+    ('马', "ma"), ('骆', "luo"), ('骏', "jun"), ('高', "gao"), ('魏', "wei"), ('魯', "lu"),
+    ('鮑', "bao"), ('鲁', "lu"), ('鲍', "bao"), ('鳳', "feng"), ('鳴', "ming"), ('鴻', "hong"),
+    ('鵬', "peng"), ('鸣', "ming"), ('鸿', "hong"), ('鹏', "peng"), ('麗', "li"), ('麥', "mai"),
+    ('麦', "mai"), ('黃', "huang"), ('黄', "huang"), ('黎', "li"), ('齊', "qi"), ('齐', "qi"),
+    ('龍', "long"), ('龐', "pang"), ('龔', "gong"), ('龙', "long"), ('龚', "gong"),
+];
+
+/// Toneless pinyin for a Han character, if it is in the table.
+pub(crate) fn pinyin(c: char) -> Option<&'static str> {
+    PINYIN
+        .binary_search_by_key(&c, |&(ch, _)| ch)
+        .ok()
+        .map(|i| PINYIN[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_sorted_and_unique() {
+        assert!(PINYIN.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(pinyin('张'), Some("zhang"));
+        assert_eq!(pinyin('張'), Some("zhang"));
+        assert_eq!(pinyin('伟'), Some("wei"));
+        assert_eq!(pinyin('曾'), Some("zeng"));
+        assert_eq!(pinyin('a'), None);
+    }
+}
@@ -0,0 +1,80 @@
+//! Full-text search settings
+
+/// Postgres text search configurations accepted as `lang`. `english` stems
+/// words and drops stop words; `simple` only lowercases, which keeps technical
+/// terms such as "qudits" or "QMA" intact. The others are the built-in
+/// configurations for languages that titles and abstracts appear in.
+pub const SEARCH_LANGUAGES: &[&str] = &[
+    "english", "simple", "french", "german", "italian", "spanish", "portuguese", "dutch", "russian",
+];
+
+/// Configuration used when `lang` is omitted; `publications.search_vector` is built with it.
+pub const DEFAULT_SEARCH_LANGUAGE: &str = "english";
+
+/// Map a client-supplied `lang` (any case) to a configuration name that is
+/// safe to pass to Postgres, or a message listing the accepted values.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::search_language;
+///
+/// assert_eq!(search_language(None), Ok("english"));
+/// assert_eq!(search_language(Some(" Simple ")), Ok("simple"));
+/// assert!(search_language(Some("klingon")).unwrap_err().contains("english, simple"));
+/// ```
+pub fn search_language(lang: Option<&str>) -> Result<&'static str, String> {
+    let Some(lang) = lang else {
+        return Ok(DEFAULT_SEARCH_LANGUAGE);
+    };
+    let lower = lang.trim().to_lowercase();
+    SEARCH_LANGUAGES
+        .iter()
+        .copied()
+        .find(|l| *l == lower)
+        .ok_or_else(|| format!("Unknown lang {:?}; expected one of {}", lang, SEARCH_LANGUAGES.join(", ")))
+}
+
+/// Escape `%`, `_` and `\` so `value` matches literally inside a `LIKE`
+/// pattern (Postgres' default escape character is the backslash).
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::escape_like;
+///
+/// assert_eq!(format!("{}%", escape_like("o_brien")), "o\\_brien%");
+/// ```
+pub fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_language_resolves_to_itself() {
+        for lang in SEARCH_LANGUAGES {
+            assert_eq!(search_language(Some(&lang.to_uppercase())), Ok(*lang));
+        }
+    }
+
+    #[test]
+    fn test_rejects_unlisted_configurations() {
+        // Real Postgres configurations, but not offered
+        assert!(search_language(Some("pg_catalog.english")).is_err());
+        assert!(search_language(Some("")).is_err());
+    }
+
+    #[test]
+    fn test_escape_like() {
+        assert_eq!(escape_like("einstein"), "einstein");
+        assert_eq!(escape_like("100%_a\\b"), "100\\%\\_a\\\\b");
+    }
+}
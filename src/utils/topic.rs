@@ -0,0 +1,69 @@
+//! Publication topic normalization
+//!
+//! Topics are research-area tags ("error-correction", "quantum-cryptography").
+//! Clients type them however they like; we store one slug per area so
+//! "Error Correction", "error_correction" and "error-correction" are the same
+//! tag. Matches the `valid_topic` check on `publication_topics`.
+
+/// Longest topic slug accepted.
+pub const MAX_TOPIC_LEN: usize = 64;
+
+/// Normalize a topic to its slug, or `None` if nothing usable is left.
+///
+/// Lowercases, treats whitespace, `_` and `-` runs as a single hyphen, and
+/// trims them from the ends. Only ASCII letters and digits are allowed between
+/// separators; the result must be at most `MAX_TOPIC_LEN` characters.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::topic;
+///
+/// assert_eq!(topic::normalize("Error Correction"), Some("error-correction".to_string()));
+/// assert_eq!(topic::normalize(" quantum_key--distribution "), Some("quantum-key-distribution".to_string()));
+/// assert_eq!(topic::normalize("QEC!"), None);
+/// ```
+pub fn normalize(topic: &str) -> Option<String> {
+    let mut slug = String::with_capacity(topic.len());
+    for word in topic.split(|c: char| c.is_whitespace() || c == '_' || c == '-') {
+        if word.is_empty() {
+            continue;
+        }
+        if !word.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word.to_ascii_lowercase());
+    }
+
+    (!slug.is_empty() && slug.len() <= MAX_TOPIC_LEN).then_some(slug)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugifies() {
+        assert_eq!(normalize("error-correction"), Some("error-correction".to_string()));
+        assert_eq!(normalize("Error Correction"), Some("error-correction".to_string()));
+        assert_eq!(normalize("  error__correction\t"), Some("error-correction".to_string()));
+        assert_eq!(normalize("-QKD-"), Some("qkd".to_string()));
+        assert_eq!(normalize("NISQ 2"), Some("nisq-2".to_string()));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(normalize(""), None);
+        assert_eq!(normalize(" - _ "), None);
+        assert_eq!(normalize("error/correction"), None);
+        assert_eq!(normalize("théorie"), None);
+    }
+
+    #[test]
+    fn test_length_limit() {
+        assert!(normalize(&"a".repeat(MAX_TOPIC_LEN)).is_some());
+        assert_eq!(normalize(&"a".repeat(MAX_TOPIC_LEN + 1)), None);
+    }
+}
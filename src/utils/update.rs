@@ -0,0 +1,125 @@
+use std::ops::Deref;
+
+use axum::http::StatusCode;
+use serde::{Deserialize, Deserializer};
+
+/// Deserializer for tri-state update fields (`Option<Option<T>>`), used with
+/// `#[serde(default, deserialize_with = "nullable")]`:
+///
+/// - key omitted → `None` (leave unchanged on PATCH)
+/// - `null` → `Some(None)` (clear the field)
+/// - a value → `Some(Some(value))`
+pub fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Borrow the value of a tri-state field, if one was supplied (for validation).
+pub fn supplied<T: Deref>(field: &Option<Option<T>>) -> Option<&T::Target> {
+    field.as_ref().and_then(|v| v.as_deref())
+}
+
+/// How an update request treats fields the client left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// `PUT`: the body is the new state of the record. Omitted optional fields
+    /// become null, omitted defaulted fields reset to their default, and
+    /// omitted required fields are rejected with 422.
+    Replace,
+    /// `PATCH`: only fields present in the body change.
+    Merge,
+}
+
+impl UpdateMode {
+    /// Resolve a nullable column from a tri-state field (see `nullable`). An
+    /// explicit `null` clears the column in both modes.
+    pub fn optional<T>(self, new: Option<Option<T>>, existing: Option<T>) -> Option<T> {
+        match (self, new) {
+            (_, Some(value)) => value,
+            (UpdateMode::Replace, None) => None,
+            (UpdateMode::Merge, None) => existing,
+        }
+    }
+
+    /// Resolve a NOT NULL column that has a default (as on create).
+    pub fn or_default<T>(self, new: Option<T>, existing: T, default: T) -> T {
+        match self {
+            UpdateMode::Replace => new.unwrap_or(default),
+            UpdateMode::Merge => new.unwrap_or(existing),
+        }
+    }
+
+    /// Resolve a NOT NULL column the client must supply on replace.
+    pub fn required<T>(self, new: Option<T>, existing: T) -> Result<T, StatusCode> {
+        match (self, new) {
+            (_, Some(value)) => Ok(value),
+            (UpdateMode::Merge, None) => Ok(existing),
+            (UpdateMode::Replace, None) => {
+                tracing::warn!("Required field missing from full update");
+                Err(StatusCode::UNPROCESSABLE_ENTITY)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Body {
+        #[serde(default, deserialize_with = "nullable")]
+        orcid: Option<Option<String>>,
+    }
+
+    fn parse(json: &str) -> Option<Option<String>> {
+        serde_json::from_str::<Body>(json).unwrap().orcid
+    }
+
+    #[test]
+    fn nullable_distinguishes_omitted_null_and_value() {
+        assert_eq!(parse("{}"), None);
+        assert_eq!(parse(r#"{"orcid": null}"#), Some(None));
+        assert_eq!(parse(r#"{"orcid": "0000-0001-2345-6789"}"#), Some(Some("0000-0001-2345-6789".into())));
+    }
+
+    #[test]
+    fn supplied_borrows_only_values() {
+        assert_eq!(supplied::<String>(&None), None);
+        assert_eq!(supplied::<String>(&Some(None)), None);
+        assert_eq!(supplied(&Some(Some("CWI".to_string()))), Some("CWI"));
+    }
+
+    #[test]
+    fn replace_clears_omitted_optional_fields() {
+        assert_eq!(UpdateMode::Replace.optional(None, Some("CWI")), None);
+        assert_eq!(UpdateMode::Replace.optional(Some(Some("QuSoft")), Some("CWI")), Some("QuSoft"));
+    }
+
+    #[test]
+    fn merge_keeps_omitted_fields() {
+        assert_eq!(UpdateMode::Merge.optional(None, Some("CWI")), Some("CWI"));
+        assert_eq!(UpdateMode::Merge.optional(Some(Some("QuSoft")), Some("CWI")), Some("QuSoft"));
+        assert!(UpdateMode::Merge.or_default(None, true, false));
+        assert_eq!(UpdateMode::Merge.required(None, 2024), Ok(2024));
+    }
+
+    #[test]
+    fn explicit_null_clears_in_both_modes() {
+        assert_eq!(UpdateMode::Merge.optional(Some(None), Some("CWI")), None);
+        assert_eq!(UpdateMode::Replace.optional(Some(None), Some("CWI")), None);
+    }
+
+    #[test]
+    fn replace_resets_defaults_and_requires_required_fields() {
+        assert!(!UpdateMode::Replace.or_default(None, true, false));
+        assert_eq!(UpdateMode::Replace.required(Some(2025), 2024), Ok(2025));
+        assert_eq!(
+            UpdateMode::Replace.required(None::<i32>, 2024),
+            Err(StatusCode::UNPROCESSABLE_ENTITY)
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use axum::http::StatusCode;
+use chrono::{Datelike, NaiveDate};
 
 /// Maximum allowed length for any URL field (RFC-recommended hard cap is ~2 KB).
 pub const MAX_URL_LEN: usize = 2048;
@@ -110,10 +111,89 @@ pub fn validate_optional_url(value: Option<&str>) -> Result<(), StatusCode> {
     }
 }
 
+/// Validate an author name: it must contain at least one letter, so blank,
+/// whitespace-only and punctuation-only names ("", "   ", "--") are refused.
+/// Such names normalize to an empty string, which matches every author in
+/// fuzzy deduplication.
+///
+/// Returns `StatusCode::UNPROCESSABLE_ENTITY` on rejection.
+pub fn validate_author_name(name: &str) -> Result<(), StatusCode> {
+    if !name.chars().any(char::is_alphabetic) {
+        tracing::warn!(name, "Rejected author name without letters");
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    Ok(())
+}
+
+/// Validate a committee term: `term_start` must not be after `term_end`, and
+/// when the conference date is known the term must overlap that calendar year.
+/// Either end may be open (`None`).
+///
+/// Returns `StatusCode::UNPROCESSABLE_ENTITY` on rejection.
+pub fn validate_term(
+    term_start: Option<NaiveDate>,
+    term_end: Option<NaiveDate>,
+    conference_date: Option<NaiveDate>,
+) -> Result<(), StatusCode> {
+    if let (Some(start), Some(end)) = (term_start, term_end) {
+        if start > end {
+            tracing::warn!(%start, %end, "Committee term starts after it ends");
+            return Err(StatusCode::UNPROCESSABLE_ENTITY);
+        }
+    }
+
+    if let Some(date) = conference_date {
+        let year = date.year();
+        let ends_before = term_end.is_some_and(|end| end.year() < year);
+        let starts_after = term_start.is_some_and(|start| start.year() > year);
+        if ends_before || starts_after {
+            tracing::warn!(year, ?term_start, ?term_end, "Committee term does not overlap the conference year");
+            return Err(StatusCode::UNPROCESSABLE_ENTITY);
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn date(s: &str) -> Option<NaiveDate> {
+        Some(s.parse().unwrap())
+    }
+
+    #[test]
+    fn term_ordering() {
+        assert!(validate_term(date("2020-01-01"), date("2024-12-31"), None).is_ok());
+        assert!(validate_term(date("2024-01-01"), date("2024-01-01"), None).is_ok());
+        assert!(validate_term(date("2024-01-01"), None, None).is_ok());
+        assert_eq!(
+            validate_term(date("2025-01-01"), date("2024-12-31"), None),
+            Err(StatusCode::UNPROCESSABLE_ENTITY)
+        );
+    }
+
+    #[test]
+    fn term_must_overlap_conference_year() {
+        let conference = date("2024-01-15");
+        assert!(validate_term(date("2022-01-01"), date("2024-01-01"), conference).is_ok());
+        assert!(validate_term(date("2024-12-01"), None, conference).is_ok());
+        assert!(validate_term(None, date("2030-01-01"), conference).is_ok());
+        assert!(validate_term(None, None, conference).is_ok());
+        assert!(validate_term(date("2020-01-01"), date("2023-12-31"), conference).is_err());
+        assert!(validate_term(date("2025-01-01"), None, conference).is_err());
+    }
+
+    #[test]
+    fn author_name_needs_a_letter() {
+        assert!(validate_author_name("Alice Smith").is_ok());
+        assert!(validate_author_name("李明").is_ok());
+        assert!(validate_author_name("  Ø. ").is_ok());
+        for name in ["", "   ", "\t\n", "--", "1234", ". ,"] {
+            assert_eq!(validate_author_name(name), Err(StatusCode::UNPROCESSABLE_ENTITY), "{:?}", name);
+        }
+    }
+
     #[test]
     fn accepts_http_and_https() {
         assert!(validate_url("http://example.com").is_ok());
@@ -0,0 +1,164 @@
+//! vCard 4.0 (RFC 6350) contact cards for authors
+//!
+//! Only fields we store are written; empty ones are omitted. Values are
+//! escaped and lines folded at 75 octets with CRLF endings, as the RFC
+//! requires, so contact managers import non-ASCII names intact.
+
+use uuid::Uuid;
+
+use crate::utils::orcid;
+
+/// Contact details written into a card.
+pub struct Contact<'a> {
+    pub id: Uuid,
+    pub full_name: &'a str,
+    pub family_name: Option<&'a str>,
+    pub given_name: Option<&'a str>,
+    pub organization: Option<&'a str>,
+    pub homepage_url: Option<&'a str>,
+    pub orcid: Option<&'a str>,
+}
+
+/// Maximum line length in octets, excluding the CRLF.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Render `contact` as a vCard. The ORCID iD goes into `X-ORCID` and, as its
+/// profile URL, into a second `URL` property.
+///
+/// # Examples
+/// ```
+/// use quantumdb::utils::vcard::{self, Contact};
+///
+/// let card = vcard::render(&Contact {
+///     id: uuid::Uuid::nil(),
+///     full_name: "Alice Smith",
+///     family_name: Some("Smith"),
+///     given_name: Some("Alice"),
+///     organization: Some("QuSoft"),
+///     homepage_url: None,
+///     orcid: Some("0000-0002-1825-0097"),
+/// });
+/// assert!(card.starts_with("BEGIN:VCARD\r\nVERSION:4.0\r\n"));
+/// assert!(card.contains("\r\nN:Smith;Alice;;;\r\n"));
+/// assert!(card.contains("\r\nX-ORCID:0000-0002-1825-0097\r\n"));
+/// ```
+pub fn render(contact: &Contact) -> String {
+    let mut lines = vec![
+        "BEGIN:VCARD".to_string(),
+        "VERSION:4.0".to_string(),
+        format!("UID:urn:uuid:{}", contact.id),
+        format!("FN:{}", escape(contact.full_name)),
+    ];
+
+    let family = non_empty(contact.family_name);
+    let given = non_empty(contact.given_name);
+    if family.is_some() || given.is_some() {
+        lines.push(format!(
+            "N:{};{};;;",
+            escape(family.unwrap_or_default()),
+            escape(given.unwrap_or_default())
+        ));
+    }
+    if let Some(org) = non_empty(contact.organization) {
+        lines.push(format!("ORG:{}", escape(org)));
+    }
+    if let Some(url) = non_empty(contact.homepage_url) {
+        lines.push(format!("URL:{}", url));
+    }
+    if let Some(orcid_id) = contact.orcid.and_then(orcid::normalize) {
+        lines.push(format!("URL;TYPE=orcid:https://orcid.org/{}", orcid_id));
+        lines.push(format!("X-ORCID:{}", orcid_id));
+    }
+    lines.push("END:VCARD".to_string());
+
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// Escape a text value (RFC 6350 §3.4).
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ',' => escaped.push_str("\\,"),
+            ';' => escaped.push_str("\\;"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Fold a content line at 75 octets without splitting a UTF-8 character;
+/// continuation lines start with a single space (RFC 6350 §3.2).
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_LINE_OCTETS * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            // The leading space counts toward the continuation line's length
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contact(full_name: &str) -> Contact<'_> {
+        Contact {
+            id: Uuid::nil(),
+            full_name,
+            family_name: None,
+            given_name: None,
+            organization: None,
+            homepage_url: None,
+            orcid: None,
+        }
+    }
+
+    #[test]
+    fn test_escapes_special_characters() {
+        let card = render(&Contact {
+            organization: Some("Dept. of Physics, ETH; Zürich"),
+            ..contact("A\\B")
+        });
+        assert!(card.contains("\r\nFN:A\\\\B\r\n"));
+        assert!(card.contains("\r\nORG:Dept. of Physics\\, ETH\\; Zürich\r\n"));
+    }
+
+    #[test]
+    fn test_skips_empty_and_invalid_fields() {
+        let card = render(&Contact {
+            family_name: Some(" "),
+            organization: Some(""),
+            orcid: Some("not-an-orcid"),
+            ..contact("Bob")
+        });
+        assert_eq!(
+            card,
+            "BEGIN:VCARD\r\nVERSION:4.0\r\nUID:urn:uuid:00000000-0000-0000-0000-000000000000\r\nFN:Bob\r\nEND:VCARD\r\n"
+        );
+    }
+
+    #[test]
+    fn test_folds_long_lines_on_char_boundaries() {
+        let name = "é".repeat(60);
+        let card = render(&contact(&name));
+        for line in card.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS, "{:?}", line);
+        }
+        let unfolded = card.replace("\r\n ", "");
+        assert!(unfolded.contains(&format!("\r\nFN:{}\r\n", name)));
+    }
+}
@@ -12,12 +12,42 @@ async fn setup() -> TestServer {
     TestServer::new(app).unwrap()
 }
 
-/// Generate a unique year for test conferences (to avoid unique constraint violations)
-fn unique_test_year() -> i32 {
-    use std::sync::atomic::{AtomicI32, Ordering};
-    static COUNTER: AtomicI32 = AtomicI32::new(5000);
-    // Each call gets a unique year starting from 5000
-    COUNTER.fetch_add(1, Ordering::SeqCst)
+/// Create a test conference from `body` (everything but `year`) in the latest
+/// year still free for its venue, returning the created conference.
+async fn create_test_conference(server: &TestServer, body: serde_json::Value) -> serde_json::Value {
+    create_test_conferences(server, &[(0, body)]).await.remove(0)
+}
+
+/// Create test conferences in years `start + offset`, for the latest `start`
+/// at which every one of them is free, returning them in the given order.
+///
+/// Test conferences share the accepted year range with real data, so they
+/// take whichever venue/year slots are free (a 409 moves on to the next
+/// start) and each test deletes the conferences it creates.
+async fn create_test_conferences(
+    server: &TestServer,
+    conferences: &[(i32, serde_json::Value)],
+) -> Vec<serde_json::Value> {
+    let max_offset = conferences.iter().map(|(offset, _)| *offset).max().unwrap_or(0);
+    let last_start = quantumdb::utils::max_conference_year() - max_offset;
+    'starts: for start in (quantumdb::utils::MIN_CONFERENCE_YEAR..=last_start).rev() {
+        let mut created: Vec<serde_json::Value> = Vec::new();
+        for (offset, body) in conferences {
+            let mut body = body.clone();
+            body["year"] = json!(start + offset);
+            let response = server.post("/conferences").json(&body).await;
+            if response.status_code() == axum::http::StatusCode::CONFLICT {
+                for conference in &created {
+                    server.delete(&format!("/conferences/{}", conference["id"].as_str().unwrap())).await;
+                }
+                continue 'starts;
+            }
+            response.assert_status(axum::http::StatusCode::CREATED);
+            created.push(response.json());
+        }
+        return created;
+    }
+    panic!("No free conference years left for {:?}", conferences);
 }
 
 // ============================================================================
@@ -92,31 +122,39 @@ async fn test_get_conference_not_found() {
     response.assert_status_not_found();
 }
 
+#[tokio::test]
+async fn test_get_handlers_report_db_failure_as_500() {
+    // A closed pool fails every query, standing in for a database outage
+    let pool = common::create_test_pool().await;
+    pool.close().await;
+    let server = TestServer::new(common::create_test_app(pool)).unwrap();
+
+    let id = Uuid::new_v4();
+    for path in ["conferences", "authors", "publications", "committees", "authorships"] {
+        server
+            .get(&format!("/{}/{}", path, id))
+            .await
+            .assert_status(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
+
 #[tokio::test]
 #[serial]
 async fn test_conference_crud() {
     let server = setup().await;
-    let test_year = unique_test_year();
 
     // Create a new conference
     let create_body = json!({
         "venue": "QIP",
-        "year": test_year,
         "city": "Test City",
         "country": "Test Country",
         "country_code": "TC",
         "creator": "test_user",
         "modifier": "test_user"
     });
-
-    let response = server.post("/conferences").json(&create_body).await;
-    if !response.status_code().is_success() {
-        let body = response.text();
-        panic!("Failed to create conference: {} - {}", response.status_code(), body);
-    }
-
-    let created: serde_json::Value = response.json();
+    let created = create_test_conference(&server, create_body).await;
     let conference_id = created["id"].as_str().expect("Created conference should have an id");
+    let test_year = created["year"].as_i64().unwrap();
 
     // Read the created conference
     let response = server.get(&format!("/conferences/{}", conference_id)).await;
@@ -149,22 +187,460 @@ async fn test_conference_crud() {
     // Verify it's deleted
     let response = server.get(&format!("/conferences/{}", conference_id)).await;
     response.assert_status_not_found();
+
+    // The audit trail outlives the conference
+    let response = server.get(&format!("/conferences/{}/history", conference_id)).await;
+    response.assert_status_ok();
+    let history: Vec<serde_json::Value> = response.json();
+    let actions: Vec<&str> = history.iter().map(|c| c["action"].as_str().unwrap()).collect();
+    assert_eq!(actions, ["create", "update", "delete"]);
+    assert_eq!(
+        history[1]["diff"]["city"],
+        json!({"old": "Test City", "new": "Updated City"})
+    );
+    assert_eq!(history[2]["diff"]["city"]["old"], "Updated City");
+}
+
+#[tokio::test]
+#[serial]
+async fn test_delete_conference_with_children() {
+    let server = setup().await;
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    let response = server
+        .post("/publications")
+        .json(&json!({
+            "conference_id": conference_id,
+            "canonical_key": format!("tqc{}-guard", conference["year"]),
+            "title": "Guarded Publication",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let pub_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    // Refused while the publication exists, with the counts in the message
+    let response = server.delete(&format!("/conferences/{}", conference_id)).await;
+    response.assert_status(axum::http::StatusCode::CONFLICT);
+    let body: serde_json::Value = response.json();
+    assert!(body["message"].as_str().unwrap().contains("1 publications and 0 committee roles"));
+    server.get(&format!("/conferences/{}", conference_id)).await.assert_status_ok();
+
+    // cascade=true removes the children and the conference together
+    let response = server
+        .delete(&format!("/conferences/{}?cascade=true", conference_id))
+        .await;
+    response.assert_status(axum::http::StatusCode::NO_CONTENT);
+    server.get(&format!("/conferences/{}", conference_id)).await.assert_status_not_found();
+    server
+        .get(&format!("/publications/{}?include_deleted=true", pub_id))
+        .await
+        .assert_status_not_found();
+}
+
+#[tokio::test]
+async fn test_delete_conference_data() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QCRYPT", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    let response = server
+        .post("/authors")
+        .json(&json!({"full_name": format!("Wipe Author {}", unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let mut pub_ids = Vec::new();
+    for title in ["Scraped Twice", "Scraped Wrong"] {
+        let response = server
+            .post("/publications")
+            .json(&json!({"conference_id": conference_id, "title": title, "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        response.assert_status(axum::http::StatusCode::CREATED);
+        pub_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": pub_ids[0],
+            "author_id": author_id,
+            "author_position": 1,
+            "published_as_name": format!("Wipe Author {}", unique_suffix),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::CREATED);
+    // Soft-deleted publications are wiped too, freeing their canonical keys
+    server.delete(&format!("/publications/{}", pub_ids[1])).await.assert_status(axum::http::StatusCode::NO_CONTENT);
+    server
+        .post("/committees")
+        .json(&json!({
+            "conference_id": conference_id,
+            "author_id": author_id,
+            "committee": "PC",
+            "position": "member",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::CREATED);
+
+    let response = server.delete(&format!("/conferences/{}/publications?modifier=curator", conference_id)).await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["conference_id"], conference_id);
+    assert_eq!(body["publications"], 2);
+    assert_eq!(body["authorships"], 1);
+    for pub_id in &pub_ids {
+        server
+            .get(&format!("/publications/{}?include_deleted=true", pub_id))
+            .await
+            .assert_status_not_found();
+    }
+
+    // Committee roles are untouched until their own wipe
+    let response = server.delete(&format!("/conferences/{}/committees?modifier=curator", conference_id)).await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<serde_json::Value>()["committee_roles"], 1);
+
+    // Each wipe is one audit entry on the conference
+    let history: Vec<serde_json::Value> = server.get(&format!("/conferences/{}/history", conference_id)).await.json();
+    let wipes: Vec<&serde_json::Value> = history.iter().filter(|c| c["action"] == "update").collect();
+    assert_eq!(wipes.len(), 2, "{:?}", history);
+    assert!(wipes.iter().all(|c| c["modifier"] == "curator"));
+    assert_eq!(wipes[0]["diff"], json!({"publications": {"old": 2, "new": 0}, "authorships": {"old": 1, "new": 0}}));
+    assert_eq!(wipes[1]["diff"], json!({"committee_roles": {"old": 1, "new": 0}}));
+
+    // Nothing left, so the conference deletes without cascade; repeat wipes are no-ops
+    let response = server.delete(&format!("/conferences/{}/publications", conference_id)).await;
+    assert_eq!(response.json::<serde_json::Value>()["publications"], 0);
+    server.delete(&format!("/conferences/{}", conference_id)).await.assert_status(axum::http::StatusCode::NO_CONTENT);
+    server.delete(&format!("/conferences/{}/committees", conference_id)).await.assert_status_not_found();
+
+    common::purge("authors", &author_id).await;
 }
 
 #[tokio::test]
 async fn test_conference_venue_validation() {
     let server = setup().await;
 
-    // Try to create with invalid venue - should fail at database level
+    // Unknown venues are rejected before reaching the database check constraint
     let create_body = json!({
         "venue": "INVALID",
-        "year": unique_test_year(),
+        "year": 2024,
         "creator": "test_user",
         "modifier": "test_user"
     });
 
     let response = server.post("/conferences").json(&create_body).await;
-    response.assert_status(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+    response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    let message = response.json::<serde_json::Value>()["message"].as_str().unwrap().to_string();
+    for venue in ["QIP", "QCRYPT", "TQC"] {
+        assert!(message.contains(venue), "{} should list {}", message, venue);
+    }
+
+    // Known venues are accepted in any case and stored canonically
+    let created = create_test_conference(
+        &server,
+        json!({"venue": "qcrypt", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    assert_eq!(created["venue"], "QCRYPT");
+    let conference_id = created["id"].as_str().unwrap().to_string();
+
+    let response = server
+        .patch(&format!("/conferences/{}", conference_id))
+        .json(&json!({"venue": "AQIS", "modifier": "test_user"}))
+        .await;
+    response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    server
+        .delete(&format!("/conferences/{}?modifier=test_user", conference_id))
+        .await;
+}
+
+#[tokio::test]
+async fn test_venue_list_matches_db_constraint() {
+    let pool = common::create_test_pool().await;
+    let definition: String = sqlx::query_scalar(
+        "SELECT pg_get_constraintdef(oid) FROM pg_constraint WHERE conname = 'conferences_venue_check'",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    // e.g. CHECK ((venue = ANY (ARRAY['QIP'::text, 'QCRYPT'::text, 'TQC'::text])))
+    let mut allowed: Vec<&str> = definition.split('\'').skip(1).step_by(2).collect();
+    allowed.sort();
+    let mut venues = quantumdb::utils::VENUES.to_vec();
+    venues.sort();
+    assert_eq!(allowed, venues, "utils::VENUES and the database constraint disagree");
+}
+
+#[tokio::test]
+#[serial]
+async fn test_create_duplicate_conference() {
+    let server = setup().await;
+
+    let mut create_body = json!({
+        "venue": "QIP",
+        "creator": "test_user",
+        "modifier": "test_user"
+    });
+    let created = create_test_conference(&server, create_body.clone()).await;
+    let conference_id = created["id"].as_str().unwrap().to_string();
+    let test_year = created["year"].as_i64().unwrap();
+
+    create_body["year"] = json!(test_year);
+    let response = server.post("/conferences").json(&create_body).await;
+    response.assert_status(axum::http::StatusCode::CONFLICT);
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["message"], format!("Conference QIP{} already exists", test_year));
+    assert_eq!(body["id"], conference_id.as_str());
+
+    server
+        .delete(&format!("/conferences/{}?modifier=test_user", conference_id))
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_conference_trends() {
+    let server = setup().await;
+
+    let create_body = json!({
+        "venue": "TQC",
+        "submission_count": 200,
+        "acceptance_count": 50,
+        "creator": "test_user",
+        "modifier": "test_user"
+    });
+    let created = create_test_conference(&server, create_body).await;
+    let conference_id = created["id"].as_str().unwrap().to_string();
+    let test_year = created["year"].as_i64().unwrap();
+
+    // Venue filter is case-insensitive
+    let response = server.get("/conferences/trends?venue=tqc").await;
+    response.assert_status_ok();
+    let trends: Vec<serde_json::Value> = response.json();
+    assert!(trends.iter().all(|t| t["venue"] == "TQC"));
+    let years: Vec<i64> = trends.iter().map(|t| t["year"].as_i64().unwrap()).collect();
+    assert!(years.windows(2).all(|w| w[0] <= w[1]), "Trends should be ordered by year");
+
+    let row = trends
+        .iter()
+        .find(|t| t["year"] == test_year)
+        .expect("Test conference should appear in trends");
+    assert_eq!(row["submission_count"], 200);
+    assert_eq!(row["acceptance_count"], 50);
+    assert_eq!(row["acceptance_rate"], 25.0);
+
+    // Unknown venue is rejected
+    let response = server.get("/conferences/trends?venue=AQIS").await;
+    response.assert_status_bad_request();
+
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_conference_index() {
+    let server = setup().await;
+
+    let created = create_test_conference(
+        &server,
+        json!({"venue": "QCRYPT", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = created["id"].as_str().unwrap().to_string();
+    let test_year = created["year"].as_i64().unwrap();
+
+    let response = server.get("/conferences/index.json").await;
+    response.assert_status_ok();
+    let entries: Vec<serde_json::Value> = response.json();
+    let years: Vec<i64> = entries.iter().map(|e| e["year"].as_i64().unwrap()).collect();
+    assert!(years.windows(2).all(|w| w[0] >= w[1]), "Index should be newest first");
+
+    let entry = entries
+        .iter()
+        .find(|e| e["year"] == test_year && e["venue"] == "QCRYPT")
+        .expect("Test conference should appear in the index");
+    assert_eq!(
+        entry,
+        &json!({
+            "slug": format!("qcrypt-{}", test_year),
+            "venue": "QCRYPT",
+            "year": test_year,
+            "publication_count": 0
+        })
+    );
+
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+}
+
+#[tokio::test]
+async fn test_get_conference_etag() {
+    let server = setup().await;
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let path = format!("/conferences/{}", conference_id);
+
+    let response = server.get(&path).await;
+    response.assert_status_ok();
+    let etag = response.header("etag").to_str().unwrap().to_string();
+    assert!(etag.starts_with("W/\""), "ETag should be weak: {}", etag);
+
+    let response = server
+        .get(&path)
+        .add_header("if-none-match", etag.as_str())
+        .await;
+    response.assert_status(axum::http::StatusCode::NOT_MODIFIED);
+    assert_eq!(response.header("etag").to_str().unwrap(), etag);
+
+    // Strong form of the same tag, in a list, still matches
+    let strong = etag.trim_start_matches("W/");
+    server
+        .get(&path)
+        .add_header("if-none-match", format!("\"other\", {}", strong).as_str())
+        .await
+        .assert_status(axum::http::StatusCode::NOT_MODIFIED);
+
+    server
+        .patch(&path)
+        .json(&json!({ "city": "Etagville", "modifier": "test_user" }))
+        .await
+        .assert_status_ok();
+
+    let response = server
+        .get(&path)
+        .add_header("if-none-match", etag.as_str())
+        .await;
+    response.assert_status_ok();
+    assert_ne!(response.header("etag").to_str().unwrap(), etag, "An update should change the ETag");
+
+    server.delete(&path).await;
+}
+
+#[tokio::test]
+async fn test_conference_geo() {
+    let server = setup().await;
+
+    // Codes are normalized on write; only assigned alpha-2 codes are accepted
+    for code in ["USA", "UK", "X"] {
+        server
+            .post("/conferences")
+            .json(&json!({"venue": "QIP", "year": 2024, "country_code": code, "creator": "test_user", "modifier": "test_user"}))
+            .await
+            .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let mut ids = Vec::new();
+    for venue in ["QIP", "TQC"] {
+        let created = create_test_conference(
+            &server,
+            json!({
+                "venue": venue,
+                "country": "Antarctica",
+                "country_code": " a.q. ",
+                "creator": "test_user",
+                "modifier": "test_user"
+            }),
+        )
+        .await;
+        assert_eq!(created["country_code"], "AQ");
+        ids.push(created["id"].as_str().unwrap().to_string());
+    }
+
+    let response = server.get("/conferences/geo").await;
+    response.assert_status_ok();
+    let countries: Vec<serde_json::Value> = response.json();
+    let antarctica = countries.iter().find(|c| c["country_code"] == "AQ").unwrap();
+    assert_eq!(antarctica["country"], "Antarctica");
+    assert_eq!(antarctica["conference_count"], 2);
+    assert_eq!(
+        antarctica["venues"],
+        json!([{"venue": "QIP", "conference_count": 1}, {"venue": "TQC", "conference_count": 1}])
+    );
+    let counts: Vec<i64> = countries.iter().map(|c| c["conference_count"].as_i64().unwrap()).collect();
+    assert!(counts.windows(2).all(|w| w[0] >= w[1]), "Most conferences first");
+
+    server
+        .patch(&format!("/conferences/{}", ids[1]))
+        .json(&json!({"country_code": "Atlantis", "modifier": "test_user"}))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    let response = server
+        .patch(&format!("/conferences/{}", ids[1]))
+        .json(&json!({"country_code": "ch", "modifier": "test_user"}))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<serde_json::Value>()["country_code"], "CH");
+
+    let countries: Vec<serde_json::Value> = server.get("/conferences/geo").await.json();
+    let antarctica = countries.iter().find(|c| c["country_code"] == "AQ").unwrap();
+    assert_eq!(antarctica["conference_count"], 1);
+
+    for id in &ids {
+        server.delete(&format!("/conferences/{}", id)).await;
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_compare_conferences() {
+    let server = setup().await;
+
+    // Pick two seeded conferences to compare
+    let response = server.get("/conferences").await;
+    let conferences: Vec<serde_json::Value> = response.json();
+    let first = conferences[0]["slug"].as_str().unwrap();
+    let second = conferences[1]["slug"].as_str().unwrap();
+
+    let response = server
+        .get(&format!("/conferences/compare?slugs={},QIP2099,{},bogus", second, first))
+        .await;
+    response.assert_status_ok();
+    let comparison: serde_json::Value = response.json();
+
+    let rows = comparison["conferences"].as_array().unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["slug"], second, "Rows should follow request order");
+    assert_eq!(rows[1]["slug"], first);
+    assert!(rows[0]["publication_count"].is_number());
+    assert!(rows[0]["committee_member_count"].is_number());
+    assert!(rows[0]["unique_author_count"].is_number());
+    assert_eq!(comparison["not_found"], json!(["QIP2099", "bogus"]));
+
+    // Other spellings of the same edition are dropped after the first
+    let (venue, year) = quantumdb::utils::parse_conference_slug(first).unwrap();
+    let canonical = quantumdb::utils::make_conference_slug(&venue, year);
+    let response = server
+        .get(&format!("/conferences/compare?slugs={},{},{},qip2099,QIP-2099", first, canonical, first.to_lowercase()))
+        .await;
+    response.assert_status_ok();
+    let comparison: serde_json::Value = response.json();
+    assert_eq!(comparison["conferences"].as_array().unwrap().len(), 1);
+    assert_eq!(comparison["not_found"], json!(["qip2099"]));
+
+    let response = server.get("/conferences/compare?slugs=").await;
+    response.assert_status_bad_request();
 }
 
 // ============================================================================
@@ -223,7 +699,7 @@ async fn test_author_crud() {
     });
 
     let response = server
-        .put(&format!("/authors/{}", author_id))
+        .patch(&format!("/authors/{}", author_id))
         .json(&update_body)
         .await;
     response.assert_status_ok();
@@ -233,107 +709,498 @@ async fn test_author_crud() {
     // Delete the author
     let response = server.delete(&format!("/authors/{}", author_id)).await;
     response.assert_status(axum::http::StatusCode::NO_CONTENT);
+
+    // Soft-deleted rows keep their ORCID; remove it so the next run can reuse it
+    common::purge("authors", author_id).await;
 }
 
 #[tokio::test]
 #[serial]
-async fn test_author_search() {
+async fn test_author_put_replaces_patch_merges_null_clears() {
     let server = setup().await;
-    let unique_id = Uuid::new_v4().simple().to_string();
+    let full_name = format!("Replace Author {}", Uuid::new_v4().simple());
 
-    // Create an author to search for
-    let create_body = json!({
-        "full_name": format!("Searchable{} Person", unique_id),
-        "family_name": "Person",
-        "given_name": format!("Searchable{}", unique_id),
-        "creator": "test_user",
-        "modifier": "test_user"
-    });
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": full_name,
+            "family_name": "Author",
+            "affiliation": "CWI",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
 
-    let response = server.post("/authors").json(&create_body).await;
-    if !response.status_code().is_success() {
-        let body = response.text();
-        panic!("Failed to create author: {} - {}", response.status_code(), body);
-    }
-    let created: serde_json::Value = response.json();
-    let author_id = created["id"].as_str().unwrap();
+    // PATCH only touches the fields it names
+    let response = server
+        .patch(&format!("/authors/{}", author_id))
+        .json(&json!({"homepage_url": "https://example.org", "modifier": "test_user"}))
+        .await;
+    response.assert_status_ok();
+    let patched: serde_json::Value = response.json();
+    assert_eq!(patched["affiliation"], "CWI");
+    assert_eq!(patched["family_name"], "Author");
+    assert_eq!(patched["homepage_url"], "https://example.org");
 
-    // Search for the author
-    let response = server.get(&format!("/authors?search=Searchable{}", unique_id)).await;
+    // An explicit null clears the field; omitted fields stay as they were
+    let response = server
+        .patch(&format!("/authors/{}", author_id))
+        .json(&json!({"homepage_url": null, "modifier": "test_user"}))
+        .await;
     response.assert_status_ok();
-    let authors: Vec<serde_json::Value> = response.json();
-    assert!(authors.iter().any(|a| a["full_name"].as_str().unwrap().contains(&unique_id)));
+    let cleared: serde_json::Value = response.json();
+    assert!(cleared["homepage_url"].is_null());
+    assert_eq!(cleared["affiliation"], "CWI");
+
+    // PUT replaces: omitted optional fields are cleared
+    let response = server
+        .put(&format!("/authors/{}", author_id))
+        .json(&json!({"full_name": full_name, "family_name": "Author", "modifier": "test_user"}))
+        .await;
+    response.assert_status_ok();
+    let replaced: serde_json::Value = response.json();
+    assert!(replaced["affiliation"].is_null());
+    assert!(replaced["homepage_url"].is_null());
+    assert_eq!(replaced["family_name"], "Author");
+
+    // PUT without the required full_name is rejected
+    server
+        .put(&format!("/authors/{}", author_id))
+        .json(&json!({"modifier": "test_user"}))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
 
-    // Cleanup
     server.delete(&format!("/authors/{}", author_id)).await;
+    common::purge("authors", &author_id).await;
 }
 
 #[tokio::test]
-async fn test_author_pagination() {
+#[serial]
+async fn test_author_soft_delete_and_restore() {
     let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+    let full_name = format!("Soft Delete Author {}", unique_suffix);
 
-    let response = server.get("/authors?limit=5&offset=0").await;
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": full_name,
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author: serde_json::Value = response.json();
+    let author_id = author["id"].as_str().unwrap().to_string();
+    assert!(author["deleted_at"].is_null());
+
+    let response = server
+        .delete(&format!("/authors/{}?modifier=curator", author_id))
+        .await;
+    response.assert_status(axum::http::StatusCode::NO_CONTENT);
+
+    // Hidden by default
+    server.get(&format!("/authors/{}", author_id)).await.assert_status_not_found();
+    let response = server.get(&format!("/authors?search={}", unique_suffix)).await;
+    assert!(response.json::<Vec<serde_json::Value>>().is_empty());
+
+    // Visible on request, with the deletion recorded
+    let response = server
+        .get(&format!("/authors/{}?include_deleted=true", author_id))
+        .await;
     response.assert_status_ok();
-    let authors: Vec<serde_json::Value> = response.json();
-    assert!(authors.len() <= 5);
-}
+    let deleted: serde_json::Value = response.json();
+    assert!(deleted["deleted_at"].is_string());
+    let response = server
+        .get(&format!("/authors?search={}&include_deleted=true", unique_suffix))
+        .await;
+    assert_eq!(response.json::<Vec<serde_json::Value>>().len(), 1);
 
-#[tokio::test]
-async fn test_author_orcid_validation() {
-    let server = setup().await;
+    // Deleted rows can't be updated or deleted again
+    server
+        .put(&format!("/authors/{}", author_id))
+        .json(&json!({"affiliation": "Nowhere", "modifier": "test_user"}))
+        .await
+        .assert_status_not_found();
+    server
+        .delete(&format!("/authors/{}", author_id))
+        .await
+        .assert_status_not_found();
+
+    // Restore
+    let response = server
+        .post(&format!("/authors/{}/restore?modifier=curator", author_id))
+        .await;
+    response.assert_status_ok();
+    let restored: serde_json::Value = response.json();
+    assert!(restored["deleted_at"].is_null());
+    server.get(&format!("/authors/{}", author_id)).await.assert_status_ok();
 
-    // Try to create with invalid ORCID format
-    let create_body = json!({
-        "full_name": "Invalid ORCID Author",
-        "orcid": "invalid-orcid",
-        "creator": "test_user",
-        "modifier": "test_user"
-    });
+    // Restoring a live author is a 404
+    server
+        .post(&format!("/authors/{}/restore", author_id))
+        .await
+        .assert_status_not_found();
 
-    let response = server.post("/authors").json(&create_body).await;
-    // Should fail due to ORCID check constraint
-    response.assert_status(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+    common::purge("authors", &author_id).await;
 }
 
-// ============================================================================
-// Publication API Tests
-// ============================================================================
-
 #[tokio::test]
-async fn test_list_publications() {
+#[serial]
+async fn test_author_history() {
     let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
 
-    let response = server.get("/publications").await;
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("History Author {}", unique_suffix),
+            "affiliation": "CWI",
+            "creator": "importer",
+            "modifier": "importer"
+        }))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    server
+        .patch(&format!("/authors/{}", author_id))
+        .json(&json!({"affiliation": "QuSoft", "modifier": "curator"}))
+        .await
+        .assert_status_ok();
+    server
+        .delete(&format!("/authors/{}?modifier=curator", author_id))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+
+    // History survives soft delete
+    let response = server.get(&format!("/authors/{}/history", author_id)).await;
     response.assert_status_ok();
-
-    let publications: Vec<serde_json::Value> = response.json();
-    // May be empty, that's ok
-    assert!(publications.is_empty() || !publications.is_empty());
+    let history: Vec<serde_json::Value> = response.json();
+    let actions: Vec<&str> = history.iter().map(|c| c["action"].as_str().unwrap()).collect();
+    assert_eq!(actions, ["create", "update", "delete"]);
+
+    assert_eq!(history[0]["modifier"], "importer");
+    assert_eq!(history[0]["diff"]["affiliation"]["new"], "CWI");
+
+    // Only the changed field (not updated_at) is in the update diff
+    assert_eq!(history[1]["modifier"], "curator");
+    assert_eq!(
+        history[1]["diff"],
+        json!({"affiliation": {"old": "CWI", "new": "QuSoft"}})
+    );
+    assert!(history[2]["diff"]["deleted_at"]["new"].is_string());
+
+    server
+        .get(&format!("/authors/{}/history", Uuid::new_v4()))
+        .await
+        .assert_status_not_found();
+
+    common::purge("authors", &author_id).await;
 }
 
 #[tokio::test]
 #[serial]
-async fn test_publication_crud() {
+async fn test_author_update_conflict() {
     let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
 
-    // First, get a conference ID to use
-    let response = server.get("/conferences").await;
-    let conferences: Vec<serde_json::Value> = response.json();
-    let conference_id = conferences[0]["id"].as_str().unwrap();
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Concurrent Author {}", unique_suffix),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author: serde_json::Value = response.json();
+    let author_id = author["id"].as_str().unwrap().to_string();
+    let seen = author["updated_at"].clone();
 
-    // Create a new publication
-    let create_body = json!({
-        "conference_id": conference_id,
-        "canonical_key": format!("test-pub-{}", Uuid::new_v4()),
-        "title": "Test Publication Title",
-        "abstract": "This is a test abstract for the publication.",
-        "paper_type": "regular",
-        "arxiv_ids": ["2301.12345"],
-        "creator": "test_user",
-        "modifier": "test_user"
-    });
+    // First editor, with the version they read: succeeds
+    let response = server
+        .patch(&format!("/authors/{}", author_id))
+        .json(&json!({"affiliation": "CWI", "modifier": "alice", "updated_at": seen}))
+        .await;
+    response.assert_status_ok();
+    let updated: serde_json::Value = response.json();
+    assert_ne!(updated["updated_at"], seen);
 
-    let response = server.post("/publications").json(&create_body).await;
+    // Second editor, with the same stale version: rejected, nothing written
+    let response = server
+        .patch(&format!("/authors/{}", author_id))
+        .json(&json!({"affiliation": "QuSoft", "modifier": "bob", "updated_at": seen}))
+        .await;
+    response.assert_status(axum::http::StatusCode::CONFLICT);
+    let response = server.get(&format!("/authors/{}", author_id)).await;
+    assert_eq!(response.json::<serde_json::Value>()["affiliation"], "CWI");
+
+    // Without updated_at the update is unconditional
+    server
+        .patch(&format!("/authors/{}", author_id))
+        .json(&json!({"affiliation": "QuSoft", "modifier": "bob"}))
+        .await
+        .assert_status_ok();
+
+    server.delete(&format!("/authors/{}", author_id)).await;
+    common::purge("authors", &author_id).await;
+}
+
+#[tokio::test]
+async fn test_create_author_duplicate_conflict() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+    let full_name = format!("Duplicate Author {}", unique_suffix);
+
+    let response = server
+        .post("/authors")
+        .json(&json!({"full_name": full_name, "orcid": "0000-0002-7182-8182", "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    // Same normalized name, different spelling
+    let response = server
+        .post("/authors")
+        .json(&json!({"full_name": full_name.to_uppercase(), "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    response.assert_status(axum::http::StatusCode::CONFLICT);
+    let body = response.json::<serde_json::Value>();
+    assert_eq!(body["id"], author_id.as_str());
+    assert!(body["message"].as_str().unwrap().contains("named like"));
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Other Author {}", unique_suffix),
+            "orcid": "0000-0002-7182-8182",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CONFLICT);
+    let body = response.json::<serde_json::Value>();
+    assert_eq!(body["id"], author_id.as_str());
+    assert!(body["message"].as_str().unwrap().contains("ORCID"));
+
+    // A soft-deleted author doesn't hold on to the name
+    server
+        .delete(&format!("/authors/{}", author_id))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+    let response = server
+        .post("/authors")
+        .json(&json!({"full_name": full_name, "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let new_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    assert_ne!(new_id, author_id);
+
+    for id in [&author_id, &new_id] {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+async fn test_author_rename_and_restore_conflict() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+    let taken = format!("Taken Author {}", unique_suffix);
+
+    let mut ids = Vec::new();
+    for full_name in [taken.clone(), format!("Renamed Author {}", unique_suffix)] {
+        let response = server
+            .post("/authors")
+            .json(&json!({"full_name": full_name, "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        response.assert_status(axum::http::StatusCode::CREATED);
+        ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    let (taken_id, other_id) = (ids[0].clone(), ids[1].clone());
+
+    // Renaming onto another live author's normalized name, by PATCH or PUT
+    let response = server
+        .patch(&format!("/authors/{}", other_id))
+        .json(&json!({"full_name": taken.to_lowercase(), "modifier": "test_user"}))
+        .await;
+    response.assert_status(axum::http::StatusCode::CONFLICT);
+    assert_eq!(response.json::<serde_json::Value>()["id"], taken_id.as_str());
+
+    let response = server
+        .put(&format!("/authors/{}", other_id))
+        .json(&json!({"full_name": taken, "modifier": "test_user"}))
+        .await;
+    response.assert_status(axum::http::StatusCode::CONFLICT);
+    assert_eq!(response.json::<serde_json::Value>()["id"], taken_id.as_str());
+
+    // Restoring an author whose name was taken while it was deleted
+    server
+        .delete(&format!("/authors/{}", taken_id))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+    server
+        .patch(&format!("/authors/{}", other_id))
+        .json(&json!({"full_name": taken, "modifier": "test_user"}))
+        .await
+        .assert_status_ok();
+    let response = server.post(&format!("/authors/{}/restore", taken_id)).await;
+    response.assert_status(axum::http::StatusCode::CONFLICT);
+    let body = response.json::<serde_json::Value>();
+    assert_eq!(body["id"], other_id.as_str());
+    assert!(body["message"].as_str().unwrap().contains("named like"));
+
+    for id in [&taken_id, &other_id] {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_author_search() {
+    let server = setup().await;
+    let unique_id = Uuid::new_v4().simple().to_string();
+
+    // Create an author to search for
+    let create_body = json!({
+        "full_name": format!("Searchable{} Person", unique_id),
+        "family_name": "Person",
+        "given_name": format!("Searchable{}", unique_id),
+        "creator": "test_user",
+        "modifier": "test_user"
+    });
+
+    let response = server.post("/authors").json(&create_body).await;
+    if !response.status_code().is_success() {
+        let body = response.text();
+        panic!("Failed to create author: {} - {}", response.status_code(), body);
+    }
+    let created: serde_json::Value = response.json();
+    let author_id = created["id"].as_str().unwrap();
+
+    // Search for the author
+    let response = server.get(&format!("/authors?search=Searchable{}", unique_id)).await;
+    response.assert_status_ok();
+    let authors: Vec<serde_json::Value> = response.json();
+    assert!(authors.iter().any(|a| a["full_name"].as_str().unwrap().contains(&unique_id)));
+
+    // Matched against the normalized name: case and accents don't matter
+    let response = server
+        .get("/authors")
+        .add_query_param("search", format!("{} PÉRSON", unique_id))
+        .await;
+    let authors: Vec<serde_json::Value> = response.json();
+    assert!(authors.iter().any(|a| a["id"] == author_id));
+
+    // Cleanup
+    server.delete(&format!("/authors/{}", author_id)).await;
+}
+
+#[tokio::test]
+async fn test_author_pagination() {
+    let server = setup().await;
+
+    let response = server.get("/authors?limit=5&offset=0").await;
+    response.assert_status_ok();
+    let authors: Vec<serde_json::Value> = response.json();
+    assert!(authors.len() <= 5);
+}
+
+#[tokio::test]
+async fn test_list_page_size_header() {
+    let server = setup().await;
+    let max = quantumdb::utils::max_page_size();
+
+    let response = server.get("/authors?limit=5").await;
+    response.assert_status_ok();
+    assert_eq!(response.header("x-page-size"), "5");
+
+    // Oversized limits are clamped to MAX_PAGE_SIZE, and the header says so
+    for path in ["/authors", "/publications", "/committees"] {
+        let response = server.get(&format!("{}?limit=1000000", path)).await;
+        response.assert_status_ok();
+        assert_eq!(response.header("x-page-size"), max.to_string().as_str(), "{}", path);
+        assert!(response.json::<Vec<serde_json::Value>>().len() as i64 <= max);
+    }
+
+    let response = server.get("/publications").await;
+    assert_eq!(response.header("x-page-size"), quantumdb::utils::DEFAULT_LIMIT.min(max).to_string().as_str());
+}
+
+#[tokio::test]
+async fn test_list_negative_pagination() {
+    let server = setup().await;
+
+    for path in ["/authors", "/publications", "/committees", "/authors/top"] {
+        let response = server.get(&format!("{}?limit=-5", path)).await;
+        response.assert_status_bad_request();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["message"], "limit must not be negative (got -5)", "{}", path);
+    }
+    for path in ["/authors", "/publications", "/committees"] {
+        let response = server.get(&format!("{}?offset=-1", path)).await;
+        response.assert_status_bad_request();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["message"], "offset must not be negative (got -1)", "{}", path);
+    }
+
+    server.get("/authors?limit=0&offset=0").await.assert_status_ok();
+}
+
+#[tokio::test]
+async fn test_author_orcid_validation() {
+    let server = setup().await;
+
+    // Try to create with invalid ORCID format
+    let create_body = json!({
+        "full_name": "Invalid ORCID Author",
+        "orcid": "invalid-orcid",
+        "creator": "test_user",
+        "modifier": "test_user"
+    });
+
+    let response = server.post("/authors").json(&create_body).await;
+    // Should fail due to ORCID check constraint
+    response.assert_status(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+// ============================================================================
+// Publication API Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_list_publications() {
+    let server = setup().await;
+
+    let response = server.get("/publications").await;
+    response.assert_status_ok();
+
+    let publications: Vec<serde_json::Value> = response.json();
+    // May be empty, that's ok
+    assert!(publications.is_empty() || !publications.is_empty());
+}
+
+#[tokio::test]
+#[serial]
+async fn test_publication_crud() {
+    let server = setup().await;
+
+    // First, get a conference ID to use
+    let response = server.get("/conferences").await;
+    let conferences: Vec<serde_json::Value> = response.json();
+    let conference_id = conferences[0]["id"].as_str().unwrap();
+
+    // Create a new publication
+    let create_body = json!({
+        "conference_id": conference_id,
+        "canonical_key": format!("test-pub-{}", Uuid::new_v4()),
+        "title": "Test Publication Title",
+        "abstract": "This is a test abstract for the publication.",
+        "paper_type": "regular",
+        "arxiv_ids": ["2301.12345"],
+        "creator": "test_user",
+        "modifier": "test_user"
+    });
+
+    let response = server.post("/publications").json(&create_body).await;
     if !response.status_code().is_success() {
         let body = response.text();
         panic!("Failed to create publication: {} - {}", response.status_code(), body);
@@ -347,6 +1214,16 @@ async fn test_publication_crud() {
     let response = server.get(&format!("/publications/{}", pub_id)).await;
     response.assert_status_ok();
 
+    // Clear the abstract with an explicit null
+    let response = server
+        .patch(&format!("/publications/{}", pub_id))
+        .json(&json!({"abstract": null, "modifier": "test_user"}))
+        .await;
+    response.assert_status_ok();
+    let patched: serde_json::Value = response.json();
+    assert!(patched["abstract"].is_null());
+    assert_eq!(patched["arxiv_ids"], json!(["2301.12345"]));
+
     // Update the publication
     let update_body = json!({
         "title": "Updated Publication Title",
@@ -364,6 +1241,21 @@ async fn test_publication_crud() {
     // Delete the publication
     let response = server.delete(&format!("/publications/{}", pub_id)).await;
     response.assert_status(axum::http::StatusCode::NO_CONTENT);
+
+    // Soft-deleted: hidden by default, retrievable and restorable
+    let response = server.get(&format!("/publications/{}", pub_id)).await;
+    response.assert_status_not_found();
+    let response = server
+        .get(&format!("/publications/{}?include_deleted=true", pub_id))
+        .await;
+    response.assert_status_ok();
+    assert!(response.json::<serde_json::Value>()["deleted_at"].is_string());
+
+    let response = server.post(&format!("/publications/{}/restore", pub_id)).await;
+    response.assert_status_ok();
+    server.get(&format!("/publications/{}", pub_id)).await.assert_status_ok();
+
+    common::purge("publications", pub_id).await;
 }
 
 #[tokio::test]
@@ -407,6 +1299,422 @@ async fn test_publication_full_text_search() {
     server.delete(&format!("/publications/{}", pub_id)).await;
 }
 
+#[tokio::test]
+async fn test_publication_search_lang() {
+    let server = setup().await;
+    let unique_term = format!("zenoeffect{}", Uuid::new_v4().simple());
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    server
+        .post("/publications")
+        .json(&json!({
+            "conference_id": conference_id,
+            "title": format!("Universality of {}", unique_term),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::CREATED);
+
+    let hits = |query: String| {
+        let server = &server;
+        async move {
+            let response = server.get(&format!("/publications?{}", query)).await;
+            response.assert_status_ok();
+            response.json::<Vec<serde_json::Value>>().len()
+        }
+    };
+
+    // English stems "universe" and "universality" alike; simple keeps them apart
+    assert_eq!(hits(format!("search=universe+{}", unique_term)).await, 1);
+    assert_eq!(hits(format!("search=universe+{}&lang=english", unique_term)).await, 1);
+    assert_eq!(hits(format!("search=universe+{}&lang=simple", unique_term)).await, 0);
+    assert_eq!(hits(format!("search=universality+{}&lang=SIMPLE", unique_term)).await, 1);
+
+    let response = server.get("/publications?search=qubit&lang=klingon").await;
+    response.assert_status_bad_request();
+    assert!(response.json::<serde_json::Value>()["message"].as_str().unwrap().contains("simple"));
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+}
+
+#[tokio::test]
+async fn test_publication_abstract_word_count() {
+    let server = setup().await;
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    let mut ids = Vec::new();
+    for (title, abstract_text) in [
+        ("Short Abstract", Some("  Qubits\n decohere. ")),
+        ("No Abstract", None),
+        ("Long Abstract", Some("We show that local Hamiltonians are hard.")),
+    ] {
+        let response = server
+            .post("/publications")
+            .json(&json!({
+                "conference_id": conference_id,
+                "title": title,
+                "abstract": abstract_text,
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        response.assert_status(axum::http::StatusCode::CREATED);
+        ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+
+    let publication: serde_json::Value = server.get(&format!("/publications/{}", ids[0])).await.json();
+    assert_eq!(publication["abstract_word_count"], 2);
+    let publication: serde_json::Value = server.get(&format!("/publications/{}", ids[1])).await.json();
+    assert!(publication["abstract_word_count"].is_null());
+
+    let counts = |sort: &'static str| {
+        let server = &server;
+        let conference_id = &conference_id;
+        async move {
+            let response = server
+                .get(&format!("/publications?conference_id={}&sort={}", conference_id, sort))
+                .await;
+            response.assert_status_ok();
+            response
+                .json::<Vec<serde_json::Value>>()
+                .iter()
+                .map(|p| p["abstract_word_count"].as_i64())
+                .collect::<Vec<_>>()
+        }
+    };
+    // Publications without an abstract sort last either way
+    assert_eq!(counts("abstract_word_count").await, vec![Some(2), Some(7), None]);
+    assert_eq!(counts("-abstract_word_count").await, vec![Some(7), Some(2), None]);
+
+    server
+        .get("/publications?sort=title")
+        .await
+        .assert_status_bad_request();
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+}
+
+#[tokio::test]
+async fn test_related_publications() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = json!({"venue": "QCRYPT", "creator": "test_user", "modifier": "test_user"});
+    let conference_ids: Vec<String> = create_test_conferences(&server, &[(0, conference.clone()), (1, conference)])
+        .await
+        .iter()
+        .map(|c| c["id"].as_str().unwrap().to_string())
+        .collect();
+    let mut author_ids = Vec::new();
+    for name in ["Related A", "Related B", "Unrelated C"] {
+        let response = server
+            .post("/authors")
+            .json(&json!({"full_name": format!("{} {}", name, unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+
+    // (title, conference, authors); the second conference is the newer one
+    let papers = [
+        ("Original", 0, vec![0, 1]),
+        ("Same Team Later", 1, vec![1, 0]),
+        ("A Alone Later", 1, vec![0]),
+        ("C Alone", 0, vec![2]),
+        ("B Alone Same Conference", 0, vec![1]),
+    ];
+    let mut publication_ids = Vec::new();
+    for (title, conference, authors) in &papers {
+        let response = server
+            .post("/publications")
+            .json(&json!({"conference_id": conference_ids[*conference], "title": title, "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        for (i, author) in authors.iter().enumerate() {
+            server
+                .post("/authorships")
+                .json(&json!({
+                    "publication_id": publication_id,
+                    "author_id": author_ids[*author],
+                    "author_position": i + 1,
+                    "published_as_name": "Someone",
+                    "creator": "test_user",
+                    "modifier": "test_user"
+                }))
+                .await
+                .assert_status(axum::http::StatusCode::CREATED);
+        }
+        publication_ids.push(publication_id);
+    }
+
+    let related = |query: &'static str| {
+        let server = &server;
+        let id = publication_ids[0].clone();
+        async move {
+            let response = server.get(&format!("/publications/{}/related{}", id, query)).await;
+            response.assert_status_ok();
+            response.json::<Vec<serde_json::Value>>()
+        }
+    };
+
+    let results = related("?other_conferences=false").await;
+    let titles: Vec<&str> = results.iter().map(|p| p["title"].as_str().unwrap()).collect();
+    assert_eq!(titles, vec!["Same Team Later", "A Alone Later", "B Alone Same Conference"]);
+    assert_eq!(results[0]["shared_author_count"], 2);
+    // In the related paper's own author order
+    assert_eq!(results[0]["shared_author_ids"], json!([author_ids[1], author_ids[0]]));
+    assert_eq!(results[1]["shared_author_count"], 1);
+    assert_eq!(results[1]["conference_id"], conference_ids[1].as_str());
+
+    let titles: Vec<String> = related("")
+        .await
+        .iter()
+        .map(|p| p["title"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(titles, vec!["Same Team Later", "A Alone Later"]);
+    assert_eq!(related("?limit=1&offset=1").await[0]["title"], "A Alone Later");
+    assert_eq!(related("?other_conferences=true").await.len(), 2);
+
+    server
+        .delete(&format!("/publications/{}", publication_ids[2]))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+    assert_eq!(related("?other_conferences=false").await.len(), 2);
+
+    server
+        .get(&format!("/publications/{}/related", Uuid::new_v4()))
+        .await
+        .assert_status_not_found();
+
+    for id in &conference_ids {
+        server.delete(&format!("/conferences/{}?cascade=true", id)).await;
+    }
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+async fn test_publication_topics() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+    let topic = format!("error-correction-{}", unique_suffix);
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let mut publication_ids = Vec::new();
+    for title in ["Tagged Paper", "Merged Duplicate", "Untagged Paper"] {
+        let response = server
+            .post("/publications")
+            .json(&json!({"conference_id": conference_id, "title": title, "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        publication_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+
+    // Spellings of the same topic collapse to one slug
+    let response = server
+        .post(&format!("/publications/{}/topics", publication_ids[0]))
+        .json(&json!({"topics": [format!("Error Correction {}", unique_suffix), topic.replace('-', "_")], "creator": "test_user"}))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<serde_json::Value>(), json!([topic]));
+
+    server
+        .post(&format!("/publications/{}/topics", publication_ids[0]))
+        .json(&json!({"topics": ["Bad/Topic"], "creator": "test_user"}))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    server
+        .post(&format!("/publications/{}/topics", Uuid::new_v4()))
+        .json(&json!({"topics": [topic], "creator": "test_user"}))
+        .await
+        .assert_status_not_found();
+
+    let tagged = |query: String| {
+        let server = &server;
+        async move {
+            let response = server.get(&format!("/publications?topic={}", query)).await;
+            response.assert_status_ok();
+            response
+                .json::<Vec<serde_json::Value>>()
+                .iter()
+                .map(|p| p["title"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        }
+    };
+    assert_eq!(tagged(topic.clone()).await, vec!["Tagged Paper"]);
+    assert_eq!(
+        tagged(format!("{}&conference_id={}", topic.to_uppercase(), conference_id)).await,
+        vec!["Tagged Paper"]
+    );
+    server.get("/publications?topic=bad/topic").await.assert_status_bad_request();
+
+    let topics = server.get("/topics?limit=1000").await.json::<Vec<serde_json::Value>>();
+    let entry = topics.iter().find(|t| t["topic"] == topic.as_str()).expect("topic listed");
+    assert_eq!(entry["publication_count"], 1);
+
+    // A merge keeps the union of both papers' topics
+    let second_topic = format!("qkd-{}", unique_suffix);
+    server
+        .post(&format!("/publications/{}/topics", publication_ids[1]))
+        .json(&json!({"topics": [topic, second_topic], "creator": "test_user"}))
+        .await
+        .assert_status_ok();
+    server
+        .post(&format!("/publications/{}/merge", publication_ids[0]))
+        .json(&json!({"source_id": publication_ids[1], "modifier": "test_user"}))
+        .await
+        .assert_status_ok();
+    let response = server.get(&format!("/publications/{}/topics", publication_ids[0])).await;
+    assert_eq!(response.json::<serde_json::Value>(), json!([topic, second_topic]));
+    // The merged-away paper no longer counts
+    let topics = server.get("/topics?limit=1000").await.json::<Vec<serde_json::Value>>();
+    let entry = topics.iter().find(|t| t["topic"] == topic.as_str()).unwrap();
+    assert_eq!(entry["publication_count"], 1);
+
+    server
+        .delete(&format!("/publications/{}/topics/{}", publication_ids[0], second_topic.to_uppercase()))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+    server
+        .delete(&format!("/publications/{}/topics/{}", publication_ids[2], topic))
+        .await
+        .assert_status_not_found();
+    let response = server.get(&format!("/publications/{}/topics", publication_ids[0])).await;
+    assert_eq!(response.json::<serde_json::Value>(), json!([topic]));
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+}
+
+#[tokio::test]
+async fn test_publication_filter_by_year_range() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    // One paper per conference, across venues in consecutive years
+    let venues = ["QIP", "QCRYPT", "TQC"];
+    let conferences: Vec<(i32, serde_json::Value)> = venues
+        .iter()
+        .zip(0..)
+        .map(|(venue, offset)| (offset, json!({"venue": venue, "creator": "test_user", "modifier": "test_user"})))
+        .collect();
+    let mut years = Vec::new();
+    let mut conference_ids = Vec::new();
+    for (venue, conference) in venues.iter().zip(create_test_conferences(&server, &conferences).await) {
+        let year = conference["year"].as_i64().unwrap();
+        let conference_id = conference["id"].as_str().unwrap().to_string();
+        server
+            .post("/publications")
+            .json(&json!({"conference_id": conference_id, "title": format!("{} Paper {}", venue, unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+        years.push(year);
+        conference_ids.push(conference_id);
+    }
+
+    let titles = |query: String| {
+        let server = &server;
+        let search = unique_suffix.clone();
+        async move {
+            let response = server.get(&format!("/publications?search={}{}", search, query)).await;
+            response.assert_status_ok();
+            let mut titles: Vec<String> = response
+                .json::<Vec<serde_json::Value>>()
+                .iter()
+                .map(|p| p["title"].as_str().unwrap().split(' ').next().unwrap().to_string())
+                .collect();
+            titles.sort();
+            titles
+        }
+    };
+    assert_eq!(titles(format!("&year_from={}&year_to={}", years[0], years[1])).await, vec!["QCRYPT", "QIP"]);
+    assert_eq!(titles(format!("&year_from={}", years[1])).await, vec!["QCRYPT", "TQC"]);
+    assert_eq!(titles(format!("&year_to={}", years[0])).await, vec!["QIP"]);
+    assert_eq!(titles(format!("&year_from={0}&year_to={0}", years[2])).await, vec!["TQC"]);
+
+    // Also applies without search
+    let response = server
+        .get(&format!("/publications?year_from={0}&year_to={0}&limit=500", years[2]))
+        .await;
+    response.assert_status_ok();
+    assert!(response
+        .json::<Vec<serde_json::Value>>()
+        .iter()
+        .all(|p| p["conference_id"] == conference_ids[2].as_str()));
+
+    server
+        .get(&format!("/publications?year_from={}&year_to={}", years[1], years[0]))
+        .await
+        .assert_status_bad_request();
+
+    for id in &conference_ids {
+        server.delete(&format!("/conferences/{}?cascade=true", id)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_publication_filter_by_venue() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let venues = ["QIP", "QIP", "TQC"];
+    let conferences: Vec<(i32, serde_json::Value)> = venues
+        .iter()
+        .zip(0..)
+        .map(|(venue, offset)| (offset, json!({"venue": venue, "creator": "test_user", "modifier": "test_user"})))
+        .collect();
+    let mut years = Vec::new();
+    let mut conference_ids = Vec::new();
+    for (venue, conference) in venues.iter().zip(create_test_conferences(&server, &conferences).await) {
+        let year = conference["year"].as_i64().unwrap();
+        let conference_id = conference["id"].as_str().unwrap().to_string();
+        server
+            .post("/publications")
+            .json(&json!({"conference_id": conference_id, "title": format!("{} {} Paper {}", venue, year, unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+        years.push(year);
+        conference_ids.push(conference_id);
+    }
+
+    let count = |query: String| {
+        let server = &server;
+        let search = unique_suffix.clone();
+        async move {
+            let response = server.get(&format!("/publications?search={}{}", search, query)).await;
+            response.assert_status_ok();
+            response.json::<Vec<serde_json::Value>>().len()
+        }
+    };
+    assert_eq!(count("&venue=qip".to_string()).await, 2);
+    assert_eq!(count("&venue=TQC".to_string()).await, 1);
+    assert_eq!(count(format!("&venue=QIP&year_from={}", years[1])).await, 1);
+    assert_eq!(count("&venue=QCRYPT".to_string()).await, 0);
+
+    let response = server.get("/publications?venue=AQIS").await;
+    response.assert_status_bad_request();
+    assert!(response.json::<serde_json::Value>()["message"].as_str().unwrap().contains("QIP"));
+
+    for id in &conference_ids {
+        server.delete(&format!("/conferences/{}?cascade=true", id)).await;
+    }
+}
+
 #[tokio::test]
 async fn test_publication_filter_by_conference() {
     let server = setup().await;
@@ -495,7 +1803,7 @@ async fn test_committee_role_crud() {
     });
 
     let response = server
-        .put(&format!("/committees/{}", role_id))
+        .patch(&format!("/committees/{}", role_id))
         .json(&update_body)
         .await;
     response.assert_status_ok();
@@ -551,27 +1859,427 @@ async fn test_committee_filter_by_author() {
     server.delete(&format!("/authors/{}", author_id)).await;
 }
 
-// ============================================================================
-// Edge Cases and Error Handling
-// ============================================================================
-
 #[tokio::test]
-async fn test_get_nonexistent_author() {
+#[serial]
+async fn test_committee_filter_by_type_and_position() {
     let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
 
-    let fake_id = Uuid::new_v4();
-    let response = server.get(&format!("/authors/{}", fake_id)).await;
-    response.assert_status_not_found();
-}
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    // PC chair, PC member, SC chair
+    let mut author_ids = Vec::new();
+    let mut role_ids = Vec::new();
+    for (committee, position) in [("PC", "chair"), ("PC", "member"), ("SC", "chair")] {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("{} {} {}", committee, position, unique_suffix),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let author: serde_json::Value = response.json();
+        let author_id = author["id"].as_str().unwrap().to_string();
+
+        let response = server
+            .post("/committees")
+            .json(&json!({
+                "conference_id": conference_id,
+                "author_id": author_id,
+                "committee": committee,
+                "position": position,
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let role: serde_json::Value = response.json();
+        role_ids.push(role["id"].as_str().unwrap().to_string());
+        author_ids.push(author_id);
+    }
 
-#[tokio::test]
-async fn test_get_nonexistent_publication() {
-    let server = setup().await;
+    // Combined filter
+    let response = server
+        .get(&format!(
+            "/committees?conference_id={}&committee_type=PC&position=chair",
+            conference_id
+        ))
+        .await;
+    response.assert_status_ok();
+    let roles: Vec<serde_json::Value> = response.json();
+    assert_eq!(roles.len(), 1);
+    assert_eq!(roles[0]["author_id"], author_ids[0].as_str());
 
-    let fake_id = Uuid::new_v4();
-    let response = server.get(&format!("/publications/{}", fake_id)).await;
-    response.assert_status_not_found();
-}
+    // Each filter on its own
+    let response = server
+        .get(&format!("/committees?conference_id={}&committee_type=pc", conference_id))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<Vec<serde_json::Value>>().len(), 2);
+
+    let response = server
+        .get(&format!("/committees?conference_id={}&position=chair", conference_id))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<Vec<serde_json::Value>>().len(), 2);
+
+    // Author + type without a conference
+    let response = server
+        .get(&format!("/committees?author_id={}&committee_type=SC", author_ids[2]))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<Vec<serde_json::Value>>().len(), 1);
+
+    // Invalid values are rejected
+    let response = server.get("/committees?committee_type=XYZ").await;
+    response.assert_status_bad_request();
+    let response = server.get("/committees?position=president").await;
+    response.assert_status_bad_request();
+
+    // Cleanup
+    for id in role_ids {
+        server.delete(&format!("/committees/{}", id)).await;
+    }
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+    for id in author_ids {
+        server.delete(&format!("/authors/{}", id)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_committee_include_author() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    let mut author_ids = Vec::new();
+    for (name, committee) in [("Chair Person", "PC"), ("Local Helper", "Local")] {
+        let response = server
+            .post("/authors")
+            .json(&json!({"full_name": format!("{} {}", name, unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        server
+            .post("/committees")
+            .json(&json!({
+                "conference_id": conference_id,
+                "author_id": author_id,
+                "committee": committee,
+                "position": "member",
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+        author_ids.push(author_id);
+    }
+
+    // Opt-in: the plain listing stays as it was
+    let response = server.get(&format!("/committees?conference_id={}", conference_id)).await;
+    let roles: Vec<serde_json::Value> = response.json();
+    assert!(roles.iter().all(|r| r.get("author").is_none()));
+
+    let response = server
+        .get(&format!("/committees?conference_id={}&include=author", conference_id))
+        .await;
+    response.assert_status_ok();
+    let roles: Vec<serde_json::Value> = response.json();
+    assert_eq!(roles.len(), 2);
+    for role in &roles {
+        let author_id = role["author_id"].as_str().unwrap();
+        assert_eq!(role["author"]["id"], author_id);
+        let expected = if author_id == author_ids[0] { "Chair Person" } else { "Local Helper" };
+        assert_eq!(role["author"]["full_name"], format!("{} {}", expected, unique_suffix));
+        assert!(role["committee"].is_string(), "role fields stay flattened: {}", role);
+    }
+
+    let role_id = roles[0]["id"].as_str().unwrap();
+    let response = server.get(&format!("/committees/{}?include=author", role_id)).await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<serde_json::Value>()["author"]["id"], roles[0]["author_id"]);
+
+    let response = server.get(&format!("/committees?conference_id={}&include=stats", conference_id)).await;
+    response.assert_status_bad_request();
+    server.get(&format!("/committees/{}?include=authors", role_id)).await.assert_status_bad_request();
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_conference_chairs() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    let mut author_ids = Vec::new();
+    let mut role_ids = Vec::new();
+    for (committee, position) in [("PC", "co_chair"), ("PC", "chair"), ("PC", "member"), ("OC", "chair")] {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("{} {} {}", committee, position, unique_suffix),
+                "affiliation": "Author Affiliation",
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let author: serde_json::Value = response.json();
+        let author_id = author["id"].as_str().unwrap().to_string();
+
+        let response = server
+            .post("/committees")
+            .json(&json!({
+                "conference_id": conference_id,
+                "author_id": author_id,
+                "committee": committee,
+                "position": position,
+                "role_title": format!("{} {}", committee, position),
+                "affiliation": if position == "chair" { Some("Role Affiliation") } else { None },
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let role: serde_json::Value = response.json();
+        role_ids.push(role["id"].as_str().unwrap().to_string());
+        author_ids.push(author_id);
+    }
+
+    let response = server
+        .get(&format!("/conferences/{}/chairs", conference_id))
+        .await;
+    response.assert_status_ok();
+    let groups: Vec<serde_json::Value> = response.json();
+    assert_eq!(groups.len(), 2, "Members are excluded; OC and PC remain");
+    assert_eq!(groups[0]["committee"], "OC");
+    assert_eq!(groups[1]["committee"], "PC");
+
+    let pc_chairs = groups[1]["chairs"].as_array().unwrap();
+    assert_eq!(pc_chairs.len(), 2);
+    assert_eq!(pc_chairs[0]["position"], "chair");
+    assert_eq!(pc_chairs[0]["affiliation"], "Role Affiliation");
+    assert_eq!(pc_chairs[0]["full_name"], format!("PC chair {}", unique_suffix));
+    assert_eq!(pc_chairs[1]["position"], "co_chair");
+    assert_eq!(pc_chairs[1]["affiliation"], "Author Affiliation");
+    assert_eq!(pc_chairs[1]["role_title"], "PC co_chair");
+
+    let response = server
+        .get(&format!("/conferences/{}/chairs", Uuid::new_v4()))
+        .await;
+    response.assert_status_not_found();
+
+    // Cleanup
+    for id in role_ids {
+        server.delete(&format!("/committees/{}", id)).await;
+    }
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+    for id in author_ids {
+        server.delete(&format!("/authors/{}", id)).await;
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_author_timeline() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Timeline Author {}", unique_suffix),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author: serde_json::Value = response.json();
+    let author_id = author["id"].as_str().unwrap().to_string();
+
+    // Two QIP papers and one TQC paper in the first year, one QIP paper two years later
+    let conferences = create_test_conferences(
+        &server,
+        &[
+            (0, json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"})),
+            (0, json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"})),
+            (2, json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"})),
+        ],
+    )
+    .await;
+    let (first_year, later_year) = (conferences[0]["year"].as_i64().unwrap(), conferences[2]["year"].as_i64().unwrap());
+    let mut conference_ids = Vec::new();
+    let mut publication_ids = Vec::new();
+    for (conference, papers) in conferences.iter().zip([2, 1, 1]) {
+        let (venue, year) = (conference["venue"].as_str().unwrap(), &conference["year"]);
+        let conference_id = conference["id"].as_str().unwrap().to_string();
+
+        for i in 0..papers {
+            let response = server
+                .post("/publications")
+                .json(&json!({
+                    "conference_id": conference_id,
+                    "canonical_key": format!("timeline-{}-{}-{}-{}", venue, year, i, unique_suffix),
+                    "title": "Timeline Test Publication",
+                    "creator": "test_user",
+                    "modifier": "test_user"
+                }))
+                .await;
+            let publication: serde_json::Value = response.json();
+            let publication_id = publication["id"].as_str().unwrap().to_string();
+
+            server
+                .post("/authorships")
+                .json(&json!({
+                    "publication_id": publication_id,
+                    "author_id": author_id,
+                    "author_position": 1,
+                    "published_as_name": "Timeline Author",
+                    "creator": "test_user",
+                    "modifier": "test_user"
+                }))
+                .await
+                .assert_status(axum::http::StatusCode::CREATED);
+            publication_ids.push(publication_id);
+        }
+        conference_ids.push(conference_id);
+    }
+
+    let response = server.get(&format!("/authors/{}/timeline", author_id)).await;
+    response.assert_status_ok();
+    let timeline: serde_json::Value = response.json();
+    assert_eq!(
+        timeline,
+        json!([
+            {"year": first_year, "publication_count": 3, "cumulative_count": 3, "venues": ["QIP", "TQC"]},
+            {"year": later_year, "publication_count": 1, "cumulative_count": 4, "venues": ["QIP"]}
+        ])
+    );
+
+    let response = server.get(&format!("/authors/{}/timeline", Uuid::new_v4())).await;
+    response.assert_status_not_found();
+
+    // Cleanup (authorships cascade with their publications). Publications are
+    // purged rather than soft-deleted so the conferences can be removed.
+    for id in publication_ids {
+        common::purge("publications", &id).await;
+    }
+    for id in conference_ids {
+        server.delete(&format!("/conferences/{}", id)).await;
+    }
+    server.delete(&format!("/authors/{}", author_id)).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_author_committee_timeline() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let author_body = json!({
+        "full_name": format!("Steering Member {}", unique_suffix),
+        "creator": "test_user",
+        "modifier": "test_user"
+    });
+    let response = server.post("/authors").json(&author_body).await;
+    let author: serde_json::Value = response.json();
+    let author_id = author["id"].as_str().unwrap().to_string();
+
+    // Three SC terms: two consecutive years, a gap, then one more
+    let body = json!({"venue": "QCRYPT", "creator": "test_user", "modifier": "test_user"});
+    let conferences = create_test_conferences(&server, &[(0, body.clone()), (1, body.clone()), (3, body)]).await;
+    let years: Vec<i64> = conferences.iter().map(|c| c["year"].as_i64().unwrap()).collect();
+    let mut conference_ids = Vec::new();
+    let mut role_ids = Vec::new();
+    for conference in &conferences {
+        let conference_id = conference["id"].as_str().unwrap().to_string();
+
+        let response = server
+            .post("/committees")
+            .json(&json!({
+                "conference_id": conference_id,
+                "author_id": author_id,
+                "committee": "SC",
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let role: serde_json::Value = response.json();
+        role_ids.push(role["id"].as_str().unwrap().to_string());
+        conference_ids.push(conference_id);
+    }
+
+    let response = server
+        .get(&format!("/authors/{}/committee-timeline", author_id))
+        .await;
+    response.assert_status_ok();
+    let timeline: Vec<serde_json::Value> = response.json();
+    assert_eq!(timeline.len(), 3);
+    let got: Vec<(i64, i64)> = timeline
+        .iter()
+        .map(|t| (t["year"].as_i64().unwrap(), t["consecutive_years"].as_i64().unwrap()))
+        .collect();
+    assert_eq!(
+        got,
+        vec![(years[0], 2), (years[1], 2), (years[2], 1)]
+    );
+    assert!(timeline.iter().all(|t| t["venue"] == "QCRYPT" && t["committee"] == "SC"));
+
+    // Unknown author
+    let response = server
+        .get(&format!("/authors/{}/committee-timeline", Uuid::new_v4()))
+        .await;
+    response.assert_status_not_found();
+
+    // Cleanup
+    for id in role_ids {
+        server.delete(&format!("/committees/{}", id)).await;
+    }
+    for id in conference_ids {
+        server.delete(&format!("/conferences/{}", id)).await;
+    }
+    server.delete(&format!("/authors/{}", author_id)).await;
+}
+
+// ============================================================================
+// Edge Cases and Error Handling
+// ============================================================================
+
+#[tokio::test]
+async fn test_get_nonexistent_author() {
+    let server = setup().await;
+
+    let fake_id = Uuid::new_v4();
+    let response = server.get(&format!("/authors/{}", fake_id)).await;
+    response.assert_status_not_found();
+}
+
+#[tokio::test]
+async fn test_get_nonexistent_publication() {
+    let server = setup().await;
+
+    let fake_id = Uuid::new_v4();
+    let response = server.get(&format!("/publications/{}", fake_id)).await;
+    response.assert_status_not_found();
+}
 
 #[tokio::test]
 async fn test_get_nonexistent_committee_role() {
@@ -625,6 +2333,289 @@ async fn test_get_nonexistent_authorship() {
     response.assert_status_not_found();
 }
 
+#[tokio::test]
+#[serial]
+async fn test_publication_duplicates_and_merge() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let test_year = conference["year"].as_i64().unwrap();
+
+    // keep and source share an arXiv id (modulo version); other is unrelated
+    let mut pub_ids = Vec::new();
+    for (key, doi, arxiv) in [
+        ("keep", Some("10.1234/dup"), vec!["2301.00001"]),
+        ("source", None, vec!["2301.00001v2", "2301.00002"]),
+        ("other", None, vec!["2301.00003"]),
+    ] {
+        let response = server
+            .post("/publications")
+            .json(&json!({
+                "conference_id": conference_id,
+                "canonical_key": format!("qip{}-{}", test_year, key),
+                "title": format!("Duplicate Test {}", key),
+                "doi": doi,
+                "arxiv_ids": arxiv,
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        response.assert_status(axum::http::StatusCode::CREATED);
+        pub_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    let (keep_id, source_id) = (&pub_ids[0], &pub_ids[1]);
+
+    // shared author on both, plus one only on the source
+    let mut author_ids = Vec::new();
+    for n in 1..=2 {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("Merge Author {} {}", n, unique_suffix),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    for (publication_id, author_id, position) in [
+        (keep_id, &author_ids[0], 1),
+        (source_id, &author_ids[1], 1),
+        (source_id, &author_ids[0], 2),
+    ] {
+        server
+            .post("/authorships")
+            .json(&json!({
+                "publication_id": publication_id,
+                "author_id": author_id,
+                "author_position": position,
+                "published_as_name": "Merge Author",
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+    }
+
+    let response = server
+        .get(&format!("/publications/duplicates?conference_id={}", conference_id))
+        .await;
+    response.assert_status_ok();
+    let clusters: Vec<serde_json::Value> = response.json();
+    assert_eq!(clusters.len(), 1);
+    assert_eq!(clusters[0]["shared_keys"], json!(["arxiv:2301.00001"]));
+    let members: Vec<&str> = clusters[0]["publications"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| p["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(members, [keep_id.as_str(), source_id.as_str()]);
+
+    server
+        .get("/publications/duplicates")
+        .await
+        .assert_status_bad_request();
+
+    let merge_url = format!("/publications/{}/merge", keep_id);
+    server
+        .post(&merge_url)
+        .json(&json!({"source_id": keep_id, "modifier": "curator"}))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    server
+        .post(&merge_url)
+        .json(&json!({"source_id": Uuid::new_v4(), "modifier": "curator"}))
+        .await
+        .assert_status_not_found();
+
+    let response = server
+        .post(&merge_url)
+        .json(&json!({"source_id": source_id, "modifier": "curator"}))
+        .await;
+    response.assert_status_ok();
+    let merged: serde_json::Value = response.json();
+    assert_eq!(merged["doi"], "10.1234/dup");
+    // 2301.00001v2 is the same preprint as the kept paper's 2301.00001
+    assert_eq!(merged["arxiv_ids"], json!(["2301.00001", "2301.00002"]));
+
+    let response = server
+        .get(&format!("/authorships?publication_id={}", keep_id))
+        .await;
+    let authorships: Vec<serde_json::Value> = response.json();
+    let positions: Vec<(&str, i64)> = authorships
+        .iter()
+        .map(|a| (a["author_id"].as_str().unwrap(), a["author_position"].as_i64().unwrap()))
+        .collect();
+    assert_eq!(positions, [(author_ids[0].as_str(), 1), (author_ids[1].as_str(), 2)]);
+
+    server
+        .get(&format!("/publications/{}", source_id))
+        .await
+        .assert_status_not_found();
+    let response = server
+        .get(&format!("/publications/duplicates?conference_id={}", conference_id))
+        .await;
+    assert!(response.json::<Vec<serde_json::Value>>().is_empty());
+
+    for publication_id in &pub_ids {
+        common::purge("publications", publication_id).await;
+    }
+    for author_id in &author_ids {
+        common::purge("authors", author_id).await;
+    }
+    server
+        .delete(&format!("/conferences/{}", conference_id))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_reorder_authorships() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let response = server.get("/conferences").await;
+    let conferences: Vec<serde_json::Value> = response.json();
+    let conference_id = conferences[0]["id"].as_str().unwrap();
+
+    let response = server
+        .post("/publications")
+        .json(&json!({
+            "conference_id": conference_id,
+            "canonical_key": format!("reorder-test-{}", unique_suffix),
+            "title": "Test Publication for Reordering",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let mut author_ids = Vec::new();
+    for position in 1..=3 {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("Reorder Author {} {}", position, unique_suffix),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        server
+            .post("/authorships")
+            .json(&json!({
+                "publication_id": publication_id,
+                "author_id": author_id,
+                "author_position": position,
+                "published_as_name": format!("Author {}", position),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+        author_ids.push(author_id);
+    }
+    let order_url = format!("/publications/{}/authorships/order", publication_id);
+
+    // Reverse the order in one request
+    let reversed: Vec<&String> = author_ids.iter().rev().collect();
+    let response = server
+        .put(&order_url)
+        .json(&json!({"author_ids": reversed, "modifier": "curator"}))
+        .await;
+    response.assert_status_ok();
+    let reordered: Vec<serde_json::Value> = response.json();
+    let positions: Vec<(&str, i64)> = reordered
+        .iter()
+        .map(|a| (a["author_id"].as_str().unwrap(), a["author_position"].as_i64().unwrap()))
+        .collect();
+    assert_eq!(
+        positions,
+        [(author_ids[2].as_str(), 1), (author_ids[1].as_str(), 2), (author_ids[0].as_str(), 3)]
+    );
+
+    // Missing, extra or duplicated authors are rejected
+    for bad in [
+        json!([author_ids[0], author_ids[1]]),
+        json!([author_ids[0], author_ids[1], author_ids[2], Uuid::new_v4()]),
+        json!([author_ids[0], author_ids[0], author_ids[1]]),
+    ] {
+        server
+            .put(&order_url)
+            .json(&json!({"author_ids": bad, "modifier": "curator"}))
+            .await
+            .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    server
+        .put(&format!("/publications/{}/authorships/order", Uuid::new_v4()))
+        .json(&json!({"author_ids": [], "modifier": "curator"}))
+        .await
+        .assert_status_not_found();
+
+    common::purge("publications", &publication_id).await;
+    for author_id in &author_ids {
+        common::purge("authors", author_id).await;
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_publication_doi_normalization() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let response = server.get("/conferences").await;
+    let conferences: Vec<serde_json::Value> = response.json();
+    let conference_id = conferences[0]["id"].as_str().unwrap();
+
+    let body = |doi: &str| {
+        json!({
+            "conference_id": conference_id,
+            "canonical_key": format!("doi-test-{}", unique_suffix),
+            "title": "DOI Test Publication",
+            "doi": doi,
+            "creator": "test_user",
+            "modifier": "test_user"
+        })
+    };
+
+    server
+        .post("/publications")
+        .json(&body("arXiv:2301.12345"))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = server.post("/publications").json(&body("https://doi.org/10.4230/LIPIcs.TQC.2024.1")).await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let created: serde_json::Value = response.json();
+    let pub_id = created["id"].as_str().unwrap().to_string();
+    assert_eq!(created["doi"], "10.4230/lipics.tqc.2024.1");
+
+    let response = server
+        .patch(&format!("/publications/{}", pub_id))
+        .json(&json!({"doi": "doi:10.4230/LIPIcs.TQC.2024.2", "modifier": "test_user"}))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<serde_json::Value>()["doi"], "10.4230/lipics.tqc.2024.2");
+
+    server
+        .patch(&format!("/publications/{}", pub_id))
+        .json(&json!({"doi": "not a doi", "modifier": "test_user"}))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::purge("publications", &pub_id).await;
+}
+
 #[tokio::test]
 #[serial]
 async fn test_authorship_crud() {
@@ -701,7 +2692,7 @@ async fn test_authorship_crud() {
     });
 
     let response = server
-        .put(&format!("/authorships/{}", authorship_id))
+        .patch(&format!("/authorships/{}", authorship_id))
         .json(&update_body)
         .await;
     response.assert_status_ok();
@@ -722,6 +2713,124 @@ async fn test_authorship_crud() {
     server.delete(&format!("/authors/{}", author_id)).await;
 }
 
+#[tokio::test]
+async fn test_authorship_contribution_flags() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let mut author_ids = Vec::new();
+    for name in ["Cofirst A", "Cofirst B"] {
+        let response = server
+            .post("/authors")
+            .json(&json!({"full_name": format!("{} {}", name, unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    let mut publication_ids = Vec::new();
+    for title in ["Shared First Authorship", "Ordinary Byline"] {
+        let response = server
+            .post("/publications")
+            .json(&json!({"conference_id": conference_id, "title": title, "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        publication_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+
+    let response = server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": publication_ids[0],
+            "author_id": author_ids[0],
+            "author_position": 1,
+            "published_as_name": "A. Cofirst",
+            "is_corresponding": true,
+            "is_equal_contribution": true,
+            "contribution_note": "  Equal contribution   with B. Cofirst ",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let first = response.json::<serde_json::Value>();
+    assert_eq!(first["is_corresponding"], true);
+    assert_eq!(first["is_equal_contribution"], true);
+    assert_eq!(first["contribution_note"], "Equal contribution with B. Cofirst");
+
+    let response = server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": publication_ids[0],
+            "author_id": author_ids[1],
+            "author_position": 2,
+            "published_as_name": "B. Cofirst",
+            "is_equal_contribution": true,
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let second_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    // Flags default to false
+    let response = server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": publication_ids[1],
+            "author_id": author_ids[0],
+            "author_position": 1,
+            "published_as_name": "A. Cofirst",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let ordinary = response.json::<serde_json::Value>();
+    assert_eq!(ordinary["is_corresponding"], false);
+    assert_eq!(ordinary["is_equal_contribution"], false);
+    assert!(ordinary["contribution_note"].is_null());
+
+    // The author's co-first papers
+    let response = server
+        .get(&format!("/authorships?author_id={}&is_equal_contribution=true", author_ids[0]))
+        .await;
+    let cofirst = response.json::<Vec<serde_json::Value>>();
+    assert_eq!(cofirst.len(), 1);
+    assert_eq!(cofirst[0]["publication_id"], publication_ids[0].as_str());
+    let response = server
+        .get(&format!("/authorships?publication_id={}&is_corresponding=false", publication_ids[0]))
+        .await;
+    let others = response.json::<Vec<serde_json::Value>>();
+    assert_eq!(others.len(), 1);
+    assert_eq!(others[0]["id"], second_id.as_str());
+
+    // PATCH changes only what's given; null clears the note
+    let response = server
+        .patch(&format!("/authorships/{}", first["id"].as_str().unwrap()))
+        .json(&json!({"is_corresponding": false, "contribution_note": null, "modifier": "test_user"}))
+        .await;
+    response.assert_status_ok();
+    let patched = response.json::<serde_json::Value>();
+    assert_eq!(patched["is_corresponding"], false);
+    assert_eq!(patched["is_equal_contribution"], true);
+    assert!(patched["contribution_note"].is_null());
+
+    // PUT resets omitted flags
+    let response = server
+        .put(&format!("/authorships/{}", second_id))
+        .json(&json!({"author_position": 2, "published_as_name": "B. Cofirst", "modifier": "test_user"}))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<serde_json::Value>()["is_equal_contribution"], false);
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+}
+
 #[tokio::test]
 #[serial]
 async fn test_authorship_filter_by_publication() {
@@ -929,7 +3038,7 @@ async fn test_committee_role_with_affiliation_and_metadata() {
     });
 
     let response = server
-        .put(&format!("/committees/{}", role_id))
+        .patch(&format!("/committees/{}", role_id))
         .json(&update_body)
         .await;
     response.assert_status_ok();
@@ -1079,7 +3188,7 @@ async fn test_authorship_with_metadata() {
     });
 
     let response = server
-        .put(&format!("/authorships/{}", authorship_id))
+        .patch(&format!("/authorships/{}", authorship_id))
         .json(&update_body)
         .await;
     response.assert_status_ok();
@@ -1237,7 +3346,7 @@ async fn test_publication_with_presenter() {
         "presenter_author_id": author1_id,
         "modifier": "test_user"
     });
-    let response = server.put(&format!("/publications/{}", publication_id)).json(&update_body).await;
+    let response = server.patch(&format!("/publications/{}", publication_id)).json(&update_body).await;
     response.assert_status_ok();
     let updated: serde_json::Value = response.json();
 
@@ -1337,7 +3446,7 @@ async fn test_proceedings_track_flag() {
     response.assert_status(axum::http::StatusCode::CREATED);
     let publication1: serde_json::Value = response.json();
     let workshop_id = publication1["id"].as_str().unwrap();
-    assert_eq!(publication1["is_proceedings_track"].as_bool().unwrap(), false, "is_proceedings_track should default to false");
+    assert!(!publication1["is_proceedings_track"].as_bool().unwrap(), "is_proceedings_track should default to false");
 
     // Create publication with is_proceedings_track set to true
     let pub_body2 = json!({
@@ -1352,17 +3461,17 @@ async fn test_proceedings_track_flag() {
     response.assert_status(axum::http::StatusCode::CREATED);
     let publication2: serde_json::Value = response.json();
     let proceedings_id = publication2["id"].as_str().unwrap();
-    assert_eq!(publication2["is_proceedings_track"].as_bool().unwrap(), true, "is_proceedings_track should be true");
+    assert!(publication2["is_proceedings_track"].as_bool().unwrap(), "is_proceedings_track should be true");
 
     // Update workshop track publication to proceedings track
     let update_body = json!({
         "is_proceedings_track": true,
         "modifier": "test_user"
     });
-    let response = server.put(&format!("/publications/{}", workshop_id)).json(&update_body).await;
+    let response = server.patch(&format!("/publications/{}", workshop_id)).json(&update_body).await;
     response.assert_status_ok();
     let updated: serde_json::Value = response.json();
-    assert_eq!(updated["is_proceedings_track"].as_bool().unwrap(), true, "is_proceedings_track should be updated to true");
+    assert!(updated["is_proceedings_track"].as_bool().unwrap(), "is_proceedings_track should be updated to true");
 
     // Cleanup
     server.delete(&format!("/publications/{}", workshop_id)).await;
@@ -1433,16 +3542,22 @@ async fn test_presenter_validation_trigger() {
         "presenter_author_id": author2_id,
         "modifier": "test_user"
     });
-    let response = server.put(&format!("/publications/{}", publication_id)).json(&update_body).await;
-    // This should fail because of the trigger
-    response.assert_status(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+    let response = server.patch(&format!("/publications/{}", publication_id)).json(&update_body).await;
+    // Rejected by the handler before the trigger gets a chance to fail
+    response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    let error: serde_json::Value = response.json();
+    assert!(
+        error["message"].as_str().unwrap().contains("not one of the publication's authors"),
+        "error should explain the presenter problem: {}",
+        error
+    );
 
     // Try to set presenter to author1 (an author) - should succeed
     let update_body = json!({
         "presenter_author_id": author1_id,
         "modifier": "test_user"
     });
-    let response = server.put(&format!("/publications/{}", publication_id)).json(&update_body).await;
+    let response = server.patch(&format!("/publications/{}", publication_id)).json(&update_body).await;
     response.assert_status_ok();
     let updated: serde_json::Value = response.json();
     assert_eq!(updated["presenter_author_id"].as_str().unwrap(), author1_id, "presenter_author_id should be set to author1");
@@ -1509,7 +3624,7 @@ async fn test_talk_scheduling() {
         "duration_minutes": 45,
         "modifier": "test_user"
     });
-    let response = server.put(&format!("/publications/{}", publication2_id)).json(&update_body).await;
+    let response = server.patch(&format!("/publications/{}", publication2_id)).json(&update_body).await;
     response.assert_status_ok();
     let updated: serde_json::Value = response.json();
     assert_eq!(updated["talk_date"].as_str().unwrap(), "2024-03-16", "talk_date should be updated");
@@ -1545,3 +3660,2779 @@ async fn test_short_paper_type_rejected() {
     // Should fail because 'short' is not a valid enum value anymore
     response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
 }
+
+// ============================================================================
+// Admin Tests
+// ============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_refresh_stats_reports_timings() {
+    let server = setup().await;
+
+    // A crawler's GET must not trigger a refresh
+    server
+        .get("/admin/refresh-stats")
+        .await
+        .assert_status(axum::http::StatusCode::METHOD_NOT_ALLOWED);
+
+    let response = server.post("/admin/refresh-stats").await;
+    response.assert_status_ok();
+    let report: serde_json::Value = response.json();
+
+    let mut views: Vec<&str> = report["views"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| {
+            assert!(v["duration_ms"].is_u64(), "Each view should report its duration");
+            v["view"].as_str().unwrap()
+        })
+        .collect();
+    views.sort();
+    assert_eq!(views, vec!["author_stats", "coauthor_pairs", "committee_pairs", "conference_stats"]);
+    assert!(report["total_ms"].is_u64());
+}
+
+#[tokio::test]
+async fn test_reindex_search() {
+    let server = setup().await;
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    for title in ["Reindexed Surface Codes", "Reindexed Magic States"] {
+        server
+            .post("/publications")
+            .json(&json!({"conference_id": conference_id, "title": title, "abstract": "Search me", "creator": "test_user", "modifier": "test_user"}))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+    }
+
+    server
+        .get("/admin/reindex-search")
+        .await
+        .assert_status(axum::http::StatusCode::METHOD_NOT_ALLOWED);
+
+    let response = server.post(&format!("/admin/reindex-search?conference={}", conference_id)).await;
+    response.assert_status_ok();
+    let report: serde_json::Value = response.json();
+    assert_eq!(report["conference_id"], conference_id.as_str());
+    assert_eq!(report["checked"], 2);
+    assert_eq!(report["recomputed"], 0, "Generated vectors should already be current");
+    assert!(report["duration_ms"].is_u64());
+
+    server
+        .post("/admin/reindex-search?conference=not-a-conference")
+        .await
+        .assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_author_committee_collaborators() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let body = json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"});
+    let conferences = create_test_conferences(&server, &[(0, body.clone()), (1, body)]).await;
+    let conference_ids: Vec<String> = conferences.iter().map(|c| c["id"].as_str().unwrap().to_string()).collect();
+    let years: Vec<i64> = conferences.iter().map(|c| c["year"].as_i64().unwrap()).collect();
+    let mut author_ids = Vec::new();
+    for name in ["Served X", "Served Y", "Served Z"] {
+        let response = server
+            .post("/authors")
+            .json(&json!({"full_name": format!("{} {}", name, unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+
+    // (conference, committee, position, author)
+    let roles = [
+        (0, "PC", "chair", 0),
+        (0, "PC", "member", 1),
+        (0, "PC", "member", 2),
+        (0, "SC", "member", 0),
+        (0, "SC", "member", 1),
+        (1, "PC", "member", 0),
+        (1, "PC", "member", 1),
+        (1, "Local", "member", 2),
+    ];
+    for (conference, committee, position, author) in roles {
+        server
+            .post("/committees")
+            .json(&json!({
+                "conference_id": conference_ids[conference],
+                "author_id": author_ids[author],
+                "committee": committee,
+                "position": position,
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+    }
+    for id in &conference_ids {
+        server
+            .post(&format!("/admin/refresh-stats?conference={}", id))
+            .await
+            .assert_status_ok();
+    }
+
+    let response = server
+        .get(&format!("/authors/{}/committee-collaborators", author_ids[0]))
+        .await;
+    response.assert_status_ok();
+    let collaborators = response.json::<Vec<serde_json::Value>>();
+    assert_eq!(collaborators.len(), 2);
+    assert_eq!(collaborators[0]["author_id"], author_ids[1].as_str());
+    assert_eq!(collaborators[0]["served_together_count"], 3);
+    assert_eq!(collaborators[0]["last_year"], years[1]);
+    // Z only shared the first PC; the second conference's Local committee doesn't count
+    assert_eq!(collaborators[1]["author_id"], author_ids[2].as_str());
+    assert_eq!(collaborators[1]["served_together_count"], 1);
+    assert_eq!(collaborators[1]["last_year"], years[0]);
+
+    let response = server
+        .get(&format!("/authors/{}/committee-collaborators?limit=1&offset=1", author_ids[0]))
+        .await;
+    assert_eq!(response.json::<Vec<serde_json::Value>>()[0]["author_id"], author_ids[2].as_str());
+
+    let response = server
+        .get(&format!("/authors/{}/committee-collaborators", author_ids[2]))
+        .await;
+    let collaborators = response.json::<Vec<serde_json::Value>>();
+    assert_eq!(collaborators.len(), 2);
+    assert!(collaborators.iter().all(|c| c["served_together_count"] == 1));
+
+    server
+        .get(&format!("/authors/{}/committee-collaborators", Uuid::new_v4()))
+        .await
+        .assert_status_not_found();
+
+    for id in &conference_ids {
+        server.delete(&format!("/conferences/{}?cascade=true", id)).await;
+    }
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+    server.post("/admin/refresh-stats").await.assert_status_ok();
+}
+
+#[tokio::test]
+#[serial]
+async fn test_concurrent_stats_refreshes() {
+    let pool = common::create_test_pool().await;
+
+    // Overlapping full refreshes of one table used to collide on its unique
+    // index: both delete, then both insert the same rows
+    for function in [
+        "refresh_author_stats",
+        "refresh_conference_stats",
+        "refresh_coauthor_pairs",
+        "refresh_committee_pairs",
+    ] {
+        let sql = format!("SELECT {}()", function);
+        let (first, second) = tokio::join!(
+            sqlx::query(&sql).execute(&pool),
+            sqlx::query(&sql).execute(&pool)
+        );
+        first.unwrap_or_else(|e| panic!("{} failed: {}", function, e));
+        second.unwrap_or_else(|e| panic!("{} failed: {}", function, e));
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_refresh_stats_for_one_conference() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let test_year = conference["year"].as_i64().unwrap();
+
+    let response = server
+        .post("/publications")
+        .json(&json!({
+            "conference_id": conference_id,
+            "canonical_key": format!("tqc{}-refresh", test_year),
+            "title": "Targeted Refresh Test",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Refresh Author {}", unique_suffix),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": publication_id,
+            "author_id": author_id,
+            "author_position": 1,
+            "published_as_name": "Refresh Author",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::CREATED);
+
+    let response = server
+        .post(&format!("/admin/refresh-stats?conference={}", conference_id))
+        .await;
+    response.assert_status_ok();
+    let report: serde_json::Value = response.json();
+    assert_eq!(report["conference_id"], conference_id.as_str());
+    assert_eq!(report["author_count"], 1);
+    assert_eq!(report["views"].as_array().unwrap().len(), 4);
+
+    let entries: Vec<serde_json::Value> = server.get("/conferences/index.json").await.json();
+    let entry = entries.iter().find(|e| e["year"] == test_year).unwrap();
+    assert_eq!(entry["publication_count"], 1, "Targeted refresh should update conference_stats");
+
+    server
+        .post("/admin/refresh-stats?conference=not-a-conference")
+        .await
+        .assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+    server.delete(&format!("/publications/{}", publication_id)).await;
+    common::purge("publications", &publication_id).await;
+    common::purge("authors", &author_id).await;
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+    // Purged rows are only dropped from the stats by a full refresh
+    server.post("/admin/refresh-stats").await.assert_status_ok();
+}
+
+#[tokio::test]
+#[serial]
+async fn test_author_include_stats() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QCRYPT", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let test_year = conference["year"].as_i64().unwrap();
+    let response = server
+        .post("/publications")
+        .json(&json!({
+            "conference_id": conference_id,
+            "title": "Include Stats Test",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let mut author_ids = Vec::new();
+    for name in ["Published", "Unpublished"] {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("Stats {} {}", name, unique_suffix),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": publication_id,
+            "author_id": author_ids[0],
+            "author_position": 1,
+            "published_as_name": "Stats Published",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::CREATED);
+    server
+        .post(&format!("/admin/refresh-stats?conference={}", conference_id))
+        .await
+        .assert_status_ok();
+
+    // Opt-in: the plain model has no counts
+    let body: serde_json::Value = server.get(&format!("/authors/{}", author_ids[0])).await.json();
+    assert!(body.get("publication_count").is_none());
+
+    let response = server.get(&format!("/authors/{}?include=stats", author_ids[0])).await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["id"], author_ids[0].as_str());
+    assert_eq!(body["publication_count"], 1);
+    assert_eq!(body["committee_role_count"], 0);
+    assert_eq!(body["first_year"], test_year);
+    assert_eq!(body["last_year"], test_year);
+    assert_eq!(body["venues"], json!(["QCRYPT"]));
+
+    let response = server
+        .get("/authors")
+        .add_query_param("search", &unique_suffix)
+        .add_query_param("include", "stats")
+        .await;
+    response.assert_status_ok();
+    let body: Vec<serde_json::Value> = response.json();
+    let unpublished = body.iter().find(|a| a["id"] == author_ids[1].as_str()).unwrap();
+    assert_eq!(unpublished["publication_count"], 0);
+    assert_eq!(unpublished["venues"], json!([]));
+    assert!(unpublished["first_year"].is_null());
+
+    let response = server.get(&format!("/authors/{}?include=coauthors", author_ids[0])).await;
+    response.assert_status_bad_request();
+    assert!(response.json::<serde_json::Value>()["message"].as_str().unwrap().contains("coauthors"));
+    server.get("/authors?include=stats&cursor=").await.assert_status_bad_request();
+
+    server.delete(&format!("/publications/{}", publication_id)).await;
+    common::purge("publications", &publication_id).await;
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+    server.post("/admin/refresh-stats").await.assert_status_ok();
+}
+
+#[tokio::test]
+#[serial]
+async fn test_global_stats() {
+    let server = setup().await;
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let test_year = conference["year"].as_i64().unwrap();
+    server
+        .patch(&format!("/conferences/{}", conference_id))
+        .json(&json!({"start_date": format!("{}-06-01", test_year), "modifier": "test_user"}))
+        .await
+        .assert_status_ok();
+
+    let response = server.get("/stats").await;
+    response.assert_status_ok();
+    let stats: serde_json::Value = response.json();
+    for key in ["total_authors", "total_publications", "total_conferences", "total_committee_roles"] {
+        assert!(stats[key].as_i64().is_some(), "{} should be a count", key);
+    }
+
+    let venues = stats["venues"].as_array().unwrap();
+    let names: Vec<&str> = venues.iter().map(|v| v["venue"].as_str().unwrap()).collect();
+    assert!(names.windows(2).all(|w| w[0] < w[1]), "Venues should be sorted");
+    let conference_total: i64 = venues.iter().map(|v| v["conference_count"].as_i64().unwrap()).sum();
+    assert_eq!(conference_total, stats["total_conferences"].as_i64().unwrap());
+
+    // Test conferences take the latest free year, so the newest TQC edition is usually a test one
+    let latest = &venues.iter().find(|v| v["venue"] == "TQC").unwrap()["latest_conference"];
+    let latest_year = latest["year"].as_i64().unwrap();
+    assert!(latest_year >= test_year);
+    assert_eq!(latest["slug"], format!("TQC{}", latest_year));
+    if latest_year == test_year {
+        assert_eq!(latest["id"], conference_id.as_str());
+        assert_eq!(latest["start_date"], format!("{}-06-01", test_year));
+    }
+
+    server
+        .delete(&format!("/conferences/{}?modifier=test_user", conference_id))
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_top_authors() {
+    let server = setup().await;
+
+    let response = server.get("/authors/top?limit=5").await;
+    response.assert_status_ok();
+    let top: Vec<serde_json::Value> = response.json();
+    assert!(top.len() <= 5);
+    let counts: Vec<i64> = top.iter().map(|a| a["publication_count"].as_i64().unwrap()).collect();
+    assert!(counts.windows(2).all(|w| w[0] >= w[1]), "Should be ranked by publications");
+    assert!(counts.iter().all(|&c| c > 0));
+
+    let response = server.get("/authors/top?by=leadership&venue=qip").await;
+    response.assert_status_ok();
+    let top: Vec<serde_json::Value> = response.json();
+    let counts: Vec<i64> = top.iter().map(|a| a["leadership_count"].as_i64().unwrap()).collect();
+    assert!(counts.windows(2).all(|w| w[0] >= w[1]), "Should be ranked by leadership roles");
+    assert!(counts.iter().all(|&c| c > 0));
+    for author in &top {
+        // Venue-scoped counts never exceed the author's totals
+        assert!(author["leadership_count"].as_i64() <= author["committee_role_count"].as_i64());
+    }
+
+    let response = server.get("/authors/top?by=citations").await;
+    response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+    let response = server.get("/authors/top?venue=AQIS").await;
+    response.assert_status(axum::http::StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_list_awards() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let test_year = conference["year"].as_i64().unwrap();
+
+    let mut pub_ids = Vec::new();
+    for (key, award) in [("awarded", Some("Best Paper")), ("plain", None)] {
+        let response = server
+            .post("/publications")
+            .json(&json!({
+                "conference_id": conference_id,
+                "canonical_key": format!("tqc{}-{}", test_year, key),
+                "title": format!("Award Test {} {}", key, unique_suffix),
+                "award": award,
+                "award_date": award.map(|_| format!("{}-07-01", test_year)),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        response.assert_status(axum::http::StatusCode::CREATED);
+        pub_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+
+    let mut author_ids = Vec::new();
+    for (n, position) in [(2, 2), (1, 1)] {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("Award Author {} {}", n, unique_suffix),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        server
+            .post("/authorships")
+            .json(&json!({
+                "publication_id": pub_ids[0],
+                "author_id": author_id,
+                "author_position": position,
+                "published_as_name": format!("A. Author {}", n),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+        author_ids.push(author_id);
+    }
+
+    let response = server.get("/awards?venue=tqc").await;
+    response.assert_status_ok();
+    let awards: Vec<serde_json::Value> = response.json();
+    assert!(awards.iter().all(|a| a["venue"] == "TQC"));
+    let years: Vec<i64> = awards.iter().map(|a| a["year"].as_i64().unwrap()).collect();
+    assert!(years.windows(2).all(|w| w[0] <= w[1]), "Awards should be ordered by year");
+
+    let ours: Vec<&serde_json::Value> = awards.iter().filter(|a| a["conference_id"] == conference_id.as_str()).collect();
+    assert_eq!(ours.len(), 1, "Only the awarded publication should be listed");
+    assert_eq!(ours[0]["publication_id"], pub_ids[0].as_str());
+    assert_eq!(ours[0]["conference"], format!("TQC{}", test_year));
+    assert_eq!(ours[0]["award"], "Best Paper");
+    assert_eq!(ours[0]["award_date"], format!("{}-07-01", test_year));
+    assert_eq!(ours[0]["authors"], json!(["A. Author 1", "A. Author 2"]));
+
+    let response = server.get("/awards?venue=QIP").await;
+    let awards: Vec<serde_json::Value> = response.json();
+    assert!(awards.iter().all(|a| a["conference_id"] != conference_id.as_str()));
+
+    server.get("/awards?venue=AQIS").await.assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+    for publication_id in &pub_ids {
+        common::purge("publications", publication_id).await;
+    }
+    for author_id in &author_ids {
+        common::purge("authors", author_id).await;
+    }
+    server
+        .delete(&format!("/conferences/{}?modifier=test_user", conference_id))
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_committee_term_validation() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let test_year = conference["year"].as_i64().unwrap();
+    server
+        .patch(&format!("/conferences/{}", conference_id))
+        .json(&json!({"start_date": format!("{}-01-20", test_year), "modifier": "test_user"}))
+        .await
+        .assert_status_ok();
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Steering Member {}", unique_suffix),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let role = |term_start: String, term_end: String| {
+        json!({
+            "conference_id": conference_id,
+            "author_id": author_id,
+            "committee": "SC",
+            "term_start": term_start,
+            "term_end": term_end,
+            "creator": "test_user",
+            "modifier": "test_user"
+        })
+    };
+
+    // Inverted range
+    let response = server
+        .post("/committees")
+        .json(&role(format!("{}-12-31", test_year), format!("{}-01-01", test_year)))
+        .await;
+    response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    // Ends the year before the conference
+    let response = server
+        .post("/committees")
+        .json(&role(format!("{}-01-01", test_year - 3), format!("{}-12-31", test_year - 1)))
+        .await;
+    response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = server
+        .post("/committees")
+        .json(&role(format!("{}-01-01", test_year - 2), format!("{}-12-31", test_year + 1)))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let role_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    // PATCH checks the merged range: a new start after the stored end is rejected
+    let response = server
+        .patch(&format!("/committees/{}", role_id))
+        .json(&json!({"term_start": format!("{}-01-01", test_year + 2), "modifier": "test_user"}))
+        .await;
+    response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = server
+        .patch(&format!("/committees/{}", role_id))
+        .json(&json!({"term_start": format!("{}-06-01", test_year), "modifier": "test_user"}))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<serde_json::Value>()["term_start"], format!("{}-06-01", test_year));
+
+    server.delete(&format!("/committees/{}", role_id)).await;
+    common::purge("authors", &author_id).await;
+    server
+        .delete(&format!("/conferences/{}?modifier=test_user", conference_id))
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_list_updated_since() {
+    let server = setup().await;
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let test_year = conference["year"].as_i64().unwrap();
+
+    let mut publications = Vec::new();
+    for key in ["first", "second"] {
+        let response = server
+            .post("/publications")
+            .json(&json!({
+                "conference_id": conference_id,
+                "canonical_key": format!("qip{}-since-{}", test_year, key),
+                "title": format!("Updated Since {}", key),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        publications.push(response.json::<serde_json::Value>());
+    }
+    let ids = |response: axum_test::TestResponse| -> Vec<String> {
+        response
+            .json::<Vec<serde_json::Value>>()
+            .iter()
+            .map(|p| p["id"].as_str().unwrap().to_string())
+            .collect()
+    };
+    let first_id = publications[0]["id"].as_str().unwrap().to_string();
+    let second_id = publications[1]["id"].as_str().unwrap().to_string();
+    let since = publications[0]["updated_at"].as_str().unwrap().to_string();
+
+    // The boundary is inclusive; after_id resumes past the last row seen
+    let url = format!("/publications?conference_id={}&updated_since={}", conference_id, since);
+    let response = server.get(&url).await;
+    response.assert_status_ok();
+    assert_eq!(ids(response), vec![first_id.clone(), second_id.clone()]);
+    let resume = format!("{}&after_id={}", url, first_id);
+    assert_eq!(ids(server.get(&resume).await), vec![second_id.clone()]);
+
+    // An edit moves the record to the end of the sync order
+    server
+        .patch(&format!("/publications/{}", first_id))
+        .json(&json!({"title": "Updated Since first (edited)", "modifier": "test_user"}))
+        .await
+        .assert_status_ok();
+    assert_eq!(ids(server.get(&resume).await), vec![second_id.clone(), first_id.clone()]);
+
+    // Rows sharing a timestamp are ordered by id, so a page ending among them
+    // resumes at the next id instead of skipping the rest
+    let pool = common::create_test_pool().await;
+    let shared: chrono::DateTime<chrono::Utc> = "2030-01-01T00:00:00Z".parse().unwrap();
+    sqlx::query("UPDATE publications SET updated_at = $1 WHERE id = ANY($2)")
+        .bind(shared)
+        .bind(vec![Uuid::parse_str(&first_id).unwrap(), Uuid::parse_str(&second_id).unwrap()])
+        .execute(&pool)
+        .await
+        .unwrap();
+    let mut by_id = [first_id.clone(), second_id.clone()];
+    by_id.sort();
+    let url = format!("/publications?conference_id={}&updated_since=2030-01-01T00:00:00Z", conference_id);
+    assert_eq!(ids(server.get(&format!("{}&limit=1", url)).await), vec![by_id[0].clone()]);
+    assert_eq!(ids(server.get(&format!("{}&after_id={}", url, by_id[0])).await), vec![by_id[1].clone()]);
+
+    let since = conference["updated_at"].as_str().unwrap();
+    let response = server.get(&format!("/conferences?updated_since={}", since)).await;
+    response.assert_status_ok();
+    assert!(ids(response).contains(&conference_id));
+    let response = server
+        .get(&format!("/conferences?updated_since={}&after_id={}", since, conference_id))
+        .await;
+    assert!(!ids(response).contains(&conference_id));
+
+    server
+        .get(&format!("/publications?after_id={}", first_id))
+        .await
+        .assert_status(axum::http::StatusCode::BAD_REQUEST);
+    server
+        .get("/publications?updated_since=yesterday")
+        .await
+        .assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+    for id in [&first_id, &second_id] {
+        common::purge("publications", id).await;
+    }
+    server
+        .delete(&format!("/conferences/{}?modifier=test_user", conference_id))
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_list_authors_cursor() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let mut author_ids = Vec::new();
+    let create = |family: &str| {
+        json!({
+            "full_name": format!("Cursor {} {}", family, unique_suffix),
+            "family_name": family,
+            "given_name": format!("Cursor {}", unique_suffix),
+            "creator": "test_user",
+            "modifier": "test_user"
+        })
+    };
+    for family in ["Bravo", "Delta", "Foxtrot"] {
+        let response = server.post("/authors").json(&create(family)).await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+
+    let page_url = |cursor: &str| format!("/authors?search={}&limit=2&cursor={}", unique_suffix, cursor);
+    let response = server.get(&page_url("")).await;
+    response.assert_status_ok();
+    let page: serde_json::Value = response.json();
+    let families: Vec<&str> = page["authors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["family_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(families, vec!["Bravo", "Delta"]);
+    let next = page["next_cursor"].as_str().expect("First page should have a cursor").to_string();
+
+    // A row inserted before the cursor position doesn't shift the next page
+    let response = server.post("/authors").json(&create("Alpha")).await;
+    author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+
+    let page: serde_json::Value = server.get(&page_url(&next)).await.json();
+    let families: Vec<&str> = page["authors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["family_name"].as_str().unwrap())
+        .collect();
+    assert_eq!(families, vec!["Foxtrot"]);
+    assert!(page["next_cursor"].is_null());
+
+    server
+        .get(&page_url("not-a-cursor"))
+        .await
+        .assert_status(axum::http::StatusCode::BAD_REQUEST);
+
+    // Without a cursor the plain list is unchanged
+    let authors: Vec<serde_json::Value> = server
+        .get(&format!("/authors?search={}", unique_suffix))
+        .await
+        .json();
+    assert_eq!(authors.len(), 4);
+
+    for author_id in &author_ids {
+        common::purge("authors", author_id).await;
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_publication_jsonld() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let test_year = conference["year"].as_i64().unwrap();
+    server
+        .patch(&format!("/conferences/{}", conference_id))
+        .json(&json!({"start_date": format!("{}-01-20", test_year), "modifier": "test_user"}))
+        .await
+        .assert_status_ok();
+
+    let title = format!("JSON-LD Test </script> {}", unique_suffix);
+    let response = server
+        .post("/publications")
+        .json(&json!({
+            "conference_id": conference_id,
+            "canonical_key": format!("qip{}-jsonld", test_year),
+            "title": title,
+            "arxiv_ids": ["2301.12345"],
+            "doi": "https://doi.org/10.4230/LIPIcs.TQC.2022.1",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("JSON-LD Author {}", unique_suffix),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": publication_id,
+            "author_id": author_id,
+            "author_position": 1,
+            "published_as_name": "J. Author",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::CREATED);
+
+    let response = server.get(&format!("/publications/{}.jsonld", publication_id)).await;
+    response.assert_status_ok();
+    assert_eq!(response.header("content-type"), "application/ld+json");
+    let article: serde_json::Value = response.json();
+    assert_eq!(article["@context"], "https://schema.org");
+    assert_eq!(article["@type"], "ScholarlyArticle");
+    assert_eq!(article["headline"], title.as_str());
+    assert_eq!(article["author"], json!([{"@type": "Person", "name": "J. Author"}]));
+    // No published or talk date: falls back to the conference start
+    assert_eq!(article["datePublished"], format!("{}-01-20", test_year));
+    assert_eq!(
+        article["sameAs"],
+        json!(["https://arxiv.org/abs/2301.12345", "https://doi.org/10.4230/lipics.tqc.2022.1"])
+    );
+    assert_eq!(article["identifier"]["value"], "10.4230/lipics.tqc.2022.1");
+
+    // The plain JSON representation is unchanged
+    let response = server.get(&format!("/publications/{}", publication_id)).await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<serde_json::Value>()["title"], title.as_str());
+
+    server
+        .get(&format!("/publications/{}.jsonld", Uuid::new_v4()))
+        .await
+        .assert_status_not_found();
+    server.get("/publications/not-a-uuid.jsonld").await.assert_status_bad_request();
+
+    common::purge("publications", &publication_id).await;
+    common::purge("authors", &author_id).await;
+    server
+        .delete(&format!("/conferences/{}?modifier=test_user", conference_id))
+        .await;
+}
+
+#[tokio::test]
+async fn test_feed_ignores_request_host() {
+    let server = setup().await;
+
+    // Feed ids and links come from PUBLIC_BASE_URL, not whatever Host the client sent
+    let response = server
+        .get("/feed.atom")
+        .add_header("host", "attacker.example")
+        .add_header("x-forwarded-proto", "https")
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.header("content-type"), "application/atom+xml");
+    let xml = response.text();
+    assert!(!xml.contains("attacker.example"), "{}", xml);
+    let base = std::env::var("PUBLIC_BASE_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
+    let base = base.trim_end_matches('/');
+    assert!(xml.contains(&format!("<id>{}/feed.atom</id>", base)), "{}", xml);
+    assert!(xml.contains(&format!("href=\"{}/publications\"", base)), "{}", xml);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_institutions() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+    let name = format!("Institute of Quantum Widgets {}", unique_suffix);
+    let acronym = format!("IQW{}", &unique_suffix[..6]);
+
+    // Created before the institution: linked by the backfill
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Early Institution Author {}", unique_suffix),
+            "affiliation": format!("Dept. of Physics, {}, Springfield", acronym),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let early = response.json::<serde_json::Value>();
+    assert!(early["institution_id"].is_null());
+    let early_id = early["id"].as_str().unwrap().to_string();
+
+    let response = server
+        .post("/institutions")
+        .json(&json!({
+            "name": name,
+            "aliases": [acronym],
+            "ror_id": "https://ror.org/042NB2S44",
+            "country_code": "us",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let institution: serde_json::Value = response.json();
+    let institution_id = institution["id"].as_str().unwrap().to_string();
+    assert_eq!(institution["ror_id"], "042nb2s44");
+    assert_eq!(institution["country_code"], "US");
+    assert_eq!(institution["author_count"], 1);
+
+    let response = server.get(&format!("/authors/{}", early_id)).await;
+    assert_eq!(response.json::<serde_json::Value>()["institution_id"], institution_id.as_str());
+
+    // Created after: matched on create, by a differently punctuated spelling
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Late Institution Author {}", unique_suffix),
+            "affiliation": format!("the {}", name.to_uppercase().replace(' ', "  ")),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let late = response.json::<serde_json::Value>();
+    assert_eq!(late["institution_id"], institution_id.as_str());
+    let late_id = late["id"].as_str().unwrap().to_string();
+
+    // Changing the affiliation re-matches; other edits keep the link
+    let response = server
+        .patch(&format!("/authors/{}", late_id))
+        .json(&json!({"homepage_url": "https://example.org", "modifier": "test_user"}))
+        .await;
+    assert_eq!(response.json::<serde_json::Value>()["institution_id"], institution_id.as_str());
+    let response = server
+        .patch(&format!("/authors/{}", late_id))
+        .json(&json!({"affiliation": "Somewhere Else Entirely", "modifier": "test_user"}))
+        .await;
+    assert!(response.json::<serde_json::Value>()["institution_id"].is_null());
+
+    let response = server.get("/institutions").await;
+    response.assert_status_ok();
+    let institutions: Vec<serde_json::Value> = response.json();
+    let ours = institutions.iter().find(|i| i["id"] == institution_id.as_str()).unwrap();
+    assert_eq!(ours["author_count"], 1);
+    assert_eq!(ours["aliases"], json!([acronym]));
+
+    let duplicate = server
+        .post("/institutions")
+        .json(&json!({"name": name, "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    duplicate.assert_status(axum::http::StatusCode::CONFLICT);
+    for body in [
+        json!({"name": "X", "ror_id": "not-a-ror", "creator": "test_user", "modifier": "test_user"}),
+        json!({"name": "X", "ror_id": "0abcdeé8", "creator": "test_user", "modifier": "test_user"}),
+        json!({"name": "X", "country_code": "USA", "creator": "test_user", "modifier": "test_user"}),
+        json!({"name": "  ", "creator": "test_user", "modifier": "test_user"}),
+    ] {
+        server
+            .post("/institutions")
+            .json(&body)
+            .await
+            .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    common::purge("authors", &early_id).await;
+    common::purge("authors", &late_id).await;
+    common::purge("institutions", &institution_id).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_conference_affiliations() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+    let institution_name = format!("Affiliation Facet Institute {}", unique_suffix);
+    let lab = format!("Quantum Lab {}", unique_suffix);
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let test_year = conference["year"].as_i64().unwrap();
+    let response = server
+        .post("/institutions")
+        .json(&json!({"name": institution_name, "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    let institution_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let mut pub_ids = Vec::new();
+    for n in 0..2 {
+        let response = server
+            .post("/publications")
+            .json(&json!({
+                "conference_id": conference_id,
+                "canonical_key": format!("qip{}-affil{}", test_year, n),
+                "title": format!("Affiliation Facet {} {}", n, unique_suffix),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        pub_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+
+    // (author's own affiliation, [(publication, affiliation printed on it)])
+    let people = [
+        (None, vec![(0, Some(lab.clone())), (1, Some(lab.clone()))]),
+        (None, vec![(0, Some(format!("{}.", lab.to_uppercase())))]),
+        (Some(institution_name.clone()), vec![(0, Some(String::new()))]),
+        (None, vec![(1, Some(lab.clone()))]),
+        (None, vec![(1, None)]),
+    ];
+    let mut author_ids = Vec::new();
+    for (n, (own, papers)) in people.iter().enumerate() {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("Facet Author {} {}", n, unique_suffix),
+                "affiliation": own,
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        for (publication, affiliation) in papers {
+            server
+                .post("/authorships")
+                .json(&json!({
+                    "publication_id": pub_ids[*publication],
+                    "author_id": author_id,
+                    "author_position": n + 1,
+                    "published_as_name": format!("F. Author {}", n),
+                    "affiliation": affiliation,
+                    "creator": "test_user",
+                    "modifier": "test_user"
+                }))
+                .await
+                .assert_status(axum::http::StatusCode::CREATED);
+        }
+        author_ids.push(author_id);
+    }
+
+    let response = server.get(&format!("/conferences/{}/affiliations", conference_id)).await;
+    response.assert_status_ok();
+    let facets: Vec<serde_json::Value> = response.json();
+    assert_eq!(facets.len(), 2, "{:?}", facets);
+    // The lab's two spellings group under the commoner one; author 0 counts once despite two papers
+    assert_eq!(facets[0]["affiliation"], lab.as_str());
+    assert_eq!(facets[0]["author_count"], 3);
+    assert!(facets[0]["institution_id"].is_null());
+    assert_eq!(facets[0]["variants"], json!([lab, format!("{}.", lab.to_uppercase())]));
+    // An empty printed affiliation falls back to the author's own, which matches the institution
+    assert_eq!(facets[1]["affiliation"], institution_name.as_str());
+    assert_eq!(facets[1]["institution_id"], institution_id.as_str());
+    assert_eq!(facets[1]["author_count"], 1);
+
+    server
+        .get(&format!("/conferences/{}/affiliations", Uuid::new_v4()))
+        .await
+        .assert_status_not_found();
+
+    for publication_id in &pub_ids {
+        common::purge("publications", publication_id).await;
+    }
+    for author_id in &author_ids {
+        common::purge("authors", author_id).await;
+    }
+    common::purge("institutions", &institution_id).await;
+    server
+        .delete(&format!("/conferences/{}?modifier=test_user", conference_id))
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_conference_affiliations_ignore_country_acronyms() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    // Aliases whose acronyms are "uk" and "usa", as with the University of Kent
+    let response = server
+        .post("/institutions")
+        .json(&json!({
+            "name": format!("Country Acronym Institute {}", unique_suffix),
+            "aliases": ["Ultra Kelvin", "Ultra Spin Array"],
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let institution_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let response = server
+        .post("/publications")
+        .json(&json!({"conference_id": conference_id, "title": "Country Acronyms", "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let affiliations = [
+        format!("Cavendish Lab {}, Cambridge, UK", unique_suffix),
+        format!("Clarendon Lab {}, Oxford, UK", unique_suffix),
+        format!("Ginzton Lab {}, Stanford, CA, USA", unique_suffix),
+    ];
+    let mut author_ids = Vec::new();
+    for (n, affiliation) in affiliations.iter().enumerate() {
+        let response = server
+            .post("/authors")
+            .json(&json!({"full_name": format!("Country Facet Author {} {}", n, unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        server
+            .post("/authorships")
+            .json(&json!({
+                "publication_id": publication_id,
+                "author_id": author_id,
+                "author_position": n + 1,
+                "published_as_name": format!("C. Author {}", n),
+                "affiliation": affiliation,
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+        author_ids.push(author_id);
+    }
+
+    // Each lab is its own facet; none is counted under the institution
+    let response = server.get(&format!("/conferences/{}/affiliations", conference_id)).await;
+    response.assert_status_ok();
+    let facets: Vec<serde_json::Value> = response.json();
+    assert_eq!(facets.len(), 3, "{:?}", facets);
+    assert!(facets.iter().all(|f| f["institution_id"].is_null() && f["author_count"] == 1), "{:?}", facets);
+
+    common::purge("publications", &publication_id).await;
+    for author_id in &author_ids {
+        common::purge("authors", author_id).await;
+    }
+    common::purge("institutions", &institution_id).await;
+    server
+        .delete(&format!("/conferences/{}?modifier=test_user", conference_id))
+        .await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_author_by_orcid() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("ORCID Lookup Author {}", unique_suffix),
+            "orcid": "0000-0003-1415-926X",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    // The check character is matched case-insensitively
+    for orcid in ["0000-0003-1415-926X", "0000-0003-1415-926x"] {
+        let response = server.get(&format!("/authors/by-orcid/{}", orcid)).await;
+        response.assert_status_ok();
+        assert_eq!(response.json::<serde_json::Value>()["id"], author_id.as_str());
+    }
+
+    server.get("/authors/by-orcid/0000-0003-1415-9260").await.assert_status_not_found();
+
+    let response = server.get("/authors/by-orcid/not-an-orcid").await;
+    response.assert_status_bad_request();
+    assert!(response.json::<serde_json::Value>()["message"]
+        .as_str()
+        .unwrap()
+        .contains("Invalid ORCID iD"));
+    // A multibyte last character is rejected, not a panic
+    server.get("/authors/by-orcid/0000-0002-1825-00%C3%A9").await.assert_status_bad_request();
+
+    // Deleted authors are hidden unless asked for
+    server.delete(&format!("/authors/{}?modifier=test_user", author_id)).await;
+    server.get("/authors/by-orcid/0000-0003-1415-926X").await.assert_status_not_found();
+    server
+        .get("/authors/by-orcid/0000-0003-1415-926X?include_deleted=true")
+        .await
+        .assert_status_ok();
+
+    common::purge("authors", &author_id).await;
+}
+
+#[tokio::test]
+async fn test_author_blank_name_rejected() {
+    let server = setup().await;
+
+    for full_name in ["", "   ", "--"] {
+        server
+            .post("/authors")
+            .json(&json!({
+                "full_name": full_name,
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await
+            .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Blank Name Author {}", Uuid::new_v4().simple()),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    server
+        .patch(&format!("/authors/{}", author_id))
+        .json(&json!({"full_name": "  ", "modifier": "test_user"}))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::purge("authors", &author_id).await;
+}
+
+#[tokio::test]
+async fn test_text_fields_whitespace_collapsed() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("  Ada \t Lovelace{}  ", unique_suffix),
+            "affiliation": " University   of\nLondon ",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let author: serde_json::Value = response.json();
+    let author_id = author["id"].as_str().unwrap().to_string();
+    assert_eq!(author["full_name"], format!("Ada Lovelace{}", unique_suffix));
+    assert_eq!(author["affiliation"], "University of London");
+
+    let response = server
+        .patch(&format!("/authors/{}", author_id))
+        .json(&json!({"affiliation": "Imperial  College ", "modifier": "test_user"}))
+        .await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<serde_json::Value>()["affiliation"], "Imperial College");
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let response = server
+        .post("/publications")
+        .json(&json!({
+            "conference_id": conference_id,
+            "canonical_key": format!("whitespace-{}", unique_suffix),
+            "title": " Quantum   error\ncorrection ",
+            "session_name": "Session  1 ",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let publication: serde_json::Value = response.json();
+    let publication_id = publication["id"].as_str().unwrap().to_string();
+    assert_eq!(publication["title"], "Quantum error correction");
+    assert_eq!(publication["session_name"], "Session 1");
+
+    let response = server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": publication_id,
+            "author_id": author_id,
+            "author_position": 1,
+            "published_as_name": "A.   Lovelace",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    assert_eq!(response.json::<serde_json::Value>()["published_as_name"], "A. Lovelace");
+
+    common::purge("publications", &publication_id).await;
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+    common::purge("authors", &author_id).await;
+}
+
+#[tokio::test]
+async fn test_idempotency_key_replays_create() {
+    let server = setup().await;
+    let key = Uuid::new_v4().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    let body = json!({
+        "conference_id": conference_id,
+        "title": "Retried After a Timeout",
+        "creator": "test_user",
+        "modifier": "test_user"
+    });
+    let response = server.post("/publications").add_header("idempotency-key", key.as_str()).json(&body).await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    assert!(response.maybe_header("idempotent-replayed").is_none());
+    let first: serde_json::Value = response.json();
+
+    // The retry gets the original response and nothing new is inserted
+    let response = server.post("/publications").add_header("idempotency-key", key.as_str()).json(&body).await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    assert_eq!(response.header("idempotent-replayed"), "true");
+    assert_eq!(response.header("content-type"), "application/json");
+    assert_eq!(response.json::<serde_json::Value>(), first);
+    let response = server.get(&format!("/publications?conference_id={}", conference_id)).await;
+    assert_eq!(response.json::<Vec<serde_json::Value>>().len(), 1);
+
+    // Same key, different request
+    let response = server
+        .post("/publications")
+        .add_header("idempotency-key", key.as_str())
+        .json(&json!({"conference_id": conference_id, "title": "Something Else", "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(response.json::<serde_json::Value>()["message"].as_str().unwrap().contains("different request"));
+
+    // Without a key the same body creates a second publication (with the next free key)
+    let response = server.post("/publications").json(&body).await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    assert_ne!(response.json::<serde_json::Value>()["id"], first["id"]);
+
+    let response = server
+        .post("/publications")
+        .add_header("idempotency-key", "x".repeat(256).as_str())
+        .json(&body)
+        .await;
+    response.assert_status_bad_request();
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+}
+
+#[tokio::test]
+async fn test_publication_canonical_key_generated() {
+    let server = setup().await;
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let year = conference["year"].as_i64().unwrap();
+
+    let mut publication_ids = Vec::new();
+    for expected in ["", "-2"] {
+        let response = server
+            .post("/publications")
+            .json(&json!({
+                "conference_id": conference_id,
+                "title": "Quantum Error Correction",
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        response.assert_status(axum::http::StatusCode::CREATED);
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["canonical_key"], format!("qip{}-quantum-error-correction{}", year, expected));
+        publication_ids.push(body["id"].as_str().unwrap().to_string());
+    }
+
+    server
+        .post("/publications")
+        .json(&json!({
+            "conference_id": Uuid::new_v4(),
+            "title": "Nowhere",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    for id in &publication_ids {
+        common::purge("publications", id).await;
+    }
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+}
+
+#[tokio::test]
+async fn test_conference_year_bounds() {
+    let server = setup().await;
+
+    // Below 1990, and above the cap of five years ahead
+    for year in [202, quantumdb::utils::max_conference_year() + 1] {
+        let response = server
+            .post("/conferences")
+            .json(&json!({"venue": "QIP", "year": year, "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        response.assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(response.json::<serde_json::Value>()["message"]
+            .as_str()
+            .unwrap()
+            .contains(&year.to_string()));
+    }
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    server
+        .patch(&format!("/conferences/{}", conference_id))
+        .json(&json!({"year": 20224, "modifier": "test_user"}))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+}
+
+#[tokio::test]
+async fn test_conference_committee_anomalies() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let anomalies = |id: &str| server.get(&format!("/conferences/{}/committee-anomalies", id));
+    let messages = |body: &serde_json::Value| -> Vec<String> {
+        body.as_array().unwrap().iter().map(|a| a["message"].as_str().unwrap().to_string()).collect()
+    };
+
+    let response = anomalies(&conference_id).await;
+    response.assert_status_ok();
+    assert!(messages(&response.json()).contains(&"No program committee members recorded".to_string()));
+
+    let mut author_ids = Vec::new();
+    for name in ["Alpha", "Beta"] {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("Anomaly {} {}", name, unique_suffix),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    // Two general chairs, and Alpha as both PC chair and PC member
+    for (author_id, committee, position) in [
+        (&author_ids[0], "OC", "chair"),
+        (&author_ids[1], "OC", "chair"),
+        (&author_ids[0], "PC", "chair"),
+        (&author_ids[0], "PC", "member"),
+    ] {
+        server
+            .post("/committees")
+            .json(&json!({
+                "conference_id": conference_id,
+                "author_id": author_id,
+                "committee": committee,
+                "position": position,
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+    }
+
+    let body: serde_json::Value = anomalies(&conference_id).await.json();
+    assert_eq!(body[0]["severity"], "error");
+    assert!(body[0]["message"].as_str().unwrap().contains(&format!("Anomaly Alpha {}", unique_suffix)));
+    assert!(body[0]["message"].as_str().unwrap().ends_with("chair, member"));
+    let messages = messages(&body);
+    assert!(messages.iter().any(|m| m.starts_with("2 general chairs")));
+    assert!(!messages.contains(&"No program committee members recorded".to_string()));
+
+    anomalies(&Uuid::new_v4().to_string()).await.assert_status_not_found();
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+async fn test_name_utils() {
+    let server = setup().await;
+
+    let response = server.get("/utils/normalize").add_query_param("name", "  José  O'Brien-García ").await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["normalized"], "jose o'brien-garcia");
+    assert_eq!(body["loose"], "jose obriengarcia");
+
+    let response = server.get("/utils/name-variants").add_query_param("name", "Albert Einstein").await;
+    response.assert_status_ok();
+    let body: serde_json::Value = response.json();
+    assert_eq!(body["name"], "Albert Einstein");
+    let expected: Vec<String> = quantumdb::utils::generate_name_variants("Albert Einstein");
+    assert_eq!(body["variants"], json!(expected));
+
+    server.get("/utils/name-variants").await.assert_status_bad_request();
+    server
+        .get("/utils/normalize")
+        .add_query_param("name", "x".repeat(10_000))
+        .await
+        .assert_status_bad_request();
+}
+
+#[tokio::test]
+async fn test_fuzzy_author_search() {
+    let server = setup().await;
+    let tag = format!("Zz{}", Uuid::new_v4().simple());
+
+    let mut author_ids = Vec::new();
+    for name in ["Erwin Schrödinger", "Erwin Schrodt", "Erwin"] {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("{} {}", name, tag),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+
+    let fuzzy_search = |term: String| server.get("/authors").add_query_param("search", term).add_query_param("fuzzy", "true");
+
+    // The accent-free spelling is an exact match after normalization
+    let response = fuzzy_search(format!("Erwin Schrodinger {}", tag)).await;
+    response.assert_status_ok();
+    let body: Vec<serde_json::Value> = response.json();
+    assert_eq!(body[0]["id"], author_ids[0].as_str());
+    assert_eq!(body[0]["score"], 1.0);
+    let scores: Vec<f64> = body.iter().map(|a| a["score"].as_f64().unwrap()).collect();
+    assert!(scores.windows(2).all(|w| w[0] >= w[1]));
+    // Exact after normalization, then a near miss on the surname, then no surname
+    let ours: Vec<&str> = body
+        .iter()
+        .map(|a| a["id"].as_str().unwrap())
+        .filter(|id| author_ids.iter().any(|ours| ours == id))
+        .collect();
+    assert_eq!(ours, author_ids.iter().map(String::as_str).collect::<Vec<_>>());
+
+    // A dropped letter still matches, as a typo
+    let body: Vec<serde_json::Value> = fuzzy_search(format!("Erwin Schrodnger {}", tag)).await.json();
+    let typo_match = body.iter().find(|a| a["id"] == author_ids[0].as_str()).unwrap();
+    assert!(typo_match["score"].as_f64().unwrap() > 0.8);
+
+    server.get("/authors?fuzzy=true").await.assert_status_bad_request();
+    server.get("/authors?fuzzy=true&search=x&cursor=").await.assert_status_bad_request();
+
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+async fn test_author_autocomplete() {
+    let server = setup().await;
+    let tag = format!("Zz{}", Uuid::new_v4().simple());
+
+    let mut author_ids = Vec::new();
+    for (name, affiliation) in [
+        (format!("{} Prefix", tag), Some("CQT")),
+        (format!("Word {}", tag), None),
+        (format!("Deleted {}ed", tag), None),
+    ] {
+        let response = server
+            .post("/authors")
+            .json(&json!({"full_name": name, "affiliation": affiliation, "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    server
+        .delete(&format!("/authors/{}", author_ids[2]))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+
+    let suggest = |q: String, limit: Option<&str>| {
+        let request = server.get("/authors/autocomplete").add_query_param("q", q);
+        match limit {
+            Some(limit) => request.add_query_param("limit", limit),
+            None => request,
+        }
+    };
+
+    // Whole-name prefix first, then word prefix; soft-deleted authors never
+    let response = suggest(tag[..10].to_uppercase(), None).await;
+    response.assert_status_ok();
+    assert_eq!(response.header("cache-control"), "public, max-age=60");
+    let body: Vec<serde_json::Value> = response.json();
+    let ids: Vec<&str> = body.iter().map(|a| a["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, vec![author_ids[0].as_str(), author_ids[1].as_str()]);
+    assert_eq!(body[0], json!({"id": author_ids[0], "full_name": format!("{} Prefix", tag), "affiliation": "CQT"}));
+
+    let body: Vec<serde_json::Value> = suggest(tag.clone(), Some("1")).await.json();
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0]["id"], author_ids[0].as_str());
+
+    // Below three characters only whole-name prefixes match
+    let body: Vec<serde_json::Value> = suggest("zz".to_string(), Some("50")).await.json();
+    assert!(body.iter().all(|a| a["id"] != author_ids[1].as_str()));
+
+    // No prefix match, but close enough by trigram similarity
+    let typo = format!("{}x", &tag[..tag.len() - 1]);
+    let body: Vec<serde_json::Value> = suggest(typo, None).await.json();
+    assert!(body.iter().any(|a| a["id"] == author_ids[0].as_str()));
+
+    assert_eq!(suggest("   ".to_string(), None).await.json::<Vec<serde_json::Value>>(), Vec::<serde_json::Value>::new());
+    server.get("/authors/autocomplete").await.assert_status_bad_request();
+    suggest(tag.clone(), Some("-1")).await.assert_status_bad_request();
+
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+async fn test_author_vcard() {
+    let server = setup().await;
+    let unique_suffix = uuid::Uuid::new_v4().to_string()[..8].to_string();
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Vera Card {}", unique_suffix),
+            "family_name": "Card",
+            "given_name": "Vera",
+            "affiliation": "Centre for Quantum Technologies, NUS",
+            "homepage_url": "https://example.org/vera",
+            "orcid": "0000-0002-7182-8185",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let response = server.get(&format!("/authors/{}/vcard", author_id)).await;
+    response.assert_status_ok();
+    assert_eq!(response.header("content-type"), "text/vcard; charset=utf-8");
+    let card = response.text();
+    assert!(card.starts_with("BEGIN:VCARD\r\nVERSION:4.0\r\n"), "{}", card);
+    assert!(card.ends_with("END:VCARD\r\n"), "{}", card);
+    for line in [
+        format!("UID:urn:uuid:{}", author_id),
+        format!("FN:Vera Card {}", unique_suffix),
+        "N:Card;Vera;;;".to_string(),
+        "ORG:Centre for Quantum Technologies\\, NUS".to_string(),
+        "URL:https://example.org/vera".to_string(),
+        "URL;TYPE=orcid:https://orcid.org/0000-0002-7182-8185".to_string(),
+        "X-ORCID:0000-0002-7182-8185".to_string(),
+    ] {
+        assert!(card.contains(&format!("\r\n{}\r\n", line)), "missing {:?} in {}", line, card);
+    }
+
+    server.get(&format!("/authors/{}/vcard", uuid::Uuid::new_v4())).await.assert_status_not_found();
+
+    common::purge("authors", &author_id).await;
+}
+
+#[tokio::test]
+async fn test_orphan_authors() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QCRYPT", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    let mut author_ids = Vec::new();
+    for name in ["Orphan", "Author", "Committee"] {
+        let response = server
+            .post("/authors")
+            .json(&json!({"full_name": format!("{} {}", name, unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    let response = server
+        .post("/publications")
+        .json(&json!({"conference_id": conference_id, "title": "Keeps Its Author", "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": publication_id,
+            "author_id": author_ids[1],
+            "author_position": 1,
+            "published_as_name": "Author",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::CREATED);
+    server
+        .post("/committees")
+        .json(&json!({
+            "conference_id": conference_id,
+            "author_id": author_ids[2],
+            "committee": "PC",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::CREATED);
+
+    let orphan_ids = || async {
+        let response = server.get(&format!("/authors/orphans?limit={}", quantumdb::utils::max_page_size())).await;
+        response.assert_status_ok();
+        response
+            .json::<Vec<serde_json::Value>>()
+            .iter()
+            .map(|a| a["id"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+    let orphans = orphan_ids().await;
+    assert!(orphans.contains(&author_ids[0]));
+    assert!(!orphans.contains(&author_ids[1]));
+    assert!(!orphans.contains(&author_ids[2]));
+
+    // Soft-deleted authors are already dealt with
+    server.delete(&format!("/authors/{}", author_ids[0])).await.assert_status(axum::http::StatusCode::NO_CONTENT);
+    assert!(!orphan_ids().await.contains(&author_ids[0]));
+
+    server.get("/authors/orphans?offset=-1").await.assert_status_bad_request();
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+async fn test_author_review_queue() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QCRYPT", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    // keep, drop and a third spelling that turns out to be someone else
+    let mut author_ids = Vec::new();
+    for name in ["Xavier Quintrell", "Xavier Quintrel", "Xavier Quintrall"] {
+        let response = server
+            .post("/authors")
+            .json(&json!({"full_name": format!("{} {}", name, unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    let (keep, drop, other) = (&author_ids[0], &author_ids[1], &author_ids[2]);
+
+    let mut publication_ids = Vec::new();
+    for (title, authors) in [("Shared Paper", vec![keep, drop]), ("Drop's Paper", vec![drop])] {
+        let response = server
+            .post("/publications")
+            .json(&json!({"conference_id": conference_id, "title": title, "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        for (i, author_id) in authors.iter().enumerate() {
+            server
+                .post("/authorships")
+                .json(&json!({
+                    "publication_id": publication_id,
+                    "author_id": author_id,
+                    "author_position": i + 1,
+                    "published_as_name": "X. Quintrell",
+                    "creator": "test_user",
+                    "modifier": "test_user"
+                }))
+                .await
+                .assert_status(axum::http::StatusCode::CREATED);
+        }
+        publication_ids.push(publication_id);
+    }
+    server
+        .post("/committees")
+        .json(&json!({
+            "conference_id": conference_id,
+            "author_id": drop,
+            "committee": "PC",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::CREATED);
+
+    // Queue entries for pairs among this test's authors, keyed by the pair
+    let queue = |query: &'static str| {
+        let server = &server;
+        let author_ids = &author_ids;
+        async move {
+            let response = server
+                .get(&format!("/review/authors?limit={}{}", quantumdb::utils::max_page_size(), query))
+                .await;
+            response.assert_status_ok();
+            response
+                .json::<Vec<serde_json::Value>>()
+                .into_iter()
+                .filter(|item| {
+                    author_ids.iter().any(|id| id == &item["author"]["id"])
+                        && author_ids.iter().any(|id| id == &item["other_author"]["id"])
+                })
+                .collect::<Vec<_>>()
+        }
+    };
+    let entry_for = |items: &[serde_json::Value], a: &str, b: &str| {
+        items
+            .iter()
+            .find(|item| {
+                let pair = [item["author"]["id"].as_str().unwrap(), item["other_author"]["id"].as_str().unwrap()];
+                pair.contains(&a) && pair.contains(&b)
+            })
+            .cloned()
+    };
+
+    // Listing is a plain read; only a scan detects pairs
+    assert!(queue("").await.is_empty());
+    let response = server.post("/review/authors/scan").await;
+    response.assert_status_ok();
+    assert!(response.json::<serde_json::Value>()["added"].as_u64().unwrap() >= 3);
+
+    let items = queue("").await;
+    assert_eq!(items.len(), 3, "{:?}", items);
+    let merge_entry = entry_for(&items, keep, drop).unwrap();
+    assert_eq!(merge_entry["status"], "pending");
+    assert!(merge_entry["score"].as_f64().unwrap() >= 0.85);
+    let drop_side = if merge_entry["author"]["id"] == *drop { "author" } else { "other_author" };
+    assert_eq!(merge_entry[drop_side]["publication_count"], 2);
+    assert_eq!(merge_entry[drop_side]["committee_role_count"], 1);
+    let separate_id = entry_for(&items, keep, other).unwrap()["id"].as_str().unwrap().to_string();
+    let defer_id = entry_for(&items, drop, other).unwrap()["id"].as_str().unwrap().to_string();
+    let merge_id = merge_entry["id"].as_str().unwrap().to_string();
+
+    let resolve = |id: String, body: serde_json::Value| {
+        let server = &server;
+        async move { server.post(&format!("/review/authors/{}/resolve", id)).json(&body).await }
+    };
+
+    let response = resolve(
+        separate_id.clone(),
+        json!({"decision": "keep_separate", "note": "Different groups", "modifier": "curator"}),
+    )
+    .await;
+    response.assert_status_ok();
+    let resolved = response.json::<serde_json::Value>();
+    assert_eq!(resolved["status"], "kept_separate");
+    assert_eq!(resolved["resolved_by"], "curator");
+    assert_eq!(resolved["note"], "Different groups");
+
+    resolve(defer_id.clone(), json!({"decision": "defer", "modifier": "curator"}))
+        .await
+        .assert_status_ok();
+
+    // Decided pairs don't come back, even on a rescan; deferred ones only on
+    // request, after pending ones
+    server.post("/review/authors/scan").await.assert_status_ok();
+    let items = queue("").await;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["id"], merge_id.as_str());
+    let items = queue("&include_deferred=true").await;
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1]["id"], defer_id.as_str());
+    assert_eq!(items[1]["status"], "deferred");
+
+    // Merging requires naming a survivor from the pair
+    resolve(merge_id.clone(), json!({"decision": "merge", "modifier": "curator"}))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    resolve(merge_id.clone(), json!({"decision": "merge", "keep_author_id": other, "modifier": "curator"}))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = resolve(merge_id.clone(), json!({"decision": "merge", "keep_author_id": keep, "modifier": "curator"})).await;
+    response.assert_status_ok();
+    let resolved = response.json::<serde_json::Value>();
+    assert_eq!(resolved["status"], "merged");
+    let keep_side = if resolved["author"]["id"] == *keep { "author" } else { "other_author" };
+    assert_eq!(resolved[keep_side]["publication_count"], 2);
+    assert_eq!(resolved[keep_side]["committee_role_count"], 1);
+
+    server.get(&format!("/authors/{}", drop)).await.assert_status_not_found();
+    for publication_id in &publication_ids {
+        let response = server.get(&format!("/authorships?publication_id={}", publication_id)).await;
+        let authorships = response.json::<Vec<serde_json::Value>>();
+        assert_eq!(authorships.len(), 1, "{:?}", authorships);
+        assert_eq!(authorships[0]["author_id"], keep.as_str());
+    }
+    let response = server.get(&format!("/committees?conference_id={}", conference_id)).await;
+    assert_eq!(response.json::<Vec<serde_json::Value>>()[0]["author_id"], keep.as_str());
+
+    // The deferred pair went with the merged author; a decided pair can't be re-decided
+    assert!(queue("&include_deferred=true").await.is_empty());
+    resolve(merge_id, json!({"decision": "keep_separate", "modifier": "curator"}))
+        .await
+        .assert_status(axum::http::StatusCode::CONFLICT);
+    resolve(Uuid::new_v4().to_string(), json!({"decision": "defer", "modifier": "curator"}))
+        .await
+        .assert_status_not_found();
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+async fn test_author_talks() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Speaker {}", unique_suffix),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "timezone": "Asia/Taipei", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    // Authors both papers, presents only the first
+    let mut publication_ids = Vec::new();
+    for title in ["Presented Talk", "Co-authored Only"] {
+        let response = server
+            .post("/publications")
+            .json(&json!({
+                "conference_id": conference_id,
+                "title": title,
+                "session_name": "Plenary",
+                "talk_date": "2024-01-15",
+                "talk_time": "09:30:00",
+                "duration_minutes": 25,
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        server
+            .post("/authorships")
+            .json(&json!({
+                "publication_id": publication_id,
+                "author_id": author_id,
+                "author_position": 1,
+                "published_as_name": "Speaker",
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+        publication_ids.push(publication_id);
+    }
+    server
+        .patch(&format!("/publications/{}", publication_ids[0]))
+        .json(&json!({"presenter_author_id": author_id, "modifier": "test_user"}))
+        .await
+        .assert_status_ok();
+
+    let response = server.get(&format!("/authors/{}/talks", author_id)).await;
+    response.assert_status_ok();
+    let talks: Vec<serde_json::Value> = response.json();
+    assert_eq!(talks.len(), 1);
+    assert_eq!(talks[0]["publication_id"], publication_ids[0].as_str());
+    assert_eq!(talks[0]["conference_id"], conference_id.as_str());
+    assert_eq!(talks[0]["session_name"], "Plenary");
+    assert_eq!(talks[0]["talk_date"], "2024-01-15");
+    assert_eq!(talks[0]["talk_time"], "09:30:00");
+    assert_eq!(talks[0]["duration_minutes"], 25);
+    // 09:30 in Taipei (UTC+8)
+    assert_eq!(talks[0]["talk_datetime_utc"], "2024-01-15T01:30:00Z");
+
+    let publication: serde_json::Value = server.get(&format!("/publications/{}", publication_ids[1])).await.json();
+    assert_eq!(publication["talk_datetime_utc"], "2024-01-15T01:30:00Z");
+    let publication: serde_json::Value = server
+        .patch(&format!("/publications/{}", publication_ids[1]))
+        .json(&json!({"talk_time": null, "modifier": "test_user"}))
+        .await
+        .json();
+    assert!(publication["talk_datetime_utc"].is_null(), "No UTC instant without a talk time");
+
+    // Without a conference timezone there is nothing to convert from
+    server
+        .patch(&format!("/conferences/{}", conference_id))
+        .json(&json!({"timezone": null, "modifier": "test_user"}))
+        .await
+        .assert_status_ok();
+    let talks: Vec<serde_json::Value> = server.get(&format!("/authors/{}/talks", author_id)).await.json();
+    assert!(talks[0]["talk_datetime_utc"].is_null());
+
+    // Daylight saving: a skipped local time has no instant, a repeated one is
+    // the earlier of the two, and an unknown zone converts nothing
+    let talk_at = |timezone: &'static str, date: &'static str, time: &'static str| {
+        let server = &server;
+        let (conference_id, publication_id) = (&conference_id, &publication_ids[1]);
+        async move {
+            server
+                .patch(&format!("/conferences/{}", conference_id))
+                .json(&json!({"timezone": timezone, "modifier": "test_user"}))
+                .await
+                .assert_status_ok();
+            server
+                .patch(&format!("/publications/{}", publication_id))
+                .json(&json!({"talk_date": date, "talk_time": time, "modifier": "test_user"}))
+                .await
+                .json::<serde_json::Value>()["talk_datetime_utc"]
+                .clone()
+        }
+    };
+    assert_eq!(talk_at("America/New_York", "2024-07-03", "09:00:00").await, "2024-07-03T13:00:00Z");
+    assert!(talk_at("America/New_York", "2024-03-10", "02:30:00").await.is_null());
+    assert_eq!(talk_at("America/New_York", "2024-11-03", "01:30:00").await, "2024-11-03T05:30:00Z");
+    assert!(talk_at("Mars/Olympus_Mons", "2024-07-03", "09:00:00").await.is_null());
+
+    server.get(&format!("/authors/{}/talks", Uuid::new_v4())).await.assert_status_not_found();
+
+    for id in &publication_ids {
+        common::purge("publications", id).await;
+    }
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+    common::purge("authors", &author_id).await;
+}
+
+/// Serve `record` for `GET /{orcid}/record` and 404 for anything else, on a
+/// local port standing in for the ORCID public API. Returns its base URL.
+async fn mock_orcid_api(orcid: &'static str, record: serde_json::Value) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let body = record.to_string();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                match socket.read(&mut buf).await {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&buf[..n]),
+                }
+            }
+            let found = String::from_utf8_lossy(&request).starts_with(&format!("GET /{}/record ", orcid));
+            let response = if found {
+                format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\r\n{}", body)
+            } else {
+                "HTTP/1.1 404 Not Found\r\n\r\n".to_string()
+            };
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    });
+    base
+}
+
+#[tokio::test]
+#[serial]
+async fn test_enrich_author_from_orcid() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+    let orcid = "0000-0003-2718-2818";
+    let base = mock_orcid_api(
+        orcid,
+        json!({
+            "person": {
+                "name": {"given-names": {"value": "Leonhard"}, "family-name": {"value": "Euler"}},
+                "researcher-urls": {"researcher-url": [{"url": {"value": "https://euler.example.org"}}]}
+            },
+            "activities-summary": {"employments": {"affiliation-group": [
+                {"summaries": [{"employment-summary": {"organization": {"name": "St. Petersburg Academy"}, "end-date": null}}]}
+            ]}}
+        }),
+    )
+    .await;
+    std::env::set_var("ORCID_API_URL", &base);
+
+    let create = |name: &str, orcid: Option<&str>| {
+        server.post("/authors").json(&json!({
+            "full_name": format!("Enrich {} {}", name, unique_suffix),
+            "given_name": "L.",
+            "orcid": orcid,
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+    };
+    let author_id = create("Author", Some(orcid)).await.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    // Only empty fields are filled
+    let response = server.post(&format!("/authors/{}/enrich/orcid?modifier=test_user", author_id)).await;
+    response.assert_status_ok();
+    let body = response.json::<serde_json::Value>();
+    assert_eq!(body["updated_fields"], json!(["family_name", "homepage_url", "affiliation"]));
+    assert_eq!(body["author"]["given_name"], "L.");
+    assert_eq!(body["author"]["family_name"], "Euler");
+    assert_eq!(body["author"]["homepage_url"], "https://euler.example.org");
+    assert_eq!(body["author"]["affiliation"], "St. Petersburg Academy");
+
+    // Nothing left to fill
+    let response = server.post(&format!("/authors/{}/enrich/orcid", author_id)).await;
+    assert_eq!(response.json::<serde_json::Value>()["updated_fields"], json!([]));
+
+    // force overwrites what differs
+    let response = server.post(&format!("/authors/{}/enrich/orcid?force=true", author_id)).await;
+    let body = response.json::<serde_json::Value>();
+    assert_eq!(body["updated_fields"], json!(["given_name"]));
+    assert_eq!(body["author"]["given_name"], "Leonhard");
+
+    // An author without an ORCID iD, and one ORCID doesn't know
+    let no_orcid_id = create("No Orcid", None).await.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    server
+        .post(&format!("/authors/{}/enrich/orcid", no_orcid_id))
+        .await
+        .assert_status(axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+    let unknown_id = create("Unknown Orcid", Some("0000-0003-2718-2819")).await.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    server.post(&format!("/authors/{}/enrich/orcid", unknown_id)).await.assert_status_not_found();
+
+    std::env::remove_var("ORCID_API_URL");
+    for id in [&author_id, &no_orcid_id, &unknown_id] {
+        common::purge("authors", id).await;
+    }
+}
+
+/// Local webhook receiver: answers every POST with 204 and forwards its JSON
+/// body on the returned channel.
+async fn mock_webhook_receiver() -> (String, tokio::sync::mpsc::UnboundedReceiver<serde_json::Value>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let sender = sender.clone();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                loop {
+                    if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                        let head = String::from_utf8_lossy(&request[..end]).to_lowercase();
+                        let length: usize = head
+                            .lines()
+                            .find_map(|l| l.strip_prefix("content-length:"))
+                            .and_then(|v| v.trim().parse().ok())
+                            .unwrap_or(0);
+                        if request.len() >= end + 4 + length {
+                            if let Ok(body) = serde_json::from_slice(&request[end + 4..end + 4 + length]) {
+                                let _ = sender.send(body);
+                            }
+                            break;
+                        }
+                    }
+                    match socket.read(&mut buf).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                // Close after each reply so the client never reuses a socket this end drops
+                let _ = socket.write_all(b"HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n").await;
+            });
+        }
+    });
+    (url, receiver)
+}
+
+/// Next webhook event about `id`, skipping events for other records.
+async fn next_event_for(
+    receiver: &mut tokio::sync::mpsc::UnboundedReceiver<serde_json::Value>,
+    id: &str,
+) -> serde_json::Value {
+    tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            let event = receiver.recv().await.expect("webhook receiver closed");
+            if event["id"] == id {
+                return event;
+            }
+        }
+    })
+    .await
+    .expect("no webhook event within 5s")
+}
+
+#[tokio::test]
+#[serial]
+async fn test_webhook_on_author_changes() {
+    let server = setup().await;
+    let (url, mut events) = mock_webhook_receiver().await;
+    std::env::set_var("WEBHOOK_URL", &url);
+
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Webhook Author {}", Uuid::new_v4().simple()),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let event = next_event_for(&mut events, &author_id).await;
+    assert_eq!(event["event"], "created");
+    assert_eq!(event["entity_type"], "author");
+    assert!(event["timestamp"].is_string());
+
+    server
+        .patch(&format!("/authors/{}", author_id))
+        .json(&json!({"affiliation": "Webhook Lab", "modifier": "test_user"}))
+        .await
+        .assert_status_ok();
+    assert_eq!(next_event_for(&mut events, &author_id).await["event"], "updated");
+
+    server.delete(&format!("/authors/{}?modifier=test_user", author_id)).await;
+    assert_eq!(next_event_for(&mut events, &author_id).await["event"], "deleted");
+
+    std::env::remove_var("WEBHOOK_URL");
+    common::purge("authors", &author_id).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_webhook_on_side_effect_changes() {
+    let server = setup().await;
+    let (url, mut events) = mock_webhook_receiver().await;
+    std::env::set_var("WEBHOOK_URL", &url);
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+    let acronym = format!("SEI{}", &unique_suffix[..6]);
+
+    // Authors re-linked by a new institution are announced as updated
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Side Effect Author {}", unique_suffix),
+            "affiliation": format!("Dept. of Physics, {}", acronym),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    assert_eq!(next_event_for(&mut events, &author_id).await["event"], "created");
+
+    let response = server
+        .post("/institutions")
+        .json(&json!({
+            "name": format!("Side Effect Institute {}", unique_suffix),
+            "aliases": [acronym],
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let institution_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    let event = next_event_for(&mut events, &author_id).await;
+    assert_eq!(event["event"], "updated");
+    assert_eq!(event["entity_type"], "author");
+
+    // Authorships removed by a cascading conference delete are announced
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let response = server
+        .post("/publications")
+        .json(&json!({"conference_id": conference_id, "title": "Side Effects", "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    let response = server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": publication_id,
+            "author_id": author_id,
+            "author_position": 1,
+            "published_as_name": "Side Effect Author",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    response.assert_status(axum::http::StatusCode::CREATED);
+    let authorship_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    assert_eq!(next_event_for(&mut events, &authorship_id).await["event"], "created");
+
+    server
+        .delete(&format!("/conferences/{}?cascade=true", conference_id))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+    let event = next_event_for(&mut events, &authorship_id).await;
+    assert_eq!(event["event"], "deleted");
+    assert_eq!(event["entity_type"], "authorship");
+    assert_eq!(next_event_for(&mut events, &publication_id).await["event"], "deleted");
+
+    std::env::remove_var("WEBHOOK_URL");
+    common::purge("authors", &author_id).await;
+    common::purge("institutions", &institution_id).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_webhook_on_conference_wipe() {
+    let server = setup().await;
+    let (url, mut events) = mock_webhook_receiver().await;
+    std::env::set_var("WEBHOOK_URL", &url);
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QCRYPT", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    assert_eq!(next_event_for(&mut events, &conference_id).await["event"], "created");
+    for title in ["Wiped One", "Wiped Two"] {
+        server
+            .post("/publications")
+            .json(&json!({"conference_id": conference_id, "title": title, "creator": "test_user", "modifier": "test_user"}))
+            .await
+            .assert_status(axum::http::StatusCode::CREATED);
+    }
+
+    // One summary event instead of one per row
+    server
+        .delete(&format!("/conferences/{}/publications?modifier=curator", conference_id))
+        .await
+        .assert_status_ok();
+    let event = next_event_for(&mut events, &conference_id).await;
+    assert_eq!(event["event"], "publications_wiped");
+    assert_eq!(event["entity_type"], "conference");
+    assert_eq!(event["count"], 2);
+
+    std::env::remove_var("WEBHOOK_URL");
+    server
+        .delete(&format!("/conferences/{}", conference_id))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_webhook_on_publication_merge() {
+    let server = setup().await;
+    let (url, mut events) = mock_webhook_receiver().await;
+    std::env::set_var("WEBHOOK_URL", &url);
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let mut publication_ids = Vec::new();
+    for key in ["keep", "source"] {
+        let response = server
+            .post("/publications")
+            .json(&json!({"conference_id": conference_id, "title": format!("Merge Webhook {}", key), "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        publication_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    let (keep_id, source_id) = (&publication_ids[0], &publication_ids[1]);
+
+    // The shared author's source authorship is dropped; the other one moves
+    let mut author_ids = Vec::new();
+    for n in 1..=2 {
+        let response = server
+            .post("/authors")
+            .json(&json!({"full_name": format!("Merge Webhook Author {} {}", n, unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    let mut authorship_ids = Vec::new();
+    for (publication_id, author_id, position) in [
+        (keep_id, &author_ids[0], 1),
+        (source_id, &author_ids[0], 1),
+        (source_id, &author_ids[1], 2),
+    ] {
+        let response = server
+            .post("/authorships")
+            .json(&json!({
+                "publication_id": publication_id,
+                "author_id": author_id,
+                "author_position": position,
+                "published_as_name": "Merge Webhook Author",
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        response.assert_status(axum::http::StatusCode::CREATED);
+        let authorship_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        assert_eq!(next_event_for(&mut events, &authorship_id).await["event"], "created");
+        authorship_ids.push(authorship_id);
+    }
+
+    server
+        .post(&format!("/publications/{}/merge", keep_id))
+        .json(&json!({"source_id": source_id, "modifier": "curator"}))
+        .await
+        .assert_status_ok();
+    let event = next_event_for(&mut events, &authorship_ids[1]).await;
+    assert_eq!((event["event"].as_str(), event["entity_type"].as_str()), (Some("deleted"), Some("authorship")));
+    let event = next_event_for(&mut events, &authorship_ids[2]).await;
+    assert_eq!((event["event"].as_str(), event["entity_type"].as_str()), (Some("updated"), Some("authorship")));
+    assert_eq!(next_event_for(&mut events, source_id).await["event"], "deleted");
+
+    std::env::remove_var("WEBHOOK_URL");
+    for publication_id in &publication_ids {
+        common::purge("publications", publication_id).await;
+    }
+    for author_id in &author_ids {
+        common::purge("authors", author_id).await;
+    }
+    server
+        .delete(&format!("/conferences/{}", conference_id))
+        .await
+        .assert_status(axum::http::StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_event_stream() {
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    // The stream never ends, so read it frame by frame instead of through TestServer
+    let app = common::create_test_app(common::create_test_pool().await);
+    let response = app
+        .oneshot(axum::http::Request::get("/events").body(axum::body::Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), axum::http::StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "text/event-stream");
+    let mut body = response.into_body();
+
+    let server = setup().await;
+    let response = server
+        .post("/authors")
+        .json(&json!({
+            "full_name": format!("Stream Author {}", Uuid::new_v4().simple()),
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let event = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            let frame = body.frame().await.expect("stream ended").unwrap();
+            let Ok(chunk) = frame.into_data() else { continue };
+            let text = String::from_utf8_lossy(&chunk).to_string();
+            if let Some(data) = text.lines().find_map(|l| l.strip_prefix("data: ")) {
+                let event: serde_json::Value = serde_json::from_str(data).unwrap();
+                if event["id"] == author_id.as_str() {
+                    return event;
+                }
+            }
+        }
+    })
+    .await
+    .expect("no change event within 5s");
+    assert_eq!(event["event"], "created");
+    assert_eq!(event["entity_type"], "author");
+
+    common::purge("authors", &author_id).await;
+}
+
+#[tokio::test]
+async fn test_conference_reference_resolution() {
+    let server = setup().await;
+
+    // A malformed reference is a 400 wherever a conference is named
+    for path in [
+        "/conferences/FOO2024",
+        "/conferences/FOO2024/chairs",
+        "/publications?conference=FOO2024",
+        "/committees?conference=FOO2024",
+        "/publications/duplicates?conference=FOO2024",
+    ] {
+        server.get(path).await.assert_status_bad_request();
+    }
+
+    // A well-formed UUID that matches nothing is a 404
+    server.get(&format!("/conferences/{}", Uuid::new_v4())).await.assert_status_not_found();
+}
+
+#[tokio::test]
+async fn test_author_publications_by_position() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let mut author_ids = Vec::new();
+    for name in ["Lead", "Middle", "Senior"] {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("{} Position Author {}", name, unique_suffix),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+    let (lead, middle, senior) = (&author_ids[0], &author_ids[1], &author_ids[2]);
+
+    let conference = create_test_conference(
+        &server,
+        json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"}),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+
+    // Paper A: lead, middle, senior. Paper B: middle, lead
+    let mut publication_ids = Vec::new();
+    for (key, byline) in [("a", vec![lead, middle, senior]), ("b", vec![middle, lead])] {
+        let response = server
+            .post("/publications")
+            .json(&json!({
+                "conference_id": conference_id,
+                "canonical_key": format!("position-{}-{}", key, unique_suffix),
+                "title": format!("Paper {}", key.to_uppercase()),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        for (i, author_id) in byline.iter().enumerate() {
+            server
+                .post("/authorships")
+                .json(&json!({
+                    "publication_id": publication_id,
+                    "author_id": author_id,
+                    "author_position": i + 1,
+                    "published_as_name": "Position Author",
+                    "creator": "test_user",
+                    "modifier": "test_user"
+                }))
+                .await
+                .assert_status(axum::http::StatusCode::CREATED);
+        }
+        publication_ids.push(publication_id);
+    }
+
+    let titles = |body: serde_json::Value| -> Vec<String> {
+        body.as_array().unwrap().iter().map(|p| p["title"].as_str().unwrap().to_string()).collect()
+    };
+
+    let response = server.get(&format!("/authors/{}/publications", lead)).await;
+    response.assert_status_ok();
+    let all: serde_json::Value = response.json();
+    assert_eq!(titles(all.clone()), ["Paper A", "Paper B"]);
+    assert_eq!(all[1]["author_position"], 2);
+    assert_eq!(all[1]["author_count"], 2);
+
+    let by_position = |id: &str, position: &str| server.get(&format!("/authors/{}/publications?position={}", id, position));
+    assert_eq!(titles(by_position(lead, "first").await.json()), ["Paper A"]);
+    // Last is per paper: position 2 of 2 on B
+    assert_eq!(titles(by_position(lead, "last").await.json()), ["Paper B"]);
+    assert_eq!(titles(by_position(senior, "last").await.json()), ["Paper A"]);
+    assert!(titles(by_position(middle, "last").await.json()).is_empty());
+
+    by_position(lead, "middle").await.assert_status_bad_request();
+    server.get(&format!("/authors/{}/publications", Uuid::new_v4())).await.assert_status_not_found();
+
+    for id in &publication_ids {
+        common::purge("publications", id).await;
+    }
+    server.delete(&format!("/conferences/{}", conference_id)).await;
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+async fn test_conference_new_authors() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let mut author_ids = Vec::new();
+    for name in ["Veteran", "Newcomer"] {
+        let response = server
+            .post("/authors")
+            .json(&json!({
+                "full_name": format!("{} {}", name, unique_suffix),
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        author_ids.push(response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string());
+    }
+
+    // The veteran publishes in both years (at different venues), the newcomer only in the later one
+    let conferences = create_test_conferences(
+        &server,
+        &[
+            (0, json!({"venue": "QIP", "creator": "test_user", "modifier": "test_user"})),
+            (1, json!({"venue": "TQC", "creator": "test_user", "modifier": "test_user"})),
+        ],
+    )
+    .await;
+    let mut conference_ids = Vec::new();
+    let mut publication_ids = Vec::new();
+    for (conference, authors) in conferences.iter().zip([vec![&author_ids[0]], vec![&author_ids[0], &author_ids[1]]]) {
+        let venue = conference["venue"].as_str().unwrap();
+        let conference_id = conference["id"].as_str().unwrap().to_string();
+        let response = server
+            .post("/publications")
+            .json(&json!({
+                "conference_id": conference_id,
+                "canonical_key": format!("new-authors-{}-{}", venue, unique_suffix),
+                "title": "Newcomer Test Publication",
+                "creator": "test_user",
+                "modifier": "test_user"
+            }))
+            .await;
+        let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+        for (i, author_id) in authors.iter().enumerate() {
+            server
+                .post("/authorships")
+                .json(&json!({
+                    "publication_id": publication_id,
+                    "author_id": author_id,
+                    "author_position": i + 1,
+                    "published_as_name": "Someone",
+                    "creator": "test_user",
+                    "modifier": "test_user"
+                }))
+                .await
+                .assert_status(axum::http::StatusCode::CREATED);
+        }
+        conference_ids.push(conference_id);
+        publication_ids.push(publication_id);
+    }
+
+    let new_authors = |conference_id: &str| server.get(&format!("/conferences/{}/new-authors", conference_id));
+    let ids = |body: serde_json::Value| -> Vec<String> {
+        body.as_array().unwrap().iter().map(|a| a["id"].as_str().unwrap().to_string()).collect()
+    };
+    let response = new_authors(&conference_ids[0]).await;
+    response.assert_status_ok();
+    assert_eq!(ids(response.json()), [author_ids[0].clone()]);
+    let response = new_authors(&conference_ids[1]).await;
+    let body: serde_json::Value = response.json();
+    assert_eq!(ids(body.clone()), [author_ids[1].clone()]);
+    assert_eq!(body[0]["publication_count"], 1);
+
+    new_authors(&Uuid::new_v4().to_string()).await.assert_status_not_found();
+
+    for id in &publication_ids {
+        common::purge("publications", id).await;
+    }
+    for id in &conference_ids {
+        server.delete(&format!("/conferences/{}", id)).await;
+    }
+    for id in &author_ids {
+        common::purge("authors", id).await;
+    }
+}
+
+#[tokio::test]
+async fn test_conference_proceedings_bib() {
+    let server = setup().await;
+    let unique_suffix = Uuid::new_v4().simple().to_string();
+
+    let conference = create_test_conference(
+        &server,
+        json!({
+            "venue": "TQC",
+            "city": "Okinawa",
+            "country": "Japan",
+            "proceedings_publisher": "Schloss Dagstuhl",
+            "proceedings_volume": "LIPIcs 999",
+            "proceedings_doi": "10.4230/LIPIcs.TQC.9999",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }),
+    )
+    .await;
+    let conference_id = conference["id"].as_str().unwrap().to_string();
+    let test_year = conference["year"].as_i64().unwrap();
+
+    let response = server
+        .post("/authors")
+        .json(&json!({"full_name": format!("Bib Author {}", unique_suffix), "creator": "test_user", "modifier": "test_user"}))
+        .await;
+    let author_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+
+    let response = server
+        .post("/publications")
+        .json(&json!({
+            "conference_id": conference_id,
+            "canonical_key": format!("tqc{}-magic", test_year),
+            "title": "Magic States & 100% Fidelity",
+            "pages": "1:1--1:20",
+            "arxiv_ids": ["2401.00001"],
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await;
+    let publication_id = response.json::<serde_json::Value>()["id"].as_str().unwrap().to_string();
+    server
+        .post("/authorships")
+        .json(&json!({
+            "publication_id": publication_id,
+            "author_id": author_id,
+            "author_position": 1,
+            "published_as_name": "B. Author",
+            "creator": "test_user",
+            "modifier": "test_user"
+        }))
+        .await
+        .assert_status(axum::http::StatusCode::CREATED);
+
+    let response = server.get(&format!("/conferences/{}/proceedings.bib", conference_id)).await;
+    response.assert_status_ok();
+    assert_eq!(response.header("content-type"), "application/x-bibtex; charset=utf-8");
+    let bib = response.text();
+    let proceedings_key = format!("tqc{}", test_year);
+    let booktitle = format!(
+        "Conference on the Theory of Quantum Computation, Communication and Cryptography (TQC {})",
+        test_year
+    );
+    assert_eq!(
+        bib,
+        format!(
+            "@inproceedings{{tqc{year}-magic,\n  author = {{B. Author}},\n  title = {{Magic States \\& 100\\% Fidelity}},\n  \
+             year = {{{year}}},\n  pages = {{1:1--1:20}},\n  eprint = {{2401.00001}},\n  archiveprefix = {{arXiv}},\n  \
+             crossref = {{{key}}},\n}}\n\n\
+             @proceedings{{{key},\n  title = {{{booktitle}}},\n  booktitle = {{{booktitle}}},\n  year = {{{year}}},\n  \
+             publisher = {{Schloss Dagstuhl}},\n  volume = {{LIPIcs 999}},\n  address = {{Okinawa, Japan}},\n  \
+             doi = {{10.4230/LIPIcs.TQC.9999}},\n}}\n",
+            year = test_year,
+            key = proceedings_key,
+            booktitle = booktitle,
+        )
+    );
+
+    server.get(&format!("/conferences/{}/proceedings.bib", Uuid::new_v4())).await.assert_status_not_found();
+
+    server.delete(&format!("/conferences/{}?cascade=true", conference_id)).await;
+    common::purge("authors", &author_id).await;
+}
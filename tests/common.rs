@@ -22,18 +22,86 @@ pub fn create_test_app(pool: Pool<Postgres>) -> Router {
         .route("/", get(|| async { "QuantumDB API - Test" }))
         // Conference routes
         .route("/conferences", get(handlers::list_conferences).post(handlers::create_conference))
-        .route("/conferences/{id}", get(handlers::get_conference).put(handlers::update_conference).delete(handlers::delete_conference))
+        .route("/conferences/trends", get(handlers::conference_trends))
+        .route("/conferences/compare", get(handlers::compare_conferences))
+        .route("/conferences/geo", get(handlers::conference_geo))
+        .route("/conferences/index.json", get(handlers::conference_index))
+        .route("/conferences/{id}", get(handlers::get_conference).put(handlers::update_conference).patch(handlers::patch_conference).delete(handlers::delete_conference))
+        .route("/conferences/{id}/publications", axum::routing::delete(handlers::delete_conference_publications))
+        .route("/conferences/{id}/committees", axum::routing::delete(handlers::delete_conference_committees))
+        .route("/conferences/{id}/chairs", get(handlers::conference_chairs))
+        .route("/conferences/{id}/committee-anomalies", get(handlers::conference_committee_anomalies))
+        .route("/conferences/{id}/history", get(handlers::conference_history))
+        .route("/conferences/{id}/affiliations", get(handlers::conference_affiliations))
+        .route("/conferences/{id}/new-authors", get(handlers::conference_new_authors))
+        .route("/conferences/{id}/proceedings.bib", get(handlers::conference_proceedings_bib))
         // Author routes
         .route("/authors", get(handlers::list_authors).post(handlers::create_author))
-        .route("/authors/{id}", get(handlers::get_author).put(handlers::update_author).delete(handlers::delete_author))
+        .route("/authors/top", get(handlers::top_authors))
+        .route("/authors/orphans", get(handlers::orphan_authors))
+        .route("/authors/autocomplete", get(handlers::author_autocomplete))
+        .route("/authors/by-orcid/{orcid}", get(handlers::get_author_by_orcid))
+        .route("/authors/{id}", get(handlers::get_author).put(handlers::update_author).patch(handlers::patch_author).delete(handlers::delete_author))
+        .route("/authors/{id}/restore", axum::routing::post(handlers::restore_author))
+        .route("/authors/{id}/enrich/orcid", axum::routing::post(handlers::enrich_author_from_orcid))
+        .route("/authors/{id}/timeline", get(handlers::author_timeline))
+        .route("/authors/{id}/publications", get(handlers::author_publications))
+        .route("/authors/{id}/talks", get(handlers::author_talks))
+        .route("/authors/{id}/vcard", get(handlers::author_vcard))
+        .route("/authors/{id}/committee-timeline", get(handlers::author_committee_timeline))
+        .route("/authors/{id}/committee-collaborators", get(handlers::author_committee_collaborators))
+        .route("/authors/{id}/history", get(handlers::author_history))
         // Publication routes
         .route("/publications", get(handlers::list_publications).post(handlers::create_publication))
-        .route("/publications/{id}", get(handlers::get_publication).put(handlers::update_publication).delete(handlers::delete_publication))
+        .route("/publications/duplicates", get(handlers::publication_duplicates))
+        .route("/publications/{id}", get(handlers::get_publication).put(handlers::update_publication).patch(handlers::patch_publication).delete(handlers::delete_publication))
+        .route("/awards", get(handlers::list_awards))
+        .route("/publications/{id}/related", get(handlers::related_publications))
+        .route("/publications/{id}/restore", axum::routing::post(handlers::restore_publication))
+        .route("/publications/{id}/merge", axum::routing::post(handlers::merge_publication))
+        .route("/publications/{id}/authorships/order", axum::routing::put(handlers::reorder_authorships))
+        .route("/publications/{id}/topics", get(handlers::get_publication_topics).post(handlers::add_publication_topics))
+        .route("/publications/{id}/topics/{topic}", axum::routing::delete(handlers::delete_publication_topic))
+        .route("/topics", get(handlers::list_topics))
         // Committee routes
         .route("/committees", get(handlers::list_committee_roles).post(handlers::create_committee_role))
-        .route("/committees/{id}", get(handlers::get_committee_role).put(handlers::update_committee_role).delete(handlers::delete_committee_role))
+        .route("/committees/{id}", get(handlers::get_committee_role).put(handlers::update_committee_role).patch(handlers::patch_committee_role).delete(handlers::delete_committee_role))
         // Authorship routes
         .route("/authorships", get(handlers::list_authorships).post(handlers::create_authorship))
-        .route("/authorships/{id}", get(handlers::get_authorship).put(handlers::update_authorship).delete(handlers::delete_authorship))
+        .route("/authorships/{id}", get(handlers::get_authorship).put(handlers::update_authorship).patch(handlers::patch_authorship).delete(handlers::delete_authorship))
+        // Statistics
+        .route("/stats", get(handlers::global_stats))
+        // Institutions
+        .route("/institutions", get(handlers::list_institutions).post(handlers::create_institution))
+        .route("/events", get(handlers::change_stream))
+        .route("/utils/name-variants", get(handlers::name_variants))
+        .route("/utils/normalize", get(handlers::normalized_name))
+        .route("/review/authors", get(handlers::list_author_reviews))
+        .route("/review/authors/scan", axum::routing::post(handlers::scan_author_reviews))
+        .route("/review/authors/{id}/resolve", axum::routing::post(handlers::resolve_author_review))
+        // Admin routes
+        .route("/admin/refresh-stats", axum::routing::post(handlers::web::refresh_stats))
+        .route("/admin/reindex-search", axum::routing::post(handlers::web::reindex_search))
+        // Public web routes
+        .route("/feed.atom", get(handlers::web::feed))
+        .layer(axum::middleware::from_fn_with_state(pool.clone(), quantumdb::middleware::idempotency_middleware))
         .with_state(pool)
 }
+
+/// Permanently remove rows that the API only soft-deletes (or can't delete at
+/// all, like institutions), for tests whose leftovers would otherwise collide
+/// on the next run (unique ORCIDs and ROR ids, or conferences that can't be
+/// deleted while publications still reference them).
+pub async fn purge(table: &str, id: &str) {
+    assert!(
+        matches!(table, "authors" | "publications" | "institutions"),
+        "purge only handles tables without a hard delete endpoint"
+    );
+    let pool = create_test_pool().await;
+    let id = uuid::Uuid::parse_str(id).expect("purge expects a UUID");
+    sqlx::query(&format!("DELETE FROM {} WHERE id = $1", table))
+        .bind(id)
+        .execute(&pool)
+        .await
+        .expect("Failed to purge test row");
+}
@@ -156,10 +156,11 @@ async def main(commit: bool):
                 # Finally delete the duplicate author
                 await conn.execute('DELETE FROM authors WHERE id = $1', dup_id)
 
-        # Refresh materialized views (after data changes)
-        await conn.execute('REFRESH MATERIALIZED VIEW CONCURRENTLY author_stats')
-        await conn.execute('REFRESH MATERIALIZED VIEW CONCURRENTLY conference_stats')
-        await conn.execute('REFRESH MATERIALIZED VIEW CONCURRENTLY coauthor_pairs')
+        # Recompute the stats tables (after data changes)
+        await conn.execute('SELECT refresh_author_stats()')
+        await conn.execute('SELECT refresh_conference_stats()')
+        await conn.execute('SELECT refresh_coauthor_pairs()')
+        await conn.execute('SELECT refresh_committee_pairs()')
 
     n_authors = await conn.fetchval('SELECT COUNT(*) FROM authors')
     n_variants = await conn.fetchval('SELECT COUNT(*) FROM author_name_variants')
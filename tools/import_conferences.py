@@ -0,0 +1,228 @@
+#!/usr/bin/env python3
+"""Upsert conference records from a CSV or JSON file, keyed on (venue, year).
+
+Seeds a venue's history in one command instead of one ``POST /conferences``
+per edition. Each record needs ``venue`` and ``year``; any other conferences
+column (dates, location, archive URLs, proceedings info, submission and
+acceptance counts) is optional. Empty or missing fields leave the stored value
+untouched, so a file with only archive URLs can fill them in for existing rows.
+
+Input formats:
+    CSV  - header row with column names, one conference per line
+    JSON - a list of objects (or ``{"conferences": [...]}``)
+
+Examples:
+    ./import_conferences.py ../data/tqc_history.csv --dry-run
+    ./import_conferences.py qcrypt.json --modifier alice
+"""
+
+import argparse
+import asyncio
+import csv
+import json
+import logging
+import os
+import sys
+from datetime import date
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+import asyncpg
+from dotenv import load_dotenv
+
+sys.path.insert(0, str(Path(__file__).resolve().parent))
+from scrapers._lib import (
+    MIN_CONFERENCE_YEAR, VENUES, max_conference_year, normalize_country_code,
+)
+
+
+logging.basicConfig(
+    level=logging.INFO,
+    format='%(asctime)s - %(levelname)s - %(message)s'
+)
+logger = logging.getLogger(__name__)
+
+
+# Importable columns and how to parse them from text
+TEXT_FIELDS = [
+    'city', 'country', 'country_code', 'timezone', 'venue_name', 'website_url',
+    'proceedings_url', 'proceedings_publisher', 'proceedings_volume', 'proceedings_doi',
+    'archive_url', 'archive_organizers_url', 'archive_pc_url', 'archive_steering_url',
+    'archive_program_url',
+]
+DATE_FIELDS = ['start_date', 'end_date']
+INT_FIELDS = ['submission_count', 'acceptance_count']
+BOOL_FIELDS = ['is_virtual', 'is_hybrid']
+FIELDS = TEXT_FIELDS + DATE_FIELDS + INT_FIELDS + BOOL_FIELDS
+
+
+def load_records(path: Path) -> List[Dict[str, Any]]:
+    """Read raw records from a .csv or .json file."""
+    if path.suffix.lower() == '.json':
+        data = json.loads(path.read_text(encoding='utf-8'))
+        if isinstance(data, dict):
+            data = data.get('conferences', [])
+        if not isinstance(data, list):
+            raise ValueError(f"{path}: expected a list of conference objects")
+        return data
+
+    with path.open(newline='', encoding='utf-8') as f:
+        return list(csv.DictReader(f))
+
+
+def parse_bool(value: Any) -> bool:
+    if isinstance(value, bool):
+        return value
+    text = str(value).strip().lower()
+    if text in ('true', 'yes', 'y', '1'):
+        return True
+    if text in ('false', 'no', 'n', '0'):
+        return False
+    raise ValueError(f"not a boolean: {value!r}")
+
+
+def parse_record(raw: Dict[str, Any]) -> Dict[str, Any]:
+    """Validate one record; returns venue, year and the non-empty fields.
+
+    Applies the API's checks, so a file the API would reject doesn't get in
+    through the database: the year must be within MIN_CONFERENCE_YEAR to
+    five years ahead, and country_code an assigned ISO 3166-1 alpha-2 code
+    (normalized the same way, so ``u.s.`` is stored as ``US``).
+    """
+    venue = str(raw.get('venue') or '').strip().upper()
+    if venue not in VENUES:
+        raise ValueError(f"unknown venue {raw.get('venue')!r} (expected one of {sorted(VENUES)})")
+    try:
+        year = int(str(raw.get('year')).strip())
+    except ValueError:
+        raise ValueError(f"invalid year {raw.get('year')!r}")
+    if not MIN_CONFERENCE_YEAR <= year <= max_conference_year():
+        raise ValueError(
+            f"year {year} out of range (expected {MIN_CONFERENCE_YEAR} to {max_conference_year()})"
+        )
+
+    record: Dict[str, Any] = {'venue': venue, 'year': year}
+    for field in FIELDS:
+        value = raw.get(field)
+        if value is None or (isinstance(value, str) and not value.strip()):
+            continue
+        if isinstance(value, str):
+            value = value.strip()
+        try:
+            if field in DATE_FIELDS:
+                value = date.fromisoformat(str(value))
+            elif field in INT_FIELDS:
+                value = int(value)
+            elif field in BOOL_FIELDS:
+                value = parse_bool(value)
+            elif field == 'country_code':
+                code = normalize_country_code(str(value))
+                if code is None:
+                    raise ValueError(
+                        f"invalid code {value!r}; expected ISO 3166-1 alpha-2 (e.g. US, GB, CH)"
+                    )
+                value = code
+        except ValueError as e:
+            raise ValueError(f"{field}: {e}")
+        record[field] = value
+
+    unknown = set(raw) - set(FIELDS) - {'venue', 'year'}
+    if unknown:
+        logger.warning(f"{venue} {year}: ignoring unknown columns {sorted(unknown)}")
+    return record
+
+
+async def upsert_conference(
+    conn: asyncpg.Connection,
+    record: Dict[str, Any],
+    modifier: str,
+    dry_run: bool
+) -> str:
+    """Insert or update one conference; returns 'created', 'updated' or 'unchanged'."""
+    fields = [f for f in FIELDS if f in record]
+    existing = await conn.fetchrow(
+        f"SELECT id, {', '.join(FIELDS)} FROM conferences WHERE venue = $1 AND year = $2",
+        record['venue'], record['year']
+    )
+
+    if existing is None:
+        if not dry_run:
+            columns = ['venue', 'year'] + fields + ['creator', 'modifier']
+            values = [record['venue'], record['year']] + [record[f] for f in fields] + [modifier, modifier]
+            placeholders = ', '.join(f'${i}' for i in range(1, len(values) + 1))
+            # ON CONFLICT guards against a concurrent insert between the SELECT and here
+            await conn.execute(
+                f"""INSERT INTO conferences ({', '.join(columns)}) VALUES ({placeholders})
+                    ON CONFLICT (venue, year) DO NOTHING""",
+                *values
+            )
+        logger.info(f"  + {record['venue']} {record['year']}: create ({', '.join(fields) or 'no details'})")
+        return 'created'
+
+    changed = [f for f in fields if existing[f] != record[f]]
+    if not changed:
+        return 'unchanged'
+
+    for f in changed:
+        logger.info(f"  ~ {record['venue']} {record['year']}: {f}: {existing[f]!r} -> {record[f]!r}")
+    if not dry_run:
+        assignments = ', '.join(f'{f} = ${i}' for i, f in enumerate(changed, start=2))
+        await conn.execute(
+            f"""UPDATE conferences
+                SET {assignments}, modifier = ${len(changed) + 2}, updated_at = NOW()
+                WHERE id = $1""",
+            existing['id'], *[record[f] for f in changed], modifier
+        )
+    return 'updated'
+
+
+async def async_main(args: argparse.Namespace) -> int:
+    records = []
+    errors = 0
+    for i, raw in enumerate(load_records(Path(args.file)), start=1):
+        try:
+            records.append(parse_record(raw))
+        except ValueError as e:
+            logger.error(f"Record {i}: {e}")
+            errors += 1
+    if errors:
+        logger.error(f"{errors} invalid record(s); nothing imported")
+        return 1
+
+    # Load environment
+    load_dotenv()
+    database_url = os.environ.get('DATABASE_URL')
+    if not database_url:
+        raise ValueError("DATABASE_URL must be set")
+
+    conn = await asyncpg.connect(database_url)
+    try:
+        logger.info(f"{'[dry run] ' if args.dry_run else ''}Importing {len(records)} conference(s) from {args.file}")
+        counts = {'created': 0, 'updated': 0, 'unchanged': 0}
+        # One transaction: a failing record leaves the table as it was
+        async with conn.transaction():
+            for record in records:
+                counts[await upsert_conference(conn, record, args.modifier, args.dry_run)] += 1
+        logger.info(
+            f"Done: {counts['created']} created, {counts['updated']} updated, "
+            f"{counts['unchanged']} unchanged{' (dry run, nothing written)' if args.dry_run else ''}"
+        )
+    finally:
+        await conn.close()
+    return 0
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(
+        description='Upsert conferences from a CSV or JSON file, keyed on (venue, year)'
+    )
+    parser.add_argument('file', help='CSV (with header row) or JSON file of conference records')
+    parser.add_argument('--dry-run', action='store_true', help="Show what would change; don't write")
+    parser.add_argument('--modifier', default='import_conferences',
+                        help='Recorded as creator/modifier (default: import_conferences)')
+    args = parser.parse_args()
+    return asyncio.run(async_main(args))
+
+
+if __name__ == '__main__':
+    sys.exit(main())
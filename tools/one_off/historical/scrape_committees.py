@@ -3,21 +3,27 @@
 
 import argparse
 import asyncio
+import csv
 import json
 import logging
 import os
 import re
-from dataclasses import dataclass
+import sys
+from dataclasses import asdict, dataclass
 from datetime import datetime
 from pathlib import Path
-from typing import List, Optional, Tuple
+from typing import Dict, List, Optional, Tuple
+from urllib.parse import urlparse
 from uuid import UUID, uuid4
 
 import asyncpg
-from bs4 import BeautifulSoup
+from bs4 import BeautifulSoup, Comment
 import aiohttp
 from dotenv import load_dotenv
 
+sys.path.insert(0, str(Path(__file__).resolve().parents[2]))
+from scrapers._lib import VENUES, clean_name, is_valid_author_name
+
 
 logging.basicConfig(
     level=logging.INFO,
@@ -65,9 +71,45 @@ def get_local_dir(args: argparse.Namespace) -> Path:
     return Path(home) / 'Web'
 
 
+# http://web.archive.org/web/20180101000000/http://qip2018.org/committees.html
+# (the timestamp may be shortened and carry a modifier such as `id_`)
+WAYBACK_URL = re.compile(
+    r'^(?:https?://)?web\.archive\.org/web/(\d{1,14})(?:[a-z]{2}_)?/+(.+)$',
+    re.IGNORECASE
+)
+
+
+def split_wayback_url(url: str) -> Tuple[Optional[str], str]:
+    """Split a Wayback Machine URL into (timestamp, archived URL).
+
+    Returns (None, url) for anything that isn't a Wayback URL.
+    """
+    match = WAYBACK_URL.match(url.strip())
+    if not match:
+        return None, url
+    timestamp, original = match.groups()
+    # Wayback collapses `http://` to `http:/` in some links
+    original = re.sub(r'^(https?):/+', r'\1://', original)
+    return timestamp, original
+
+
+def fetch_url(url: str) -> str:
+    """URL to download. For Wayback snapshots, ask for the raw archived page
+    (`id_`) so links aren't rewritten and the toolbar isn't injected."""
+    timestamp, original = split_wayback_url(url)
+    if timestamp is None:
+        return url
+    return f"https://web.archive.org/web/{timestamp}id_/{original}"
+
+
 def url_to_local_path(args: argparse.Namespace, url: str) -> Path:
-    """Convert URL to local file path."""
+    """Convert URL to local file path.
+
+    Wayback URLs map to the mirror of the archived site, e.g.
+    ``~/Web/qip2018.org/committees.html``.
+    """
     local_dir = get_local_dir(args)
+    _, url = split_wayback_url(url)
     
     # Parse the URL to extract domain and path
     without_protocol = url.removeprefix('http://').removeprefix('https://')
@@ -105,9 +147,9 @@ async def get_conferences_to_scrape(
     """
     params = []
     
-    if args.venue and args.venue.lower() != 'all':
+    if args.venue and args.venue != 'ALL':
         query += " AND venue = $1"
-        params.append(args.venue.upper())
+        params.append(args.venue)
     
     if args.year:
         param_num = len(params) + 1
@@ -140,12 +182,90 @@ async def check_committee_exists(pool: asyncpg.Pool, conference_id: UUID) -> boo
     return count > 0
 
 
+# Archive.org regularly answers with a 502/503 or stalls under load; those are
+# worth retrying, a 404 for a page that was never archived is not
+RETRY_STATUSES = {429, 500, 502, 503, 504}
+FETCH_ATTEMPTS = 3
+FETCH_BACKOFF = 2.0  # seconds before the first retry, doubled after each
+
+
+async def fetch_html(
+    session: aiohttp.ClientSession,
+    url: str,
+    timeout: float,
+    attempts: int = FETCH_ATTEMPTS,
+    backoff: float = FETCH_BACKOFF
+) -> str:
+    """GET ``url`` and return the body, retrying transient failures.
+
+    Connection errors, timeouts and 429/5xx responses are tried up to
+    ``attempts`` times in all, sleeping ``backoff``, ``2 * backoff``, ...
+    in between. Other HTTP errors are raised straight away.
+    """
+    for attempt in range(1, attempts + 1):
+        try:
+            async with session.get(url, timeout=aiohttp.ClientTimeout(total=timeout)) as response:
+                response.raise_for_status()
+                return await response.text()
+        except (aiohttp.ClientError, asyncio.TimeoutError) as e:
+            transient = not isinstance(e, aiohttp.ClientResponseError) or e.status in RETRY_STATUSES
+            if not transient or attempt == attempts:
+                raise
+            delay = backoff * 2 ** (attempt - 1)
+            logger.warning(f"Fetching {url} failed ({e!r}); retrying in {delay:g}s ({attempt}/{attempts - 1})")
+            await asyncio.sleep(delay)
+    raise ValueError("attempts must be at least 1")
+
+
+HOST_INTERVAL = 1.0  # minimum seconds between request starts to one host
+
+
+class PoliteFetcher:
+    """Shared HTTP client for a scrape run.
+
+    At most ``concurrency`` requests are in flight overall, and requests to
+    the same host start at least ``host_interval`` seconds apart. Every
+    Wayback snapshot lives on web.archive.org, so archive scrapes are mostly
+    paced by the interval; the concurrency helps when live sites are mixed in.
+    """
+
+    def __init__(
+        self,
+        session: aiohttp.ClientSession,
+        timeout: float,
+        concurrency: int,
+        host_interval: float = HOST_INTERVAL
+    ):
+        self.session = session
+        self.timeout = timeout
+        self.slots = asyncio.Semaphore(concurrency)
+        self.host_interval = host_interval
+        self.next_start: Dict[str, float] = {}
+
+    async def _wait_for_host(self, host: str) -> None:
+        # No await between reading and booking the slot, so tasks can't
+        # claim the same one
+        now = asyncio.get_running_loop().time()
+        start = max(now, self.next_start.get(host, now))
+        self.next_start[host] = start + self.host_interval
+        await asyncio.sleep(start - now)
+
+    async def get(self, url: str) -> str:
+        async with self.slots:
+            await self._wait_for_host(urlparse(url).hostname or '')
+            return await fetch_html(self.session, url, self.timeout)
+
+
 async def scrape_committee_page(
     url: str,
     args: argparse.Namespace,
-    committee_type: str
+    committee_type: str,
+    fetcher: Optional[PoliteFetcher] = None
 ) -> List[CommitteeMember]:
-    """Scrape committee page and extract members."""
+    """Scrape committee page and extract members.
+
+    ``fetcher`` is required unless ``args.local`` is set.
+    """
     logger.info(f"Scraping {committee_type} from: {url}")
     
     # Get HTML content
@@ -158,9 +278,7 @@ async def scrape_committee_page(
         
         html_content = local_path.read_text(encoding='utf-8', errors='ignore')
     else:
-        async with aiohttp.ClientSession() as session:
-            async with session.get(url, timeout=aiohttp.ClientTimeout(total=30)) as response:
-                html_content = await response.text()
+        html_content = await fetcher.get(fetch_url(url))
     
     soup = BeautifulSoup(html_content, 'html.parser')
     
@@ -199,12 +317,7 @@ def parse_committee_members(soup: BeautifulSoup, committee_type: str) -> List[Co
         if elements:
             logger.info(f"Using specific selector: {selector} ({len(elements)} elements)")
             for element in elements:
-                text = element.get_text(' ', strip=True)
-                
-                if len(text) < 3 or len(text) > 300:
-                    continue
-                
-                member = parse_member_entry(text, committee_type)
+                member = extract_member_from_element(element, committee_type)
                 if member:
                     members.append(member)
             
@@ -218,12 +331,7 @@ def parse_committee_members(soup: BeautifulSoup, committee_type: str) -> List[Co
     
     for selector in generic_selectors:
         for element in soup.select(selector):
-            text = element.get_text(' ', strip=True)
-            
-            if len(text) < 3 or len(text) > 300:
-                continue
-            
-            member = parse_member_entry(text, committee_type)
+            member = extract_member_from_element(element, committee_type)
             if member:
                 members.append(member)
     
@@ -240,8 +348,14 @@ def parse_section_based(
     section_patterns: List[str],
     committee_type: str
 ) -> Optional[List[CommitteeMember]]:
-    """Parse using heading-based sections."""
+    """Parse using heading-based sections.
+
+    Collects every matching section, since pages often split one committee
+    under several headings ("Program Committee Co-Chairs", then "Program
+    Committee").
+    """
     headings = soup.find_all(['h1', 'h2', 'h3', 'h4', 'h5', 'h6'])
+    found = []
     
     for idx, heading in enumerate(headings):
         heading_text = heading.get_text().lower()
@@ -265,10 +379,22 @@ def parse_section_based(
             )
             
             if members:
-                logger.info(f"Found {len(members)} members using section-based parsing")
-                return members
+                logger.info(f"Found {len(members)} members under '{heading.get_text().strip()}'")
+                found.extend(members)
     
-    return None
+    return deduplicate_members(found) if found else None
+
+
+def heading_role(heading_text: str) -> Optional[Tuple[str, Optional[str]]]:
+    """Position implied by a heading such as "Program Committee Co-Chairs".
+
+    None for plain committee headings, and for mixed ones like "Chair and
+    Members" where the entries have to speak for themselves.
+    """
+    if 'member' in heading_text.lower():
+        return None
+    position, role_title = detect_position('', heading_text, '')
+    return None if position == 'member' else (position, role_title)
 
 
 def extract_members_between_headings(
@@ -277,8 +403,21 @@ def extract_members_between_headings(
     end_heading,
     committee_type: str
 ) -> List[CommitteeMember]:
-    """Extract members between two headings by traversing siblings."""
+    """Extract members between two headings by traversing siblings.
+
+    Entries without a role keyword of their own take the position implied by
+    the closest heading above them, so names listed under "Co-Chairs" become
+    co-chairs rather than members.
+    """
     members = []
+    section_role = heading_role(start_heading.get_text(' ', strip=True))
+    
+    def add(member: Optional[CommitteeMember]) -> None:
+        if member is None:
+            return
+        if section_role and member.position == 'member':
+            member.position, member.role_title = section_role
+        members.append(member)
     
     # Start from the heading and iterate through siblings
     current = start_heading
@@ -291,14 +430,19 @@ def extract_members_between_headings(
         if current == end_heading:
             break
         
-        # Skip text nodes
-        if not hasattr(current, 'name'):
+        # Skip text nodes (NavigableString has a `name` too, but it's None)
+        if getattr(current, 'name', None) is None:
             continue
         
         # Stop if we hit another h2 (major section boundary)
         if current.name == 'h2':
             break
         
+        # Sub-heading: "Chairs", "Members", ...
+        if current.name in ('h1', 'h3', 'h4', 'h5', 'h6'):
+            section_role = heading_role(current.get_text(' ', strip=True))
+            continue
+        
         # Process member containers
         # Check if current element contains a section.members (might be nested in p, div, etc.)
         member_section = None
@@ -338,21 +482,16 @@ def extract_members_between_headings(
                             # Detect position from role text
                             position, role_title = detect_position(name, role_text, role_text)
                             
-                            member = CommitteeMember(
+                            add(CommitteeMember(
                                 name=clean_name(name),
                                 committee=committee_type,
                                 position=position,
                                 role_title=role_title,
                                 affiliation=affiliation
-                            )
-                            members.append(member)
+                            ))
                     else:
-                        # Fallback to text extraction for non-structured cards
-                        text = li.get_text(' ', strip=True)
-                        if 3 <= len(text) <= 300:
-                            member = parse_member_entry(text, committee_type)
-                            if member:
-                                members.append(member)
+                        # Fallback for non-structured cards
+                        add(extract_member_from_element(li, committee_type))
         
         elif current.name == 'ul':
             # Check if this is a member list (not navigation, socials, etc.)
@@ -360,28 +499,122 @@ def extract_members_between_headings(
             if 'menu' not in ul_classes and 'social' not in ul_classes and 'socials' not in ul_classes:
                 # Plain list - extract members
                 for li in current.find_all('li', recursive=False):
-                    text = li.get_text(' ', strip=True)
-                    if 3 <= len(text) <= 300:
-                        member = parse_member_entry(text, committee_type)
-                        if member:
-                            members.append(member)
+                    add(extract_member_from_element(li, committee_type))
     
     return deduplicate_members(members)
 
 
+# Class names sites use for the affiliation part of a member entry
+AFFILIATION_SELECTOR = ', '.join(
+    f'.{cls}' for cls in ['affiliation', 'institution', 'organization', 'organisation', 'affil', 'org']
+)
+NAME_SELECTOR = '.name, .member-name, .person-name'
+
+
+def is_role_text(text: str) -> bool:
+    """Whether a line of an entry describes a role rather than an affiliation."""
+    lowered = text.lower()
+    return any(kw in lowered for kw in ['chair', 'member', 'organizer', 'organiser'])
+
+
+def split_on_br(element) -> List[str]:
+    """Text of an element as the lines its `<br>` tags separate."""
+    lines = ['']
+    for node in element.descendants:
+        if getattr(node, 'name', None) == 'br':
+            lines.append('')
+        elif getattr(node, 'name', None) is None and not isinstance(node, Comment):
+            lines[-1] += str(node)
+    return [' '.join(line.split()) for line in lines if line.strip()]
+
+
+def extract_member_from_element(element, committee_type: str) -> Optional[CommitteeMember]:
+    """Parse one list item or card.
+
+    When the markup keeps the affiliation apart from the name, in a child like
+    `<span class="affiliation">` or on a line after `<br>`, the parts are read
+    separately; flattening them would run the affiliation into the name.
+    Otherwise falls back to `parse_member_entry` on the element's text.
+    """
+    text = element.get_text(' ', strip=True)
+    if len(text) < 3 or len(text) > 300:
+        return None
+
+    affiliation_node = element.select_one(AFFILIATION_SELECTOR)
+    if affiliation_node is not None:
+        affiliation = affiliation_node.get_text(' ', strip=True)
+        name_node = element.select_one(NAME_SELECTOR)
+        if name_node is not None:
+            name_text = name_node.get_text(' ', strip=True)
+        else:
+            # Everything outside the affiliation node, e.g. "Alice Smith (chair)"
+            name_text = ' '.join(
+                s.strip() for s in element.find_all(string=True)
+                if s.strip() and not isinstance(s, Comment)
+                and not any(parent is affiliation_node for parent in s.parents)
+            )
+        return member_from_parts(name_text, affiliation or None, '', committee_type)
+
+    lines = split_on_br(element)
+    if len(lines) >= 2:
+        roles = [line for line in lines[1:] if is_role_text(line)]
+        affiliations = [line for line in lines[1:] if not is_role_text(line)]
+        return member_from_parts(
+            lines[0], ', '.join(affiliations) or None, ' '.join(roles), committee_type
+        )
+
+    return parse_member_entry(text, committee_type)
+
+
+def member_from_parts(
+    name_text: str,
+    affiliation: Optional[str],
+    role_text: str,
+    committee_type: str
+) -> Optional[CommitteeMember]:
+    """Member from separately located name, affiliation and role text.
+
+    The name part still goes through `parse_member_entry`, so the blacklist
+    applies and inline roles like "Alice Smith (chair)" are recognised.
+    """
+    member = parse_member_entry(name_text, committee_type)
+    if member is None:
+        return None
+    if affiliation:
+        member.affiliation = affiliation
+    if role_text:
+        member.position, member.role_title = detect_position(member.name, name_text, role_text)
+    return member
+
+
+# Blacklist - only filter if the whole text is mostly blacklisted content
+BLACKLIST_PRIMARY = [
+    'accepted papers', 'call for papers', 'code of conduct', 'charter',
+    'schedule', 'speakers', 'poster', 'pictures', 'sponsors', 'partners',
+    'proceedings', 'registration', 'venue', 'travel',
+    'accommodation', 'contact', 'about', 'home', 'news', 'archive',
+    'previous', 'next', 'program', 'tutorials', 'workshops',
+    'members only', 'login', 'logout', 'search',
+]
+
+# Per-site additions from --blacklist-file, treated like BLACKLIST_PRIMARY
+extra_blacklist: List[str] = []
+
+
+def load_blacklist(path: str) -> List[str]:
+    """Read blacklist terms, one per line; blank lines and `#` comments are skipped."""
+    terms = []
+    for line in Path(path).read_text(encoding='utf-8').splitlines():
+        term = line.split('#', 1)[0].strip().lower()
+        if term:
+            terms.append(term)
+    return terms
+
+
 def parse_member_entry(text: str, committee_type: str) -> Optional[CommitteeMember]:
     """Parse a single member entry."""
     text_lower = text.lower()
-    
-    # Blacklist - only filter if the whole text is mostly blacklisted content
-    blacklist_primary = [
-        'accepted papers', 'call for papers', 'code of conduct', 'charter',
-        'schedule', 'speakers', 'poster', 'pictures', 'sponsors', 'partners',
-        'proceedings', 'registration', 'venue', 'travel',
-        'accommodation', 'contact', 'about', 'home', 'news', 'archive',
-        'previous', 'next', 'program', 'tutorials', 'workshops',
-        'members only', 'login', 'logout', 'search',
-    ]
+    blacklist_primary = BLACKLIST_PRIMARY + extra_blacklist
     
     # Navigation items - filter more aggressively
     blacklist_nav = [
@@ -577,11 +810,6 @@ def detect_position(name: str, full_text: str, role_info: str) -> Tuple[str, Opt
         return 'member', None
 
 
-def clean_name(name: str) -> str:
-    """Clean and normalize name."""
-    return ' '.join(name.split())
-
-
 def deduplicate_members(members: List[CommitteeMember]) -> List[CommitteeMember]:
     """Remove duplicate members."""
     seen = {}
@@ -596,38 +824,76 @@ def deduplicate_members(members: List[CommitteeMember]) -> List[CommitteeMember]
     return result
 
 
+OUTPUT_FIELDS = ['venue', 'year', 'name', 'committee', 'position', 'role_title', 'affiliation']
+
+
+def write_members(
+    scraped: List[Tuple[ConferenceToScrape, CommitteeMember]],
+    fmt: str,
+    output_file: Optional[str]
+) -> None:
+    """Write scraped members as JSON or CSV to ``output_file`` or stdout.
+
+    Rows are sorted so scrapes of different snapshots diff cleanly.
+    """
+    rows = sorted(
+        (
+            {'venue': conf.venue, 'year': conf.year, **asdict(member)}
+            for conf, member in scraped
+        ),
+        key=lambda r: (r['venue'], r['year'], r['committee'], r['name'])
+    )
+
+    out = open(output_file, 'w', newline='', encoding='utf-8') if output_file else sys.stdout
+    try:
+        if fmt == 'json':
+            json.dump([{k: row[k] for k in OUTPUT_FIELDS} for row in rows], out, indent=2, ensure_ascii=False)
+            out.write('\n')
+        else:
+            writer = csv.DictWriter(out, fieldnames=OUTPUT_FIELDS, extrasaction='ignore')
+            writer.writeheader()
+            writer.writerows(rows)
+    finally:
+        if output_file:
+            out.close()
+
+    logger.info(f"Wrote {len(rows)} member(s) as {fmt} to {output_file or 'stdout'}")
+
+
 async def get_or_create_author(
     pool: asyncpg.Pool,
     name: str,
     affiliation: Optional[str]
-) -> UUID:
-    """Get or create author record."""
+) -> Optional[UUID]:
+    """Get or create author record, or None if the name isn't a plausible one.
+
+    A single upsert against the unique index on live ``normalized_name``s, so
+    concurrent scrapes (or the API) can't both create the same person. The
+    no-op ``DO UPDATE`` makes ``RETURNING`` yield the existing row's id.
+    Names are checked with ``is_valid_author_name`` first, like the importers.
+    """
+    if not is_valid_author_name(name):
+        logger.warning(f"Skipping committee member with invalid name: {name!r}")
+        return None
+
     normalized = normalize_name(name)
-    
-    # Try to find existing
-    existing = await pool.fetchval(
-        "SELECT id FROM authors WHERE normalized_name = $1",
-        normalized
-    )
-    
-    if existing:
-        logger.info(f"Found existing author: {name} ({existing})")
-        return existing
-    
-    # Create new
-    author_id = uuid4()
-    
     metadata = {'affiliation': affiliation} if affiliation else {}
     
-    await pool.execute(
+    row = await pool.fetchrow(
         """INSERT INTO authors (id, full_name, normalized_name, affiliation, metadata, created_at, updated_at, creator, modifier)
-           VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)""",
-        author_id, name, normalized, affiliation, json.dumps(metadata),
+           VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+           ON CONFLICT (normalized_name) WHERE deleted_at IS NULL
+           DO UPDATE SET normalized_name = EXCLUDED.normalized_name
+           RETURNING id, (xmax = 0) AS created""",
+        uuid4(), name, normalized, affiliation, json.dumps(metadata),
         datetime.utcnow(), datetime.utcnow(), 'scraper', 'scraper'
     )
     
-    logger.info(f"Created new author: {name} ({author_id})")
-    return author_id
+    if row['created']:
+        logger.info(f"Created new author: {name} ({row['id']})")
+    else:
+        logger.info(f"Found existing author: {name} ({row['id']})")
+    return row['id']
 
 
 async def insert_committee_role(
@@ -657,15 +923,73 @@ async def insert_committee_members(
     conference_id: UUID,
     members: List[CommitteeMember]
 ) -> None:
-    """Insert all committee members."""
+    """Insert all committee members, skipping those with invalid names."""
+    inserted = 0
     for member in members:
         author_id = await get_or_create_author(pool, member.name, member.affiliation)
+        if author_id is None:
+            continue
         await insert_committee_role(
             pool, conference_id, author_id,
             member.committee, member.position, member.role_title
         )
+        inserted += 1
     
-    logger.info(f"Inserted {len(members)} committee members")
+    logger.info(f"Inserted {inserted} of {len(members)} committee members")
+
+
+COMMITTEE_URLS = [
+    ('PC', 'archive_pc_url'),
+    ('OC', 'archive_organizers_url'),
+    ('SC', 'archive_steering_url'),
+]
+
+
+async def process_conference(
+    pool: asyncpg.Pool,
+    conf: ConferenceToScrape,
+    args: argparse.Namespace,
+    fetcher: Optional[PoliteFetcher]
+) -> List[Tuple[ConferenceToScrape, CommitteeMember]]:
+    """Fetch a conference's committee pages concurrently, then insert them."""
+    label = f"{conf.venue} {conf.year}"
+
+    if not args.force:
+        exists = await check_committee_exists(pool, conf.id)
+        if exists:
+            logger.info(f"Committee data already exists for {label}. Use --force to re-scrape.")
+            return []
+
+    pages = [
+        (committee, getattr(conf, attr))
+        for committee, attr in COMMITTEE_URLS
+        if getattr(conf, attr)
+    ]
+    results = await asyncio.gather(
+        *(scrape_committee_page(url, args, committee, fetcher) for committee, url in pages),
+        return_exceptions=True
+    )
+
+    scraped: List[Tuple[ConferenceToScrape, CommitteeMember]] = []
+    for (committee, _), members in zip(pages, results):
+        if isinstance(members, Exception):
+            logger.warning(f"Failed to scrape {committee} for {label}: {members}")
+            continue
+
+        logger.info(f"Found {len(members)} {committee} members for {label}")
+        scraped.extend((conf, member) for member in members)
+
+        if args.dry_run:
+            for member in members:
+                logger.info(f"  - {member.name} ({member.affiliation or '?'}) [{member.position}]")
+            continue
+
+        try:
+            await insert_committee_members(pool, conf.id, members)
+        except Exception as e:
+            logger.warning(f"Failed to insert {committee} for {label}: {e}")
+
+    return scraped
 
 
 async def main():
@@ -673,14 +997,34 @@ async def main():
     parser = argparse.ArgumentParser(
         description='Scrape committee membership data from archived conference websites'
     )
-    parser.add_argument('-v', '--venue', help='Conference venue (QIP, QCRYPT, TQC, or all)')
+    parser.add_argument('-v', '--venue', type=str.upper, choices=[*VENUES, 'ALL'],
+                        help=f"Conference venue ({', '.join(VENUES)}, or all; case-insensitive)")
     parser.add_argument('-y', '--year', type=int, help='Specific conference year')
     parser.add_argument('--dry-run', action='store_true', help="Don't commit to database")
     parser.add_argument('--force', action='store_true', help='Force re-scrape even if data exists')
     parser.add_argument('--local', action='store_true', help='Use local files from ~/Web/')
     parser.add_argument('--local-dir', type=str, help='Custom local web directory')
+    parser.add_argument('--timeout', type=float, default=30,
+                        help=f'Seconds per HTTP request before giving up on it (default: 30; '
+                             f'failed requests are tried {FETCH_ATTEMPTS} times)')
+    parser.add_argument('--concurrency', type=int, default=2,
+                        help=f'Committee pages fetched at once across conferences (default: 2); '
+                             f'requests to one host still start {HOST_INTERVAL:g}s apart')
+    parser.add_argument('--blacklist-file', type=str,
+                        help='Extra non-member terms to skip, one per line (added to the built-ins)')
+    parser.add_argument('--output', choices=['json', 'csv'],
+                        help='Also write the parsed members in this format (e.g. to diff snapshots)')
+    parser.add_argument('--output-file', type=str, help='File for --output (default: stdout)')
     
     args = parser.parse_args()
+    if args.output_file and not args.output:
+        parser.error('--output-file requires --output')
+    if args.concurrency < 1:
+        parser.error('--concurrency must be at least 1')
+    
+    if args.blacklist_file:
+        extra_blacklist.extend(load_blacklist(args.blacklist_file))
+        logger.info(f"Loaded {len(extra_blacklist)} extra blacklist term(s) from {args.blacklist_file}")
     
     # Load environment
     load_dotenv()
@@ -710,59 +1054,16 @@ async def main():
             return
         
         logger.info(f"Found {len(conferences)} conference(s) to scrape")
+        async with aiohttp.ClientSession() as session:
+            fetcher = None if args.local else PoliteFetcher(session, args.timeout, args.concurrency)
+            results = await asyncio.gather(*(
+                process_conference(pool, conf, args, fetcher)
+                for conf in conferences
+            ))
+        scraped = [row for rows in results for row in rows]
         
-        # Process each conference
-        for conf in conferences:
-            logger.info(f"\n=== Processing {conf.venue} {conf.year} ===")
-            
-            # Check if should skip
-            if not args.force:
-                exists = await check_committee_exists(pool, conf.id)
-                if exists:
-                    logger.info(f"Committee data already exists for {conf.venue} {conf.year}. Use --force to re-scrape.")
-                    continue
-            
-            # Scrape Program Committee
-            if conf.archive_pc_url:
-                try:
-                    members = await scrape_committee_page(conf.archive_pc_url, args, 'PC')
-                    logger.info(f"Found {len(members)} PC members")
-                    
-                    if args.dry_run:
-                        for member in members:
-                            logger.info(f"  - {member.name} ({member.affiliation or '?'}) [{member.position}]")
-                    else:
-                        await insert_committee_members(pool, conf.id, members)
-                except Exception as e:
-                    logger.warning(f"Failed to scrape PC: {e}")
-            
-            # Scrape Organizing Committee
-            if conf.archive_organizers_url:
-                try:
-                    members = await scrape_committee_page(conf.archive_organizers_url, args, 'OC')
-                    logger.info(f"Found {len(members)} OC members")
-                    
-                    if args.dry_run:
-                        for member in members:
-                            logger.info(f"  - {member.name} ({member.affiliation or '?'}) [{member.position}]")
-                    else:
-                        await insert_committee_members(pool, conf.id, members)
-                except Exception as e:
-                    logger.warning(f"Failed to scrape OC: {e}")
-            
-            # Scrape Steering Committee
-            if conf.archive_steering_url:
-                try:
-                    members = await scrape_committee_page(conf.archive_steering_url, args, 'SC')
-                    logger.info(f"Found {len(members)} SC members")
-                    
-                    if args.dry_run:
-                        for member in members:
-                            logger.info(f"  - {member.name} ({member.affiliation or '?'}) [{member.position}]")
-                    else:
-                        await insert_committee_members(pool, conf.id, members)
-                except Exception as e:
-                    logger.warning(f"Failed to scrape SC: {e}")
+        if args.output:
+            write_members(scraped, args.output, args.output_file)
         
         logger.info("\nScraping complete!")
     
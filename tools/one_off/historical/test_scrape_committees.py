@@ -0,0 +1,167 @@
+"""Parser tests for scrape_committees.py against saved HTML fixtures.
+
+Run from this directory (needs the script's own dependencies installed):
+    python3 -m unittest test_scrape_committees
+"""
+
+import asyncio
+import unittest
+from pathlib import Path
+
+import aiohttp
+from bs4 import BeautifulSoup
+
+from scrape_committees import (
+    CommitteeMember,
+    PoliteFetcher,
+    fetch_html,
+    insert_committee_members,
+    parse_committee_members,
+)
+
+FIXTURES = Path(__file__).parent / 'fixtures'
+
+
+def load_fixture(name: str) -> BeautifulSoup:
+    return BeautifulSoup((FIXTURES / name).read_text(encoding='utf-8'), 'html.parser')
+
+
+class NestedAffiliationTests(unittest.TestCase):
+    def test_affiliation_in_child_element_or_after_br(self):
+        soup = load_fixture('committee_nested_affiliations.html')
+        members = parse_committee_members(soup, 'PC')
+
+        self.assertEqual(
+            [(m.name, m.affiliation, m.position) for m in members],
+            [
+                ('Alice Smith', 'University of Waterloo', 'member'),
+                ('Bob Jones', 'ETH Zurich', 'member'),
+                ('Carol White', 'Massachusetts Institute of Technology', 'chair'),
+                ('Dan Brown', 'CNRS, Paris', 'member'),
+                ('Eve Black', 'Caltech', 'member'),
+            ],
+        )
+
+
+
+class SectionHeadingTests(unittest.TestCase):
+    def test_chair_headings_set_default_position(self):
+        soup = load_fixture('committee_chair_headings.html')
+
+        pc = parse_committee_members(soup, 'PC')
+        self.assertEqual(
+            [(m.name, m.position) for m in pc],
+            [('Alice Smith', 'co_chair'), ('Bob Jones', 'co_chair'), ('Carol White', 'member')],
+        )
+
+        oc = parse_committee_members(soup, 'OC')
+        self.assertEqual(
+            [(m.name, m.position, m.role_title) for m in oc],
+            [('Dan Brown', 'chair', 'General Chair'), ('Eve Black', 'member', None)],
+        )
+
+
+class FakeResponse:
+    def __init__(self, status: int, body: str = ''):
+        self.status = status
+        self.body = body
+
+    async def __aenter__(self):
+        return self
+
+    async def __aexit__(self, *exc):
+        return False
+
+    def raise_for_status(self):
+        if self.status >= 400:
+            raise aiohttp.ClientResponseError(None, (), status=self.status)
+
+    async def text(self):
+        return self.body
+
+
+class FakeSession:
+    """Plays back one outcome (a status code or an exception) per GET."""
+
+    def __init__(self, *outcomes):
+        self.outcomes = list(outcomes)
+        self.calls = 0
+        self.started = []
+
+    def get(self, url, timeout=None):
+        self.calls += 1
+        self.started.append(asyncio.get_running_loop().time())
+        outcome = self.outcomes.pop(0)
+        if isinstance(outcome, Exception):
+            raise outcome
+        return FakeResponse(outcome, '<html>ok</html>')
+
+
+class FetchRetryTests(unittest.TestCase):
+    def fetch(self, session):
+        return asyncio.run(fetch_html(session, 'http://example.org', timeout=1, backoff=0))
+
+    def test_transient_failures_are_retried(self):
+        session = FakeSession(503, asyncio.TimeoutError(), 200)
+        self.assertEqual(self.fetch(session), '<html>ok</html>')
+        self.assertEqual(session.calls, 3)
+
+    def test_gives_up_after_three_attempts(self):
+        session = FakeSession(502, 502, 502, 200)
+        with self.assertRaises(aiohttp.ClientResponseError):
+            self.fetch(session)
+        self.assertEqual(session.calls, 3)
+
+    def test_client_errors_are_not_retried(self):
+        session = FakeSession(404, 200)
+        with self.assertRaises(aiohttp.ClientResponseError):
+            self.fetch(session)
+        self.assertEqual(session.calls, 1)
+
+
+class PoliteFetcherTests(unittest.TestCase):
+    def test_same_host_requests_are_spaced_out(self):
+        session = FakeSession(200, 200, 200)
+
+        async def run():
+            fetcher = PoliteFetcher(session, timeout=1, concurrency=3, host_interval=0.05)
+            await asyncio.gather(*(fetcher.get(f'http://example.org/{i}') for i in range(3)))
+
+        asyncio.run(run())
+        gaps = [b - a for a, b in zip(session.started, session.started[1:])]
+        self.assertEqual(len(gaps), 2)
+        self.assertTrue(all(gap >= 0.045 for gap in gaps), gaps)
+
+
+
+class RecordingPool:
+    """Stands in for asyncpg.Pool: records statements, upserts return a new author."""
+
+    def __init__(self):
+        self.fetched = []
+        self.executed = []
+
+    async def fetchrow(self, query, *args):
+        self.fetched.append(args)
+        return {'id': args[0], 'created': True}
+
+    async def execute(self, query, *args):
+        self.executed.append(args)
+
+
+class InsertMembersTests(unittest.TestCase):
+    def test_invalid_names_are_skipped(self):
+        pool = RecordingPool()
+        members = [
+            CommitteeMember(name='Ada Lovelace', committee='PC', position='member'),
+            CommitteeMember(name='', committee='PC', position='member'),
+            CommitteeMember(name='- , -', committee='PC', position='member'),
+        ]
+        asyncio.run(insert_committee_members(pool, conference_id=None, members=members))
+
+        self.assertEqual([args[1] for args in pool.fetched], ['Ada Lovelace'])
+        self.assertEqual(len(pool.executed), 1)
+
+
+if __name__ == '__main__':
+    unittest.main()
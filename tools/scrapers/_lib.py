@@ -3,6 +3,7 @@ import logging
 import os
 import re
 import unicodedata
+from datetime import datetime, timezone
 from pathlib import Path
 from typing import List, Optional
 from urllib.parse import unquote
@@ -13,6 +14,52 @@ from dotenv import load_dotenv
 logger = logging.getLogger(__name__)
 
 
+# Known conference venues. Mirror of src/utils/conference.rs::VENUES and the
+# conferences_venue_check constraint.
+VENUES = ('QCRYPT', 'QIP', 'TQC')
+
+# Officially assigned ISO 3166-1 alpha-2 codes. Mirror of
+# src/utils/country.rs::ALPHA2_CODES, so imports accept what the API accepts.
+COUNTRY_CODES = frozenset((
+    'AD', 'AE', 'AF', 'AG', 'AI', 'AL', 'AM', 'AO', 'AQ', 'AR', 'AS', 'AT', 'AU', 'AW',
+    'AX', 'AZ', 'BA', 'BB', 'BD', 'BE', 'BF', 'BG', 'BH', 'BI', 'BJ', 'BL', 'BM', 'BN',
+    'BO', 'BQ', 'BR', 'BS', 'BT', 'BV', 'BW', 'BY', 'BZ', 'CA', 'CC', 'CD', 'CF', 'CG',
+    'CH', 'CI', 'CK', 'CL', 'CM', 'CN', 'CO', 'CR', 'CU', 'CV', 'CW', 'CX', 'CY', 'CZ',
+    'DE', 'DJ', 'DK', 'DM', 'DO', 'DZ', 'EC', 'EE', 'EG', 'EH', 'ER', 'ES', 'ET', 'FI',
+    'FJ', 'FK', 'FM', 'FO', 'FR', 'GA', 'GB', 'GD', 'GE', 'GF', 'GG', 'GH', 'GI', 'GL',
+    'GM', 'GN', 'GP', 'GQ', 'GR', 'GS', 'GT', 'GU', 'GW', 'GY', 'HK', 'HM', 'HN', 'HR',
+    'HT', 'HU', 'ID', 'IE', 'IL', 'IM', 'IN', 'IO', 'IQ', 'IR', 'IS', 'IT', 'JE', 'JM',
+    'JO', 'JP', 'KE', 'KG', 'KH', 'KI', 'KM', 'KN', 'KP', 'KR', 'KW', 'KY', 'KZ', 'LA',
+    'LB', 'LC', 'LI', 'LK', 'LR', 'LS', 'LT', 'LU', 'LV', 'LY', 'MA', 'MC', 'MD', 'ME',
+    'MF', 'MG', 'MH', 'MK', 'ML', 'MM', 'MN', 'MO', 'MP', 'MQ', 'MR', 'MS', 'MT', 'MU',
+    'MV', 'MW', 'MX', 'MY', 'MZ', 'NA', 'NC', 'NE', 'NF', 'NG', 'NI', 'NL', 'NO', 'NP',
+    'NR', 'NU', 'NZ', 'OM', 'PA', 'PE', 'PF', 'PG', 'PH', 'PK', 'PL', 'PM', 'PN', 'PR',
+    'PS', 'PT', 'PW', 'PY', 'QA', 'RE', 'RO', 'RS', 'RU', 'RW', 'SA', 'SB', 'SC', 'SD',
+    'SE', 'SG', 'SH', 'SI', 'SJ', 'SK', 'SL', 'SM', 'SN', 'SO', 'SR', 'SS', 'ST', 'SV',
+    'SX', 'SY', 'SZ', 'TC', 'TD', 'TF', 'TG', 'TH', 'TJ', 'TK', 'TL', 'TM', 'TN', 'TO',
+    'TR', 'TT', 'TV', 'TW', 'TZ', 'UA', 'UG', 'UM', 'US', 'UY', 'UZ', 'VA', 'VC', 'VE',
+    'VG', 'VI', 'VN', 'VU', 'WF', 'WS', 'YE', 'YT', 'ZA', 'ZM', 'ZW',
+))
+
+# Accepted conference years: mirror of MIN_CONFERENCE_YEAR and
+# max_conference_year() in src/utils/conference.rs.
+MIN_CONFERENCE_YEAR = 1990
+CONFERENCE_YEARS_AHEAD = 5
+
+
+def normalize_country_code(code: str) -> Optional[str]:
+    """Canonical alpha-2 code for ``code`` (dots and whitespace dropped,
+    uppercased, so ``u.s.`` becomes ``US``), or None unless it is assigned.
+    Mirror of src/utils/country.rs::normalize_country_code."""
+    code = code.strip().replace('.', '').upper()
+    return code if code in COUNTRY_CODES else None
+
+
+def max_conference_year() -> int:
+    """Latest conference year the API accepts: five years past the current one."""
+    return datetime.now(timezone.utc).year + CONFERENCE_YEARS_AHEAD
+
+
 # Special characters that don't decompose via Unicode NFD — mapped explicitly.
 # Mirror of src/utils/normalize.rs::replace_special_chars so Python and Rust
 # normalizers agree.
@@ -54,6 +101,25 @@ def normalize_name(name: str) -> str:
     return ' '.join(tokens)
 
 
+def clean_name(value: str) -> str:
+    """Trim and collapse runs of whitespace, keeping case and accents.
+
+    Mirror of src/utils/normalize.rs::collapse_whitespace, which the API applies
+    to names, titles, session names and affiliations on create/update.
+    """
+    return ' '.join(value.split())
+
+
+def is_valid_author_name(name: Optional[str]) -> bool:
+    """Whether ``name`` can be stored as an author: it needs at least one letter.
+
+    Blank and punctuation-only names are scrape artefacts; they normalize to ''
+    and would match every author. Mirror of
+    src/utils/validation.rs::validate_author_name, which makes the API reject them.
+    """
+    return bool(name) and any(c.isalpha() for c in name)
+
+
 def split_name(full_name: str) -> tuple[str, str]:
     """Split a (normalized) full name into (family_name, given_name)."""
     normalized = normalize_name(full_name)
@@ -63,16 +129,27 @@ def split_name(full_name: str) -> tuple[str, str]:
     return parts[1], parts[0]
 
 
+# http://web.archive.org/web/20180101000000/http://qip2018.org/committees.html
+_WAYBACK_URL = re.compile(
+    r'^(?:https?://)?web\.archive\.org/web/\d{1,14}(?:[a-z]{2}_)?/+(.+)$',
+    re.IGNORECASE,
+)
+
+
 def url_to_local_path(url: str, local_dir: Optional[Path] = None) -> Path:
     """Map an http(s) URL to its mirror under ``local_dir``.
 
     Default ``local_dir`` is ``~/Web``. URLs ending in '/' or with no file
-    extension get an ``index.html`` appended.
+    extension get an ``index.html`` appended. Wayback Machine snapshot URLs
+    map to the mirror of the archived site, not ``web.archive.org``.
     """
     if local_dir is None:
         local_dir = Path.home() / 'Web'
 
     url = unquote(url)
+    wayback = _WAYBACK_URL.match(url.strip())
+    if wayback:
+        url = re.sub(r'^(https?):/+', r'\1://', wayback.group(1))
     without_protocol = url.removeprefix('http://').removeprefix('https://')
     parts = without_protocol.split('/', 1)
     domain = parts[0]
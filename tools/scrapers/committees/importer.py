@@ -11,7 +11,7 @@ from uuid import UUID
 import asyncpg
 from dotenv import load_dotenv
 
-from scrapers._lib import normalize_name, split_name
+from scrapers._lib import clean_name, is_valid_author_name, normalize_name, split_name
 
 
 logging.basicConfig(
@@ -52,45 +52,47 @@ async def get_or_create_author(
     full_name: str,
     affiliation: Optional[str]
 ) -> UUID:
-    """Get existing author or create new one."""
+    """Get existing author or create new one.
+
+    A name recorded as a variant resolves to its author. Otherwise a single
+    upsert against the unique index on live ``normalized_name``s finds or
+    creates the author, so concurrent imports (or the API) can't both create
+    the same person; a given affiliation replaces the stored one.
+    """
     family_name, given_name = split_name(full_name)
     normalized_full = normalize_name(full_name).lower()
     
-    # Try to find existing author by normalized_name
     author_id = await conn.fetchval(
         """
-        SELECT a.id FROM authors a
-        LEFT JOIN author_name_variants v ON a.id = v.author_id
-        WHERE a.normalized_name = $1
-           OR LOWER(v.variant_name) = $1
+        SELECT v.author_id FROM author_name_variants v
+        JOIN authors a ON a.id = v.author_id AND a.deleted_at IS NULL
+        WHERE LOWER(v.variant_name) = $1
         LIMIT 1
         """,
         normalized_full
     )
     
     if author_id:
-        logger.debug(f"Found existing author: {full_name} -> {author_id}")
-        
-        # Update affiliation if provided and different
+        logger.debug(f"Found existing author by variant: {full_name} -> {author_id}")
         if affiliation:
             await conn.execute(
                 """
-                UPDATE authors 
-                SET affiliation = $1 
+                UPDATE authors
+                SET affiliation = $1
                 WHERE id = $2 AND (affiliation IS NULL OR affiliation != $1)
                 """,
                 affiliation,
                 author_id
             )
-        
         return author_id
     
-    # Create new author
-    author_id = await conn.fetchval(
+    row = await conn.fetchrow(
         """
         INSERT INTO authors (full_name, family_name, given_name, normalized_name, affiliation, creator, modifier)
         VALUES ($1, $2, $3, $4, $5, 'import_from_csv', 'import_from_csv')
-        RETURNING id
+        ON CONFLICT (normalized_name) WHERE deleted_at IS NULL
+        DO UPDATE SET affiliation = COALESCE(EXCLUDED.affiliation, authors.affiliation)
+        RETURNING id, (xmax = 0) AS created
         """,
         full_name,
         family_name,
@@ -99,8 +101,11 @@ async def get_or_create_author(
         affiliation
     )
     
-    logger.info(f"Created new author: {full_name} ({author_id})")
-    return author_id
+    if row['created']:
+        logger.info(f"Created new author: {full_name} ({row['id']})")
+    else:
+        logger.debug(f"Found existing author: {full_name} -> {row['id']}")
+    return row['id']
 
 
 async def get_conference_id(
@@ -124,6 +129,10 @@ async def import_member(
 ) -> bool:
     """Import a single committee member."""
     
+    if not is_valid_author_name(member.get('full_name')):
+        logger.warning(f"Skipping committee member with invalid name: {member.get('full_name')!r}")
+        return False
+
     # Get conference
     conference_id = await get_conference_id(conn, venue, year)
     if not conference_id:
@@ -131,10 +140,11 @@ async def import_member(
         return False
     
     # Get or create author
+    affiliation = clean_name(member['affiliation']) if member.get('affiliation') else None
     author_id = await get_or_create_author(
         conn,
-        member['full_name'],
-        member.get('affiliation')
+        clean_name(member['full_name']),
+        affiliation
     )
     
     # Map values to database enums
@@ -161,7 +171,7 @@ async def import_member(
             WHERE id = $4
             """,
             db_position,
-            member.get('affiliation'),
+            affiliation,
             member.get('role_title'),
             existing
         )
@@ -177,7 +187,7 @@ async def import_member(
             author_id,
             db_committee,
             db_position,
-            member.get('affiliation'),
+            affiliation,
             member.get('role_title')
         )
         logger.info(f"Imported: {member['full_name']} - {member['committee_type']} ({member.get('position') or 'member'})")
@@ -13,7 +13,7 @@ from datetime import datetime
 import asyncpg
 from dotenv import load_dotenv
 
-from scrapers._lib import normalize_name, split_name
+from scrapers._lib import clean_name, is_valid_author_name, normalize_name, split_name
 
 
 logging.basicConfig(
@@ -27,7 +27,7 @@ def parse_semicolon_list(value: str) -> Optional[List[str]]:
     """Parse semicolon-separated string into list."""
     if not value or not value.strip():
         return None
-    return [item.strip() for item in value.split(';') if item.strip()]
+    return [clean_name(item) for item in value.split(';') if item.strip()]
 
 
 def generate_canonical_key(venue: str, year: int, paper_type: str, index: int) -> str:
@@ -44,26 +44,28 @@ async def get_or_create_author(
     full_name: str,
     affiliation: Optional[str]
 ) -> UUID:
-    """Get existing author or create new one."""
+    """Get existing author or create new one.
+
+    A name recorded as a variant resolves to its author. Otherwise a single
+    upsert against the unique index on live ``normalized_name``s finds or
+    creates the author, so concurrent imports (or the API) can't both create
+    the same person; a given affiliation replaces the stored one.
+    """
     family_name, given_name = split_name(full_name)
     normalized_full = normalize_name(full_name).lower()
 
-    # Try to find existing author by normalized_name
     author_id = await conn.fetchval(
         """
-        SELECT a.id FROM authors a
-        LEFT JOIN author_name_variants v ON a.id = v.author_id
-        WHERE a.normalized_name = $1
-           OR LOWER(v.variant_name) = $1
+        SELECT v.author_id FROM author_name_variants v
+        JOIN authors a ON a.id = v.author_id AND a.deleted_at IS NULL
+        WHERE LOWER(v.variant_name) = $1
         LIMIT 1
         """,
         normalized_full
     )
 
     if author_id:
-        logger.debug(f"Found existing author: {full_name} -> {author_id}")
-
-        # Update affiliation if provided and different
+        logger.debug(f"Found existing author by variant: {full_name} -> {author_id}")
         if affiliation:
             await conn.execute(
                 """
@@ -74,15 +76,15 @@ async def get_or_create_author(
                 affiliation,
                 author_id
             )
-
         return author_id
 
-    # Create new author
-    author_id = await conn.fetchval(
+    row = await conn.fetchrow(
         """
         INSERT INTO authors (full_name, family_name, given_name, normalized_name, affiliation, creator, modifier)
         VALUES ($1, $2, $3, $4, $5, 'import_from_csv', 'import_from_csv')
-        RETURNING id
+        ON CONFLICT (normalized_name) WHERE deleted_at IS NULL
+        DO UPDATE SET affiliation = COALESCE(EXCLUDED.affiliation, authors.affiliation)
+        RETURNING id, (xmax = 0) AS created
         """,
         full_name,
         family_name,
@@ -91,8 +93,11 @@ async def get_or_create_author(
         affiliation
     )
 
-    logger.info(f"Created new author: {full_name} ({author_id})")
-    return author_id
+    if row['created']:
+        logger.info(f"Created new author: {full_name} ({row['id']})")
+    else:
+        logger.debug(f"Found existing author: {full_name} -> {row['id']}")
+    return row['id']
 
 
 async def get_conference_id(
@@ -125,6 +130,11 @@ async def import_talk(
         logger.error(f"Conference not found: {venue} {year}")
         return False
 
+    # Match the server-side cleanup of free-text fields
+    for field in ('title', 'session_name'):
+        if talk.get(field):
+            talk[field] = clean_name(talk[field])
+
     # Parse list fields
     speakers = parse_semicolon_list(talk.get('speakers', ''))
     authors = parse_semicolon_list(talk.get('authors', ''))
@@ -139,6 +149,12 @@ async def import_talk(
         logger.warning(f"No authors for talk: {talk.get('title', 'unknown')}")
         return False
 
+    # Affiliations are matched by position, so a junk name can't just be dropped
+    invalid = [name for name in authors if not is_valid_author_name(name)]
+    if invalid:
+        logger.warning(f"Skipping talk with invalid author names {invalid!r}: {talk.get('title', 'unknown')}")
+        return False
+
     # Check if publication already exists
     existing = await conn.fetchval(
         "SELECT id FROM publications WHERE canonical_key = $1",